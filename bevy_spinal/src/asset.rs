@@ -1,28 +1,36 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, PoisonError, Weak},
+};
 
 use bevy::{
     asset::{
-        Asset, AssetLoader, AssetPath, Handle, LoadContext, ParseAssetPathError,
-        ReadAssetBytesError, RenderAssetUsages, UntypedAssetId, VisitAssetDependencies, io::Reader,
+        Asset, AssetLoader, AssetPath, AsyncWriteExt, Handle, LoadContext, ParseAssetPathError,
+        ReadAssetBytesError, RenderAssetUsages, UntypedAssetId, VisitAssetDependencies,
+        io::{Reader, Writer},
+        processor::{Process, ProcessContext, ProcessError},
     },
     image::{
-        CompressedImageFormats, Image, ImageAddressMode, ImageFilterMode, ImageSampler,
-        ImageSamplerDescriptor, ImageType, TextureError,
+        CompressedImageFormats, Image, ImageAddressMode, ImageFilterMode, ImageLoaderSettings,
+        ImageSampler, ImageSamplerDescriptor, ImageType, TextureError,
     },
     reflect::TypePath,
 };
 use serde::{Deserialize, Serialize};
 use spinal::{
-    AtlasPageId, AtlasPageRef, Diagnostic, IdError, PixelSize, SkeletonAsset, TextureFilter,
-    WrapMode,
+    AtlasPageId, AtlasPageRef, Diagnostic, IdError, PixelSize, SkeletonAsset, TextAtlas,
+    TextureFilter, WrapMode,
 };
 use thiserror::Error;
 
 /// A linked Spinal skeleton and its Bevy-managed atlas page images.
 ///
 /// The value is replaced as one Bevy asset when its skeleton JSON or text
-/// atlas reloads successfully. Each page image uses a stable labeled handle,
-/// so successful reloads do not invalidate renderer references.
+/// atlas reloads successfully. Each primary atlas page image uses a stable
+/// labeled handle, so successful reloads do not invalidate renderer
+/// references. Pages of further atlases are loaded by path and shared by every
+/// skeleton that links the same atlas.
 #[derive(Debug, TypePath)]
 pub struct SpinalAsset {
     skeleton: Arc<SkeletonAsset>,
@@ -180,6 +188,18 @@ pub struct SpinalAssetLoaderSettings {
     /// When omitted, `cat.spine.json` and `cat.json` both infer
     /// `cat.atlas`.
     pub atlas_path: Option<String>,
+
+    /// Further text-atlas paths embedded relative to the skeleton JSON,
+    /// linked after the primary atlas in order.
+    ///
+    /// An attachment draws from the first linked atlas with its region name,
+    /// so a shared cosmetics atlas listed here never overrides the
+    /// skeleton's own regions. Page images load beside the atlas that
+    /// declares them, by path, so every skeleton linking the same atlas shares
+    /// one image handle per page. Their declared sizes are not checked against
+    /// the decoded images.
+    #[serde(default)]
+    pub atlas_paths: Vec<String>,
 }
 
 /// Bevy loader for a Spine 4.3 JSON export, text atlas, and atlas images.
@@ -187,8 +207,13 @@ pub struct SpinalAssetLoaderSettings {
 /// The preferred filename is `name.spine.json`. A typed
 /// `Handle<SpinalAsset>` may also load an unrenamed `name.json`; the loader
 /// deliberately does not claim every `.json` file by extension.
-#[derive(Clone, Copy, Debug, Default, TypePath)]
-pub struct SpinalAssetLoader;
+///
+/// Parsed text atlases are cached by path and content, so skeletons linking
+/// the same atlas share one [`TextAtlas`] while any of them is alive.
+#[derive(Clone, Debug, Default, TypePath)]
+pub struct SpinalAssetLoader {
+    atlases: TextAtlasCache,
+}
 
 impl AssetLoader for SpinalAssetLoader {
     type Asset = SpinalAsset;
@@ -201,69 +226,22 @@ impl AssetLoader for SpinalAssetLoader {
         settings: &Self::Settings,
         load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
-        let skeleton_path = load_context.path().clone_owned();
-        let atlas_reference = match settings.atlas_path.as_deref() {
-            Some(path) => path.to_owned(),
-            None => infer_atlas_reference(&skeleton_path)?,
-        };
-        let atlas_path = resolve_dependency(&skeleton_path, &atlas_reference, "atlas")?;
-
+        let atlas_paths = atlas_paths(load_context.path(), settings)?;
         let mut skeleton_json = Vec::new();
         reader.read_to_end(&mut skeleton_json).await?;
-        let atlas_text = load_context
-            .read_asset_bytes(atlas_path.clone())
-            .await
-            .map_err(|source| SpinalAssetLoaderError::AtlasRead {
-                path: atlas_path.clone(),
-                source: Box::new(source),
-            })?;
-        let skeleton = spinal::load_json(&skeleton_json, &atlas_text)?.into_asset();
-
-        let page_specs = skeleton
-            .atlas_pages()
-            .map(PageLoadSpec::from)
-            .collect::<Vec<_>>();
-        let mut pages = Vec::with_capacity(page_specs.len());
-
-        for spec in page_specs {
-            let page_path = resolve_dependency(&atlas_path, &spec.name, "atlas page")?;
-            let sampler = page_sampler(spec.min_filter, spec.mag_filter, spec.wrap);
-            let image_bytes = load_context
-                .read_asset_bytes(page_path.clone())
+        let mut atlases = Vec::with_capacity(atlas_paths.len());
+        for atlas_path in &atlas_paths {
+            let atlas_text = load_context
+                .read_asset_bytes(atlas_path.clone())
                 .await
-                .map_err(|source| SpinalAssetLoaderError::PageImageRead {
-                    page: spec.name.clone(),
-                    path: page_path.clone(),
+                .map_err(|source| SpinalAssetLoaderError::AtlasRead {
+                    path: atlas_path.clone(),
                     source: Box::new(source),
                 })?;
-            let extension = page_path
-                .path()
-                .extension()
-                .and_then(|extension| extension.to_str())
-                .ok_or_else(|| SpinalAssetLoaderError::MissingPageImageExtension {
-                    page: spec.name.clone(),
-                    path: page_path.clone(),
-                })?;
-            let image_asset = Image::from_buffer(
-                &image_bytes,
-                ImageType::Extension(extension),
-                CompressedImageFormats::NONE,
-                true,
-                sampler,
-                RenderAssetUsages::default(),
-            )
-            .map_err(|source| SpinalAssetLoaderError::PageImageDecode {
-                page: spec.name.clone(),
-                path: page_path.clone(),
-                source: Box::new(source),
-            })?;
-            validate_page_image_size(&spec.name, &page_path, spec.declared_size, &image_asset)?;
-            let image =
-                load_context.add_labeled_asset(format!("page-{}", spec.ordinal), image_asset);
-            pages.push(SpinalAtlasPage::from_loaded(spec.name, page_path, image));
+            atlases.push(self.atlases.get_or_parse(atlas_path, atlas_text)?);
         }
-
-        SpinalAsset::new(skeleton, pages)
+        let skeleton = spinal::load_json_with_atlases(&skeleton_json, &atlases)?.into_asset();
+        load_pages(skeleton, &atlas_paths, load_context).await
     }
 
     fn extensions(&self) -> &[&str] {
@@ -271,6 +249,72 @@ impl AssetLoader for SpinalAssetLoader {
     }
 }
 
+/// Bevy loader for skeleton caches written by [`SpinalCacheProcessor`].
+///
+/// The cache replaces the skeleton JSON and text atlases; page images are
+/// still loaded from beside the atlas paths the settings name or infer,
+/// exactly as [`SpinalAssetLoader`] resolves them.
+#[derive(Clone, Copy, Debug, Default, TypePath)]
+pub struct SpinalCacheLoader;
+
+impl AssetLoader for SpinalCacheLoader {
+    type Asset = SpinalAsset;
+    type Settings = SpinalAssetLoaderSettings;
+    type Error = SpinalAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let atlas_paths = atlas_paths(load_context.path(), settings)?;
+        let mut cache = Vec::new();
+        reader.read_to_end(&mut cache).await?;
+        let skeleton = spinal::read_cache(&cache)?.into_asset();
+        if skeleton.atlases().len() != atlas_paths.len() {
+            return Err(SpinalAssetLoaderError::CachedAtlasCountMismatch {
+                configured: atlas_paths.len(),
+                cached: skeleton.atlases().len(),
+            });
+        }
+        load_pages(skeleton, &atlas_paths, load_context).await
+    }
+}
+
+/// Bevy asset processor that links `.spine.json` exports into skeleton
+/// caches.
+///
+/// [`crate::SpinalPlugin`] makes it the default processor for `spine.json`,
+/// so processed builds load each skeleton with [`SpinalCacheLoader`] and skip
+/// parsing, linking and validation at startup and on hot reload.
+#[derive(Clone, Copy, Debug, Default, TypePath)]
+pub struct SpinalCacheProcessor;
+
+impl Process for SpinalCacheProcessor {
+    type Settings = SpinalAssetLoaderSettings;
+    type OutputLoader = SpinalCacheLoader;
+
+    async fn process(
+        &self,
+        context: &mut ProcessContext<'_>,
+        settings: &Self::Settings,
+        writer: &mut Writer,
+    ) -> Result<SpinalAssetLoaderSettings, ProcessError> {
+        let loaded = context
+            .load_source_asset::<SpinalAssetLoader>(settings)
+            .await?;
+        let asset = loaded
+            .get::<SpinalAsset>()
+            .ok_or(ProcessError::WrongMetaType)?;
+        writer
+            .write_all(&spinal::write_cache(asset.skeleton()))
+            .await
+            .map_err(|error| ProcessError::AssetSaveError(Box::new(error)))?;
+        Ok(settings.clone())
+    }
+}
+
 /// A failure while constructing or loading a [`SpinalAsset`].
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -332,6 +376,22 @@ pub enum SpinalAssetLoaderError {
     #[error("could not parse and link Spinal export: {0}")]
     Core(#[from] spinal::LoadError),
 
+    /// A processed skeleton cache was stale or damaged.
+    #[error("could not read Spinal skeleton cache: {0}")]
+    Cache(#[from] spinal::CacheError),
+
+    /// A skeleton cache was linked against a different number of atlases
+    /// than the loader settings name.
+    #[error(
+        "Spinal skeleton cache links {cached} text atlases but the loader settings name {configured}"
+    )]
+    CachedAtlasCountMismatch {
+        /// Atlas paths named or inferred by the loader settings.
+        configured: usize,
+        /// Atlases linked into the cached skeleton.
+        cached: usize,
+    },
+
     /// Atlas page image bytes could not be read.
     #[error("could not read Spinal atlas page `{page}` from `{path}`: {source}")]
     PageImageRead {
@@ -406,9 +466,59 @@ pub enum SpinalAssetLoaderError {
     },
 }
 
+/// Parsed text atlases shared between loads of the same atlas file.
+///
+/// Entries hold the source bytes so a hot-reloaded atlas is parsed again, and
+/// only a weak reference so an atlas is freed with its last skeleton.
+#[derive(Clone, Default)]
+struct TextAtlasCache(Arc<Mutex<HashMap<AssetPath<'static>, CachedTextAtlas>>>);
+
+struct CachedTextAtlas {
+    source: Vec<u8>,
+    atlas: Weak<TextAtlas>,
+}
+
+impl TextAtlasCache {
+    fn get_or_parse(
+        &self,
+        path: &AssetPath<'static>,
+        source: Vec<u8>,
+    ) -> Result<Arc<TextAtlas>, spinal::LoadError> {
+        // Parsing under the lock keeps concurrent loads of one atlas from
+        // each producing their own copy.
+        let mut entries = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(atlas) = entries
+            .get(path)
+            .filter(|cached| cached.source == source)
+            .and_then(|cached| cached.atlas.upgrade())
+        {
+            return Ok(atlas);
+        }
+        let atlas = Arc::new(TextAtlas::parse(&source)?);
+        entries.retain(|_, cached| cached.atlas.strong_count() > 0);
+        entries.insert(
+            path.clone(),
+            CachedTextAtlas {
+                source,
+                atlas: Arc::downgrade(&atlas),
+            },
+        );
+        Ok(atlas)
+    }
+}
+
+impl fmt::Debug for TextAtlasCache {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("TextAtlasCache")
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Clone, Debug)]
 struct PageLoadSpec {
     ordinal: usize,
+    atlas: usize,
     name: Box<str>,
     declared_size: PixelSize,
     min_filter: TextureFilter,
@@ -420,6 +530,7 @@ impl From<AtlasPageRef<'_>> for PageLoadSpec {
     fn from(page: AtlasPageRef<'_>) -> Self {
         Self {
             ordinal: page.ordinal(),
+            atlas: page.atlas(),
             name: Box::<str>::from(page.name()),
             declared_size: page.size(),
             min_filter: page.min_filter(),
@@ -429,6 +540,86 @@ impl From<AtlasPageRef<'_>> for PageLoadSpec {
     }
 }
 
+/// Resolves the primary text atlas configured or inferred for a skeleton,
+/// followed by any further atlases in link order.
+fn atlas_paths(
+    skeleton_path: &AssetPath<'_>,
+    settings: &SpinalAssetLoaderSettings,
+) -> Result<Vec<AssetPath<'static>>, SpinalAssetLoaderError> {
+    let skeleton_path = skeleton_path.clone_owned();
+    let atlas_reference = match settings.atlas_path.as_deref() {
+        Some(path) => path.to_owned(),
+        None => infer_atlas_reference(&skeleton_path)?,
+    };
+    std::iter::once(atlas_reference.as_str())
+        .chain(settings.atlas_paths.iter().map(String::as_str))
+        .map(|reference| resolve_dependency(&skeleton_path, reference, "atlas"))
+        .collect()
+}
+
+/// Loads every primary atlas page image of a linked skeleton as a labeled
+/// asset, and the pages of further atlases by path so skeletons share them.
+async fn load_pages(
+    skeleton: Arc<SkeletonAsset>,
+    atlas_paths: &[AssetPath<'static>],
+    load_context: &mut LoadContext<'_>,
+) -> Result<SpinalAsset, SpinalAssetLoaderError> {
+    let page_specs = skeleton
+        .atlas_pages()
+        .map(PageLoadSpec::from)
+        .collect::<Vec<_>>();
+    let mut pages = Vec::with_capacity(page_specs.len());
+
+    for spec in page_specs {
+        let page_path = resolve_dependency(&atlas_paths[spec.atlas], &spec.name, "atlas page")?;
+        let sampler = page_sampler(spec.min_filter, spec.mag_filter, spec.wrap);
+        if spec.atlas > 0 {
+            let image = load_context
+                .loader()
+                .with_settings(move |settings: &mut ImageLoaderSettings| {
+                    settings.sampler = sampler.clone();
+                })
+                .load(page_path.clone());
+            pages.push(SpinalAtlasPage::from_loaded(spec.name, page_path, image));
+            continue;
+        }
+        let image_bytes = load_context
+            .read_asset_bytes(page_path.clone())
+            .await
+            .map_err(|source| SpinalAssetLoaderError::PageImageRead {
+                page: spec.name.clone(),
+                path: page_path.clone(),
+                source: Box::new(source),
+            })?;
+        let extension = page_path
+            .path()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .ok_or_else(|| SpinalAssetLoaderError::MissingPageImageExtension {
+                page: spec.name.clone(),
+                path: page_path.clone(),
+            })?;
+        let image_asset = Image::from_buffer(
+            &image_bytes,
+            ImageType::Extension(extension),
+            CompressedImageFormats::NONE,
+            true,
+            sampler,
+            RenderAssetUsages::default(),
+        )
+        .map_err(|source| SpinalAssetLoaderError::PageImageDecode {
+            page: spec.name.clone(),
+            path: page_path.clone(),
+            source: Box::new(source),
+        })?;
+        validate_page_image_size(&spec.name, &page_path, spec.declared_size, &image_asset)?;
+        let image = load_context.add_labeled_asset(format!("page-{}", spec.ordinal), image_asset);
+        pages.push(SpinalAtlasPage::from_loaded(spec.name, page_path, image));
+    }
+
+    SpinalAsset::new(skeleton, pages)
+}
+
 fn validate_page_image_size(
     page: &str,
     path: &AssetPath<'static>,
@@ -675,6 +866,7 @@ mod tests {
     fn loader_settings_default_to_sibling_atlas_inference() {
         let settings = SpinalAssetLoaderSettings::default();
         assert_eq!(settings.atlas_path, None);
+        assert!(settings.atlas_paths.is_empty());
     }
 
     #[test]
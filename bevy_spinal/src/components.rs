@@ -12,10 +12,11 @@ use bevy::{
     camera::visibility::{self, Visibility, VisibilityClass},
     color::Color,
     ecs::component::Component,
-    math::Vec2,
+    image::Image,
+    math::{Rect, Vec2},
     transform::components::{GlobalTransform, Transform},
 };
-use spinal::{BoneTransform, Mix, PlaybackMode, Transition, WeightFade};
+use spinal::{BoneTransform, Mix, MixCurve, PlaybackMode, Transition, WeightFade};
 use thiserror::Error;
 
 use crate::SpinalAsset;
@@ -34,6 +35,7 @@ use crate::SpinalAsset;
     SpinalAppearance,
     SpinalSkinLayers,
     SpinalPoseOverrides,
+    SpinalSlotOverrides,
     SpinalControlTargets,
     SpinalInstanceState,
     SpinalPlaybackState,
@@ -212,7 +214,8 @@ pub struct SpinalAnimator {
 pub(crate) struct DesiredPlayback {
     pub(crate) animation: Box<str>,
     pub(crate) mode: PlaybackMode,
-    pub(crate) transition: Transition,
+    // `None` lets the entity's `SpinalMixTable` choose.
+    pub(crate) transition: Option<Transition>,
 }
 
 impl SpinalAnimator {
@@ -235,7 +238,7 @@ impl SpinalAnimator {
             desired: Some(DesiredPlayback {
                 animation: animation.into(),
                 mode,
-                transition: Transition::Immediate,
+                transition: Some(Transition::Immediate),
             }),
             stop_transition: Transition::Immediate,
             speed: 1.0,
@@ -256,7 +259,19 @@ impl SpinalAnimator {
         self.desired = Some(DesiredPlayback {
             animation: animation.into(),
             mode,
-            transition,
+            transition: Some(transition),
+        });
+        self.clear_seek();
+        self.bump_revision();
+    }
+
+    /// Requests a playback whose transition is chosen by the entity's
+    /// [`SpinalMixTable`], or is immediate when the entity has none.
+    pub fn play_mixed(&mut self, animation: impl Into<Box<str>>, mode: PlaybackMode) {
+        self.desired = Some(DesiredPlayback {
+            animation: animation.into(),
+            mode,
+            transition: None,
         });
         self.clear_seek();
         self.bump_revision();
@@ -318,11 +333,18 @@ impl SpinalAnimator {
     }
 
     /// Returns the requested transition.
+    ///
+    /// A [`Self::play_mixed`] request reports an immediate transition here;
+    /// the runtime resolves it through [`SpinalMixTable`].
     #[must_use]
     pub fn transition(&self) -> Transition {
+        self.requested_transition().unwrap_or(Transition::Immediate)
+    }
+
+    pub(crate) fn requested_transition(&self) -> Option<Transition> {
         self.desired
             .as_ref()
-            .map_or(self.stop_transition, |playback| playback.transition)
+            .map_or(Some(self.stop_transition), |playback| playback.transition)
     }
 
     /// Returns whether clock advancement is paused.
@@ -459,7 +481,26 @@ impl SpinalAnimationTracks {
         intent.desired = Some(DesiredPlayback {
             animation: animation.into(),
             mode,
-            transition,
+            transition: Some(transition),
+        });
+        intent.play_revision = revision;
+    }
+
+    /// Requests an animation on a named override track using the entity's
+    /// [`SpinalMixTable`] to choose the transition.
+    pub fn play_mixed(
+        &mut self,
+        track: impl AsRef<str>,
+        animation: impl Into<Box<str>>,
+        mode: PlaybackMode,
+    ) {
+        let index = self.ensure_index(track.as_ref());
+        let revision = self.issue_generation();
+        let intent = &mut self.tracks[index];
+        intent.desired = Some(DesiredPlayback {
+            animation: animation.into(),
+            mode,
+            transition: None,
         });
         intent.play_revision = revision;
     }
@@ -673,12 +714,15 @@ impl<'a> SpinalTrackIntentRef<'a> {
     }
 
     /// Returns the transition requested by the latest play command.
+    ///
+    /// Returns `None` when stopped or when the entity's [`SpinalMixTable`]
+    /// chooses the transition.
     #[must_use]
     pub fn play_transition(self) -> Option<Transition> {
         self.intent
             .desired
             .as_ref()
-            .map(|desired| desired.transition)
+            .and_then(|desired| desired.transition)
     }
 
     /// Returns the transition requested by the latest stop command.
@@ -872,6 +916,181 @@ impl SpinalSkinLayers {
     }
 }
 
+/// Named default crossfades for play requests without an explicit transition.
+///
+/// The table applies to the base track and every named override track of the
+/// entity, and is consulted by [`SpinalAnimator::play_mixed`] and
+/// [`SpinalAnimationTracks::play_mixed`]. Pairs naming an animation missing
+/// from the current asset are ignored and reported as
+/// [`crate::SpinalIssueKind::MissingAnimation`].
+#[derive(Clone, Component, Debug, Default, PartialEq)]
+pub struct SpinalMixTable {
+    default_duration: Duration,
+    curve: MixCurve,
+    pairs: Vec<MixPairIntent>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct MixPairIntent {
+    from: Box<str>,
+    to: Box<str>,
+    duration: Duration,
+}
+
+impl SpinalMixTable {
+    /// Creates a linear table with no pair overrides.
+    #[must_use]
+    pub const fn new(default_duration: Duration) -> Self {
+        Self {
+            default_duration,
+            curve: MixCurve::Linear,
+            pairs: Vec::new(),
+        }
+    }
+
+    /// Replaces the interpolation used by every crossfade from this table.
+    #[must_use]
+    pub fn with_curve(mut self, curve: MixCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Inserts or replaces one ordered pair by animation name.
+    #[must_use]
+    pub fn with_mix(
+        mut self,
+        from: impl Into<Box<str>>,
+        to: impl Into<Box<str>>,
+        duration: Duration,
+    ) -> Self {
+        self.set_mix(from, to, duration);
+        self
+    }
+
+    /// Replaces the duration used by pairs without an override.
+    pub fn set_default_duration(&mut self, duration: Duration) {
+        self.default_duration = duration;
+    }
+
+    /// Replaces the crossfade interpolation.
+    pub fn set_curve(&mut self, curve: MixCurve) {
+        self.curve = curve;
+    }
+
+    /// Inserts or replaces one ordered pair by animation name.
+    pub fn set_mix(
+        &mut self,
+        from: impl Into<Box<str>>,
+        to: impl Into<Box<str>>,
+        duration: Duration,
+    ) {
+        let from = from.into();
+        let to = to.into();
+        if let Some(pair) = self
+            .pairs
+            .iter_mut()
+            .find(|pair| pair.from == from && pair.to == to)
+        {
+            pair.duration = duration;
+        } else {
+            self.pairs.push(MixPairIntent { from, to, duration });
+        }
+    }
+
+    /// Removes one ordered pair and returns its duration.
+    pub fn remove_mix(&mut self, from: &str, to: &str) -> Option<Duration> {
+        let index = self
+            .pairs
+            .iter()
+            .position(|pair| pair.from.as_ref() == from && pair.to.as_ref() == to)?;
+        Some(self.pairs.remove(index).duration)
+    }
+
+    /// Returns the duration used from `from` to `to`.
+    #[must_use]
+    pub fn mix(&self, from: &str, to: &str) -> Duration {
+        self.pairs
+            .iter()
+            .find(|pair| pair.from.as_ref() == from && pair.to.as_ref() == to)
+            .map_or(self.default_duration, |pair| pair.duration)
+    }
+
+    /// Returns the duration used by pairs without an override.
+    #[must_use]
+    pub const fn default_duration(&self) -> Duration {
+        self.default_duration
+    }
+
+    /// Returns the crossfade interpolation.
+    #[must_use]
+    pub const fn curve(&self) -> MixCurve {
+        self.curve
+    }
+
+    /// Iterates pair overrides in insertion order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &str, Duration)> {
+        self.pairs
+            .iter()
+            .map(|pair| (pair.from.as_ref(), pair.to.as_ref(), pair.duration))
+    }
+}
+
+/// Opt-in root motion that moves the entity instead of the skeleton.
+///
+/// Each frame the named bone's authored translation and rotation delta, as
+/// blended by the base track, crossfades, and override-track weights, is
+/// carried from the bone's parent space into skeleton space through the solved
+/// parent bone, then applied to the entity's [`Transform`] after
+/// [`SpinalAppearance`] facing and the transform's own rotation and scale. By
+/// default the bone is then pinned to setup pose so the motion is not shown
+/// twice. A missing bone is reported as
+/// [`crate::SpinalIssueKind::MissingBone`] and moves nothing.
+#[derive(Clone, Component, Debug, PartialEq)]
+pub struct SpinalRootMotion {
+    bone: Box<str>,
+    zero_in_pose: bool,
+}
+
+impl SpinalRootMotion {
+    /// Extracts motion from the stable bone name and pins it in the pose.
+    #[must_use]
+    pub fn new(bone: impl Into<Box<str>>) -> Self {
+        Self {
+            bone: bone.into(),
+            zero_in_pose: true,
+        }
+    }
+
+    /// Returns a copy that chooses whether the bone is pinned to setup pose.
+    #[must_use]
+    pub const fn with_zero_in_pose(mut self, zero_in_pose: bool) -> Self {
+        self.zero_in_pose = zero_in_pose;
+        self
+    }
+
+    /// Returns the stable bone name.
+    #[must_use]
+    pub fn bone(&self) -> &str {
+        &self.bone
+    }
+
+    /// Replaces the extracted bone by stable name.
+    pub fn set_bone(&mut self, bone: impl Into<Box<str>>) {
+        self.bone = bone.into();
+    }
+
+    /// Returns whether the bone is pinned to setup pose after extraction.
+    #[must_use]
+    pub const fn zero_in_pose(&self) -> bool {
+        self.zero_in_pose
+    }
+
+    /// Sets whether the bone is pinned to setup pose after extraction.
+    pub fn set_zero_in_pose(&mut self, zero_in_pose: bool) {
+        self.zero_in_pose = zero_in_pose;
+    }
+}
+
 /// One stable-name procedural replacement applied before constraint solving.
 #[derive(Clone, Debug, PartialEq)]
 pub struct BoneOverride {
@@ -1037,6 +1256,278 @@ impl SpinalPoseOverrides {
     }
 }
 
+/// Where a [`SlotOverride`] moves its slot in the draw order.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SlotDrawPlacement {
+    /// Directly in front of the other slot.
+    InFrontOf,
+    /// Directly behind the other slot.
+    Behind,
+}
+
+/// One stable-name procedural slot edit applied before constraint solving.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlotOverride {
+    slot: Box<str>,
+    color: Option<Color>,
+    alpha: Option<f32>,
+    attachment: Option<Option<Box<str>>>,
+    draw_order: Option<(SlotDrawPlacement, Box<str>)>,
+}
+
+impl SlotOverride {
+    /// Creates an edit of `slot` that changes nothing yet.
+    #[must_use]
+    pub fn new(slot: impl Into<Box<str>>) -> Self {
+        Self {
+            slot: slot.into(),
+            color: None,
+            alpha: None,
+            attachment: None,
+            draw_order: None,
+        }
+    }
+
+    /// Replaces the slot's animated colour, for example for a damage flash.
+    ///
+    /// Channels are read in sRGB like authored Spine colours and clamped to
+    /// `0.0..=1.0`.
+    #[must_use]
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Replaces only the slot's alpha, after any colour, clamped to
+    /// `0.0..=1.0`.
+    #[must_use]
+    pub const fn with_alpha(mut self, alpha: f32) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    /// Shows the attachment the active skins provide for `placeholder`.
+    #[must_use]
+    pub fn with_attachment(mut self, placeholder: impl Into<Box<str>>) -> Self {
+        self.attachment = Some(Some(placeholder.into()));
+        self
+    }
+
+    /// Hides the slot's attachment.
+    #[must_use]
+    pub fn hidden(mut self) -> Self {
+        self.attachment = Some(None);
+        self
+    }
+
+    /// Moves the slot directly in front of or behind `other` in draw order.
+    #[must_use]
+    pub fn with_draw_order(
+        mut self,
+        placement: SlotDrawPlacement,
+        other: impl Into<Box<str>>,
+    ) -> Self {
+        self.draw_order = Some((placement, other.into()));
+        self
+    }
+
+    /// Returns the stable slot name.
+    #[must_use]
+    pub fn slot(&self) -> &str {
+        &self.slot
+    }
+
+    /// Returns the replacement colour, if any.
+    #[must_use]
+    pub const fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// Returns the replacement alpha, if any.
+    #[must_use]
+    pub const fn alpha(&self) -> Option<f32> {
+        self.alpha
+    }
+
+    /// Returns the requested attachment placeholder, where `Some(None)` hides
+    /// the slot and `None` keeps the animated attachment.
+    #[must_use]
+    pub fn attachment(&self) -> Option<Option<&str>> {
+        self.attachment.as_ref().map(Option::as_deref)
+    }
+
+    /// Returns the requested draw-order placement and the other slot's name.
+    #[must_use]
+    pub fn draw_order(&self) -> Option<(SlotDrawPlacement, &str)> {
+        self.draw_order
+            .as_ref()
+            .map(|(placement, other)| (*placement, other.as_ref()))
+    }
+}
+
+/// Procedural slot colour, attachment and draw-order edits applied after
+/// animation and before ordered constraint solving.
+///
+/// Edits apply in insertion order every frame. Names missing from the current
+/// asset are reported as [`crate::SpinalIssueKind::MissingSlot`].
+#[derive(Clone, Component, Debug, Default, PartialEq)]
+pub struct SpinalSlotOverrides {
+    overrides: Vec<SlotOverride>,
+}
+
+impl SpinalSlotOverrides {
+    /// Inserts or replaces one edit by stable slot name.
+    pub fn set(&mut self, replacement: SlotOverride) {
+        if let Some(existing) = self
+            .overrides
+            .iter_mut()
+            .find(|existing| existing.slot == replacement.slot)
+        {
+            *existing = replacement;
+        } else {
+            self.overrides.push(replacement);
+        }
+    }
+
+    /// Removes and returns one edit.
+    pub fn remove(&mut self, slot: &str) -> Option<SlotOverride> {
+        let index = self
+            .overrides
+            .iter()
+            .position(|replacement| replacement.slot() == slot)?;
+        Some(self.overrides.remove(index))
+    }
+
+    /// Returns one edit by stable slot name.
+    #[must_use]
+    pub fn get(&self, slot: &str) -> Option<&SlotOverride> {
+        self.overrides
+            .iter()
+            .find(|replacement| replacement.slot() == slot)
+    }
+
+    /// Iterates edits in deterministic insertion order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &SlotOverride> + ExactSizeIterator {
+        self.overrides.iter()
+    }
+
+    /// Removes every edit.
+    pub fn clear(&mut self) {
+        self.overrides.clear();
+    }
+}
+
+/// One caller-supplied image drawn in place of a named attachment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttachmentTexture {
+    slot: Box<str>,
+    attachment: Box<str>,
+    image: Handle<Image>,
+    uv_rect: Rect,
+}
+
+impl AttachmentTexture {
+    /// Draws the whole of `image` for the region or mesh attachment named
+    /// `attachment` on `slot`.
+    #[must_use]
+    pub fn new(
+        slot: impl Into<Box<str>>,
+        attachment: impl Into<Box<str>>,
+        image: Handle<Image>,
+    ) -> Self {
+        Self {
+            slot: slot.into(),
+            attachment: attachment.into(),
+            image,
+            uv_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+        }
+    }
+
+    /// Draws only `uv_rect` of the image, in normalized coordinates with a
+    /// top-left origin.
+    #[must_use]
+    pub const fn with_uv_rect(mut self, uv_rect: Rect) -> Self {
+        self.uv_rect = uv_rect;
+        self
+    }
+
+    /// Returns the stable slot name.
+    #[must_use]
+    pub fn slot(&self) -> &str {
+        &self.slot
+    }
+
+    /// Returns the stable attachment name.
+    #[must_use]
+    pub fn attachment(&self) -> &str {
+        &self.attachment
+    }
+
+    /// Returns the replacement image.
+    #[must_use]
+    pub const fn image(&self) -> &Handle<Image> {
+        &self.image
+    }
+
+    /// Returns the normalized image rectangle mapped onto the attachment.
+    #[must_use]
+    pub const fn uv_rect(&self) -> Rect {
+        self.uv_rect
+    }
+}
+
+/// Caller-supplied images drawn in place of named attachments, such as a
+/// player's uploaded banner.
+///
+/// An override applies to the attachment with that name on that slot in
+/// every skin. Each image is batched as its own texture page, and an
+/// overridden attachment is not drawn until its image has loaded. Entries
+/// that match no region or mesh attachment are reported as
+/// [`crate::SpinalIssueKind::TextureOverride`].
+#[derive(Clone, Component, Debug, Default, PartialEq)]
+pub struct SpinalTextureOverrides {
+    overrides: Vec<AttachmentTexture>,
+}
+
+impl SpinalTextureOverrides {
+    /// Inserts or replaces one override by slot and attachment name.
+    pub fn set(&mut self, replacement: AttachmentTexture) {
+        if let Some(existing) = self.overrides.iter_mut().find(|existing| {
+            existing.slot == replacement.slot && existing.attachment == replacement.attachment
+        }) {
+            *existing = replacement;
+        } else {
+            self.overrides.push(replacement);
+        }
+    }
+
+    /// Removes and returns one override.
+    pub fn remove(&mut self, slot: &str, attachment: &str) -> Option<AttachmentTexture> {
+        let index = self.overrides.iter().position(|replacement| {
+            replacement.slot() == slot && replacement.attachment() == attachment
+        })?;
+        Some(self.overrides.remove(index))
+    }
+
+    /// Returns one override by slot and attachment name.
+    #[must_use]
+    pub fn get(&self, slot: &str, attachment: &str) -> Option<&AttachmentTexture> {
+        self.overrides.iter().find(|replacement| {
+            replacement.slot() == slot && replacement.attachment() == attachment
+        })
+    }
+
+    /// Iterates overrides in deterministic insertion order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &AttachmentTexture> + ExactSizeIterator {
+        self.overrides.iter()
+    }
+
+    /// Removes every override.
+    pub fn clear(&mut self) {
+        self.overrides.clear();
+    }
+}
+
 /// Public lifecycle state for one ECS skeleton instance.
 #[derive(Clone, Component, Debug, Default, Eq, PartialEq)]
 #[non_exhaustive]
@@ -19,13 +19,15 @@ pub use spinal;
 
 pub use asset::{
     SpinalAsset, SpinalAssetLoader, SpinalAssetLoaderError, SpinalAssetLoaderSettings,
-    SpinalAtlasPage,
+    SpinalAtlasPage, SpinalCacheLoader, SpinalCacheProcessor,
 };
 pub use components::{
-    BoneOverride, InvalidControlTargetPosition, InvalidPlaybackSpeed, SpinalAnimationTracks,
-    SpinalAnimator, SpinalAppearance, SpinalControlTargets, SpinalInstance, SpinalInstanceState,
-    SpinalPlaybackState, SpinalPoseOverrides, SpinalSkinLayers, SpinalTrackIntentRef,
-    SpinalTrackState, SpinalTrackStates, TrackReorderError, WorldToSkeletonPositionError,
+    AttachmentTexture, BoneOverride, InvalidControlTargetPosition, InvalidPlaybackSpeed,
+    SlotDrawPlacement, SlotOverride, SpinalAnimationTracks, SpinalAnimator, SpinalAppearance,
+    SpinalControlTargets, SpinalInstance, SpinalInstanceState, SpinalMixTable, SpinalPlaybackState,
+    SpinalPoseOverrides, SpinalRootMotion, SpinalSkinLayers, SpinalSlotOverrides,
+    SpinalTextureOverrides, SpinalTrackIntentRef, SpinalTrackState, SpinalTrackStates,
+    TrackReorderError, WorldToSkeletonPositionError,
 };
 pub use plugin::{SpinalPlugin, SpinalSet};
 pub use runtime::{SpinalAnimationEvent, SpinalIssue, SpinalIssueKind, SpinalRuntimeConfig};
@@ -6,7 +6,8 @@ use bevy::{
 };
 
 use crate::{
-    SpinalAnimationEvent, SpinalAsset, SpinalAssetLoader, SpinalIssue, SpinalRuntimeConfig,
+    SpinalAnimationEvent, SpinalAsset, SpinalAssetLoader, SpinalCacheLoader, SpinalCacheProcessor,
+    SpinalIssue, SpinalRuntimeConfig,
     runtime::{cleanup_removed_instances, prepare_instances, update_instances},
 };
 
@@ -38,7 +39,10 @@ impl Plugin for SpinalPlugin {
         }
 
         app.init_asset::<SpinalAsset>()
-            .register_asset_loader(SpinalAssetLoader)
+            .register_asset_loader(SpinalAssetLoader::default())
+            .register_asset_loader(SpinalCacheLoader)
+            .register_asset_processor(SpinalCacheProcessor)
+            .set_default_asset_processor::<SpinalCacheProcessor>("spine.json")
             .init_resource::<SpinalRuntimeConfig>()
             .add_message::<SpinalAnimationEvent>()
             .add_message::<SpinalIssue>()
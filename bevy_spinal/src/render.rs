@@ -55,8 +55,9 @@ use bevy::{
 };
 
 use crate::{
-    SpinalAppearance, SpinalAsset, SpinalInstance, SpinalRuntimeConfig, plugin::SpinalSet,
-    runtime::SpinalFrame,
+    SpinalAppearance, SpinalAsset, SpinalInstance, SpinalRuntimeConfig,
+    plugin::SpinalSet,
+    runtime::{SpinalDrawPage, SpinalFrame},
 };
 
 const GPU_VERTEX_FLOATS: usize = 9;
@@ -476,7 +477,10 @@ fn append_extracted_frame_geometry(
     let draw_start = extracted.draws.len();
     let result = (|| -> Option<Range<usize>> {
         for draw in &frame.draws {
-            let image = resolve_image(draw.page_ordinal)?;
+            let image = match draw.page {
+                SpinalDrawPage::Atlas(ordinal) => resolve_image(ordinal)?,
+                SpinalDrawPage::Custom(image) => image,
+            };
             let color = modulated_linear_color(draw.color, appearance.modulation())?;
             let source_vertices = frame.vertices.get(draw.vertices.clone())?;
             let source_indices = frame.indices.get(draw.indices.clone())?;
@@ -906,11 +910,12 @@ mod tests {
         let mut images = Assets::<Image>::default();
         let page_a = images.add(Image::default()).id();
         let page_b = images.add(Image::default()).id();
+        let banner = images.add(Image::default()).id();
         let mut extracted = ExtractedSpinalFrames::default();
         let prefix = SpinalFrame {
             revision: 1,
             draws: vec![crate::runtime::SpinalDraw {
-                page_ordinal: 1,
+                page: SpinalDrawPage::Atlas(1),
                 vertices: 0..3,
                 indices: 0..3,
                 color: [1.0; 4],
@@ -968,25 +973,25 @@ mod tests {
             revision: 2,
             draws: vec![
                 crate::runtime::SpinalDraw {
-                    page_ordinal: 0,
+                    page: SpinalDrawPage::Atlas(0),
                     vertices: 0..5,
                     indices: 0..9,
                     color: [1.0; 4],
                 },
                 crate::runtime::SpinalDraw {
-                    page_ordinal: 0,
+                    page: SpinalDrawPage::Atlas(0),
                     vertices: 5..9,
                     indices: 9..15,
                     color: [1.0; 4],
                 },
                 crate::runtime::SpinalDraw {
-                    page_ordinal: 1,
+                    page: SpinalDrawPage::Atlas(1),
                     vertices: 9..12,
                     indices: 15..18,
                     color: [1.0; 4],
                 },
                 crate::runtime::SpinalDraw {
-                    page_ordinal: 0,
+                    page: SpinalDrawPage::Custom(banner),
                     vertices: 12..15,
                     indices: 18..21,
                     color: [1.0; 4],
@@ -1041,7 +1046,7 @@ mod tests {
                 (page_a, 3..12),
                 (page_a, 12..18),
                 (page_b, 18..21),
-                (page_a, 21..24),
+                (banner, 21..24),
             ]
         );
 
@@ -1066,7 +1071,7 @@ mod tests {
                     indices: 18..21,
                 },
                 AdjacentBatch {
-                    key: page_a,
+                    key: banner,
                     indices: 21..24,
                 },
             ]
@@ -1095,13 +1100,13 @@ mod tests {
             revision: 1,
             draws: vec![
                 crate::runtime::SpinalDraw {
-                    page_ordinal: 0,
+                    page: SpinalDrawPage::Atlas(0),
                     vertices: 0..3,
                     indices: 0..3,
                     color: [1.0; 4],
                 },
                 crate::runtime::SpinalDraw {
-                    page_ordinal: 1,
+                    page: SpinalDrawPage::Atlas(1),
                     vertices: 3..6,
                     indices: 3..6,
                     color: [1.0; 4],
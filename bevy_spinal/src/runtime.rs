@@ -3,7 +3,7 @@ use std::{ops::Range, sync::Arc, time::Duration};
 use bevy::{
     asset::{AssetId, AssetServer, Assets, LoadState},
     ecs::{
-        change_detection::DetectChangesMut,
+        change_detection::{DetectChangesMut, Mut},
         component::Component,
         entity::Entity,
         lifecycle::RemovedComponents,
@@ -14,17 +14,20 @@ use bevy::{
     image::Image,
     math::Vec2,
     time::Time,
+    transform::components::Transform,
 };
 use spinal::{
-    AnimationEvent, AnimationMixer, Diagnostic, DiagnosticCode, DiagnosticScope, DrawItemRef,
-    IkSolveIssue, Mix, PlayOptions, PlaybackMode, Skeleton, SlotBlendMode, TrackAnimationEvent,
-    TrackId, TrackOptions,
+    AnimationEvent, AnimationMixer, BatchContext, BatchInstance, BatchParts, BoneSpace, Diagnostic,
+    DiagnosticCode, DiagnosticScope, DrawItemRef, EditablePose, IkSolveIssue, Mix, PlayOptions,
+    PlaybackMode, PlayerError, RootMotion, RootMotionDelta, Skeleton, SlotBlendMode, SolvedFrame,
+    TrackAnimationEvent, TrackEventSink, TrackId, TrackOptions,
 };
 
 use crate::{
-    SpinalAnimationTracks, SpinalAnimator, SpinalAsset, SpinalControlTargets, SpinalInstance,
-    SpinalInstanceState, SpinalPlaybackState, SpinalPoseOverrides, SpinalSkinLayers,
-    SpinalTrackState, SpinalTrackStates,
+    SlotDrawPlacement, SlotOverride, SpinalAnimationTracks, SpinalAnimator, SpinalAppearance,
+    SpinalAsset, SpinalControlTargets, SpinalInstance, SpinalInstanceState, SpinalMixTable,
+    SpinalPlaybackState, SpinalPoseOverrides, SpinalRootMotion, SpinalSkinLayers,
+    SpinalSlotOverrides, SpinalTextureOverrides, SpinalTrackState, SpinalTrackStates,
     components::{DesiredPlayback, TrackNamespace},
 };
 
@@ -85,6 +88,10 @@ pub enum SpinalIssueKind {
     MissingSkin,
     /// A requested procedural bone name does not exist in the current asset.
     MissingBone,
+    /// A requested procedural slot name does not exist in the current asset.
+    MissingSlot,
+    /// A requested attachment placeholder does not exist on its slot.
+    MissingAttachment,
     /// A retained core diagnostic became active.
     AssetDiagnostic(DiagnosticCode),
     /// IK preserved the finite FK pose because solving was unsafe.
@@ -102,6 +109,9 @@ pub enum SpinalIssueKind {
     ControlTarget,
     /// An active override track contains a deferred property.
     UnsupportedOverrideProperty(spinal::PropertyKey),
+    /// A texture override matched no region or mesh attachment, or its UV
+    /// rectangle was not finite.
+    TextureOverride,
 }
 
 /// An owned, entity-scoped adapter issue.
@@ -284,9 +294,24 @@ pub(crate) struct SpinalRuntime {
     skin_request: Vec<Box<str>>,
     override_request: Vec<(Box<str>, spinal::BoneTransform)>,
     resolved_overrides: Vec<(spinal::BoneId, spinal::BoneTransform)>,
+    slot_request: Vec<SlotOverride>,
+    resolved_slots: Vec<ResolvedSlotEdit>,
     target_request: Vec<(Box<str>, Vec2)>,
     resolved_targets: Vec<ResolvedControlTarget>,
+    mix_table_request: Option<SpinalMixTable>,
+    mix_table: Option<Arc<spinal::MixTable>>,
+    missing_mix_animations: Vec<Box<str>>,
+    root_motion_request: Option<SpinalRootMotion>,
+    texture_request: Option<SpinalTextureOverrides>,
+    /// Custom page images, indexed by [`spinal::CustomPageKey`].
+    texture_pages: Vec<AssetId<Image>>,
+    textured_attachments: Vec<spinal::AttachmentId>,
+    texture_issues: Vec<Box<str>>,
     active_issues: Vec<IssueFingerprint>,
+    /// Event issues and authored events collected during one batched update,
+    /// kept so their storage is reused every frame.
+    event_issues: Vec<ActiveIssue>,
+    authored_events: Vec<SpinalAnimationEvent>,
 }
 
 #[derive(Debug)]
@@ -311,6 +336,14 @@ struct CachedTrackWeight {
     weight: spinal::Mix,
 }
 
+#[derive(Clone, Copy, Debug)]
+struct ResolvedSlotEdit {
+    request_index: usize,
+    slot: spinal::SlotId,
+    color: Option<spinal::Rgba>,
+    draw_order: Option<(SlotDrawPlacement, spinal::SlotId)>,
+}
+
 #[derive(Clone, Copy, Debug)]
 struct ResolvedControlTarget {
     request_index: usize,
@@ -322,7 +355,7 @@ struct CachedAnimationIntent {
     revision: u64,
     animation: Option<Box<str>>,
     mode: Option<PlaybackMode>,
-    transition: spinal::Transition,
+    transition: Option<spinal::Transition>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -337,7 +370,7 @@ impl CachedAnimationIntent {
             revision: animator.revision(),
             animation: animator.animation().map(Box::<str>::from),
             mode: animator.mode(),
-            transition: animator.transition(),
+            transition: animator.requested_transition(),
         }
     }
 
@@ -345,7 +378,7 @@ impl CachedAnimationIntent {
         self.revision == animator.revision()
             && self.animation.as_deref() == animator.animation()
             && self.mode == animator.mode()
-            && self.transition == animator.transition()
+            && self.transition == animator.requested_transition()
     }
 }
 
@@ -397,9 +430,21 @@ impl SpinalRuntime {
             skin_request: Vec::new(),
             override_request: Vec::new(),
             resolved_overrides: Vec::new(),
+            slot_request: Vec::new(),
+            resolved_slots: Vec::new(),
             target_request: Vec::new(),
             resolved_targets: Vec::new(),
+            mix_table_request: None,
+            mix_table: None,
+            missing_mix_animations: Vec::new(),
+            root_motion_request: None,
+            texture_request: None,
+            texture_pages: Vec::new(),
+            textured_attachments: Vec::new(),
+            texture_issues: Vec::new(),
             active_issues: Vec::new(),
+            event_issues: Vec::new(),
+            authored_events: Vec::new(),
         }
     }
 
@@ -411,12 +456,22 @@ impl SpinalRuntime {
 #[derive(Clone, Debug)]
 #[cfg_attr(not(feature = "render"), allow(dead_code))]
 pub(crate) struct SpinalDraw {
-    pub(crate) page_ordinal: usize,
+    pub(crate) page: SpinalDrawPage,
     pub(crate) vertices: Range<usize>,
     pub(crate) indices: Range<usize>,
     pub(crate) color: [f32; 4],
 }
 
+/// The texture one draw samples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(not(feature = "render"), allow(dead_code))]
+pub(crate) enum SpinalDrawPage {
+    /// A page of the asset's atlas, by ordinal.
+    Atlas(usize),
+    /// A caller-supplied image from [`SpinalTextureOverrides`].
+    Custom(AssetId<Image>),
+}
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(not(feature = "render"), allow(dead_code))]
 pub(crate) struct SpinalVertex {
@@ -533,7 +588,15 @@ pub(crate) fn update_instances(
             &SpinalAnimationTracks,
             &SpinalSkinLayers,
             &SpinalPoseOverrides,
+            &SpinalSlotOverrides,
             &SpinalControlTargets,
+            Option<&SpinalMixTable>,
+            (
+                Option<&SpinalRootMotion>,
+                Option<&SpinalTextureOverrides>,
+                &SpinalAppearance,
+                &mut Transform,
+            ),
             &mut SpinalInstanceState,
             &mut SpinalPlaybackState,
             &mut SpinalTrackStates,
@@ -544,6 +607,10 @@ pub(crate) fn update_instances(
 ) {
     let wall_delta = time.as_deref().map_or(Duration::ZERO, Time::delta);
 
+    // Intents resolve serially against the ECS; the independent mixer
+    // updates and solves then run as core batches of borrowed components.
+    let mut batch: [BatchSlot<'_>; BATCH_CHUNK] = std::array::from_fn(|_| BatchSlot(None));
+    let mut pending = 0;
     for (
         entity,
         instance,
@@ -551,12 +618,15 @@ pub(crate) fn update_instances(
         animation_tracks,
         skin_layers,
         pose_overrides,
+        slot_overrides,
         control_targets,
-        mut instance_state,
-        mut playback_state,
-        mut track_states,
+        mix_table,
+        (root_motion, texture_overrides, appearance, transform),
+        instance_state,
+        playback_state,
+        track_states,
         mut runtime,
-        mut output,
+        output,
     ) in &mut instances
     {
         let Some(asset) = assets.get(instance.asset()) else {
@@ -570,12 +640,21 @@ pub(crate) fn update_instances(
         let mut active_issues = Vec::new();
         apply_skin_intent(&mut runtime, skin_layers, root_point, &mut active_issues);
         apply_override_intent(&mut runtime, pose_overrides, root_point, &mut active_issues);
+        apply_slot_intent(&mut runtime, slot_overrides, root_point, &mut active_issues);
         apply_target_intent(
             &mut runtime,
             control_targets,
             root_point,
             &mut active_issues,
         );
+        apply_mix_table_intent(&mut runtime, mix_table, root_point, &mut active_issues);
+        apply_root_motion_intent(&mut runtime, root_motion, root_point, &mut active_issues);
+        apply_texture_intent(
+            &mut runtime,
+            texture_overrides,
+            root_point,
+            &mut active_issues,
+        );
         let base_seek_applied =
             apply_animation_intent(&mut runtime, animator, root_point, &mut active_issues);
         apply_track_intent(
@@ -590,163 +669,385 @@ pub(crate) fn update_instances(
             mixer,
             track_intents,
             resolved_overrides,
+            slot_request,
+            resolved_slots,
             target_request,
             resolved_targets,
             animation_seek,
+            texture_pages,
             active_issues: previous_issues,
+            event_issues,
+            authored_events,
             ..
-        } = &mut *runtime;
-        let mut authored_events = Vec::new();
-        let mut event_issues = Vec::new();
-        let core_asset = Arc::clone(asset.skeleton());
+        } = runtime.into_inner();
+        event_issues.clear();
+        authored_events.clear();
         let base_track = mixer.base_track_id();
-        let update_result = mixer.update(
+        batch[pending] = BatchSlot(Some(InstanceUpdate {
+            mixer,
             skeleton,
-            wall_delta,
-            &mut |track_event: TrackAnimationEvent<'_>| {
-                let event = track_event.event();
-                let animation_name = core_asset
-                    .animation(event.animation())
-                    .map_or("<invalid>", |animation| animation.name());
-                let track = (track_event.track() != base_track).then(|| {
-                    track_intents
-                        .iter()
-                        .find(|intent| intent.track == track_event.track())
-                        .map_or("<removed>", |intent| intent.key.as_ref())
-                });
-                let owned = owned_event(entity, track, animation_name, event);
-                for diagnostic in event
-                    .diagnostics()
-                    .filter(|diagnostic| diagnostic.is_degraded())
-                {
-                    event_issues.push(
-                        ActiveIssue::new(
-                            SpinalIssueKind::AssetDiagnostic(diagnostic.code()),
-                            diagnostic.message(),
-                            root_point,
-                        )
-                        .with_track(track),
-                    );
-                }
-                authored_events.push(owned);
+            frame: InstanceFrame {
+                entity,
+                asset,
+                images: &images,
+                texture_pages,
+                diagnostic_markers: config.diagnostic_markers(),
+                root_point,
+                base_track,
+                track_intents,
+                resolved_overrides,
+                slot_request,
+                resolved_slots,
+                target_request,
+                resolved_targets,
+                appearance,
+                transform,
+                output,
+                active_issues,
+                event_issues,
+                authored_events,
+                frame_ready: None,
             },
-        );
+            animation_seek,
+            previous_issues,
+            base_seek_applied,
+            paused: animator.is_paused(),
+            instance_state,
+            playback_state,
+            track_states,
+        }));
+        pending += 1;
+        if pending == BATCH_CHUNK {
+            evaluate_batch(
+                &mut batch,
+                wall_delta,
+                &mut event_messages,
+                &mut issue_messages,
+            );
+            pending = 0;
+        }
+    }
+
+    evaluate_batch(
+        &mut batch[..pending],
+        wall_delta,
+        &mut event_messages,
+        &mut issue_messages,
+    );
+}
+
+/// Instances evaluated by one core batch. The batch lives in a fixed stack
+/// buffer, so steady-state updates allocate nothing however many instances
+/// there are.
+const BATCH_CHUNK: usize = 32;
+
+/// Advances and solves a filled batch, then publishes each instance's
+/// results and empties its slot.
+fn evaluate_batch(
+    batch: &mut [BatchSlot<'_>],
+    wall_delta: Duration,
+    event_messages: &mut MessageWriter<'_, SpinalAnimationEvent>,
+    issue_messages: &mut MessageWriter<'_, SpinalIssue>,
+) {
+    spinal::update_batch(batch, wall_delta);
+    for slot in batch {
+        let update = slot.0.take().expect("every batched slot is filled");
+        publish_update(update, event_messages, issue_messages);
+    }
+}
+
+/// Publishes one evaluated instance's events, issues and observations.
+fn publish_update(
+    update: InstanceUpdate<'_>,
+    event_messages: &mut MessageWriter<'_, SpinalAnimationEvent>,
+    issue_messages: &mut MessageWriter<'_, SpinalIssue>,
+) {
+    let InstanceUpdate {
+        mixer,
+        skeleton,
+        frame,
+        animation_seek,
+        previous_issues,
+        base_seek_applied,
+        paused,
+        mut instance_state,
+        mut playback_state,
+        mut track_states,
+    } = update;
+    let entity = frame.entity;
+    if base_seek_applied {
+        mixer.base_track_mut().set_paused(paused);
+    }
+    let Some(frame_ready) = frame.frame_ready else {
         if base_seek_applied {
-            mixer.base_track_mut().set_paused(animator.is_paused());
+            // No sought pose was published, so the one-shot command
+            // must remain pending for the first successful frame.
+            *animation_seek = None;
         }
+        instance_state.set_if_neq(SpinalInstanceState::Failed);
+        emit_new_issues(
+            entity,
+            previous_issues,
+            &frame.active_issues,
+            issue_messages,
+        );
+        return;
+    };
 
-        let mut editable = match update_result {
-            Ok(editable) => editable,
-            Err(error) => {
-                if base_seek_applied {
-                    // No sought pose was published, so the one-shot command
-                    // must remain pending for the first successful frame.
-                    *animation_seek = None;
-                }
-                active_issues.push(ActiveIssue::new(
-                    SpinalIssueKind::Player,
-                    error.to_string(),
-                    root_point,
-                ));
-                output.draws.clear();
-                output.issue_points.clear();
-                output.ready = false;
-                instance_state.set_if_neq(SpinalInstanceState::Failed);
-                emit_new_issues(entity, previous_issues, &active_issues, &mut issue_messages);
-                continue;
-            }
-        };
+    emit_new_issues(
+        entity,
+        previous_issues,
+        &frame.active_issues,
+        issue_messages,
+    );
+    for authored_event in frame.authored_events.drain(..) {
+        event_messages.write(authored_event);
+    }
+
+    let draws_empty = frame.output.draws.is_empty();
+    let state = if !frame_ready {
+        SpinalInstanceState::Loading
+    } else if frame.active_issues.is_empty() && draws_empty {
+        SpinalInstanceState::ReadyNoDraws
+    } else if frame.active_issues.is_empty() {
+        SpinalInstanceState::Ready
+    } else if draws_empty {
+        SpinalInstanceState::DegradedNoDraws
+    } else {
+        SpinalInstanceState::Degraded
+    };
+    instance_state.set_if_neq(state);
+    let base_status = mixer.base_track().status();
+    let playback_changed = write_playback_observation(
+        playback_state.bypass_change_detection(),
+        skeleton.asset(),
+        base_status,
+    );
+    if playback_changed {
+        playback_state.set_changed();
+    }
+    let tracks_changed = write_track_observations(
+        track_states.bypass_change_detection(),
+        skeleton.asset(),
+        mixer,
+        frame.track_intents,
+    );
+    if tracks_changed {
+        track_states.set_changed();
+    }
+}
+
+/// One instance's borrowed ECS state while the core batch evaluates it.
+pub(crate) struct InstanceUpdate<'w> {
+    mixer: &'w mut AnimationMixer,
+    skeleton: &'w mut Skeleton,
+    frame: InstanceFrame<'w>,
+    animation_seek: &'w mut Option<CachedAnimationSeek>,
+    previous_issues: &'w mut Vec<IssueFingerprint>,
+    base_seek_applied: bool,
+    paused: bool,
+    instance_state: Mut<'w, SpinalInstanceState>,
+    playback_state: Mut<'w, SpinalPlaybackState>,
+    track_states: Mut<'w, SpinalTrackStates>,
+}
 
+/// One entry of the stack batch buffer, filled while intents resolve.
+pub(crate) struct BatchSlot<'w>(Option<InstanceUpdate<'w>>);
+
+impl<'w> BatchInstance for BatchSlot<'w> {
+    type Context = InstanceFrame<'w>;
+
+    fn parts(&mut self) -> BatchParts<'_, InstanceFrame<'w>> {
+        let update = self.0.as_mut().expect("only filled slots are batched");
+        BatchParts::new(update.mixer, update.skeleton, &mut update.frame)
+    }
+}
+
+/// Collects one instance's events, pose edits, issues and draw output.
+pub(crate) struct InstanceFrame<'w> {
+    entity: Entity,
+    asset: &'w SpinalAsset,
+    images: &'w Assets<Image>,
+    texture_pages: &'w [AssetId<Image>],
+    diagnostic_markers: bool,
+    root_point: Vec2,
+    base_track: TrackId,
+    track_intents: &'w [CachedTrackIntent],
+    resolved_overrides: &'w [(spinal::BoneId, spinal::BoneTransform)],
+    slot_request: &'w [SlotOverride],
+    resolved_slots: &'w [ResolvedSlotEdit],
+    target_request: &'w [(Box<str>, Vec2)],
+    resolved_targets: &'w [ResolvedControlTarget],
+    appearance: &'w SpinalAppearance,
+    transform: Mut<'w, Transform>,
+    output: Mut<'w, SpinalFrame>,
+    active_issues: Vec<ActiveIssue>,
+    event_issues: &'w mut Vec<ActiveIssue>,
+    authored_events: &'w mut Vec<SpinalAnimationEvent>,
+    /// Whether the frame's textures were ready, or `None` after a failed
+    /// update.
+    frame_ready: Option<bool>,
+}
+
+impl<'w> InstanceFrame<'w> {
+    fn track_name(&self, track: TrackId) -> &'w str {
+        self.track_intents
+            .iter()
+            .find(|intent| intent.track == track)
+            .map_or("<removed>", |intent| intent.key.as_ref())
+    }
+}
+
+impl TrackEventSink for InstanceFrame<'_> {
+    fn event(&mut self, track_event: TrackAnimationEvent<'_>) {
+        let event = track_event.event();
+        let animation_name = self
+            .asset
+            .skeleton()
+            .animation(event.animation())
+            .map_or("<invalid>", |animation| animation.name());
+        let track =
+            (track_event.track() != self.base_track).then(|| self.track_name(track_event.track()));
+        let owned = owned_event(self.entity, track, animation_name, event);
+        for diagnostic in event
+            .diagnostics()
+            .filter(|diagnostic| diagnostic.is_degraded())
         {
-            let mut editor = editable.edit();
-            for (bone, transform) in resolved_overrides.iter().copied() {
+            self.event_issues.push(
+                ActiveIssue::new(
+                    SpinalIssueKind::AssetDiagnostic(diagnostic.code()),
+                    diagnostic.message(),
+                    self.root_point,
+                )
+                .with_track(track),
+            );
+        }
+        self.authored_events.push(owned);
+    }
+}
+
+impl BatchContext for InstanceFrame<'_> {
+    fn edit(&mut self, pose: &mut EditablePose<'_>) {
+        {
+            let mut editor = pose.edit();
+            for (bone, transform) in self.resolved_overrides.iter().copied() {
                 editor
                     .set_bone_local(bone, transform)
                     .expect("cached override IDs belong to the active skeleton");
             }
-        }
-        {
-            let mut targets = editable.targets();
-            for resolved in resolved_targets.iter().copied() {
-                let (name, position) = &target_request[resolved.request_index];
-                if let Err(error) = targets.set_skeleton_position(resolved.bone, *position) {
-                    active_issues.push(ActiveIssue::new(
-                        SpinalIssueKind::ControlTarget,
-                        format!("control target bone `{name}` could not be placed: {error}"),
-                        root_point,
+            for resolved in self.resolved_slots.iter().copied() {
+                let request = &self.slot_request[resolved.request_index];
+                if let Some(color) = resolved.color {
+                    editor
+                        .set_slot_color(resolved.slot, color)
+                        .expect("cached slot IDs belong to the active skeleton");
+                }
+                if let Some(alpha) = request.alpha() {
+                    editor
+                        .set_slot_alpha(resolved.slot, unit_channel(alpha))
+                        .expect("a clamped alpha is normalized");
+                }
+                if let Some(placeholder) = request.attachment()
+                    && editor
+                        .set_slot_attachment(resolved.slot, placeholder)
+                        .is_err()
+                {
+                    self.active_issues.push(ActiveIssue::new(
+                        SpinalIssueKind::MissingAttachment,
+                        format!(
+                            "slot `{}` has no attachment placeholder `{}`",
+                            request.slot(),
+                            placeholder.unwrap_or_default()
+                        ),
+                        self.root_point,
                     ));
                 }
+                match resolved.draw_order {
+                    Some((SlotDrawPlacement::InFrontOf, other)) => {
+                        editor.draw_slot_in_front_of(resolved.slot, other)
+                    }
+                    Some((SlotDrawPlacement::Behind, other)) => {
+                        editor.draw_slot_behind(resolved.slot, other)
+                    }
+                    None => Ok(()),
+                }
+                .expect("cached slot IDs belong to the active skeleton");
             }
         }
-        let solved = editable.solve();
-
-        active_issues.extend(event_issues);
-        append_frame_issues(&solved, &mut active_issues);
-        for issue in mixer.active_deferred_properties() {
-            let track = track_intents
-                .iter()
-                .find(|intent| intent.track == issue.track())
-                .map_or("<removed>", |intent| intent.key.as_ref());
-            active_issues.push(
-                ActiveIssue::new(
-                    SpinalIssueKind::UnsupportedOverrideProperty(issue.property()),
-                    format!(
-                        "override track `{track}` property {:?} is deferred and was ignored",
-                        issue.property()
-                    ),
-                    root_point,
-                )
-                .with_track(Some(track)),
-            );
-        }
-        let frame_ready = write_draws(asset, &images, &solved, &mut output, &mut active_issues);
-
-        output.issue_points.clear();
-        if config.diagnostic_markers() {
-            for issue in &active_issues {
-                if !output.issue_points.contains(&issue.point) {
-                    output.issue_points.push(issue.point);
-                }
+        let mut targets = pose.targets();
+        for resolved in self.resolved_targets.iter().copied() {
+            let (name, position) = &self.target_request[resolved.request_index];
+            if let Err(error) = targets.set_skeleton_position(resolved.bone, *position) {
+                self.active_issues.push(ActiveIssue::new(
+                    SpinalIssueKind::ControlTarget,
+                    format!("control target bone `{name}` could not be placed: {error}"),
+                    self.root_point,
+                ));
             }
         }
-        output.ready = frame_ready;
-        output.revision = output.revision.wrapping_add(1);
+    }
 
-        emit_new_issues(entity, previous_issues, &active_issues, &mut issue_messages);
-        for authored_event in authored_events {
-            event_messages.write(authored_event);
+    fn solved(&mut self, mixer: &AnimationMixer, frame: Result<SolvedFrame<'_>, PlayerError>) {
+        let solved = match frame {
+            Ok(solved) => solved,
+            Err(error) => {
+                self.active_issues.push(ActiveIssue::new(
+                    SpinalIssueKind::Player,
+                    error.to_string(),
+                    self.root_point,
+                ));
+                self.output.draws.clear();
+                self.output.issue_points.clear();
+                self.output.ready = false;
+                self.frame_ready = None;
+                return;
+            }
+        };
+        let delta = mixer.root_motion_delta();
+        if let Some(root_motion) = mixer.root_motion()
+            && delta != RootMotionDelta::ZERO
+        {
+            let space = solved
+                .bone_space(root_motion.bone())
+                .expect("root-motion bones are resolved against the active asset");
+            apply_root_motion(&mut self.transform, self.appearance, space, delta);
         }
 
-        let state = if !frame_ready {
-            SpinalInstanceState::Loading
-        } else if active_issues.is_empty() && output.draws.is_empty() {
-            SpinalInstanceState::ReadyNoDraws
-        } else if active_issues.is_empty() {
-            SpinalInstanceState::Ready
-        } else if output.draws.is_empty() {
-            SpinalInstanceState::DegradedNoDraws
-        } else {
-            SpinalInstanceState::Degraded
-        };
-        instance_state.set_if_neq(state);
-        let base_status = mixer.base_track().status();
-        let playback_changed = write_playback_observation(
-            playback_state.bypass_change_detection(),
-            skeleton.asset(),
-            base_status,
-        );
-        if playback_changed {
-            playback_state.set_changed();
+        self.active_issues.append(self.event_issues);
+        append_frame_issues(&solved, &mut self.active_issues);
+        for issue in mixer.active_deferred_properties() {
+            let track = self.track_name(issue.track());
+            let issue = ActiveIssue::new(
+                SpinalIssueKind::UnsupportedOverrideProperty(issue.property()),
+                format!(
+                    "override track `{track}` property {:?} is deferred and was ignored",
+                    issue.property()
+                ),
+                self.root_point,
+            )
+            .with_track(Some(track));
+            self.active_issues.push(issue);
         }
-        let tracks_changed = write_track_observations(
-            track_states.bypass_change_detection(),
-            skeleton.asset(),
-            mixer,
-            track_intents,
+        let frame_ready = write_draws(
+            self.asset,
+            self.images,
+            self.texture_pages,
+            &solved,
+            &mut self.output,
+            &mut self.active_issues,
         );
-        if tracks_changed {
-            track_states.set_changed();
+
+        self.output.issue_points.clear();
+        if self.diagnostic_markers {
+            for issue in &self.active_issues {
+                if !self.output.issue_points.contains(&issue.point) {
+                    self.output.issue_points.push(issue.point);
+                }
+            }
         }
+        self.output.ready = frame_ready;
+        self.output.revision = self.output.revision.wrapping_add(1);
+        self.frame_ready = Some(frame_ready);
     }
 }
 
@@ -832,6 +1133,63 @@ fn apply_override_intent(
     }
 }
 
+fn apply_slot_intent(
+    runtime: &mut SpinalRuntime,
+    slot_overrides: &SpinalSlotOverrides,
+    root_point: Vec2,
+    issues: &mut Vec<ActiveIssue>,
+) {
+    if !runtime.slot_request.iter().eq(slot_overrides.iter()) {
+        runtime.slot_request.clear();
+        runtime.slot_request.extend(slot_overrides.iter().cloned());
+        runtime.resolved_slots.clear();
+        let asset = runtime.skeleton.asset();
+        for (request_index, request) in runtime.slot_request.iter().enumerate() {
+            let Some(slot) = asset.slot_id(request.slot()) else {
+                continue;
+            };
+            runtime.resolved_slots.push(ResolvedSlotEdit {
+                request_index,
+                slot,
+                color: request.color().map(|color| {
+                    let color = color.to_srgba();
+                    spinal::Rgba::new(
+                        unit_channel(color.red),
+                        unit_channel(color.green),
+                        unit_channel(color.blue),
+                        unit_channel(color.alpha),
+                    )
+                    .expect("clamped channels are normalized")
+                }),
+                draw_order: request.draw_order().and_then(|(placement, other)| {
+                    asset.slot_id(other).map(|other| (placement, other))
+                }),
+            });
+        }
+    }
+    for request in &runtime.slot_request {
+        let names = std::iter::once(request.slot())
+            .chain(request.draw_order().map(|(_placement, other)| other));
+        for name in names {
+            if runtime.skeleton.asset().slot_id(name).is_none() {
+                issues.push(ActiveIssue::new(
+                    SpinalIssueKind::MissingSlot,
+                    format!("procedural slot `{name}` does not exist"),
+                    root_point,
+                ));
+            }
+        }
+    }
+}
+
+fn unit_channel(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
 fn apply_target_intent(
     runtime: &mut SpinalRuntime,
     targets: &SpinalControlTargets,
@@ -890,6 +1248,200 @@ fn apply_target_intent(
     }
 }
 
+fn apply_mix_table_intent(
+    runtime: &mut SpinalRuntime,
+    mix_table: Option<&SpinalMixTable>,
+    root_point: Vec2,
+    issues: &mut Vec<ActiveIssue>,
+) {
+    if runtime.mix_table_request.as_ref() != mix_table {
+        runtime.missing_mix_animations.clear();
+        let table = mix_table.map(|request| {
+            let asset = runtime.skeleton.asset();
+            let mut table = spinal::MixTable::new(asset)
+                .with_default_duration(request.default_duration())
+                .with_curve(request.curve());
+            for (from, to, duration) in request.iter() {
+                let from_id = asset.animation_id(from);
+                let to_id = asset.animation_id(to);
+                if let (Some(from_id), Some(to_id)) = (from_id, to_id) {
+                    table
+                        .set_mix(from_id, to_id, duration)
+                        .expect("names resolved against the active asset produce local IDs");
+                    continue;
+                }
+                for (name, id) in [(from, from_id), (to, to_id)] {
+                    if id.is_none()
+                        && !runtime
+                            .missing_mix_animations
+                            .iter()
+                            .any(|missing| missing.as_ref() == name)
+                    {
+                        runtime.missing_mix_animations.push(name.into());
+                    }
+                }
+            }
+            Arc::new(table)
+        });
+        runtime
+            .mixer
+            .base_track_mut()
+            .set_mix_table(table.clone())
+            .expect("the mix table was built against the active asset");
+        for intent in &runtime.track_intents {
+            runtime
+                .mixer
+                .track_mut(intent.track)
+                .expect("cached track IDs belong to the active mixer")
+                .set_mix_table(table.clone())
+                .expect("the mix table was built against the active asset");
+        }
+        runtime.mix_table = table;
+        runtime.mix_table_request = mix_table.cloned();
+    }
+    for name in &runtime.missing_mix_animations {
+        issues.push(ActiveIssue::new(
+            SpinalIssueKind::MissingAnimation,
+            format!("mix table animation `{name}` does not exist"),
+            root_point,
+        ));
+    }
+}
+
+fn apply_texture_intent(
+    runtime: &mut SpinalRuntime,
+    texture_overrides: Option<&SpinalTextureOverrides>,
+    root_point: Vec2,
+    issues: &mut Vec<ActiveIssue>,
+) {
+    if runtime.texture_request.as_ref() != texture_overrides {
+        for attachment in runtime.textured_attachments.drain(..) {
+            runtime
+                .skeleton
+                .clear_texture_override(attachment)
+                .expect("cached attachment IDs belong to the active skeleton");
+        }
+        runtime.texture_pages.clear();
+        runtime.texture_issues.clear();
+        let asset = Arc::clone(runtime.skeleton.asset_handle());
+        for request in texture_overrides
+            .into_iter()
+            .flat_map(SpinalTextureOverrides::iter)
+        {
+            let key = spinal::CustomPageKey::new(runtime.texture_pages.len() as u64);
+            let uv_rect = request.uv_rect();
+            let Ok(texture) = spinal::TextureOverride::new(key, uv_rect.min, uv_rect.max) else {
+                runtime.texture_issues.push(
+                    format!(
+                        "texture override for attachment `{}` has a non-finite UV rectangle",
+                        request.attachment()
+                    )
+                    .into(),
+                );
+                continue;
+            };
+            let Some(slot) = asset.slot_id(request.slot()) else {
+                runtime.texture_issues.push(
+                    format!("texture override slot `{}` does not exist", request.slot()).into(),
+                );
+                continue;
+            };
+            let mut matched = false;
+            for skin in asset.skins() {
+                let Some(attachment) = skin
+                    .attachment(slot, request.attachment())
+                    .expect("resolved slot IDs belong to the active asset")
+                else {
+                    continue;
+                };
+                let applied = runtime.skeleton.set_texture_override(attachment, texture);
+                if applied == Err(spinal::TextureOverrideError::Untextured) {
+                    continue;
+                }
+                applied.expect("resolved attachment IDs belong to the active asset");
+                matched = true;
+                runtime.textured_attachments.push(attachment);
+            }
+            if !matched {
+                runtime.texture_issues.push(
+                    format!(
+                        "texture override attachment `{}` on slot `{}` is not a region or mesh",
+                        request.attachment(),
+                        request.slot()
+                    )
+                    .into(),
+                );
+            }
+            runtime.texture_pages.push(request.image().id());
+        }
+        runtime.texture_request = texture_overrides.cloned();
+    }
+    for message in &runtime.texture_issues {
+        issues.push(ActiveIssue::new(
+            SpinalIssueKind::TextureOverride,
+            message.as_ref(),
+            root_point,
+        ));
+    }
+}
+
+fn apply_root_motion_intent(
+    runtime: &mut SpinalRuntime,
+    root_motion: Option<&SpinalRootMotion>,
+    root_point: Vec2,
+    issues: &mut Vec<ActiveIssue>,
+) {
+    if runtime.root_motion_request.as_ref() != root_motion {
+        let configured = root_motion.and_then(|request| {
+            let bone = runtime.skeleton.asset().bone_id(request.bone())?;
+            Some(RootMotion::new(bone).with_zero_in_pose(request.zero_in_pose()))
+        });
+        runtime
+            .mixer
+            .set_root_motion(configured)
+            .expect("root-motion bones are resolved against the active asset");
+        runtime.root_motion_request = root_motion.cloned();
+    }
+    if let Some(request) = root_motion
+        && runtime.mixer.root_motion().is_none()
+    {
+        issues.push(ActiveIssue::new(
+            SpinalIssueKind::MissingBone,
+            format!("root-motion bone `{}` does not exist", request.bone()),
+            root_point,
+        ));
+    }
+}
+
+/// Moves the entity by one root-motion delta. The delta is authored in the
+/// bone's parent space, so it is first carried into skeleton space through the
+/// solved parent transform; a mirrored parent also reverses the turn. Facing is
+/// honored next so a mirrored walk still travels the way it faces.
+fn apply_root_motion(
+    transform: &mut Transform,
+    appearance: &SpinalAppearance,
+    space: BoneSpace,
+    delta: RootMotionDelta,
+) {
+    let mut translation = space.parent_to_world_vector(delta.translation());
+    let mut rotation = delta.rotation().as_radians();
+    if space.parent_world_transform().determinant() < 0.0 {
+        rotation = -rotation;
+    }
+    if appearance.flip_x() {
+        translation.x = -translation.x;
+    }
+    if appearance.flip_y() {
+        translation.y = -translation.y;
+    }
+    if appearance.flip_x() != appearance.flip_y() {
+        rotation = -rotation;
+    }
+    let offset = transform.rotation * (transform.scale * translation.extend(0.0));
+    transform.translation += offset;
+    transform.rotate_local_z(rotation);
+}
+
 fn apply_animation_intent(
     runtime: &mut SpinalRuntime,
     animator: &SpinalAnimator,
@@ -926,12 +1478,14 @@ fn apply_animation_intent(
         runtime.animation_seek = None;
         match (desired_id, animator.mode()) {
             (Some(animation), Some(mode)) => {
-                let options = match mode {
+                let mut options = match mode {
                     PlaybackMode::Once => PlayOptions::once(),
                     PlaybackMode::Loop => PlayOptions::looping(),
                     _other => PlayOptions::once(),
+                };
+                if let Some(transition) = animator.requested_transition() {
+                    options = options.with_transition(transition);
                 }
-                .with_transition(animator.transition());
                 runtime
                     .mixer
                     .base_track_mut()
@@ -989,6 +1543,7 @@ fn apply_track_intent(
         mixer,
         track_intents,
         track_weight_seeds,
+        mix_table,
         ..
     } = runtime;
 
@@ -1033,6 +1588,11 @@ fn apply_track_intent(
                 let track = mixer
                     .insert_track(TrackOptions::override_track().with_weight(initial_weight))
                     .expect("a Bevy instance cannot exhaust core track identities");
+                mixer
+                    .track_mut(track)
+                    .expect("the inserted track belongs to the active mixer")
+                    .set_mix_table(mix_table.clone())
+                    .expect("the mix table was built against the active asset");
                 track_intents.push(CachedTrackIntent {
                     key: intent.key.clone(),
                     namespace: Arc::clone(tracks.namespace()),
@@ -1109,12 +1669,14 @@ fn apply_track_intent(
         }
         match (&intent.desired, desired_id) {
             (Some(desired), Some(animation)) => {
-                let options = match desired.mode {
+                let mut options = match desired.mode {
                     PlaybackMode::Once => PlayOptions::once(),
                     PlaybackMode::Loop => PlayOptions::looping(),
                     _other => PlayOptions::once(),
+                };
+                if let Some(transition) = desired.transition {
+                    options = options.with_transition(transition);
                 }
-                .with_transition(desired.transition);
                 track
                     .play(animation, options)
                     .expect("resolved animation IDs belong to the active mixer");
@@ -1182,6 +1744,7 @@ fn append_frame_issues(frame: &spinal::SolvedFrame<'_>, issues: &mut Vec<ActiveI
 fn write_draws(
     asset: &SpinalAsset,
     images: &Assets<Image>,
+    texture_pages: &[AssetId<Image>],
     solved: &spinal::SolvedFrame<'_>,
     output: &mut SpinalFrame,
     issues: &mut Vec<ActiveIssue>,
@@ -1191,10 +1754,11 @@ fn write_draws(
     output.indices.clear();
     let mut ready = true;
     for draw in solved.draw_items() {
-        let (page_id, region_id, slot_id, blend_mode, color) = match draw {
+        let (page_id, region_id, texture, slot_id, blend_mode, color) = match draw {
             DrawItemRef::Region(region) => (
                 region.atlas_page(),
                 region.atlas_region(),
+                region.texture_override(),
                 region.slot(),
                 region.blend_mode(),
                 region.color(),
@@ -1202,36 +1766,49 @@ fn write_draws(
             DrawItemRef::Mesh(mesh) => (
                 mesh.atlas_page(),
                 mesh.atlas_region(),
+                mesh.texture_override(),
                 mesh.slot(),
                 mesh.blend_mode(),
                 mesh.color(),
             ),
             _future => continue,
         };
-        let page = solved
-            .asset()
-            .atlas_page(page_id)
-            .expect("draw page IDs belong to the solved asset");
-        let Some(bevy_page) = asset.page(page.ordinal()) else {
-            ready = false;
-            issues.push(ActiveIssue::new(
-                SpinalIssueKind::MissingAtlasPage,
-                format!("atlas page {} is not linked to a Bevy image", page.name()),
-                Vec2::ZERO,
-            ));
-            continue;
-        };
-        let Some(image) = images.get(bevy_page.image()) else {
-            ready = false;
-            continue;
+        let (draw_page, image) = if let Some(texture) = texture {
+            let image_id = *texture_pages
+                .get(texture.page().get() as usize)
+                .expect("custom texture pages are registered with their overrides");
+            // A custom image still loading hides only its own attachment.
+            let Some(image) = images.get(image_id) else {
+                continue;
+            };
+            (SpinalDrawPage::Custom(image_id), image)
+        } else {
+            let page = solved
+                .asset()
+                .atlas_page(page_id)
+                .expect("draw page IDs belong to the solved asset");
+            let Some(bevy_page) = asset.page(page.ordinal()) else {
+                ready = false;
+                issues.push(ActiveIssue::new(
+                    SpinalIssueKind::MissingAtlasPage,
+                    format!("atlas page {} is not linked to a Bevy image", page.name()),
+                    Vec2::ZERO,
+                ));
+                continue;
+            };
+            let Some(image) = images.get(bevy_page.image()) else {
+                ready = false;
+                continue;
+            };
+            if page.alpha_encoding() != spinal::AlphaEncoding::Straight {
+                continue;
+            }
+            (SpinalDrawPage::Atlas(page.ordinal()), image)
         };
         let atlas_region = solved
             .asset()
             .atlas_region(region_id)
             .expect("draw region IDs belong to the solved asset");
-        if page.alpha_encoding() != spinal::AlphaEncoding::Straight {
-            continue;
-        }
         if blend_mode != SlotBlendMode::Normal {
             let slot_point = solved
                 .asset()
@@ -1333,7 +1910,7 @@ fn write_draws(
         }
         let index_end = output.indices.len();
         output.draws.push(SpinalDraw {
-            page_ordinal: page.ordinal(),
+            page: draw_page,
             vertices: vertex_start..vertex_end,
             indices: index_start..index_end,
             color: color.to_array(),
@@ -1963,6 +2540,67 @@ mod tests {
         assert_eq!(allocations.bytes_total, 0);
     }
 
+    #[test]
+    fn instances_beyond_one_batch_chunk_update_without_allocating() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), SpinalPlugin));
+        let skeleton = load_json(
+            br#"{
+              "skeleton":{"spine":"4.3.23"},
+              "bones":[{"name":"root"}],
+              "slots":[{"name":"body","bone":"root","attachment":"body"}],
+              "skins":[{"name":"default","attachments":{"body":{"body":{"width":2,"height":2}}}}],
+              "animations":{
+                "idle":{"bones":{"root":{"rotate":[{"value":0},{"time":1,"value":5}]}}}
+              }
+            }"#,
+            b"cat.png\n\tsize:1,1\nbody\n\tbounds:0,0,1,1\n",
+        )
+        .expect("the batch fixture is valid")
+        .into_asset();
+        let image = app
+            .world_mut()
+            .resource_mut::<Assets<Image>>()
+            .add(Image::default());
+        let asset = SpinalAsset::new(skeleton, vec![SpinalAtlasPage::new("cat.png", image)])
+            .expect("the manual image matches the atlas");
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<SpinalAsset>>()
+            .add(asset);
+        let entities = (0..BATCH_CHUNK * 2 + 3)
+            .map(|_| {
+                app.world_mut()
+                    .spawn((
+                        SpinalInstance::new(handle.clone()),
+                        SpinalAnimator::looping("idle"),
+                    ))
+                    .id()
+            })
+            .collect::<Vec<_>>();
+
+        app.update();
+        app.update();
+
+        for entity in &entities {
+            assert_eq!(
+                app.world().entity(*entity).get::<SpinalInstanceState>(),
+                Some(&SpinalInstanceState::Ready)
+            );
+        }
+        let mut animate = IntoSystem::into_system(update_instances);
+        animate.initialize(app.world_mut());
+        animate
+            .run((), app.world_mut())
+            .expect("the batched adapter system warms successfully");
+        let allocations = allocation_counter::measure(|| {
+            animate
+                .run((), app.world_mut())
+                .expect("the batched adapter system remains valid");
+        });
+        assert_eq!(allocations.count_total, 0);
+    }
+
     #[test]
     fn omitted_page_size_uses_actual_image_dimensions() {
         let bounds = spinal::PixelRect::new(10, 20, 30, 40);
@@ -11,21 +11,23 @@ use std::{
 use bevy::{
     app::TaskPoolPlugin,
     asset::{
-        AssetApp, AssetPlugin, AssetServer, Assets, LoadState, RenderAssetUsages,
+        AssetApp, AssetPlugin, AssetServer, Assets, LoadState, RecursiveDependencyLoadState,
+        RenderAssetUsages,
         io::{
             AssetSourceBuilder, AssetSourceEvent, AssetSourceId, AssetWatcher,
             memory::{Dir, MemoryAssetReader},
         },
     },
     image::{
-        CompressedImageFormats, Image, ImageAddressMode, ImageFilterMode, ImagePlugin,
+        CompressedImageFormats, Image, ImageAddressMode, ImageFilterMode, ImageLoader, ImagePlugin,
         ImageSampler, ImageType,
     },
     prelude::{App, MinimalPlugins},
+    reflect::TypePath,
 };
 use bevy_spinal::{
-    SpinalAsset, SpinalAssetLoader, SpinalAssetLoaderSettings, SpinalInstance, SpinalInstanceState,
-    SpinalPlugin,
+    SpinalAsset, SpinalAssetLoader, SpinalAssetLoaderSettings, SpinalCacheLoader, SpinalInstance,
+    SpinalInstanceState, SpinalPlugin,
 };
 use spinal::{DiagnosticCode, DrawItemRef, Skeleton};
 
@@ -87,7 +89,7 @@ fn typed_plain_json_load_can_select_an_explicit_relative_atlas() {
         ImagePlugin::default(),
     ))
     .init_asset::<SpinalAsset>()
-    .register_asset_loader(SpinalAssetLoader);
+    .register_asset_loader(SpinalAssetLoader::default());
 
     let asset_server = app.world().resource::<AssetServer>().clone();
     let handle = asset_server.load_with_settings::<SpinalAsset, SpinalAssetLoaderSettings>(
@@ -122,6 +124,273 @@ fn typed_plain_json_load_can_select_an_explicit_relative_atlas() {
     );
 }
 
+#[test]
+fn further_atlas_paths_link_in_order_and_load_pages_beside_their_atlas() {
+    let files = Dir::default();
+    files.insert_asset_text(
+        Path::new("cats/tabby.spine.json"),
+        r#"{
+  "skeleton": { "spine": "4.3.23" },
+  "bones": [{ "name": "root" }],
+  "slots": [{ "name": "hat", "bone": "root", "attachment": "hat" }],
+  "skins": [{
+    "name": "default",
+    "attachments": { "hat": { "hat": { "path": "cosmetics/hat", "width": 1, "height": 1 } } }
+  }]
+}"#,
+    );
+    files.insert_asset_text(Path::new("cats/tabby.atlas"), NEAREST_ATLAS);
+    files.insert_asset(Path::new("cats/cat.png"), PIXEL_PNG.to_vec());
+    files.insert_asset_text(
+        Path::new("shared/cosmetics.atlas"),
+        "\
+cosmetics.png
+	size: 1, 1
+cosmetics/hat
+	bounds: 0, 0, 1, 1
+",
+    );
+    files.insert_asset(Path::new("shared/cosmetics.png"), PIXEL_PNG.to_vec());
+
+    let memory_reader = MemoryAssetReader { root: files };
+    let mut app = App::new();
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSourceBuilder::new(move || Box::new(memory_reader.clone())),
+    )
+    .add_plugins((
+        TaskPoolPlugin::default(),
+        AssetPlugin {
+            watch_for_changes_override: Some(false),
+            use_asset_processor_override: Some(false),
+            ..Default::default()
+        },
+        ImagePlugin::default(),
+    ))
+    .init_asset::<SpinalAsset>()
+    .register_asset_loader(SpinalAssetLoader::default());
+
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let handle = asset_server.load_with_settings::<SpinalAsset, SpinalAssetLoaderSettings>(
+        "cats/tabby.spine.json",
+        |settings| {
+            settings.atlas_paths = vec!["../shared/cosmetics.atlas".to_owned()];
+        },
+    );
+
+    update_until(&mut app, |app| match asset_server.load_state(&handle) {
+        LoadState::Failed(error) => panic!("multi-atlas load failed: {error}"),
+        LoadState::Loaded => app
+            .world()
+            .resource::<Assets<SpinalAsset>>()
+            .get(&handle)
+            .is_some(),
+        LoadState::NotLoaded | LoadState::Loading => false,
+    });
+
+    let assets = app.world().resource::<Assets<SpinalAsset>>();
+    let asset = assets.get(&handle).expect("multi-atlas asset is retained");
+    assert_eq!(asset.skeleton().atlases().len(), 2);
+    assert_eq!(
+        asset
+            .pages()
+            .iter()
+            .map(|page| page.source_path().map(ToString::to_string))
+            .collect::<Vec<_>>(),
+        [
+            Some("cats/cat.png".to_owned()),
+            Some("shared/cosmetics.png".to_owned())
+        ]
+    );
+    assert!(
+        asset
+            .diagnostics()
+            .iter()
+            .all(|diagnostic| diagnostic.code() != DiagnosticCode::ShadowedAtlasRegion)
+    );
+}
+
+#[test]
+fn skeletons_linking_one_shared_atlas_share_its_parse_and_page_images() {
+    const HAT_SKELETON_JSON: &str = r#"{
+  "skeleton": { "spine": "4.3.23" },
+  "bones": [{ "name": "root" }],
+  "slots": [{ "name": "hat", "bone": "root", "attachment": "hat" }],
+  "skins": [{
+    "name": "default",
+    "attachments": { "hat": { "hat": { "path": "cosmetics/hat", "width": 1, "height": 1 } } }
+  }]
+}"#;
+    let files = Dir::default();
+    for cat in ["tabby", "calico"] {
+        files.insert_asset_text(
+            Path::new(&format!("cats/{cat}.spine.json")),
+            HAT_SKELETON_JSON,
+        );
+        files.insert_asset_text(Path::new(&format!("cats/{cat}.atlas")), NEAREST_ATLAS);
+    }
+    files.insert_asset(Path::new("cats/cat.png"), PIXEL_PNG.to_vec());
+    files.insert_asset_text(
+        Path::new("shared/cosmetics.atlas"),
+        "\
+cosmetics.png
+	size: 1, 1
+	filter: Nearest, Nearest
+cosmetics/hat
+	bounds: 0, 0, 1, 1
+",
+    );
+    files.insert_asset(Path::new("shared/cosmetics.png"), PIXEL_PNG.to_vec());
+
+    let memory_reader = MemoryAssetReader { root: files };
+    let mut app = App::new();
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSourceBuilder::new(move || Box::new(memory_reader.clone())),
+    )
+    .add_plugins((
+        TaskPoolPlugin::default(),
+        AssetPlugin {
+            watch_for_changes_override: Some(false),
+            use_asset_processor_override: Some(false),
+            ..Default::default()
+        },
+        ImagePlugin::default(),
+    ))
+    .init_asset::<SpinalAsset>()
+    .register_asset_loader(SpinalAssetLoader::default())
+    .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE));
+
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let handles = ["cats/tabby.spine.json", "cats/calico.spine.json"].map(|path| {
+        asset_server.load_with_settings::<SpinalAsset, SpinalAssetLoaderSettings>(
+            path,
+            |settings| {
+                settings.atlas_paths = vec!["../shared/cosmetics.atlas".to_owned()];
+            },
+        )
+    });
+
+    update_until(&mut app, |app| {
+        handles.iter().all(
+            |handle| match asset_server.recursive_dependency_load_state(handle) {
+                RecursiveDependencyLoadState::Failed(error) => {
+                    panic!("shared-atlas load failed: {error}")
+                }
+                RecursiveDependencyLoadState::Loaded => app
+                    .world()
+                    .resource::<Assets<SpinalAsset>>()
+                    .get(handle)
+                    .is_some(),
+                _ => false,
+            },
+        )
+    });
+
+    let assets = app.world().resource::<Assets<SpinalAsset>>();
+    let [tabby, calico] =
+        handles.map(|handle| assets.get(&handle).expect("each skeleton is retained"));
+    assert!(Arc::ptr_eq(
+        &tabby.skeleton().atlases()[1],
+        &calico.skeleton().atlases()[1]
+    ));
+    let shared_page = |asset: &SpinalAsset| asset.page(1).expect("shared page").image().clone();
+    assert_eq!(shared_page(tabby), shared_page(calico));
+    assert_ne!(
+        tabby.page(0).expect("own page").image(),
+        calico.page(0).expect("own page").image()
+    );
+    let images = app.world().resource::<Assets<Image>>();
+    let image = images
+        .get(&shared_page(tabby))
+        .expect("the shared page image is loaded");
+    assert_sampler(
+        image,
+        ImageFilterMode::Nearest,
+        ImageFilterMode::Nearest,
+        ImageAddressMode::ClampToEdge,
+        ImageAddressMode::ClampToEdge,
+    );
+}
+
+#[test]
+fn processed_skeleton_cache_loads_with_its_atlas_pages() {
+    let report = spinal::load_json(SKELETON_JSON.as_bytes(), NEAREST_ATLAS.as_bytes())
+        .expect("the fixture loads");
+    let files = Dir::default();
+    files.insert_asset(
+        Path::new("cat.spine.json"),
+        spinal::write_cache(report.asset()),
+    );
+    files.insert_meta_text(
+        Path::new("cat.spine.json"),
+        &format!(
+            r#"(
+    meta_format_version: "1.0",
+    asset: Load(
+        loader: "{}",
+        settings: (atlas_path: None),
+    ),
+)"#,
+            SpinalCacheLoader::type_path()
+        ),
+    );
+    files.insert_asset_text(Path::new("cat.atlas"), NEAREST_ATLAS);
+    files.insert_asset(Path::new("cat.png"), PIXEL_PNG.to_vec());
+
+    let memory_reader = MemoryAssetReader { root: files };
+    let mut app = App::new();
+    app.register_asset_source(
+        AssetSourceId::Default,
+        AssetSourceBuilder::new(move || Box::new(memory_reader.clone())),
+    )
+    .add_plugins((
+        TaskPoolPlugin::default(),
+        AssetPlugin {
+            watch_for_changes_override: Some(false),
+            use_asset_processor_override: Some(false),
+            ..Default::default()
+        },
+        ImagePlugin::default(),
+    ))
+    .init_asset::<SpinalAsset>()
+    .register_asset_loader(SpinalAssetLoader::default())
+    .register_asset_loader(SpinalCacheLoader);
+
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let handle = asset_server.load::<SpinalAsset>("cat.spine.json");
+
+    update_until(&mut app, |app| match asset_server.load_state(&handle) {
+        LoadState::Failed(error) => panic!("cached skeleton load failed: {error}"),
+        LoadState::Loaded => app
+            .world()
+            .resource::<Assets<SpinalAsset>>()
+            .get(&handle)
+            .is_some(),
+        LoadState::NotLoaded | LoadState::Loading => false,
+    });
+
+    let assets = app.world().resource::<Assets<SpinalAsset>>();
+    let asset = assets.get(&handle).expect("cached asset is retained");
+    assert_eq!(
+        asset
+            .skeleton()
+            .bones()
+            .map(|bone| bone.name())
+            .collect::<Vec<_>>(),
+        ["root"]
+    );
+    assert_eq!(
+        asset
+            .page(0)
+            .expect("one page")
+            .source_path()
+            .map(ToString::to_string)
+            .as_deref(),
+        Some("cat.png")
+    );
+}
+
 #[test]
 fn mesh_without_positive_atlas_bounds_fails_before_it_can_report_ready() {
     let files = Dir::default();
@@ -222,7 +491,7 @@ fn compound_load_and_atlas_hot_reload_are_atomic_and_keep_page_handles_stable()
         ImagePlugin::default(),
     ))
     .init_asset::<SpinalAsset>()
-    .register_asset_loader(SpinalAssetLoader);
+    .register_asset_loader(SpinalAssetLoader::default());
 
     let source_events = watcher_receiver
         .recv_timeout(Duration::from_secs(1))
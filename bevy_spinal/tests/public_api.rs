@@ -9,9 +9,9 @@ use bevy::{
 };
 use bevy_spinal::{
     BoneOverride, SpinalAnimationTracks, SpinalAnimator, SpinalAppearance, SpinalAsset,
-    SpinalControlTargets, SpinalInstance, SpinalPoseOverrides, SpinalSkinLayers, SpinalTrackStates,
-    TrackReorderError, WorldToSkeletonPositionError,
-    spinal::{BoneTransform, Mix, PlaybackMode, Transition, WeightFade},
+    SpinalControlTargets, SpinalInstance, SpinalMixTable, SpinalPoseOverrides, SpinalSkinLayers,
+    SpinalTrackStates, TrackReorderError, WorldToSkeletonPositionError,
+    spinal::{BoneTransform, Mix, MixCurve, PlaybackMode, Transition, WeightFade},
 };
 
 #[test]
@@ -292,3 +292,29 @@ fn appearance_rejects_singular_or_nonfinite_world_position_conversions() {
         Err(WorldToSkeletonPositionError::InvalidEntityTransform)
     );
 }
+
+#[test]
+fn mix_table_replaces_pairs_by_ordered_name() {
+    let mut table = SpinalMixTable::new(Duration::from_millis(200))
+        .with_curve(MixCurve::SmoothStep)
+        .with_mix("idle", "walk", Duration::from_millis(100))
+        .with_mix("walk", "idle", Duration::from_millis(300));
+    table.set_mix("idle", "walk", Duration::from_millis(150));
+
+    assert_eq!(table.curve(), MixCurve::SmoothStep);
+    assert_eq!(table.mix("idle", "walk"), Duration::from_millis(150));
+    assert_eq!(table.mix("walk", "idle"), Duration::from_millis(300));
+    assert_eq!(table.mix("idle", "jump"), Duration::from_millis(200));
+    assert_eq!(
+        table.iter().collect::<Vec<_>>(),
+        [
+            ("idle", "walk", Duration::from_millis(150)),
+            ("walk", "idle", Duration::from_millis(300)),
+        ]
+    );
+    assert_eq!(
+        table.remove_mix("walk", "idle"),
+        Some(Duration::from_millis(300))
+    );
+    assert_eq!(table.remove_mix("walk", "idle"), None);
+}
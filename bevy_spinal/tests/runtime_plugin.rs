@@ -6,14 +6,15 @@ use bevy::{
     asset::{AssetPlugin, Assets, Handle, uuid::Uuid},
     ecs::message::Messages,
     image::Image,
-    prelude::{App, MinimalPlugins},
+    prelude::{App, MinimalPlugins, Quat, Transform, Vec3},
     time::TimeUpdateStrategy,
 };
 use bevy_spinal::{
-    BoneOverride, SpinalAnimationEvent, SpinalAnimationTracks, SpinalAnimator, SpinalAsset,
-    SpinalAtlasPage, SpinalControlTargets, SpinalInstance, SpinalInstanceState, SpinalIssue,
-    SpinalIssueKind, SpinalPlaybackState, SpinalPlugin, SpinalPoseOverrides, SpinalSkinLayers,
-    SpinalTrackStates,
+    BoneOverride, SlotDrawPlacement, SlotOverride, SpinalAnimationEvent, SpinalAnimationTracks,
+    SpinalAnimator, SpinalAppearance, SpinalAsset, SpinalAtlasPage, SpinalControlTargets,
+    SpinalInstance, SpinalInstanceState, SpinalIssue, SpinalIssueKind, SpinalMixTable,
+    SpinalPlaybackState, SpinalPlugin, SpinalPoseOverrides, SpinalRootMotion, SpinalSkinLayers,
+    SpinalSlotOverrides, SpinalTrackStates,
     spinal::{DiagnosticCode, Mix, SlotBlendMode, WeightFade, glam::Vec2},
 };
 use spinal::{Angle, BoneTransform, Crossfade, PlaybackMode, Shear, Transition, load_json};
@@ -818,6 +819,246 @@ fn ecs_crossfade_observation_and_owned_events_compose_with_skin_and_override_int
     );
 }
 
+#[test]
+fn mix_table_component_chooses_transitions_for_mixed_play_requests() {
+    let mut app = headless_app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let asset_handle = add_asset(&mut app, JSON);
+    let mut tracks = SpinalAnimationTracks::default();
+    tracks.play("aim", "idle", PlaybackMode::Loop, Transition::Immediate);
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpinalInstance::new(asset_handle),
+            SpinalAnimator::looping("idle"),
+            tracks,
+            SpinalMixTable::new(Duration::from_millis(800))
+                .with_mix("idle", "eat", Duration::from_millis(400))
+                .with_mix("idle", "swim", Duration::from_millis(200)),
+        ))
+        .id();
+    let mut issue_cursor = app
+        .world()
+        .resource::<Messages<SpinalIssue>>()
+        .get_cursor_current();
+
+    app.update();
+    app.update();
+    let messages = app.world().resource::<Messages<SpinalIssue>>();
+    assert!(issue_cursor.read(messages).any(|issue| {
+        issue.entity() == entity
+            && issue.kind() == SpinalIssueKind::MissingAnimation
+            && issue.message().contains("`swim`")
+    }));
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::Degraded)
+    );
+
+    let mut entity_mut = app.world_mut().entity_mut(entity);
+    entity_mut
+        .get_mut::<SpinalAnimator>()
+        .expect("required animator exists")
+        .play_mixed("eat", PlaybackMode::Once);
+    entity_mut
+        .get_mut::<SpinalAnimationTracks>()
+        .expect("required tracks exist")
+        .play_mixed("aim", "eat", PlaybackMode::Once);
+    entity_mut
+        .get_mut::<SpinalMixTable>()
+        .expect("mix table exists")
+        .remove_mix("idle", "swim");
+    app.update();
+
+    let base_mix = app
+        .world()
+        .entity(entity)
+        .get::<SpinalPlaybackState>()
+        .and_then(SpinalPlaybackState::transition_mix)
+        .expect("the table crossfades the base track")
+        .get();
+    assert!((base_mix - 0.25).abs() < 1.0e-6);
+    let track_mix = app
+        .world()
+        .entity(entity)
+        .get::<SpinalTrackStates>()
+        .and_then(|tracks| tracks.get("aim"))
+        .and_then(|track| track.playback().transition_mix())
+        .expect("the table crossfades named tracks")
+        .get();
+    assert!((track_mix - 0.25).abs() < 1.0e-6);
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::Ready),
+        "removing the unresolved pair clears its issue"
+    );
+
+    app.world_mut()
+        .entity_mut(entity)
+        .get_mut::<SpinalAnimator>()
+        .expect("required animator exists")
+        .play("idle", PlaybackMode::Loop, Transition::Immediate);
+    app.update();
+    assert_eq!(
+        app.world()
+            .entity(entity)
+            .get::<SpinalPlaybackState>()
+            .and_then(SpinalPlaybackState::transition_mix),
+        None,
+        "an explicit transition bypasses the table"
+    );
+}
+
+#[test]
+fn root_motion_component_moves_the_entity_through_facing_rotation_and_scale() {
+    let mut app = headless_app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let asset_handle = add_asset(&mut app, JSON);
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpinalInstance::new(asset_handle),
+            SpinalAnimator::looping("idle"),
+            SpinalAppearance::default().with_flip_x(true),
+            Transform::from_scale(Vec3::splat(2.0))
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+            SpinalRootMotion::new("root"),
+        ))
+        .id();
+    let mut issue_cursor = app
+        .world()
+        .resource::<Messages<SpinalIssue>>()
+        .get_cursor_current();
+
+    app.update();
+    app.update();
+    let translation = |app: &App| {
+        app.world()
+            .entity(entity)
+            .get::<Transform>()
+            .expect("required transform exists")
+            .translation
+    };
+    let before = translation(&app);
+    app.update();
+    // One 100 ms step of `idle` moves the root one tenth of a unit along +X;
+    // facing mirrors it, scale doubles it, and the quarter turn maps it to -Y.
+    let step = translation(&app) - before;
+    assert!(
+        (step - Vec3::new(0.0, -0.2, 0.0)).length() < 1.0e-4,
+        "unexpected step {step:?}"
+    );
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::Ready)
+    );
+
+    app.world_mut()
+        .entity_mut(entity)
+        .get_mut::<SpinalRootMotion>()
+        .expect("root motion exists")
+        .set_bone("tail");
+    app.update();
+    let messages = app.world().resource::<Messages<SpinalIssue>>();
+    assert!(issue_cursor.read(messages).any(|issue| {
+        issue.entity() == entity
+            && issue.kind() == SpinalIssueKind::MissingBone
+            && issue.message().contains("`tail`")
+    }));
+    let before = translation(&app);
+    app.update();
+    assert_eq!(
+        translation(&app),
+        before,
+        "an unresolved bone moves nothing"
+    );
+
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<SpinalRootMotion>();
+    app.update();
+    assert_eq!(translation(&app), before);
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::Ready)
+    );
+}
+
+#[test]
+fn root_motion_of_a_child_bone_follows_its_rotated_and_mirrored_parent() {
+    const CHILD_ROOT_MOTION: &[u8] = br#"{
+      "skeleton": { "spine": "4.3.23" },
+      "bones": [
+        { "name": "root", "rotation": 90, "scaleX": 2, "scaleY": -2 },
+        { "name": "hip", "parent": "root" }
+      ],
+      "slots": [{ "name": "body", "bone": "root", "attachment": "body" }],
+      "skins": [
+        {
+          "name": "default",
+          "attachments": { "body": { "body": { "width": 32, "height": 32 } } }
+        }
+      ],
+      "animations": {
+        "walk": {
+          "bones": {
+            "hip": {
+              "translate": [{ "x": 0, "y": 0 }, { "time": 1, "x": 1, "y": 0 }],
+              "rotate": [{ "value": 0 }, { "time": 1, "value": 10 }]
+            }
+          }
+        }
+      }
+    }"#;
+    let mut app = headless_app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let asset_handle = add_asset(&mut app, CHILD_ROOT_MOTION);
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpinalInstance::new(asset_handle),
+            SpinalAnimator::looping("walk"),
+            SpinalRootMotion::new("hip"),
+        ))
+        .id();
+
+    app.update();
+    app.update();
+    let transform = |app: &App| {
+        *app.world()
+            .entity(entity)
+            .get::<Transform>()
+            .expect("required transform exists")
+    };
+    let before = transform(&app);
+    app.update();
+    let after = transform(&app);
+    // One 100 ms step moves the hip a tenth of a unit along its parent's X
+    // axis, which the parent turns to +Y and doubles before the entity's own
+    // accumulated turn applies; the parent's mirror reverses the one-degree
+    // turn.
+    let step = after.translation - before.translation;
+    assert!(
+        (step - before.rotation * Vec3::new(0.0, 0.2, 0.0)).length() < 1.0e-4,
+        "unexpected step {step:?}"
+    );
+    let turn = after.rotation * before.rotation.inverse();
+    assert!(
+        turn.abs_diff_eq(Quat::from_rotation_z(-1.0_f32.to_radians()), 1.0e-5),
+        "unexpected turn {turn:?}"
+    );
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::Ready)
+    );
+}
+
 #[test]
 fn named_override_tracks_mix_emit_identity_and_publish_observation() {
     let mut app = headless_app();
@@ -1596,6 +1837,65 @@ fn unresolved_public_intent_degrades_without_preventing_a_frame() {
     );
 }
 
+#[test]
+fn slot_overrides_edit_the_draw_stream_by_slot_name() {
+    let mut app = headless_app();
+    let mut issue_cursor = app
+        .world()
+        .resource::<Messages<SpinalIssue>>()
+        .get_cursor_current();
+    let asset_handle = add_asset(&mut app, JSON);
+    let mut slots = SpinalSlotOverrides::default();
+    slots.set(SlotOverride::new("body").hidden());
+    let entity = app
+        .world_mut()
+        .spawn((SpinalInstance::new(asset_handle), slots))
+        .id();
+
+    app.update();
+    app.update();
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::ReadyNoDraws),
+        "the only slot is hidden"
+    );
+
+    let mut slots = SpinalSlotOverrides::default();
+    slots.set(
+        SlotOverride::new("body")
+            .with_attachment("missing/tail")
+            .with_draw_order(SlotDrawPlacement::InFrontOf, "missing/bowl"),
+    );
+    app.world_mut().entity_mut(entity).insert(slots);
+    app.update();
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::Degraded),
+        "a rejected edit keeps the animated attachment"
+    );
+    let messages = app.world().resource::<Messages<SpinalIssue>>();
+    let kinds = issue_cursor
+        .read(messages)
+        .filter(|issue| issue.entity() == entity)
+        .map(SpinalIssue::kind)
+        .collect::<Vec<_>>();
+    assert!(kinds.contains(&SpinalIssueKind::MissingSlot));
+    assert!(kinds.contains(&SpinalIssueKind::MissingAttachment));
+
+    let mut slots = SpinalSlotOverrides::default();
+    slots.set(
+        SlotOverride::new("body")
+            .with_attachment("body")
+            .with_alpha(0.5),
+    );
+    app.world_mut().entity_mut(entity).insert(slots);
+    app.update();
+    assert_eq!(
+        app.world().entity(entity).get::<SpinalInstanceState>(),
+        Some(&SpinalInstanceState::Ready)
+    );
+}
+
 #[test]
 fn failed_control_target_reports_its_stable_bone_name() {
     let mut app = headless_app();
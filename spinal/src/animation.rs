@@ -1,16 +1,20 @@
 use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     BendDirection, BoneId, IkConstraintId, Mix, Rgba, Rgba8, SlotId, TransformConstraintId,
     TransformMix,
     asset::{IkConstraintData, TransformConstraintPoseData},
     id::AssetKey,
+    json::{JsonMember, JsonValue},
 };
 
 pub(crate) const NANOS_PER_SECOND: u64 = 1_000_000_000;
 
 /// How an absolute animation sample behaves at the end of a clip.
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum PlaybackMode {
     /// Clamp at the final key and hold the final pose.
@@ -91,6 +95,20 @@ pub enum TransformMixChannel {
     ShearY,
 }
 
+impl TransformMixChannel {
+    /// Returns the channel's position in a transform key's six mixes.
+    pub(crate) const fn index(self) -> usize {
+        match self {
+            Self::Rotate => 0,
+            Self::X => 1,
+            Self::Y => 2,
+            Self::ScaleX => 3,
+            Self::ScaleY => 4,
+            Self::ShearY => 5,
+        }
+    }
+}
+
 /// Whether one authored property participates in an override track.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
@@ -259,9 +277,18 @@ pub(crate) enum TimelineData {
     },
     Unsupported {
         name: Box<str>,
+        source: RetainedTimeline,
     },
 }
 
+/// An unsupported timeline kept verbatim so it can be written back out.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RetainedTimeline {
+    /// Member names leading from the animation object to the timeline value.
+    pub(crate) path: Box<[Box<str>]>,
+    pub(crate) value: JsonValue,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum PropertyData {
     BoneTranslation(u32),
@@ -310,6 +337,17 @@ pub(crate) struct AnimationData {
     pub(crate) deferred_override_properties: Box<[PropertyData]>,
 }
 
+impl AnimationData {
+    /// Returns the time-ordered event keys, or `None` without an event
+    /// timeline.
+    pub(crate) fn event_frames(&self) -> Option<&[EventFrame]> {
+        self.timelines.iter().find_map(|timeline| match timeline {
+            TimelineData::Events { frames } => Some(frames.as_ref()),
+            _ => None,
+        })
+    }
+}
+
 pub(crate) fn animation_properties(timelines: &[TimelineData]) -> Box<[PropertyData]> {
     let mut properties = Vec::new();
     for timeline in timelines {
@@ -411,6 +449,7 @@ pub(crate) struct EventDefinitionData {
     pub(crate) name: Box<str>,
     pub(crate) payload: EventPayload,
     pub(crate) audio: Option<Box<str>>,
+    pub(crate) retained: Box<[JsonMember]>,
 }
 
 pub(crate) fn resolve_sample_time(
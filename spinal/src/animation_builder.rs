@@ -0,0 +1,816 @@
+//! Procedural animations built from typed keys into a derived asset.
+
+use std::time::Duration;
+
+use glam::Vec2;
+
+use crate::{
+    BendDirection, BezierCurve, BoneId, DeriveError, DerivedAsset, EventId, IkConstraintId,
+    KeyCurve, LoadDocument, LoadError, LoadErrorKind, Mix, Rgba, Rgba8, SkeletonAsset, SlotId,
+    SourceLocation, TransformConstraintId, TransformMix, TransformMixChannel,
+    animation::{
+        AnimationData, AttachmentFrame, ColourFrame, DrawOrderFrame, DrawOrderOffset, EventFrame,
+        EventPayload, FrameCurve, IkFrame, ScalarFrame, TimelineData, TimelineTime, TransformFrame,
+        Vec2Frame, transform_pose_from_values, transform_pose_values,
+    },
+    derived::derive_asset,
+    load::{
+        animation::{require_nondecreasing_time, require_strict_time},
+        schema::{error, index_pointer, pointer, schema_error},
+    },
+};
+
+/// One animation assembled from typed keys against a base asset's rig.
+///
+/// Keys are recorded without validation; [`build_animations`] checks them
+/// with the loader's rules. Keys for one element and property form one
+/// timeline and must be added in strictly increasing time order, except
+/// event keys, which may share a time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationBuilder {
+    name: Box<str>,
+    rotate: Timelines<BoneId, f32, KeyCurve>,
+    translate: Timelines<BoneId, Vec2, [KeyCurve; 2]>,
+    scale: Timelines<BoneId, Vec2, [KeyCurve; 2]>,
+    shear: Timelines<BoneId, Vec2, [KeyCurve; 2]>,
+    attachment: Timelines<SlotId, Option<Box<str>>>,
+    color: Timelines<SlotId, Rgba8, [KeyCurve; 4]>,
+    ik: Timelines<IkConstraintId, (Mix, BendDirection), KeyCurve>,
+    transform: Timelines<TransformConstraintId, TransformKey>,
+    draw_order: Vec<Key<DrawOrderOffsets>>,
+    events: Vec<Key<EventKey>>,
+}
+
+/// The keys of one element's property, per keyed element.
+type Timelines<T, V, C = ()> = Vec<(T, Vec<Key<V, C>>)>;
+
+/// The slot offsets of one draw-order key.
+type DrawOrderOffsets = Box<[(SlotId, i32)]>;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Key<V, C = ()> {
+    time: Duration,
+    value: V,
+    curve: C,
+}
+
+impl AnimationBuilder {
+    /// Starts an empty animation named `name`.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            rotate: Vec::new(),
+            translate: Vec::new(),
+            scale: Vec::new(),
+            shear: Vec::new(),
+            attachment: Vec::new(),
+            color: Vec::new(),
+            ik: Vec::new(),
+            transform: Vec::new(),
+            draw_order: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the animation name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Keys a bone's rotation, in degrees added to its setup rotation.
+    pub fn rotate_key(
+        &mut self,
+        bone: BoneId,
+        time: Duration,
+        degrees: f32,
+        curve: KeyCurve,
+    ) -> &mut Self {
+        push_key(&mut self.rotate, bone, time, degrees, curve);
+        self
+    }
+
+    /// Keys a bone's translation, added to its setup translation.
+    pub fn translate_key(
+        &mut self,
+        bone: BoneId,
+        time: Duration,
+        offset: Vec2,
+        curves: [KeyCurve; 2],
+    ) -> &mut Self {
+        push_key(&mut self.translate, bone, time, offset, curves);
+        self
+    }
+
+    /// Keys a bone's scale, multiplying its setup scale per axis.
+    pub fn scale_key(
+        &mut self,
+        bone: BoneId,
+        time: Duration,
+        scale: Vec2,
+        curves: [KeyCurve; 2],
+    ) -> &mut Self {
+        push_key(&mut self.scale, bone, time, scale, curves);
+        self
+    }
+
+    /// Keys a bone's shear, in degrees added to its setup shear.
+    pub fn shear_key(
+        &mut self,
+        bone: BoneId,
+        time: Duration,
+        degrees: Vec2,
+        curves: [KeyCurve; 2],
+    ) -> &mut Self {
+        push_key(&mut self.shear, bone, time, degrees, curves);
+        self
+    }
+
+    /// Keys the attachment placeholder a slot shows, or hides the slot with
+    /// `None`.
+    pub fn attachment_key(
+        &mut self,
+        slot: SlotId,
+        time: Duration,
+        placeholder: Option<&str>,
+    ) -> &mut Self {
+        push_key(
+            &mut self.attachment,
+            slot,
+            time,
+            placeholder.map(Box::from),
+            (),
+        );
+        self
+    }
+
+    /// Keys a slot's light colour, with one curve per red, green, blue and
+    /// alpha channel.
+    ///
+    /// Bezier control values of colour curves are normalized channels from 0
+    /// through 1.
+    pub fn color_key(
+        &mut self,
+        slot: SlotId,
+        time: Duration,
+        color: Rgba8,
+        curves: [KeyCurve; 4],
+    ) -> &mut Self {
+        push_key(&mut self.color, slot, time, color, curves);
+        self
+    }
+
+    /// Keys an IK constraint's mix and bend direction. The bend direction
+    /// holds until the next key.
+    pub fn ik_key(
+        &mut self,
+        constraint: IkConstraintId,
+        time: Duration,
+        mix: Mix,
+        bend_direction: BendDirection,
+        mix_curve: KeyCurve,
+    ) -> &mut Self {
+        push_key(
+            &mut self.ik,
+            constraint,
+            time,
+            (mix, bend_direction),
+            mix_curve,
+        );
+        self
+    }
+
+    /// Keys a transform constraint's mix channels.
+    pub fn transform_key(
+        &mut self,
+        constraint: TransformConstraintId,
+        time: Duration,
+        key: TransformKey,
+    ) -> &mut Self {
+        push_key(&mut self.transform, constraint, time, key, ());
+        self
+    }
+
+    /// Keys the slot draw order as offsets from setup order, in the form
+    /// [`DrawOrderKeyRef::offsets`](crate::DrawOrderKeyRef::offsets) reports.
+    ///
+    /// Offsets may be given in any slot order. No offsets restore the setup
+    /// draw order.
+    pub fn draw_order_key(&mut self, time: Duration, offsets: &[(SlotId, i32)]) -> &mut Self {
+        self.draw_order.push(Key {
+            time,
+            value: offsets.into(),
+            curve: (),
+        });
+        self
+    }
+
+    /// Keys an event occurrence.
+    pub fn event_key(&mut self, time: Duration, key: EventKey) -> &mut Self {
+        self.events.push(Key {
+            time,
+            value: key,
+            curve: (),
+        });
+        self
+    }
+}
+
+fn push_key<T: PartialEq, V, C>(
+    timelines: &mut Timelines<T, V, C>,
+    target: T,
+    time: Duration,
+    value: V,
+    curve: C,
+) {
+    let key = Key { time, value, curve };
+    match timelines.iter_mut().find(|(other, _keys)| *other == target) {
+        Some((_target, keys)) => keys.push(key),
+        None => timelines.push((target, vec![key])),
+    }
+}
+
+/// The six mix channels of one transform-constraint key.
+///
+/// Channels that are not set key the constraint's setup mix, as omitted
+/// fields do in Spine JSON. Every channel interpolates linearly unless given
+/// a curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[must_use]
+pub struct TransformKey {
+    mixes: [Option<TransformMix>; 6],
+    curves: [KeyCurve; 6],
+}
+
+impl Default for TransformKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransformKey {
+    /// Keys every channel at its setup mix with a linear curve.
+    pub const fn new() -> Self {
+        Self {
+            mixes: [None; 6],
+            curves: [KeyCurve::Linear; 6],
+        }
+    }
+
+    /// Keys one channel's mix.
+    pub const fn with_mix(mut self, channel: TransformMixChannel, mix: TransformMix) -> Self {
+        self.mixes[channel.index()] = Some(mix);
+        self
+    }
+
+    /// Sets one channel's curve toward the next key.
+    pub const fn with_curve(mut self, channel: TransformMixChannel, curve: KeyCurve) -> Self {
+        self.curves[channel.index()] = curve;
+        self
+    }
+}
+
+/// One event occurrence with optional payload overrides.
+///
+/// Payload values that are not set use the event definition's defaults.
+#[derive(Clone, Debug, PartialEq)]
+#[must_use]
+pub struct EventKey {
+    event: EventId,
+    integer: Option<i32>,
+    float: Option<f32>,
+    string: Option<Option<Box<str>>>,
+    volume: Option<f32>,
+    balance: Option<f32>,
+}
+
+impl EventKey {
+    /// Keys `event` with its default payload.
+    pub const fn new(event: EventId) -> Self {
+        Self {
+            event,
+            integer: None,
+            float: None,
+            string: None,
+            volume: None,
+            balance: None,
+        }
+    }
+
+    /// Overrides the integer payload.
+    pub const fn with_integer(mut self, integer: i32) -> Self {
+        self.integer = Some(integer);
+        self
+    }
+
+    /// Overrides the floating-point payload.
+    pub const fn with_float(mut self, float: f32) -> Self {
+        self.float = Some(float);
+        self
+    }
+
+    /// Overrides the string payload, or clears it with `None`.
+    pub fn with_string(mut self, string: Option<&str>) -> Self {
+        self.string = Some(string.map(Box::from));
+        self
+    }
+
+    /// Overrides the audio volume.
+    pub const fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    /// Overrides the audio balance.
+    pub const fn with_balance(mut self, balance: f32) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+}
+
+/// Copies `base` under a fresh identity with `animations` added.
+///
+/// Each built animation replaces a base animation of the same name or is
+/// appended after them. Keys are validated with the loader's rules and a
+/// failure is reported as the [`LoadError`] the equivalent Spine JSON would
+/// produce, located by its JSON pointer; exhausting asset identities is a
+/// document-level [`LoadErrorKind::CapacityExceeded`], as when loading. As
+/// with JSON, an animation lasts until its last key. Skeletons must be
+/// created from the derived asset to play the built clips.
+pub fn build_animations(
+    base: &SkeletonAsset,
+    animations: impl IntoIterator<Item = AnimationBuilder>,
+) -> Result<DerivedAsset, LoadError> {
+    let mut built = Vec::<AnimationData>::new();
+    for animation in animations {
+        let path = pointer("/animations", &animation.name);
+        if animation.name.is_empty() {
+            return Err(schema_error(&path, "animation name must not be empty"));
+        }
+        if built.iter().any(|other| other.name == animation.name) {
+            return Err(error(
+                LoadErrorKind::DuplicateName,
+                &path,
+                format!("animation {:?} is built more than once", animation.name),
+            ));
+        }
+        let mut duration = TimelineTime::ZERO;
+        let timelines = timelines(base, &animation, &path, &mut duration)?;
+        built.push(AnimationData {
+            name: animation.name,
+            duration,
+            timelines: timelines.into_boxed_slice(),
+            properties: Box::default(),
+            deferred_override_properties: Box::default(),
+        });
+    }
+    derive_asset(base, built, Vec::new()).map_err(|derive| match derive {
+        DeriveError::CapacityExceeded => LoadError::new(
+            LoadErrorKind::CapacityExceeded,
+            derive.to_string(),
+            SourceLocation::for_document(LoadDocument::SkeletonJson),
+        ),
+        DeriveError::Id(id) => error(
+            LoadErrorKind::UnresolvedReference,
+            "/animations",
+            id.to_string(),
+        ),
+    })
+}
+
+fn timelines(
+    asset: &SkeletonAsset,
+    animation: &AnimationBuilder,
+    path: &str,
+    duration: &mut TimelineTime,
+) -> Result<Vec<TimelineData>, LoadError> {
+    let mut timelines = Vec::new();
+    let bones = pointer(path, "bones");
+    for (bone, keys) in &animation.rotate {
+        let path = pointer(&pointer(&bones, bone_name(asset, *bone, &bones)?), "rotate");
+        let frames = frames(keys, &path, duration, |key, next, key_path| {
+            Ok(ScalarFrame {
+                time: TimelineTime::ZERO,
+                value: finite(key.value, &pointer(key_path, "value"))?,
+                curve: frame_curve(
+                    [key.curve],
+                    [key.value],
+                    next.map(|next| [next.value]),
+                    key_path,
+                )?,
+            })
+        })?;
+        timelines.push(TimelineData::BoneRotate {
+            bone: bone.index(),
+            frames,
+        });
+    }
+    for (property, table) in [
+        ("translate", &animation.translate),
+        ("scale", &animation.scale),
+        ("shear", &animation.shear),
+    ] {
+        for (bone, keys) in table {
+            let path = pointer(&pointer(&bones, bone_name(asset, *bone, &bones)?), property);
+            let frames = frames(keys, &path, duration, |key, next, key_path| {
+                Ok(Vec2Frame {
+                    time: TimelineTime::ZERO,
+                    x: finite(key.value.x, &pointer(key_path, "x"))?,
+                    y: finite(key.value.y, &pointer(key_path, "y"))?,
+                    curve: frame_curve(
+                        key.curve,
+                        key.value.to_array(),
+                        next.map(|next| next.value.to_array()),
+                        key_path,
+                    )?,
+                })
+            })?;
+            let bone = bone.index();
+            timelines.push(match property {
+                "translate" => TimelineData::BoneTranslate { bone, frames },
+                "scale" => TimelineData::BoneScale { bone, frames },
+                _ => TimelineData::BoneShear { bone, frames },
+            });
+        }
+    }
+
+    let slots = pointer(path, "slots");
+    for (slot, keys) in &animation.attachment {
+        let path = pointer(
+            &pointer(&slots, slot_name(asset, *slot, &slots)?),
+            "attachment",
+        );
+        let frames = frames(keys, &path, duration, |key, _next, key_path| {
+            if let Some(name) = &key.value
+                && asset
+                    .attachment_placeholder_index(slot.index(), name)
+                    .is_none()
+            {
+                return Err(error(
+                    LoadErrorKind::UnresolvedReference,
+                    &pointer(key_path, "name"),
+                    format!("attachment timeline references unknown attachment {name:?}"),
+                ));
+            }
+            Ok(AttachmentFrame {
+                time: TimelineTime::ZERO,
+                placeholder_name: key.value.clone(),
+            })
+        })?;
+        timelines.push(TimelineData::SlotAttachment {
+            slot: slot.index(),
+            frames,
+        });
+    }
+    for (slot, keys) in &animation.color {
+        let path = pointer(&pointer(&slots, slot_name(asset, *slot, &slots)?), "rgba");
+        let frames = frames(keys, &path, duration, |key, next, key_path| {
+            Ok(ColourFrame {
+                time: TimelineTime::ZERO,
+                colour: key.value,
+                curve: frame_curve(
+                    key.curve,
+                    Rgba::from_rgba8(key.value).to_array(),
+                    next.map(|next| Rgba::from_rgba8(next.value).to_array()),
+                    key_path,
+                )?,
+            })
+        })?;
+        timelines.push(TimelineData::SlotColour {
+            slot: slot.index(),
+            frames,
+        });
+    }
+
+    let constraints = pointer(path, "ik");
+    for (constraint, keys) in &animation.ik {
+        let ik = asset.ik_constraint(*constraint).map_err(|id| {
+            error(
+                LoadErrorKind::UnresolvedReference,
+                &constraints,
+                id.to_string(),
+            )
+        })?;
+        let path = pointer(&constraints, ik.name());
+        let frames = frames(keys, &path, duration, |key, next, key_path| {
+            let (mix, bend_direction) = key.value;
+            Ok(IkFrame {
+                time: TimelineTime::ZERO,
+                mix,
+                bend_direction,
+                // The second channel is the unsupported softness, always zero.
+                curve: frame_curve(
+                    [key.curve, KeyCurve::Linear],
+                    [mix.get(), 0.0],
+                    next.map(|next| [next.value.0.get(), 0.0]),
+                    key_path,
+                )?,
+            })
+        })?;
+        timelines.push(TimelineData::Ik {
+            constraint: constraint.index(),
+            frames,
+        });
+    }
+    let constraints = pointer(path, "transform");
+    for (constraint, keys) in &animation.transform {
+        let transform = asset.transform_constraint(*constraint).map_err(|id| {
+            error(
+                LoadErrorKind::UnresolvedReference,
+                &constraints,
+                id.to_string(),
+            )
+        })?;
+        let setup = transform_pose_values(
+            asset
+                .transform_constraint_data(constraint.index() as usize)
+                .setup_pose,
+        );
+        let mixes = |key: &TransformKey| -> [f32; 6] {
+            core::array::from_fn(|channel| {
+                key.mixes[channel].map_or(setup[channel], TransformMix::get)
+            })
+        };
+        let path = pointer(&constraints, transform.name());
+        let frames = frames(keys, &path, duration, |key, next, key_path| {
+            let values = mixes(&key.value);
+            Ok(TransformFrame {
+                time: TimelineTime::ZERO,
+                pose: transform_pose_from_values(values),
+                curve: frame_curve(
+                    key.value.curves,
+                    values,
+                    next.map(|next| mixes(&next.value)),
+                    key_path,
+                )?,
+            })
+        })?;
+        timelines.push(TimelineData::Transform {
+            constraint: constraint.index(),
+            frames,
+        });
+    }
+
+    if !animation.draw_order.is_empty() {
+        let path = pointer(path, "drawOrder");
+        let frames = frames(
+            &animation.draw_order,
+            &path,
+            duration,
+            |key, _next, key_path| draw_order(asset, &key.value, key_path),
+        )?;
+        timelines.push(TimelineData::DrawOrder { frames });
+    }
+    if !animation.events.is_empty() {
+        let path = pointer(path, "events");
+        let mut previous = None;
+        let mut frames = Vec::with_capacity(animation.events.len());
+        for (index, key) in animation.events.iter().enumerate() {
+            let key_path = index_pointer(&path, index);
+            let time = key_time(key.time, &key_path, duration)?;
+            require_nondecreasing_time(previous, time, &pointer(&key_path, "time"))?;
+            previous = Some(time);
+            frames.push(event(asset, time, &key.value, &key_path)?);
+        }
+        timelines.push(TimelineData::Events {
+            frames: frames.into_boxed_slice(),
+        });
+    }
+    Ok(timelines)
+}
+
+/// Validates one interpolated timeline's key order and builds each frame
+/// from its key, the next key, and the key's JSON pointer.
+fn frames<V, C, F: Timed>(
+    keys: &[Key<V, C>],
+    path: &str,
+    duration: &mut TimelineTime,
+    mut frame: impl FnMut(&Key<V, C>, Option<&Key<V, C>>, &str) -> Result<F, LoadError>,
+) -> Result<Box<[F]>, LoadError> {
+    let mut previous = None;
+    let mut frames = Vec::with_capacity(keys.len());
+    for (index, key) in keys.iter().enumerate() {
+        let key_path = index_pointer(path, index);
+        let time = key_time(key.time, &key_path, duration)?;
+        require_strict_time(previous, time, &pointer(&key_path, "time"))?;
+        previous = Some(time);
+        let mut built = frame(key, keys.get(index + 1), &key_path)?;
+        built.set_time(time);
+        frames.push(built);
+    }
+    Ok(frames.into_boxed_slice())
+}
+
+fn key_time(
+    time: Duration,
+    path: &str,
+    duration: &mut TimelineTime,
+) -> Result<TimelineTime, LoadError> {
+    let time = u64::try_from(time.as_nanos())
+        .map(|ticks| TimelineTime { ticks })
+        .map_err(|_overflow| {
+            error(
+                LoadErrorKind::NonFiniteNumber,
+                &pointer(path, "time"),
+                "key time must be finite, nonnegative, and representable in nanoseconds",
+            )
+        })?;
+    *duration = (*duration).max(time);
+    Ok(time)
+}
+
+fn bone_name<'a>(asset: &'a SkeletonAsset, bone: BoneId, path: &str) -> Result<&'a str, LoadError> {
+    asset
+        .bone(bone)
+        .map(|bone| bone.name())
+        .map_err(|id| error(LoadErrorKind::UnresolvedReference, path, id.to_string()))
+}
+
+fn slot_name<'a>(asset: &'a SkeletonAsset, slot: SlotId, path: &str) -> Result<&'a str, LoadError> {
+    asset
+        .slot(slot)
+        .map(|slot| slot.name())
+        .map_err(|id| error(LoadErrorKind::UnresolvedReference, path, id.to_string()))
+}
+
+fn draw_order(
+    asset: &SkeletonAsset,
+    offsets: &[(SlotId, i32)],
+    path: &str,
+) -> Result<DrawOrderFrame, LoadError> {
+    let path = pointer(path, "offsets");
+    let mut checked = Vec::with_capacity(offsets.len());
+    for (index, (slot, offset)) in offsets.iter().enumerate() {
+        let offset_path = index_pointer(&path, index);
+        slot_name(asset, *slot, &pointer(&offset_path, "slot"))?;
+        let offset = DrawOrderOffset {
+            slot: slot.index(),
+            offset: *offset,
+        };
+        checked.push((offset_path, offset));
+    }
+    checked.sort_by_key(|(_path, offset)| offset.slot);
+    let slot_count = i64::try_from(asset.slots().len()).unwrap_or(i64::MAX);
+    let mut destinations = Vec::with_capacity(checked.len());
+    for (position, (offset_path, offset)) in checked.iter().enumerate() {
+        if position > 0 && checked[position - 1].1.slot == offset.slot {
+            return Err(error(
+                LoadErrorKind::InvalidOrder,
+                &pointer(offset_path, "slot"),
+                format!(
+                    "draw-order slot {:?} is listed more than once",
+                    asset.slot_data(offset.slot as usize).name
+                ),
+            ));
+        }
+        let destination = i64::from(offset.slot) + i64::from(offset.offset);
+        if !(0..slot_count).contains(&destination) {
+            return Err(error(
+                LoadErrorKind::InvalidOrder,
+                &pointer(offset_path, "offset"),
+                format!("draw-order destination {destination} is outside the slot table"),
+            ));
+        }
+        if destinations.contains(&destination) {
+            return Err(error(
+                LoadErrorKind::InvalidOrder,
+                &pointer(offset_path, "offset"),
+                format!("multiple draw-order offsets target destination {destination}"),
+            ));
+        }
+        destinations.push(destination);
+    }
+    Ok(DrawOrderFrame {
+        time: TimelineTime::ZERO,
+        offsets: checked.into_iter().map(|(_path, offset)| offset).collect(),
+    })
+}
+
+fn event(
+    asset: &SkeletonAsset,
+    time: TimelineTime,
+    key: &EventKey,
+    path: &str,
+) -> Result<EventFrame, LoadError> {
+    let definition = asset.event_definition(key.event).map_err(|id| {
+        error(
+            LoadErrorKind::UnresolvedReference,
+            &pointer(path, "name"),
+            id.to_string(),
+        )
+    })?;
+    let payload = EventPayload {
+        integer: key.integer.unwrap_or(definition.integer()),
+        float: finite(
+            key.float.unwrap_or(definition.float()),
+            &pointer(path, "float"),
+        )?,
+        string: key
+            .string
+            .clone()
+            .unwrap_or_else(|| definition.string().map(Box::from)),
+        volume: finite(
+            key.volume.unwrap_or(definition.volume()),
+            &pointer(path, "volume"),
+        )?,
+        balance: finite(
+            key.balance.unwrap_or(definition.balance()),
+            &pointer(path, "balance"),
+        )?,
+    };
+    Ok(EventFrame {
+        time,
+        event: key.event.index(),
+        payload,
+    })
+}
+
+/// A frame whose time is assigned once its key order is validated.
+trait Timed {
+    fn set_time(&mut self, time: TimelineTime);
+}
+
+macro_rules! timed {
+    ($($frame:ty),* $(,)?) => {
+        $(impl Timed for $frame {
+            fn set_time(&mut self, time: TimelineTime) {
+                self.time = time;
+            }
+        })*
+    };
+}
+
+timed!(
+    ScalarFrame,
+    Vec2Frame,
+    AttachmentFrame,
+    ColourFrame,
+    IkFrame,
+    TransformFrame,
+    DrawOrderFrame,
+);
+
+fn finite(value: f32, path: &str) -> Result<f32, LoadError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(error(
+            LoadErrorKind::NonFiniteNumber,
+            path,
+            "number must be finite and representable as f32",
+        ))
+    }
+}
+
+/// Converts per-channel key curves to the loaded representation, with
+/// Bezier control values running from `start` to the next key's values.
+///
+/// A linear channel beside Bezier channels becomes the straight Bezier line
+/// between the two keys.
+fn frame_curve<const CHANNELS: usize>(
+    curves: [KeyCurve; CHANNELS],
+    start: [f32; CHANNELS],
+    end: Option<[f32; CHANNELS]>,
+    path: &str,
+) -> Result<FrameCurve<CHANNELS>, LoadError> {
+    if curves.iter().all(|curve| *curve == KeyCurve::Linear) {
+        return Ok(FrameCurve::Linear);
+    }
+    let stepped = curves
+        .iter()
+        .filter(|curve| **curve == KeyCurve::Stepped)
+        .count();
+    if stepped == CHANNELS {
+        return Ok(FrameCurve::Stepped);
+    }
+    let curve_path = pointer(path, "curve");
+    if stepped > 0 {
+        return Err(schema_error(
+            &curve_path,
+            "a stepped curve must apply to every channel of its key",
+        ));
+    }
+    let end = end.unwrap_or(start);
+    let mut points = [[0.0; 4]; CHANNELS];
+    for (channel, curve) in curves.into_iter().enumerate() {
+        points[channel] = match curve {
+            KeyCurve::Bezier(curve) => curve.points(),
+            _ => {
+                let line = BezierCurve::new(
+                    Vec2::new(1.0 / 3.0, lerp(start[channel], end[channel], 1.0 / 3.0)),
+                    Vec2::new(2.0 / 3.0, lerp(start[channel], end[channel], 2.0 / 3.0)),
+                );
+                line.points()
+            }
+        };
+        for (index, value) in points[channel].iter().enumerate() {
+            finite(*value, &index_pointer(&curve_path, channel * 4 + index))?;
+        }
+    }
+    Ok(FrameCurve::Bezier(points))
+}
+
+fn lerp(start: f32, end: f32, amount: f32) -> f32 {
+    (f64::from(start) + (f64::from(end) - f64::from(start)) * f64::from(amount)) as f32
+}
@@ -1,24 +1,30 @@
-use std::{collections::HashMap, ops::Range, time::Duration};
+use std::{collections::HashMap, ops::Range, sync::Arc, time::Duration};
 
 use crate::{
     AlphaEncoding, Angle, AnimationId, AtlasPageId, AtlasRegionId, AtlasRotation, AttachmentId,
-    BoneId, BoneTransform, ConstraintId, Diagnostic, EventId, IdError, IkConstraintId, Mix,
-    PixelRect, PixelSize, Rgba8, SkinId, SlotId, TextureFilter, TextureFormat,
-    TransformConstraintId, TransformMix, Trim, WrapMode,
-    animation::{AnimationData, EventDefinitionData},
+    BoneId, BoneTransform, ConstraintId, Diagnostic, DiagnosticScope, EventId, IdError,
+    IkConstraintId, Mix, PixelRect, PixelSize, Rgba8, SkinId, SlotId, TextAtlas, TextureFilter,
+    TextureFormat, TransformConstraintId, TransformMix, Trim, WrapMode,
+    animation::{
+        AnimationData, EventDefinitionData, animation_deferred_override_properties,
+        animation_properties,
+    },
     id::AssetKey,
+    json::JsonMember,
+    load::{PendingDiagnostic, PendingScope},
     mesh::{MeshAttachmentData, MeshAttachmentRef, MeshGeometryData},
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct BoneData {
     pub(crate) name: Box<str>,
     pub(crate) parent: Option<u32>,
     pub(crate) length: f32,
     pub(crate) setup_transform: BoneTransform,
+    pub(crate) retained: Box<[JsonMember]>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct SlotData {
     pub(crate) name: Box<str>,
     pub(crate) bone: u32,
@@ -26,15 +32,17 @@ pub(crate) struct SlotData {
     pub(crate) colour: Rgba8,
     pub(crate) blend_mode: SlotBlendMode,
     pub(crate) blend_token: Box<str>,
+    pub(crate) retained: Box<[JsonMember]>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct SkinData {
     pub(crate) name: Box<str>,
     pub(crate) attachments: Range<u32>,
+    pub(crate) retained: Box<[JsonMember]>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct AttachmentData {
     pub(crate) placeholder_name: Box<str>,
     pub(crate) name: Box<str>,
@@ -42,9 +50,10 @@ pub(crate) struct AttachmentData {
     pub(crate) skin: u32,
     pub(crate) slot: u32,
     pub(crate) kind: AttachmentDataKind,
+    pub(crate) retained: Box<[JsonMember]>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) enum AttachmentDataKind {
     Region(RegionAttachmentData),
     Mesh(MeshAttachmentData),
@@ -53,7 +62,7 @@ pub(crate) enum AttachmentDataKind {
     Unsupported { source_type: Box<str> },
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct RegionAttachmentData {
     pub(crate) transform: BoneTransform,
     pub(crate) size: PixelSize,
@@ -61,13 +70,13 @@ pub(crate) struct RegionAttachmentData {
     pub(crate) atlas_region: u32,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct AtlasExtension {
     pub(crate) key: Box<str>,
     pub(crate) value: Box<str>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct AtlasPageData {
     pub(crate) name: Box<str>,
     pub(crate) size: PixelSize,
@@ -84,7 +93,7 @@ pub(crate) struct AtlasPageData {
     pub(crate) extensions: Box<[AtlasExtension]>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct AtlasRegionData {
     pub(crate) name: Box<str>,
     pub(crate) page: u32,
@@ -97,7 +106,7 @@ pub(crate) struct AtlasRegionData {
     pub(crate) extensions: Box<[AtlasExtension]>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct IkConstraintData {
     pub(crate) constraint: u32,
     pub(crate) name: Box<str>,
@@ -133,7 +142,7 @@ impl TransformConstraintPoseData {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct TransformConstraintData {
     pub(crate) constraint: u32,
     pub(crate) name: Box<str>,
@@ -149,13 +158,14 @@ pub(crate) struct TransformConstraintData {
     pub(crate) setup_pose: TransformConstraintPoseData,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct ConstraintData {
     pub(crate) name: Box<str>,
     pub(crate) source_type: Box<str>,
     pub(crate) order: u32,
     pub(crate) ik_constraint: Option<u32>,
     pub(crate) transform_constraint: Option<u32>,
+    pub(crate) retained: Box<[JsonMember]>,
 }
 
 #[derive(Debug)]
@@ -170,10 +180,14 @@ pub(crate) struct AssetData {
     pub(crate) ik_constraints: Box<[IkConstraintData]>,
     pub(crate) transform_constraints: Box<[TransformConstraintData]>,
     pub(crate) constraints: Box<[ConstraintData]>,
-    pub(crate) atlas_pages: Box<[AtlasPageData]>,
-    pub(crate) atlas_regions: Box<[AtlasRegionData]>,
+    /// The linked atlases in resolution order, shared rather than copied.
+    pub(crate) atlases: Box<[Arc<TextAtlas>]>,
     pub(crate) events: Box<[EventDefinitionData]>,
     pub(crate) diagnostics: Box<[Diagnostic]>,
+    /// Skeleton metadata other than the version, kept for the JSON writer.
+    pub(crate) retained_metadata: Box<[JsonMember]>,
+    /// Unknown top-level sections, kept for the JSON writer.
+    pub(crate) retained_sections: Box<[JsonMember]>,
 }
 
 /// The supported or retained category of an authored attachment.
@@ -283,8 +297,13 @@ pub struct SkeletonAsset {
     transform_constraints: Box<[TransformConstraintData]>,
     constraints: Box<[ConstraintData]>,
     constraint_evaluation_order: Box<[u32]>,
-    atlas_pages: Box<[AtlasPageData]>,
-    atlas_regions: Box<[AtlasRegionData]>,
+    atlases: Box<[Arc<TextAtlas>]>,
+    /// The asset-scoped index of each atlas's first page.
+    atlas_page_starts: Box<[u32]>,
+    /// The asset-scoped index of each atlas's first region.
+    atlas_region_starts: Box<[u32]>,
+    atlas_page_count: usize,
+    atlas_region_count: usize,
     events: Box<[EventDefinitionData]>,
     bone_by_name: HashMap<Box<str>, u32>,
     slot_by_name: HashMap<Box<str>, u32>,
@@ -300,6 +319,8 @@ pub struct SkeletonAsset {
     attachment_placeholder_by_slot: HashMap<u32, HashMap<Box<str>, u32>>,
     default_skin: Option<u32>,
     diagnostics: Box<[Diagnostic]>,
+    retained_metadata: Box<[JsonMember]>,
+    retained_sections: Box<[JsonMember]>,
 }
 
 impl SkeletonAsset {
@@ -320,15 +341,36 @@ impl SkeletonAsset {
             .collect::<Vec<_>>();
         constraint_evaluation_order.sort_by_key(|index| data.constraints[*index as usize].order);
         let event_by_name = lookup(&data.events, |event| &event.name);
-        let atlas_page_by_name = lookup(&data.atlas_pages, |page| &page.name);
+        let mut atlas_page_starts = Vec::with_capacity(data.atlases.len());
+        let mut atlas_region_starts = Vec::with_capacity(data.atlases.len());
+        let mut atlas_page_by_name = HashMap::<Box<str>, u32>::new();
         let mut region_indexes = HashMap::<Box<str>, Vec<u32>>::new();
-        for (index, region) in data.atlas_regions.iter().enumerate() {
-            let index = u32::try_from(index)
-                .expect("validated atlas region tables fit the asset-scoped ID representation");
-            region_indexes
-                .entry(region.name.clone())
-                .or_default()
-                .push(index);
+        let mut atlas_page_count = 0;
+        let mut atlas_region_count = 0;
+        for atlas in &data.atlases {
+            atlas_page_starts.push(
+                u32::try_from(atlas_page_count)
+                    .expect("validated atlas page tables fit the asset-scoped ID representation"),
+            );
+            atlas_region_starts
+                .push(u32::try_from(atlas_region_count).expect(
+                    "validated atlas region tables fit the asset-scoped ID representation",
+                ));
+            for page in &atlas.pages {
+                let index = u32::try_from(atlas_page_count)
+                    .expect("validated atlas page tables fit the asset-scoped ID representation");
+                atlas_page_by_name.entry(page.name.clone()).or_insert(index);
+                atlas_page_count += 1;
+            }
+            for region in &atlas.regions {
+                let index = u32::try_from(atlas_region_count)
+                    .expect("validated atlas region tables fit the asset-scoped ID representation");
+                region_indexes
+                    .entry(region.name.clone())
+                    .or_default()
+                    .push(index);
+                atlas_region_count += 1;
+            }
         }
         let atlas_regions_by_name = region_indexes
             .into_iter()
@@ -364,8 +406,11 @@ impl SkeletonAsset {
             transform_constraints: data.transform_constraints,
             constraints: data.constraints,
             constraint_evaluation_order: constraint_evaluation_order.into_boxed_slice(),
-            atlas_pages: data.atlas_pages,
-            atlas_regions: data.atlas_regions,
+            atlases: data.atlases,
+            atlas_page_starts: atlas_page_starts.into_boxed_slice(),
+            atlas_region_starts: atlas_region_starts.into_boxed_slice(),
+            atlas_page_count,
+            atlas_region_count,
             events: data.events,
             bone_by_name,
             slot_by_name,
@@ -381,6 +426,8 @@ impl SkeletonAsset {
             attachment_placeholder_by_slot,
             default_skin,
             diagnostics: data.diagnostics,
+            retained_metadata: data.retained_metadata,
+            retained_sections: data.retained_sections,
         }
     }
 
@@ -463,6 +510,9 @@ impl SkeletonAsset {
     }
 
     /// Resolves an atlas page name without allocating.
+    ///
+    /// When several linked atlases have a page of this name, the first one in
+    /// link order is returned.
     #[must_use]
     pub fn atlas_page_id(&self, name: &str) -> Option<AtlasPageId> {
         self.atlas_page_by_name
@@ -530,13 +580,13 @@ impl SkeletonAsset {
 
     /// Borrows one atlas page after validating its asset identity.
     pub fn atlas_page(&self, id: AtlasPageId) -> Result<AtlasPageRef<'_>, IdError> {
-        let index = self.checked_index(id.asset(), id.index(), self.atlas_pages.len())?;
+        let index = self.checked_index(id.asset(), id.index(), self.atlas_page_count)?;
         Ok(AtlasPageRef { asset: self, index })
     }
 
     /// Borrows one atlas region after validating its asset identity.
     pub fn atlas_region(&self, id: AtlasRegionId) -> Result<AtlasRegionRef<'_>, IdError> {
-        let index = self.checked_index(id.asset(), id.index(), self.atlas_regions.len())?;
+        let index = self.checked_index(id.asset(), id.index(), self.atlas_region_count)?;
         Ok(AtlasRegionRef { asset: self, index })
     }
 
@@ -607,21 +657,31 @@ impl SkeletonAsset {
         (0..self.events.len()).map(|index| EventDefinitionRef { asset: self, index })
     }
 
-    /// Iterates texture-atlas pages in source order.
+    /// Iterates texture-atlas pages in link order, then source order.
     pub fn atlas_pages(
         &self,
     ) -> impl DoubleEndedIterator<Item = AtlasPageRef<'_>> + ExactSizeIterator + '_ {
-        (0..self.atlas_pages.len()).map(|index| AtlasPageRef { asset: self, index })
+        (0..self.atlas_page_count).map(|index| AtlasPageRef { asset: self, index })
     }
 
-    /// Iterates texture-atlas regions in source order.
+    /// Iterates texture-atlas regions in link order, then source order.
     pub fn atlas_regions(
         &self,
     ) -> impl DoubleEndedIterator<Item = AtlasRegionRef<'_>> + ExactSizeIterator + '_ {
-        (0..self.atlas_regions.len()).map(|index| AtlasRegionRef { asset: self, index })
+        (0..self.atlas_region_count).map(|index| AtlasRegionRef { asset: self, index })
+    }
+
+    /// Returns the linked atlases in resolution order.
+    ///
+    /// Assets linked against the same `Arc` share it, as do assets derived
+    /// from this one.
+    #[must_use]
+    pub fn atlases(&self) -> &[Arc<TextAtlas>] {
+        &self.atlases
     }
 
-    /// Iterates every atlas region with the given name without allocating.
+    /// Iterates every atlas region with the given name, in link order, without
+    /// allocating.
     pub fn atlas_regions_named<'a>(
         &'a self,
         name: &str,
@@ -708,6 +768,22 @@ impl SkeletonAsset {
         &self.constraint_evaluation_order
     }
 
+    pub(crate) fn skin_data(&self, index: usize) -> &SkinData {
+        &self.skins[index]
+    }
+
+    pub(crate) fn event_data(&self, index: usize) -> &EventDefinitionData {
+        &self.events[index]
+    }
+
+    pub(crate) fn retained_metadata(&self) -> &[JsonMember] {
+        &self.retained_metadata
+    }
+
+    pub(crate) fn retained_sections(&self) -> &[JsonMember] {
+        &self.retained_sections
+    }
+
     pub(crate) fn animation_data(&self, index: usize) -> &AnimationData {
         &self.animations[index]
     }
@@ -716,29 +792,27 @@ impl SkeletonAsset {
         self.skins.len()
     }
 
-    pub(crate) fn resolve_attachment_index(
+    /// Looks up one placeholder in one authored skin.
+    pub(crate) fn skin_attachment_index(
         &self,
-        skin_layers: &[u32],
+        skin: u32,
         slot: u32,
         placeholder_name: &str,
     ) -> Option<u32> {
-        skin_layers
-            .iter()
-            .rev()
-            .find_map(|skin| {
-                self.attachment_by_skin_slot
-                    .get(&(*skin, slot))
-                    .and_then(|attachments| attachments.get(placeholder_name))
-                    .copied()
-            })
-            .or_else(|| {
-                self.default_skin.and_then(|skin| {
-                    self.attachment_by_skin_slot
-                        .get(&(skin, slot))
-                        .and_then(|attachments| attachments.get(placeholder_name))
-                        .copied()
-                })
-            })
+        self.attachment_by_skin_slot
+            .get(&(skin, slot))
+            .and_then(|attachments| attachments.get(placeholder_name))
+            .copied()
+    }
+
+    /// Looks up one placeholder in the default skin.
+    pub(crate) fn default_skin_attachment_index(
+        &self,
+        slot: u32,
+        placeholder_name: &str,
+    ) -> Option<u32> {
+        self.default_skin
+            .and_then(|skin| self.skin_attachment_index(skin, slot, placeholder_name))
     }
 
     pub(crate) fn attachment_placeholder_index(
@@ -756,6 +830,113 @@ impl SkeletonAsset {
         self.key
     }
 
+    /// Copies the asset under `key` with `animations` replacing same-named
+    /// clips in place or appended in order.
+    ///
+    /// The property tables of the added clips are rebuilt from their
+    /// timelines. Pending diagnostics scoped to [`PendingScope::Animation`] index into
+    /// `animations` and are rescoped to the clip's final index. Returns the
+    /// copy and the final index of every added clip.
+    pub(crate) fn with_animations(
+        &self,
+        key: AssetKey,
+        animations: Vec<AnimationData>,
+        pending: Vec<PendingDiagnostic>,
+    ) -> (Self, Vec<u32>) {
+        let mut table = self.animations.to_vec();
+        let mut by_name = self.animation_by_name.clone();
+        let mut indexes = Vec::with_capacity(animations.len());
+        for mut animation in animations {
+            animation.properties = animation_properties(&animation.timelines);
+            animation.deferred_override_properties =
+                animation_deferred_override_properties(&animation.timelines, &self.ik_constraints);
+            let index = match by_name.get(&animation.name) {
+                Some(index) => {
+                    table[*index as usize] = animation;
+                    *index
+                }
+                None => {
+                    let index = u32::try_from(table.len())
+                        .expect("derived animation tables fit the asset-scoped ID representation");
+                    by_name.insert(animation.name.clone(), index);
+                    table.push(animation);
+                    index
+                }
+            };
+            indexes.push(index);
+        }
+
+        let diagnostics = self
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| match diagnostic.scope {
+                DiagnosticScope::Animation(animation) => !indexes.contains(&animation.index()),
+                _ => true,
+            })
+            .map(|diagnostic| diagnostic.clone().with_asset(key))
+            .chain(pending.into_iter().map(|mut diagnostic| {
+                if let PendingScope::Animation(index) = diagnostic.scope {
+                    diagnostic.scope = PendingScope::Animation(indexes[index as usize]);
+                }
+                diagnostic.materialize(key)
+            }))
+            .collect();
+        let asset = Self::from_data(
+            key,
+            AssetData {
+                spine_version: self.spine_version.clone(),
+                bones: self.bones.clone(),
+                slots: self.slots.clone(),
+                skins: self.skins.clone(),
+                attachments: self.attachments.clone(),
+                mesh_geometries: self.mesh_geometries.clone(),
+                animations: table.into_boxed_slice(),
+                ik_constraints: self.ik_constraints.clone(),
+                transform_constraints: self.transform_constraints.clone(),
+                constraints: self.constraints.clone(),
+                atlases: self.atlases.clone(),
+                events: self.events.clone(),
+                diagnostics,
+                retained_metadata: self.retained_metadata.clone(),
+                retained_sections: self.retained_sections.clone(),
+            },
+        );
+        (asset, indexes)
+    }
+
+    /// Copies the linked tables back into the loader's output form.
+    pub(crate) fn to_data(&self) -> AssetData {
+        AssetData {
+            spine_version: self.spine_version.clone(),
+            bones: self.bones.clone(),
+            slots: self.slots.clone(),
+            skins: self.skins.clone(),
+            attachments: self.attachments.clone(),
+            mesh_geometries: self.mesh_geometries.clone(),
+            animations: self.animations.clone(),
+            ik_constraints: self.ik_constraints.clone(),
+            transform_constraints: self.transform_constraints.clone(),
+            constraints: self.constraints.clone(),
+            atlases: self.atlases.clone(),
+            events: self.events.clone(),
+            diagnostics: self.diagnostics.clone(),
+            retained_metadata: self.retained_metadata.clone(),
+            retained_sections: self.retained_sections.clone(),
+        }
+    }
+
+    /// Returns the atlas holding an asset-scoped page and the page's data.
+    fn atlas_page_data(&self, index: usize) -> (usize, &AtlasPageData) {
+        let (atlas, local) = locate(&self.atlas_page_starts, index);
+        (atlas, &self.atlases[atlas].pages[local])
+    }
+
+    /// Returns the atlas holding an asset-scoped region and the region's data.
+    fn atlas_region_data(&self, index: usize) -> (usize, &AtlasRegionData) {
+        let (atlas, local) = locate(&self.atlas_region_starts, index);
+        (atlas, &self.atlases[atlas].regions[local])
+    }
+
     fn checked_index(&self, asset: AssetKey, index: u32, len: usize) -> Result<usize, IdError> {
         if asset != self.key {
             return Err(IdError::foreign_asset());
@@ -778,12 +959,14 @@ impl SkeletonAsset {
                 parent: None,
                 length: 0.0,
                 setup_transform: BoneTransform::IDENTITY,
+                retained: Box::default(),
             },
             BoneData {
                 name: format!("{label}-head").into_boxed_str(),
                 parent: Some(0),
                 length: 0.0,
                 setup_transform: BoneTransform::IDENTITY,
+                retained: Box::default(),
             },
         ]
         .into_boxed_slice();
@@ -796,6 +979,7 @@ impl SkeletonAsset {
                 colour: Rgba8::WHITE,
                 blend_mode: SlotBlendMode::Normal,
                 blend_token: "normal".into(),
+                retained: Box::default(),
             })
             .collect();
         let skins = ["default", "blue"]
@@ -803,6 +987,7 @@ impl SkeletonAsset {
             .map(|name| SkinData {
                 name: name.into(),
                 attachments: 0..0,
+                retained: Box::default(),
             })
             .collect();
         let animations = vec![AnimationData {
@@ -847,12 +1032,14 @@ impl SkeletonAsset {
                     order: 0,
                     ik_constraint: Some(0),
                     transform_constraint: None,
+                    retained: Box::default(),
                 }]
                 .into_boxed_slice(),
-                atlas_pages: Box::default(),
-                atlas_regions: Box::default(),
+                atlases: Box::default(),
                 events: Box::default(),
                 diagnostics: Box::default(),
+                retained_metadata: Box::default(),
+                retained_sections: Box::default(),
             },
         )
     }
@@ -1209,6 +1396,37 @@ impl<'a> AnimationRef<'a> {
             .map(|property| property.to_key(self.asset.key))
     }
 
+    /// Iterates named markers derived from authored event keys, in time
+    /// order.
+    ///
+    /// Every event key is a marker named after its event definition, so an
+    /// animation keying `loop_start` and `loop_end` events can locate its
+    /// loop window without hard-coded times.
+    pub fn markers(
+        self,
+    ) -> impl DoubleEndedIterator<Item = AnimationMarker<'a>> + ExactSizeIterator + 'a {
+        let asset = self.asset;
+        asset.animations[self.index]
+            .event_frames()
+            .unwrap_or_default()
+            .iter()
+            .map(move |frame| AnimationMarker {
+                definition: EventDefinitionRef {
+                    asset,
+                    index: frame.event as usize,
+                },
+                time: frame.time.as_duration(),
+            })
+    }
+
+    /// Returns the time of the first marker named `name`.
+    #[must_use]
+    pub fn marker(self, name: &str) -> Option<Duration> {
+        self.markers()
+            .find(|marker| marker.name() == name)
+            .map(AnimationMarker::time)
+    }
+
     /// Classifies this animation for use on an override track.
     #[must_use]
     pub const fn override_compatibility(self) -> OverrideCompatibility<'a> {
@@ -1220,6 +1438,41 @@ impl<'a> AnimationRef<'a> {
     pub const fn ordinal(self) -> usize {
         self.index
     }
+
+    pub(crate) const fn asset(self) -> &'a SkeletonAsset {
+        self.asset
+    }
+
+    pub(crate) fn data(self) -> &'a AnimationData {
+        &self.asset.animations[self.index]
+    }
+}
+
+/// One named time in an animation, derived from an authored event key.
+#[derive(Clone, Copy, Debug)]
+pub struct AnimationMarker<'a> {
+    definition: EventDefinitionRef<'a>,
+    time: Duration,
+}
+
+impl<'a> AnimationMarker<'a> {
+    /// Returns the marker name, which is its event definition's name.
+    #[must_use]
+    pub fn name(self) -> &'a str {
+        self.definition.name()
+    }
+
+    /// Returns the event definition keyed at this time.
+    #[must_use]
+    pub const fn definition(self) -> EventDefinitionRef<'a> {
+        self.definition
+    }
+
+    /// Returns the exact animation-local time of the event key.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.time
+    }
 }
 
 /// A borrowed compatibility view for one animation on an override track.
@@ -1620,6 +1873,10 @@ pub struct AtlasPageRef<'a> {
 }
 
 impl<'a> AtlasPageRef<'a> {
+    fn data(self) -> &'a AtlasPageData {
+        self.asset.atlas_page_data(self.index).1
+    }
+
     /// Returns the asset-scoped page ID.
     #[must_use]
     pub fn id(self) -> AtlasPageId {
@@ -1629,83 +1886,91 @@ impl<'a> AtlasPageRef<'a> {
     /// Returns the page image name exactly as authored.
     #[must_use]
     pub fn name(self) -> &'a str {
-        &self.asset.atlas_pages[self.index].name
+        &self.data().name
     }
 
     /// Returns the declared page size, or zeroes when omitted.
     #[must_use]
     pub fn size(self) -> PixelSize {
-        self.asset.atlas_pages[self.index].size
+        self.data().size
     }
 
     /// Returns the documented texture format classification.
     #[must_use]
     pub fn format(self) -> TextureFormat {
-        self.asset.atlas_pages[self.index].format
+        self.data().format
     }
 
     /// Returns the original texture format token.
     #[must_use]
     pub fn format_token(self) -> &'a str {
-        &self.asset.atlas_pages[self.index].format_token
+        &self.data().format_token
     }
 
     /// Returns the minification filter.
     #[must_use]
     pub fn min_filter(self) -> TextureFilter {
-        self.asset.atlas_pages[self.index].min_filter
+        self.data().min_filter
     }
 
     /// Returns the original minification filter token.
     #[must_use]
     pub fn min_filter_token(self) -> &'a str {
-        &self.asset.atlas_pages[self.index].min_filter_token
+        &self.data().min_filter_token
     }
 
     /// Returns the magnification filter.
     #[must_use]
     pub fn mag_filter(self) -> TextureFilter {
-        self.asset.atlas_pages[self.index].mag_filter
+        self.data().mag_filter
     }
 
     /// Returns the original magnification filter token.
     #[must_use]
     pub fn mag_filter_token(self) -> &'a str {
-        &self.asset.atlas_pages[self.index].mag_filter_token
+        &self.data().mag_filter_token
     }
 
     /// Returns the page wrap mode.
     #[must_use]
     pub fn wrap(self) -> WrapMode {
-        self.asset.atlas_pages[self.index].wrap
+        self.data().wrap
     }
 
     /// Returns the page alpha encoding.
     #[must_use]
     pub fn alpha_encoding(self) -> AlphaEncoding {
-        self.asset.atlas_pages[self.index].alpha_encoding
+        self.data().alpha_encoding
     }
 
     /// Returns the atlas export scale.
     #[must_use]
     pub fn scale(self) -> f32 {
-        self.asset.atlas_pages[self.index].scale
+        self.data().scale
     }
 
     /// Iterates this page's atlas regions in source order.
     pub fn regions(self) -> impl DoubleEndedIterator<Item = AtlasRegionRef<'a>> + 'a {
-        let range = self.asset.atlas_pages[self.index].regions.clone();
-        range.map(|index| AtlasRegionRef {
+        let start = self.asset.atlas_region_starts[self.atlas()];
+        let range = self.data().regions.clone();
+        range.map(move |index| AtlasRegionRef {
             asset: self.asset,
-            index: index as usize,
+            index: (start + index) as usize,
         })
     }
 
+    /// Returns the position of this page's atlas in
+    /// [`SkeletonAsset::atlases`].
+    #[must_use]
+    pub fn atlas(self) -> usize {
+        self.asset.atlas_page_data(self.index).0
+    }
+
     /// Iterates unknown or extended page properties in source order.
     pub fn extensions(
         self,
     ) -> impl DoubleEndedIterator<Item = AtlasPropertyRef<'a>> + ExactSizeIterator + 'a {
-        self.asset.atlas_pages[self.index]
+        self.data()
             .extensions
             .iter()
             .map(|property| AtlasPropertyRef { property })
@@ -1726,6 +1991,10 @@ pub struct AtlasRegionRef<'a> {
 }
 
 impl<'a> AtlasRegionRef<'a> {
+    fn data(self) -> &'a AtlasRegionData {
+        self.asset.atlas_region_data(self.index).1
+    }
+
     /// Returns the asset-scoped region ID.
     #[must_use]
     pub fn id(self) -> AtlasRegionId {
@@ -1735,56 +2004,60 @@ impl<'a> AtlasRegionRef<'a> {
     /// Returns the authored region name.
     #[must_use]
     pub fn name(self) -> &'a str {
-        &self.asset.atlas_regions[self.index].name
+        &self.data().name
     }
 
     /// Returns the containing page.
     #[must_use]
     pub fn page(self) -> AtlasPageId {
-        AtlasPageId::new(self.asset.key, self.asset.atlas_regions[self.index].page)
+        let (atlas, region) = self.asset.atlas_region_data(self.index);
+        AtlasPageId::new(
+            self.asset.key,
+            self.asset.atlas_page_starts[atlas] + region.page,
+        )
     }
 
     /// Returns the optional sequence index.
     #[must_use]
     pub fn index(self) -> Option<u32> {
-        self.asset.atlas_regions[self.index].index
+        self.data().index
     }
 
     /// Returns the packed page-space bounds.
     #[must_use]
     pub fn bounds(self) -> PixelRect {
-        self.asset.atlas_regions[self.index].bounds
+        self.data().bounds
     }
 
     /// Returns the unpacked trimming metadata.
     #[must_use]
     pub fn trim(self) -> Trim {
-        self.asset.atlas_regions[self.index].trim
+        self.data().trim
     }
 
     /// Returns the authored packed rotation.
     #[must_use]
     pub fn rotation(self) -> AtlasRotation {
-        self.asset.atlas_regions[self.index].rotation
+        self.data().rotation
     }
 
     /// Returns optional nine-patch splits.
     #[must_use]
     pub fn split(self) -> Option<[i32; 4]> {
-        self.asset.atlas_regions[self.index].split
+        self.data().split
     }
 
     /// Returns optional nine-patch padding.
     #[must_use]
     pub fn pad(self) -> Option<[i32; 4]> {
-        self.asset.atlas_regions[self.index].pad
+        self.data().pad
     }
 
     /// Iterates extended region properties in source order.
     pub fn extensions(
         self,
     ) -> impl DoubleEndedIterator<Item = AtlasPropertyRef<'a>> + ExactSizeIterator + 'a {
-        self.asset.atlas_regions[self.index]
+        self.data()
             .extensions
             .iter()
             .map(|property| AtlasPropertyRef { property })
@@ -1797,6 +2070,12 @@ impl<'a> AtlasRegionRef<'a> {
     }
 }
 
+/// Splits an asset-scoped atlas index into its atlas and the index within it.
+fn locate(starts: &[u32], index: usize) -> (usize, usize) {
+    let atlas = starts.partition_point(|start| *start as usize <= index) - 1;
+    (atlas, index - starts[atlas] as usize)
+}
+
 fn lookup<T>(values: &[T], name: impl Fn(&T) -> &str) -> HashMap<Box<str>, u32> {
     values
         .iter()
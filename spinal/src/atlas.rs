@@ -1,6 +1,7 @@
 use std::{collections::HashMap, ops::Range, str};
 
 use crate::{
+    asset::{AtlasExtension as AssetAtlasExtension, AtlasPageData, AtlasRegionData},
     geometry::{
         AlphaEncoding, AtlasRotation, PixelRect, PixelSize, TextureFilter, TextureFormat, Trim,
         WrapMode,
@@ -8,6 +9,137 @@ use crate::{
     load::error::{LoadDocument, LoadError, LoadErrorKind, SourceLocation},
 };
 
+/// A parsed text atlas that any number of skeletons can link against.
+///
+/// Parse an atlas shared by several exports, such as a sheet of cosmetics,
+/// once and pass the same [`Arc`](std::sync::Arc) to
+/// [`load_json_with_atlases`](crate::load_json_with_atlases) for each
+/// skeleton. Every linked [`SkeletonAsset`](crate::SkeletonAsset) then holds
+/// that `Arc` rather than a copy of the pages and regions.
+#[derive(Debug)]
+pub struct TextAtlas {
+    pub(crate) pages: Box<[AtlasPageData]>,
+    pub(crate) regions: Box<[AtlasRegionData]>,
+    pub(crate) issues: Box<[AtlasIssue]>,
+    regions_by_name: HashMap<Box<str>, Box<[u32]>>,
+}
+
+impl TextAtlas {
+    /// Parses and validates Spine text-atlas data.
+    ///
+    /// Renderer-profile issues, such as a premultiplied-alpha page, are not
+    /// errors; they become diagnostics of every skeleton linked against the
+    /// atlas.
+    pub fn parse(atlas_text: &[u8]) -> Result<Self, LoadError> {
+        Self::from_parsed(parse_atlas(atlas_text)?)
+    }
+
+    /// Returns the number of pages.
+    #[must_use]
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns the number of regions across all pages.
+    #[must_use]
+    pub fn region_count(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub(crate) fn from_parsed(atlas: ParsedAtlas) -> Result<Self, LoadError> {
+        let pages = atlas
+            .pages
+            .into_iter()
+            .map(|page| {
+                Ok(AtlasPageData {
+                    name: page.name,
+                    size: page.size,
+                    format: page.format,
+                    format_token: page.format_token,
+                    min_filter: page.min_filter,
+                    min_filter_token: page.min_filter_token,
+                    mag_filter: page.mag_filter,
+                    mag_filter_token: page.mag_filter_token,
+                    wrap: page.wrap,
+                    alpha_encoding: page.alpha_encoding,
+                    scale: page.scale,
+                    regions: table_index(page.region_range.start, "/pages")?
+                        ..table_index(page.region_range.end, "/pages")?,
+                    extensions: convert_extensions(page.extensions),
+                })
+            })
+            .collect::<Result<Box<_>, LoadError>>()?;
+        let regions = atlas
+            .regions
+            .into_iter()
+            .map(|region| {
+                Ok(AtlasRegionData {
+                    name: region.name,
+                    page: table_index(region.page, "/regions")?,
+                    index: region.index,
+                    bounds: region.bounds,
+                    trim: region.offsets,
+                    rotation: region.rotation,
+                    split: region.split,
+                    pad: region.pad,
+                    extensions: convert_extensions(region.extensions),
+                })
+            })
+            .collect::<Result<Box<_>, LoadError>>()?;
+        Ok(Self::from_tables(pages, regions, atlas.issues.into()))
+    }
+
+    /// Assembles validated tables and indexes their regions by name.
+    pub(crate) fn from_tables(
+        pages: Box<[AtlasPageData]>,
+        regions: Box<[AtlasRegionData]>,
+        issues: Box<[AtlasIssue]>,
+    ) -> Self {
+        let mut indexes = HashMap::<Box<str>, Vec<u32>>::new();
+        for (index, region) in regions.iter().enumerate() {
+            let index = u32::try_from(index)
+                .expect("validated atlas region tables fit the asset-scoped ID representation");
+            indexes.entry(region.name.clone()).or_default().push(index);
+        }
+        Self {
+            pages,
+            regions,
+            issues,
+            regions_by_name: indexes
+                .into_iter()
+                .map(|(name, indexes)| (name, indexes.into_boxed_slice()))
+                .collect(),
+        }
+    }
+
+    /// Returns every region with the given name in source order.
+    pub(crate) fn regions_named(&self, name: &str) -> &[u32] {
+        self.regions_by_name
+            .get(name)
+            .map_or(&[], |indexes| indexes)
+    }
+}
+
+fn convert_extensions(extensions: Vec<AtlasExtension>) -> Box<[AssetAtlasExtension]> {
+    extensions
+        .into_iter()
+        .map(|extension| AssetAtlasExtension {
+            key: extension.key,
+            value: extension.value,
+        })
+        .collect()
+}
+
+fn table_index(index: usize, path: &str) -> Result<u32, LoadError> {
+    u32::try_from(index).map_err(|_error| {
+        LoadError::new(
+            LoadErrorKind::CapacityExceeded,
+            "atlas table exceeds the asset-scoped ID representation",
+            SourceLocation::for_document(LoadDocument::Atlas).with_path(path),
+        )
+    })
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct ParsedAtlas {
     pub(crate) pages: Vec<ParsedAtlasPage>,
@@ -72,6 +204,18 @@ pub(crate) struct AtlasIssue {
 }
 
 impl AtlasIssue {
+    pub(crate) const fn new(
+        target: AtlasIssueTarget,
+        kind: AtlasIssueKind,
+        message: Box<str>,
+    ) -> Self {
+        Self {
+            target,
+            kind,
+            message,
+        }
+    }
+
     pub(crate) const fn target(&self) -> AtlasIssueTarget {
         self.target
     }
@@ -298,6 +442,134 @@ pub(crate) fn parse_atlas(bytes: &[u8]) -> Result<ParsedAtlas, LoadError> {
     })
 }
 
+/// Validates pages and regions assembled in memory with the rules applied to
+/// atlas text, and collects the same renderer-profile issues.
+///
+/// Each page's `region_range` must already index `regions`.
+pub(crate) fn assemble_atlas(
+    pages: Vec<ParsedAtlasPage>,
+    regions: Vec<ParsedAtlasRegion>,
+) -> Result<ParsedAtlas, LoadError> {
+    let location = |path: String| SourceLocation::for_document(LoadDocument::Atlas).with_path(path);
+    if pages.is_empty() {
+        return Err(LoadError::new(
+            LoadErrorKind::SchemaViolation,
+            "atlas must contain at least one page",
+            location("/pages/0/name".into()),
+        ));
+    }
+
+    let mut issues = Vec::new();
+    let mut page_names: HashMap<&str, (usize, SourceLocation)> = HashMap::new();
+    for (page_index, page) in pages.iter().enumerate() {
+        let name_location = location(format!("/pages/{page_index}/name"));
+        if page.name.is_empty() {
+            return Err(LoadError::new(
+                LoadErrorKind::SchemaViolation,
+                "atlas page name cannot be empty",
+                name_location,
+            ));
+        }
+        if let Some((previous_index, previous_location)) = page_names.get(page.name.as_ref()) {
+            return Err(LoadError::new(
+                LoadErrorKind::DuplicateName,
+                format!(
+                    "atlas page name {:?} duplicates page {previous_index}",
+                    page.name
+                ),
+                name_location,
+            )
+            .with_related_locations(vec![previous_location.clone()].into_boxed_slice()));
+        }
+        page_names.insert(&page.name, (page_index, name_location));
+        validate_page_size(page_index, page.size, None)?;
+        if page.alpha_encoding == AlphaEncoding::Premultiplied {
+            issues.push(AtlasIssue {
+                target: AtlasIssueTarget::Page(page_index),
+                kind: AtlasIssueKind::PremultipliedAlpha,
+                message: "premultiplied-alpha page differs from the straight-alpha profile".into(),
+            });
+        }
+        if page.min_filter == TextureFilter::Unknown || page.mag_filter == TextureFilter::Unknown {
+            unsupported_page_setting(
+                &mut issues,
+                page_index,
+                format!(
+                    "unknown atlas texture filter {:?}, {:?}",
+                    page.min_filter_token, page.mag_filter_token
+                ),
+            );
+        }
+    }
+
+    let mut region_names: HashMap<(&str, Option<u32>), (usize, SourceLocation)> = HashMap::new();
+    for (region_index, region) in regions.iter().enumerate() {
+        let name_location = location(format!("/regions/{region_index}/name"));
+        if region.name.is_empty() {
+            return Err(LoadError::new(
+                LoadErrorKind::SchemaViolation,
+                "atlas region name cannot be empty",
+                name_location,
+            ));
+        }
+        let duplicate_key = (region.name.as_ref(), region.index);
+        if let Some((previous_index, previous_location)) = region_names.get(&duplicate_key) {
+            return Err(LoadError::new(
+                LoadErrorKind::DuplicateName,
+                format!(
+                    "atlas region ({:?}, {:?}) duplicates region {previous_index}",
+                    region.name, region.index
+                ),
+                name_location,
+            )
+            .with_related_locations(vec![previous_location.clone()].into_boxed_slice()));
+        }
+        region_names.insert(duplicate_key, (region_index, name_location));
+        validate_region_geometry(
+            region_index,
+            pages[region.page].size,
+            region.bounds,
+            region.offsets,
+            region.rotation,
+            region.split,
+            region.pad,
+            &HashMap::new(),
+        )?;
+        if !region.rotation.is_quarter_turn() {
+            issues.push(AtlasIssue {
+                target: AtlasIssueTarget::Region(region_index),
+                kind: AtlasIssueKind::UnsupportedRotation,
+                message: format!(
+                    "region {:?} uses unsupported packed rotation {} degrees",
+                    region.name,
+                    region.rotation.as_degrees()
+                )
+                .into(),
+            });
+        }
+    }
+
+    Ok(ParsedAtlas {
+        pages,
+        regions,
+        issues,
+    })
+}
+
+/// Returns the atlas text token for a documented texture filter.
+pub(crate) const fn texture_filter_token(filter: TextureFilter) -> &'static str {
+    match filter {
+        TextureFilter::Nearest => "Nearest",
+        TextureFilter::Linear => "Linear",
+        TextureFilter::MipMap => "MipMap",
+        TextureFilter::MipMapNearestNearest => "MipMapNearestNearest",
+        TextureFilter::MipMapLinearNearest => "MipMapLinearNearest",
+        TextureFilter::MipMapNearestLinear => "MipMapNearestLinear",
+        TextureFilter::MipMapLinearLinear => "MipMapLinearLinear",
+        TextureFilter::Unknown => "Unknown",
+    }
+}
+
 struct ParsedRegionWithLocation {
     data: ParsedAtlasRegion,
     name_line: usize,
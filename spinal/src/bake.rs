@@ -0,0 +1,560 @@
+//! Offline pose baking and cached playback for background characters.
+
+use std::{mem::size_of_val, num::NonZeroU32, sync::Arc, time::Duration};
+
+use glam::Vec2;
+
+use crate::{
+    AnimationId, AnimationRef, AttachmentId, BoneId, DrawItemRef, IdError, MeshDrawItemRef,
+    PlaybackMode, RegionDrawItemRef, Rgba, Skeleton, SkeletonAsset, SlotId, WorldTransform,
+    skeleton::skin_mesh,
+};
+
+const DEFAULT_FRAME_RATE: NonZeroU32 = NonZeroU32::new(30).expect("thirty is nonzero");
+const NO_BAKED_VERTICES: u32 = u32::MAX;
+
+/// Sampling settings for [`BakedAnimation::bake`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BakeOptions {
+    frame_rate: NonZeroU32,
+    meshes: bool,
+}
+
+impl BakeOptions {
+    /// Samples thirty frames per second and skins meshes at playback.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            frame_rate: DEFAULT_FRAME_RATE,
+            meshes: false,
+        }
+    }
+
+    /// Sets how many frames are sampled per second of animation.
+    #[must_use]
+    pub const fn with_frame_rate(mut self, frame_rate: NonZeroU32) -> Self {
+        self.frame_rate = frame_rate;
+        self
+    }
+
+    /// Stores skinned mesh vertices in every frame instead of re-skinning
+    /// interpolated bones at playback, trading memory for playback work.
+    #[must_use]
+    pub const fn with_meshes(mut self, meshes: bool) -> Self {
+        self.meshes = meshes;
+        self
+    }
+
+    /// Returns the sampled frames per second.
+    #[must_use]
+    pub const fn frame_rate(self) -> NonZeroU32 {
+        self.frame_rate
+    }
+
+    /// Returns whether mesh vertices are baked.
+    #[must_use]
+    pub const fn bakes_meshes(self) -> bool {
+        self.meshes
+    }
+}
+
+impl Default for BakeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Memory and accuracy of one bake.
+///
+/// Errors are measured at the midpoint of every sampled interval against a
+/// live solve, in skeleton-space units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BakeReport {
+    frames: usize,
+    bytes: usize,
+    max_bone_error: f32,
+    max_vertex_error: f32,
+}
+
+impl BakeReport {
+    /// Returns the number of stored frames, including both endpoints.
+    #[must_use]
+    pub const fn frames(self) -> usize {
+        self.frames
+    }
+
+    /// Returns the heap bytes retained by the baked frames.
+    #[must_use]
+    pub const fn bytes(self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the largest distance between an interpolated and a solved bone
+    /// origin.
+    #[must_use]
+    pub const fn max_bone_error(self) -> f32 {
+        self.max_bone_error
+    }
+
+    /// Returns the largest distance between an interpolated and a solved
+    /// draw vertex, for draw items visible at both.
+    #[must_use]
+    pub const fn max_vertex_error(self) -> f32 {
+        self.max_vertex_error
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BakedDraw {
+    slot: u32,
+    attachment: u32,
+    color: Rgba,
+    mesh_start: u32,
+}
+
+/// One animation sampled at a fixed rate into solved bone world transforms
+/// and draw state.
+///
+/// Slot attachments, colours and draw order step between frames; bone
+/// transforms and baked mesh vertices are interpolated linearly.
+#[derive(Debug)]
+pub struct BakedAnimation {
+    asset: Arc<SkeletonAsset>,
+    animation: AnimationId,
+    frame_rate: NonZeroU32,
+    duration: Duration,
+    frames: usize,
+    bones: Box<[WorldTransform]>,
+    draw_offsets: Box<[u32]>,
+    draws: Box<[BakedDraw]>,
+    mesh_positions: Option<Box<[Vec2]>>,
+    max_draws: usize,
+    max_mesh_vertices: usize,
+    report: BakeReport,
+}
+
+impl BakedAnimation {
+    /// Samples `animation` on `skeleton`, including its skin layers and
+    /// constraints, and measures the interpolation error.
+    ///
+    /// The skeleton's local pose is overwritten.
+    pub fn bake(
+        skeleton: &mut Skeleton,
+        animation: AnimationRef<'_>,
+        options: BakeOptions,
+    ) -> Result<Self, IdError> {
+        let asset = Arc::clone(skeleton.asset_handle());
+        let id = animation.id();
+        let duration = asset.animation(id)?.duration();
+        let rate = f64::from(options.frame_rate.get());
+        let frames = (duration.as_secs_f64() * rate).ceil() as usize + 1;
+        let bone_count = asset.bones().len();
+        let mut baked = Self {
+            asset,
+            animation: id,
+            frame_rate: options.frame_rate,
+            duration,
+            frames,
+            bones: Box::default(),
+            draw_offsets: Box::default(),
+            draws: Box::default(),
+            mesh_positions: None,
+            max_draws: 0,
+            max_mesh_vertices: 0,
+            report: BakeReport {
+                frames,
+                bytes: 0,
+                max_bone_error: 0.0,
+                max_vertex_error: 0.0,
+            },
+        };
+
+        let mut bones = Vec::with_capacity(frames * bone_count);
+        let mut draw_offsets = Vec::with_capacity(frames + 1);
+        let mut draws = Vec::new();
+        let mut mesh_positions = Vec::new();
+        draw_offsets.push(0);
+        for frame in 0..frames {
+            skeleton.sample_animation(id, baked.frame_time(frame), PlaybackMode::Once)?;
+            let solved = skeleton.editable_pose().solve();
+            let skeleton = solved.skeleton;
+            bones.extend_from_slice(&skeleton.world_transforms);
+            let first_draw = draws.len();
+            let mut frame_vertices = 0;
+            for slot_pose in skeleton.draw_order() {
+                let Some(attachment) = slot_pose.attachment() else {
+                    continue;
+                };
+                let attachment = baked.asset.attachment(attachment)?;
+                let mesh = attachment.as_mesh();
+                if attachment.as_region().is_none() && mesh.is_none() {
+                    continue;
+                }
+                let mut mesh_start = NO_BAKED_VERTICES;
+                if let Some(mesh) = mesh {
+                    frame_vertices += mesh.vertex_count();
+                    if options.meshes {
+                        mesh_start = u32::try_from(mesh_positions.len())
+                            .expect("baked mesh vertices fit the compact index");
+                        let range = skeleton.mesh_vertex_ranges[attachment.ordinal()].clone();
+                        mesh_positions.extend_from_slice(&skeleton.mesh_world_positions[range]);
+                    }
+                }
+                draws.push(BakedDraw {
+                    slot: slot_pose.id().index(),
+                    attachment: attachment.id().index(),
+                    color: slot_pose.color(),
+                    mesh_start,
+                });
+            }
+            baked.max_draws = baked.max_draws.max(draws.len() - first_draw);
+            baked.max_mesh_vertices = baked.max_mesh_vertices.max(frame_vertices);
+            draw_offsets
+                .push(u32::try_from(draws.len()).expect("baked draws fit the compact index"));
+        }
+        baked.bones = bones.into_boxed_slice();
+        baked.draw_offsets = draw_offsets.into_boxed_slice();
+        baked.draws = draws.into_boxed_slice();
+        baked.mesh_positions = options.meshes.then(|| mesh_positions.into_boxed_slice());
+        baked.report.bytes = size_of_val(&*baked.bones)
+            + size_of_val(&*baked.draw_offsets)
+            + size_of_val(&*baked.draws)
+            + baked.mesh_positions.as_deref().map_or(0, size_of_val);
+        baked.measure_error(skeleton)?;
+        Ok(baked)
+    }
+
+    /// Returns the asset the animation was baked from.
+    #[must_use]
+    pub fn asset(&self) -> &SkeletonAsset {
+        &self.asset
+    }
+
+    /// Returns the baked animation.
+    #[must_use]
+    pub const fn animation(&self) -> AnimationId {
+        self.animation
+    }
+
+    /// Returns the sampled frames per second.
+    #[must_use]
+    pub const fn frame_rate(&self) -> NonZeroU32 {
+        self.frame_rate
+    }
+
+    /// Returns the animation duration.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns whether mesh vertices were baked.
+    #[must_use]
+    pub const fn bakes_meshes(&self) -> bool {
+        self.mesh_positions.is_some()
+    }
+
+    /// Returns the memory and error budget measured at bake time.
+    #[must_use]
+    pub const fn report(&self) -> BakeReport {
+        self.report
+    }
+
+    fn frame_time(&self, frame: usize) -> Duration {
+        Duration::from_secs_f64(frame as f64 / f64::from(self.frame_rate.get())).min(self.duration)
+    }
+
+    fn frame_bones(&self, frame: usize) -> &[WorldTransform] {
+        let count = self.asset.bones().len();
+        &self.bones[frame * count..(frame + 1) * count]
+    }
+
+    fn frame_draws(&self, frame: usize) -> &[BakedDraw] {
+        &self.draws[self.draw_offsets[frame] as usize..self.draw_offsets[frame + 1] as usize]
+    }
+
+    /// Returns the frame at or before `time` and the interpolation weight
+    /// toward the following frame.
+    fn locate(&self, time: Duration) -> (usize, usize, f32) {
+        if self.frames == 1 {
+            return (0, 0, 0.0);
+        }
+        let time = time.min(self.duration);
+        let scaled = time.as_secs_f64() * f64::from(self.frame_rate.get());
+        let frame = (scaled.floor() as usize).min(self.frames - 2);
+        let start = self.frame_time(frame);
+        let span = self
+            .frame_time(frame + 1)
+            .saturating_sub(start)
+            .as_secs_f64();
+        let alpha = if span > 0.0 {
+            ((time.saturating_sub(start).as_secs_f64()) / span).clamp(0.0, 1.0) as f32
+        } else {
+            0.0
+        };
+        (frame, frame + 1, alpha)
+    }
+
+    fn measure_error(&mut self, skeleton: &mut Skeleton) -> Result<(), IdError> {
+        let mut pose = BakedPose::new(self);
+        let mut max_bone_error = 0.0_f32;
+        let mut max_vertex_error = 0.0_f32;
+        for frame in 0..self.frames.saturating_sub(1) {
+            let start = self.frame_time(frame);
+            let midpoint = start + (self.frame_time(frame + 1) - start) / 2;
+            skeleton.sample_animation(self.animation, midpoint, PlaybackMode::Once)?;
+            let solved = skeleton.editable_pose().solve();
+            pose.sample(self, midpoint);
+            for (live, baked) in solved.skeleton.world_transforms.iter().zip(&pose.world) {
+                max_bone_error =
+                    max_bone_error.max(live.translation().distance(baked.translation()));
+            }
+            for (live, baked) in solved.draw_items().zip(pose.draw_items(&self.asset)) {
+                let distance = |(live, baked): (&Vec2, &Vec2)| live.distance(*baked);
+                let error = match (live, baked) {
+                    (DrawItemRef::Region(live), DrawItemRef::Region(baked))
+                        if live.attachment() == baked.attachment() =>
+                    {
+                        live.positions()
+                            .iter()
+                            .zip(&baked.positions())
+                            .map(distance)
+                            .fold(0.0, f32::max)
+                    }
+                    (DrawItemRef::Mesh(live), DrawItemRef::Mesh(baked))
+                        if live.attachment() == baked.attachment() =>
+                    {
+                        live.positions()
+                            .iter()
+                            .zip(baked.positions())
+                            .map(distance)
+                            .fold(0.0, f32::max)
+                    }
+                    _ => 0.0,
+                };
+                max_vertex_error = max_vertex_error.max(error);
+            }
+        }
+        self.report.max_bone_error = max_bone_error;
+        self.report.max_vertex_error = max_vertex_error;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PoseDraw {
+    slot: u32,
+    attachment: u32,
+    color: Rgba,
+    vertices: (usize, usize),
+}
+
+/// Interpolated output storage sized once for one baked animation.
+#[derive(Debug)]
+struct BakedPose {
+    world: Box<[WorldTransform]>,
+    draws: Vec<PoseDraw>,
+    mesh_positions: Vec<Vec2>,
+}
+
+impl BakedPose {
+    fn new(baked: &BakedAnimation) -> Self {
+        Self {
+            world: baked.frame_bones(0).into(),
+            draws: Vec::with_capacity(baked.max_draws),
+            mesh_positions: Vec::with_capacity(baked.max_mesh_vertices),
+        }
+    }
+
+    fn sample(&mut self, baked: &BakedAnimation, time: Duration) {
+        let (frame, next, alpha) = baked.locate(time);
+        for ((world, from), to) in self
+            .world
+            .iter_mut()
+            .zip(baked.frame_bones(frame))
+            .zip(baked.frame_bones(next))
+        {
+            *world = from.lerp(*to, alpha);
+        }
+
+        self.draws.clear();
+        self.mesh_positions.clear();
+        let asset = &*baked.asset;
+        for draw in baked.frame_draws(frame) {
+            let mut vertices = (0, 0);
+            let attachment = asset
+                .attachment(AttachmentId::new(asset.key(), draw.attachment))
+                .expect("baked attachments belong to their asset");
+            if let Some(mesh) = attachment.as_mesh() {
+                let start = self.mesh_positions.len();
+                let count = mesh.vertex_count();
+                match &baked.mesh_positions {
+                    Some(positions) => {
+                        let from = &positions[draw.mesh_start as usize..][..count];
+                        let to = baked
+                            .frame_draws(next)
+                            .iter()
+                            .find(|other| {
+                                other.slot == draw.slot && other.attachment == draw.attachment
+                            })
+                            .map_or(from, |other| {
+                                &positions[other.mesh_start as usize..][..count]
+                            });
+                        self.mesh_positions
+                            .extend(from.iter().zip(to).map(|(from, to)| from.lerp(*to, alpha)));
+                    }
+                    None => {
+                        self.mesh_positions.resize(start + count, Vec2::ZERO);
+                        skin_mesh(
+                            asset,
+                            attachment.ordinal(),
+                            &self.world,
+                            &mut self.mesh_positions[start..],
+                        );
+                    }
+                }
+                vertices = (start, count);
+            }
+            self.draws.push(PoseDraw {
+                slot: draw.slot,
+                attachment: draw.attachment,
+                color: draw.color,
+                vertices,
+            });
+        }
+    }
+
+    fn draw_items<'a>(
+        &'a self,
+        asset: &'a SkeletonAsset,
+    ) -> impl Iterator<Item = DrawItemRef<'a>> + 'a {
+        self.draws.iter().map(move |draw| {
+            let slot = asset
+                .slot(SlotId::new(asset.key(), draw.slot))
+                .expect("baked slots belong to their asset");
+            let attachment = asset
+                .attachment(AttachmentId::new(asset.key(), draw.attachment))
+                .expect("baked attachments belong to their asset");
+            if let Some(region) = attachment.as_region() {
+                let bone = asset
+                    .bone(slot.bone())
+                    .expect("a linked slot bone belongs to its asset");
+                return DrawItemRef::from(
+                    RegionDrawItemRef::from_asset(
+                        asset,
+                        slot,
+                        region,
+                        self.world[bone.ordinal()],
+                        draw.color,
+                        None,
+                    )
+                    .expect("baked draw references belong to one asset"),
+                );
+            }
+            let mesh = attachment
+                .as_mesh()
+                .expect("baked draws are regions or meshes");
+            let (start, count) = draw.vertices;
+            DrawItemRef::from(
+                MeshDrawItemRef::from_asset(
+                    asset,
+                    slot,
+                    mesh,
+                    &self.mesh_positions[start..start + count],
+                    draw.color,
+                    None,
+                )
+                .expect("baked mesh references belong to one asset"),
+            )
+        })
+    }
+}
+
+/// Plays a [`BakedAnimation`] by interpolating between its frames.
+///
+/// The player owns output storage sized at construction, so advancing and
+/// drawing allocate nothing.
+#[derive(Debug)]
+pub struct BakedPlayer {
+    baked: Arc<BakedAnimation>,
+    mode: PlaybackMode,
+    position: Duration,
+    pose: BakedPose,
+}
+
+impl BakedPlayer {
+    /// Creates a player posed at the start of the baked animation.
+    #[must_use]
+    pub fn new(baked: Arc<BakedAnimation>, mode: PlaybackMode) -> Self {
+        let mut pose = BakedPose::new(&baked);
+        pose.sample(&baked, Duration::ZERO);
+        Self {
+            baked,
+            mode,
+            position: Duration::ZERO,
+            pose,
+        }
+    }
+
+    /// Returns the baked animation being played.
+    #[must_use]
+    pub fn baked(&self) -> &BakedAnimation {
+        &self.baked
+    }
+
+    /// Returns whether playback holds the end or wraps.
+    #[must_use]
+    pub const fn mode(&self) -> PlaybackMode {
+        self.mode
+    }
+
+    /// Returns the current animation-local position.
+    #[must_use]
+    pub const fn position(&self) -> Duration {
+        self.position
+    }
+
+    /// Returns whether one-shot playback has reached the end.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        matches!(self.mode, PlaybackMode::Once) && self.position >= self.baked.duration
+    }
+
+    /// Advances the clock by `delta` and interpolates the new pose.
+    pub fn advance(&mut self, delta: Duration) {
+        self.seek_to(self.position.saturating_add(delta));
+    }
+
+    /// Moves the clock to `position`, wrapping or clamping it by the playback
+    /// mode, and interpolates the new pose.
+    pub fn seek_to(&mut self, position: Duration) {
+        let duration = self.baked.duration;
+        self.position = match self.mode {
+            PlaybackMode::Loop if !duration.is_zero() => {
+                let wrapped = position.as_nanos() % duration.as_nanos();
+                Duration::new(
+                    (wrapped / 1_000_000_000) as u64,
+                    (wrapped % 1_000_000_000) as u32,
+                )
+            }
+            _ => position.min(duration),
+        };
+        self.pose.sample(&self.baked, self.position);
+    }
+
+    /// Returns one interpolated bone world transform.
+    pub fn bone_world(&self, bone: BoneId) -> Result<WorldTransform, IdError> {
+        let index = self.baked.asset.bone_index(bone)?;
+        Ok(self.pose.world[index])
+    }
+
+    /// Iterates interpolated bone world transforms in source order.
+    pub fn bone_worlds(&self) -> impl ExactSizeIterator<Item = WorldTransform> + '_ {
+        self.pose.world.iter().copied()
+    }
+
+    /// Iterates the interpolated draw items in back-to-front draw order.
+    pub fn draw_items(&self) -> impl Iterator<Item = DrawItemRef<'_>> + '_ {
+        self.pose.draw_items(&self.baked.asset)
+    }
+}
@@ -0,0 +1,95 @@
+//! Evaluation of many independent mixer and skeleton pairs in one call.
+
+use std::time::Duration;
+
+use crate::{AnimationMixer, EditablePose, PlayerError, Skeleton, SolvedFrame, TrackEventSink};
+
+/// Receives one batch instance's events, pose edits and solved frame.
+///
+/// The callbacks for one instance run in the order of a sequential
+/// [`AnimationMixer::update`], pose edit and [`EditablePose::solve`], on the
+/// thread evaluating that instance.
+pub trait BatchContext: TrackEventSink {
+    /// Edits the sampled local pose before constraints are solved.
+    ///
+    /// The default leaves the pose unchanged.
+    fn edit(&mut self, pose: &mut EditablePose<'_>) {
+        let _ = pose;
+    }
+
+    /// Receives the solved frame, or the error that rejected the update.
+    fn solved(&mut self, mixer: &AnimationMixer, frame: Result<SolvedFrame<'_>, PlayerError>);
+}
+
+/// One independent mixer and skeleton pair evaluated by [`update_batch`].
+pub trait BatchInstance {
+    /// The receiver of this instance's events and results.
+    type Context: BatchContext + ?Sized;
+
+    /// Splits the instance into its mixer, bound skeleton and context.
+    fn parts(&mut self) -> BatchParts<'_, Self::Context>;
+}
+
+/// Disjoint borrows of one [`BatchInstance`].
+#[derive(Debug)]
+pub struct BatchParts<'a, C: ?Sized> {
+    mixer: &'a mut AnimationMixer,
+    skeleton: &'a mut Skeleton,
+    context: &'a mut C,
+}
+
+impl<'a, C: ?Sized> BatchParts<'a, C> {
+    /// Groups a mixer, the skeleton it is bound to and their context.
+    #[must_use]
+    pub const fn new(
+        mixer: &'a mut AnimationMixer,
+        skeleton: &'a mut Skeleton,
+        context: &'a mut C,
+    ) -> Self {
+        Self {
+            mixer,
+            skeleton,
+            context,
+        }
+    }
+}
+
+/// Advances and solves every instance by `delta`.
+///
+/// Instances are independent, so each produces exactly the events and frame
+/// of a sequential update. With the `rayon` feature they are evaluated in
+/// parallel on the global rayon pool; otherwise in slice order. Mixers and
+/// skeletons keep reusing their own scratch storage, so a batch allocates no
+/// more than the equivalent sequential updates.
+pub fn update_batch<T>(instances: &mut [T], delta: Duration)
+where
+    T: BatchInstance + Send,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+        instances
+            .par_iter_mut()
+            .for_each(|instance| evaluate(instance, delta));
+    }
+    #[cfg(not(feature = "rayon"))]
+    for instance in instances {
+        evaluate(instance, delta);
+    }
+}
+
+fn evaluate<T: BatchInstance>(instance: &mut T, delta: Duration) {
+    let BatchParts {
+        mixer,
+        skeleton,
+        context,
+    } = instance.parts();
+    match mixer.update(skeleton, delta, context) {
+        Ok(mut pose) => {
+            context.edit(&mut pose);
+            context.solved(mixer, Ok(pose.solve()));
+        }
+        Err(error) => context.solved(mixer, Err(error)),
+    }
+}
@@ -0,0 +1,193 @@
+//! Conversions between skeleton space and one bone's coordinate systems.
+
+use glam::Vec2;
+
+use crate::{
+    Angle, BoneId, BoneTransform, EditablePose, IdError, SolvedFrame, float,
+    frame::{current_unconstrained_world, replace_rotation},
+    world::{WorldTransform, normal_local_to_world, solve_world_rotation},
+};
+
+/// One bone's local and parent coordinate systems at a point in the solve.
+///
+/// Local space is the bone's own frame, in which its X axis points along the
+/// bone. Parent space is the frame its local transform is expressed in: the
+/// parent bone's local space, or skeleton space for a root. A value from
+/// [`SolvedFrame::bone_space`] reflects the constrained pose; one from
+/// [`EditablePose::bone_space`] reflects the mixed and edited pose before
+/// constraints.
+///
+/// Conversions into a bone or parent space return `None` when the transform
+/// is singular or the result leaves finite `f32` range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoneSpace {
+    bone: BoneId,
+    local: BoneTransform,
+    world: WorldTransform,
+    parent: Option<WorldTransform>,
+}
+
+impl BoneSpace {
+    /// Returns the bone this space belongs to.
+    #[must_use]
+    pub const fn bone(self) -> BoneId {
+        self.bone
+    }
+
+    /// Returns the bone's local transform in parent space.
+    #[must_use]
+    pub const fn local_transform(self) -> BoneTransform {
+        self.local
+    }
+
+    /// Returns the transform from local space to skeleton space.
+    #[must_use]
+    pub const fn world_transform(self) -> WorldTransform {
+        self.world
+    }
+
+    /// Returns the transform from parent space to skeleton space, which is
+    /// the identity for a root bone.
+    #[must_use]
+    pub fn parent_world_transform(self) -> WorldTransform {
+        self.parent.unwrap_or_default()
+    }
+
+    /// Converts a skeleton-space point into local space.
+    #[must_use]
+    pub fn world_to_local_point(self, point: Vec2) -> Option<Vec2> {
+        self.world.try_inverse_point(point)
+    }
+
+    /// Converts a local-space point into skeleton space.
+    #[must_use]
+    pub fn local_to_world_point(self, point: Vec2) -> Vec2 {
+        self.world.transform_point(point)
+    }
+
+    /// Converts a skeleton-space direction or offset into local space.
+    #[must_use]
+    pub fn world_to_local_vector(self, vector: Vec2) -> Option<Vec2> {
+        self.world.try_inverse_vector(vector)
+    }
+
+    /// Converts a local-space direction or offset into skeleton space.
+    #[must_use]
+    pub fn local_to_world_vector(self, vector: Vec2) -> Vec2 {
+        self.world.transform_vector(vector)
+    }
+
+    /// Converts the angle of a skeleton-space direction into the angle of the
+    /// same direction in local space, measured from the bone's X axis.
+    #[must_use]
+    pub fn world_to_local_rotation(self, rotation: Angle) -> Option<Angle> {
+        direction_angle(self.world_to_local_vector(direction(rotation))?)
+    }
+
+    /// Converts the angle of a local-space direction into skeleton space.
+    #[must_use]
+    pub fn local_to_world_rotation(self, rotation: Angle) -> Option<Angle> {
+        direction_angle(self.local_to_world_vector(direction(rotation)))
+    }
+
+    /// Converts a skeleton-space point into parent space, giving the
+    /// translation that places the bone origin there.
+    #[must_use]
+    pub fn world_to_parent_point(self, point: Vec2) -> Option<Vec2> {
+        match self.parent {
+            None => point.is_finite().then_some(point),
+            Some(parent) => parent.try_inverse_point(point),
+        }
+    }
+
+    /// Converts a parent-space point into skeleton space.
+    #[must_use]
+    pub fn parent_to_world_point(self, point: Vec2) -> Vec2 {
+        self.parent_world_transform().transform_point(point)
+    }
+
+    /// Converts a skeleton-space direction or offset into parent space.
+    #[must_use]
+    pub fn world_to_parent_vector(self, vector: Vec2) -> Option<Vec2> {
+        match self.parent {
+            None => vector.is_finite().then_some(vector),
+            Some(parent) => parent.try_inverse_vector(vector),
+        }
+    }
+
+    /// Converts a parent-space direction or offset into skeleton space.
+    #[must_use]
+    pub fn parent_to_world_vector(self, vector: Vec2) -> Vec2 {
+        self.parent_world_transform().transform_vector(vector)
+    }
+
+    /// Returns the local rotation that points the bone's X axis along a
+    /// skeleton-space rotation, keeping its translation, scale and shear.
+    ///
+    /// The result is the equivalent angle nearest the current rotation, so it
+    /// can be written back or blended without a full turn.
+    #[must_use]
+    pub fn world_to_parent_rotation(self, rotation: Angle) -> Option<Angle> {
+        solve_world_rotation(self.parent, self.local, rotation)
+    }
+
+    /// Returns the skeleton-space rotation of the bone's X axis if its local
+    /// rotation were `rotation`.
+    #[must_use]
+    pub fn parent_to_world_rotation(self, rotation: Angle) -> Option<Angle> {
+        let world = normal_local_to_world(self.parent, replace_rotation(self.local, rotation));
+        direction_angle(world.x_axis())
+    }
+}
+
+fn direction(rotation: Angle) -> Vec2 {
+    let (sine, cosine) = float::sin_cos(f64::from(rotation.as_radians()));
+    Vec2::new(cosine as f32, sine as f32)
+}
+
+fn direction_angle(vector: Vec2) -> Option<Angle> {
+    (vector.length_squared() > f32::EPSILON)
+        .then(|| Angle::from_radians(float::atan2_f32(vector.y, vector.x)).ok())
+        .flatten()
+}
+
+impl SolvedFrame<'_> {
+    /// Returns one bone's coordinate systems in the solved pose.
+    pub fn bone_space(&self, bone: BoneId) -> Result<BoneSpace, IdError> {
+        let asset = self.skeleton.asset();
+        let index = asset.bone_index(bone)?;
+        Ok(BoneSpace {
+            bone,
+            local: self.skeleton.applied_bones[index].local_transform,
+            world: self.skeleton.world_transforms[index],
+            parent: asset
+                .bone_data(index)
+                .parent
+                .map(|parent| self.skeleton.world_transforms[parent as usize]),
+        })
+    }
+}
+
+impl EditablePose<'_> {
+    /// Returns one bone's coordinate systems in the mixed and procedurally
+    /// edited pose, before constraints run.
+    ///
+    /// Use it to express [`crate::PoseEditor`] edits in skeleton-space terms.
+    /// The space is a snapshot: after editing a bone or its ancestors, query it
+    /// again.
+    pub fn bone_space(&mut self, bone: BoneId) -> Result<BoneSpace, IdError> {
+        let skeleton = &mut *self.skeleton;
+        let index = skeleton.asset().bone_index(bone)?;
+        let world = current_unconstrained_world(skeleton, index);
+        Ok(BoneSpace {
+            bone,
+            local: skeleton.pose.bones[index].local_transform,
+            world,
+            parent: skeleton
+                .asset()
+                .bone_data(index)
+                .parent
+                .map(|parent| skeleton.world_transforms[parent as usize]),
+        })
+    }
+}
@@ -0,0 +1,1825 @@
+//! Versioned, checksummed binary caches of linked skeleton assets.
+//!
+//! [`write_cache`] encodes every table of a [`SkeletonAsset`], its retained
+//! JSON records and its diagnostics. [`read_cache`] rebuilds the asset without
+//! parsing, linking or validating the export again: after the header and
+//! checksum, reading only checks that lengths, enum tags and cross-table
+//! indexes stay in bounds.
+
+use std::{ops::Range, sync::Arc};
+
+use glam::Vec2;
+use thiserror::Error;
+
+use crate::{
+    AlphaEncoding, Angle, AtlasRotation, BendDirection, BoneTransform, Diagnostic, DiagnosticCode,
+    DiagnosticScope, DiagnosticSeverity, LoadReport, Mix, PixelRect, PixelSize, Rgba8, Shear,
+    SkeletonAsset, SlotBlendMode, TextAtlas, TextureFilter, TextureFormat, TransformMix,
+    TransformMixChannel, Trim, WrapMode,
+    animation::{
+        AnimationData, AttachmentFrame, ColourFrame, DrawOrderFrame, DrawOrderOffset,
+        EventDefinitionData, EventFrame, EventPayload, FrameCurve, IkFrame, PropertyData,
+        RetainedTimeline, ScalarFrame, TimelineData, TimelineTime, TransformFrame, Vec2Frame,
+    },
+    asset::{
+        AssetData, AtlasExtension, AtlasPageData, AtlasRegionData, AttachmentData,
+        AttachmentDataKind, BoneData, ConstraintData, IkConstraintData, RegionAttachmentData,
+        SkinData, SlotData, TransformConstraintData, TransformConstraintPoseData,
+    },
+    atlas::{AtlasIssue, AtlasIssueKind, AtlasIssueTarget},
+    id::AssetKey,
+    json::{JsonMember, JsonValue},
+    load::{PendingDiagnostic, PendingScope},
+    mesh::{MeshAttachmentData, MeshGeometryData, MeshInfluenceData, MeshVerticesData},
+};
+
+/// The skeleton cache format version written by this crate.
+///
+/// Caches written with any other version are rejected rather than migrated;
+/// regenerate them from the source export.
+pub const SKELETON_CACHE_VERSION: u32 = 2;
+
+const MAGIC: [u8; 8] = *b"SPINALSK";
+const HEADER_LEN: usize = MAGIC.len() + 4 + 8 + 8;
+/// The deepest retained JSON nesting accepted, matching the parser's limit.
+const MAX_JSON_DEPTH: u32 = 128;
+
+/// A failure to read a skeleton cache.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum CacheError {
+    /// The bytes do not start with the skeleton cache signature.
+    #[error("the bytes are not a Spinal skeleton cache")]
+    NotACache,
+    /// The cache was written with another format version.
+    #[error("skeleton cache version {found} is not supported; expected {SKELETON_CACHE_VERSION}")]
+    UnsupportedVersion {
+        /// The version recorded in the cache header.
+        found: u32,
+    },
+    /// The payload does not match the checksum recorded in the header.
+    #[error("the skeleton cache checksum does not match its contents")]
+    ChecksumMismatch,
+    /// A length, tag, index or number is out of bounds or truncated.
+    #[error("the skeleton cache is malformed at byte {offset}")]
+    Malformed {
+        /// The byte offset of the rejected value.
+        offset: usize,
+    },
+    /// No further process-local asset identities are available.
+    #[error("process-local asset identity space is exhausted")]
+    CapacityExceeded,
+}
+
+/// Encodes a linked asset and its diagnostics as a skeleton cache.
+///
+/// The cache is specific to [`SKELETON_CACHE_VERSION`], not to the Spine
+/// export version, and reads back with [`read_cache`] into an asset that
+/// behaves identically to `asset` under a new identity.
+#[must_use]
+pub fn write_cache(asset: &SkeletonAsset) -> Vec<u8> {
+    let data = asset.to_data();
+    let mut payload = Encoder::default();
+    Counts::of(&data).encode(&mut payload);
+    data.spine_version.encode(&mut payload);
+    data.retained_metadata.encode(&mut payload);
+    data.retained_sections.encode(&mut payload);
+    payload.table(&data.bones);
+    payload.table(&data.slots);
+    payload.table(&data.skins);
+    payload.table(&data.attachments);
+    payload.table(&data.mesh_geometries);
+    payload.table(&data.animations);
+    payload.table(&data.ik_constraints);
+    payload.table(&data.transform_constraints);
+    payload.table(&data.constraints);
+    data.atlases.encode(&mut payload);
+    payload.table(&data.events);
+    data.diagnostics.encode(&mut payload);
+
+    let payload = payload.0;
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.extend_from_slice(&MAGIC);
+    bytes.extend_from_slice(&SKELETON_CACHE_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&checksum(&payload).to_le_bytes());
+    bytes.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(&payload);
+    bytes
+}
+
+/// Reads a skeleton cache written by [`write_cache`].
+///
+/// The checksum detects damaged files, not deliberate tampering; only load
+/// caches your own build produced. Every read is bounds-checked, so a damaged
+/// cache fails with [`CacheError::Malformed`] instead of panicking.
+pub fn read_cache(bytes: &[u8]) -> Result<LoadReport, CacheError> {
+    let payload = payload(bytes)?;
+    let key = AssetKey::try_fresh().ok_or(CacheError::CapacityExceeded)?;
+    let mut input = Decoder {
+        bytes: payload,
+        offset: 0,
+        base: HEADER_LEN,
+        counts: Counts::default(),
+        key,
+        depth: 0,
+    };
+    input.counts = Counts::decode(&mut input)?;
+    let counts = input.counts;
+    let data = AssetData {
+        spine_version: Cached::decode(&mut input)?,
+        retained_metadata: Cached::decode(&mut input)?,
+        retained_sections: Cached::decode(&mut input)?,
+        bones: input.table(counts.bones)?,
+        slots: input.table(counts.slots)?,
+        skins: input.table(counts.skins)?,
+        attachments: input.table(counts.attachments)?,
+        mesh_geometries: input.table(counts.mesh_geometries)?,
+        animations: input.table(counts.animations)?,
+        ik_constraints: input.table(counts.ik_constraints)?,
+        transform_constraints: input.table(counts.transform_constraints)?,
+        constraints: input.table(counts.constraints)?,
+        atlases: decode_atlases(&mut input)?,
+        events: input.table(counts.events)?,
+        diagnostics: Cached::decode(&mut input)?,
+    };
+    if input.offset != payload.len() {
+        return Err(input.malformed());
+    }
+    Ok(LoadReport::new(SkeletonAsset::from_data(key, data)))
+}
+
+/// Reads the linked atlases, whose tables must add up to the counted totals.
+fn decode_atlases(input: &mut Decoder<'_>) -> Result<Box<[Arc<TextAtlas>]>, CacheError> {
+    let offset = input.offset;
+    let atlases = Box::<[Arc<TextAtlas>]>::decode(input)?;
+    let total =
+        |len: fn(&TextAtlas) -> usize| atlases.iter().map(|atlas| len(atlas)).sum::<usize>();
+    if total(TextAtlas::page_count) == input.counts.atlas_pages as usize
+        && total(TextAtlas::region_count) == input.counts.atlas_regions as usize
+    {
+        Ok(atlases)
+    } else {
+        Err(input.malformed_at(offset))
+    }
+}
+
+/// Checks the header and returns the payload it describes.
+fn payload(bytes: &[u8]) -> Result<&[u8], CacheError> {
+    let (header, payload) = bytes
+        .split_at_checked(HEADER_LEN)
+        .ok_or(CacheError::NotACache)?;
+    let (magic, header) = header.split_at(MAGIC.len());
+    if magic != MAGIC {
+        return Err(CacheError::NotACache);
+    }
+    let (version, header) = header.split_at(4);
+    let version = u32::from_le_bytes(version.try_into().expect("four header bytes"));
+    if version != SKELETON_CACHE_VERSION {
+        return Err(CacheError::UnsupportedVersion { found: version });
+    }
+    let (expected, length) = header.split_at(8);
+    let expected = u64::from_le_bytes(expected.try_into().expect("eight header bytes"));
+    let length = u64::from_le_bytes(length.try_into().expect("eight header bytes"));
+    if length != payload.len() as u64 {
+        return Err(CacheError::Malformed {
+            offset: HEADER_LEN.min(bytes.len()),
+        });
+    }
+    if checksum(payload) != expected {
+        return Err(CacheError::ChecksumMismatch);
+    }
+    Ok(payload)
+}
+
+/// 64-bit FNV-1a over the payload.
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Table lengths, written first so every index can be checked as it is read.
+#[derive(Clone, Copy, Debug, Default)]
+struct Counts {
+    bones: u32,
+    slots: u32,
+    skins: u32,
+    attachments: u32,
+    mesh_geometries: u32,
+    animations: u32,
+    ik_constraints: u32,
+    transform_constraints: u32,
+    constraints: u32,
+    atlas_pages: u32,
+    atlas_regions: u32,
+    events: u32,
+}
+
+impl Counts {
+    fn of(data: &AssetData) -> Self {
+        let count = |len: usize| {
+            u32::try_from(len).expect("linked tables fit the asset-scoped ID representation")
+        };
+        Self {
+            bones: count(data.bones.len()),
+            slots: count(data.slots.len()),
+            skins: count(data.skins.len()),
+            attachments: count(data.attachments.len()),
+            mesh_geometries: count(data.mesh_geometries.len()),
+            animations: count(data.animations.len()),
+            ik_constraints: count(data.ik_constraints.len()),
+            transform_constraints: count(data.transform_constraints.len()),
+            constraints: count(data.constraints.len()),
+            atlas_pages: count(data.atlases.iter().map(|atlas| atlas.page_count()).sum()),
+            atlas_regions: count(data.atlases.iter().map(|atlas| atlas.region_count()).sum()),
+            events: count(data.events.len()),
+        }
+    }
+
+    fn as_array(self) -> [u32; 12] {
+        [
+            self.bones,
+            self.slots,
+            self.skins,
+            self.attachments,
+            self.mesh_geometries,
+            self.animations,
+            self.ik_constraints,
+            self.transform_constraints,
+            self.constraints,
+            self.atlas_pages,
+            self.atlas_regions,
+            self.events,
+        ]
+    }
+}
+
+#[derive(Default)]
+struct Encoder(Vec<u8>);
+
+impl Encoder {
+    fn len(&mut self, len: usize) {
+        u32::try_from(len)
+            .expect("cached sequences fit the asset-scoped ID representation")
+            .encode(self);
+    }
+
+    /// Writes a table whose length was already written with the counts.
+    fn table<T: Cached>(&mut self, items: &[T]) {
+        for item in items {
+            item.encode(self);
+        }
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    /// The payload's offset in the file, added to reported offsets.
+    base: usize,
+    counts: Counts,
+    key: AssetKey,
+    depth: u32,
+}
+
+impl<'a> Decoder<'a> {
+    const fn malformed(&self) -> CacheError {
+        self.malformed_at(self.offset)
+    }
+
+    const fn malformed_at(&self, offset: usize) -> CacheError {
+        CacheError::Malformed {
+            offset: self.base + offset,
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CacheError> {
+        let bytes = self
+            .offset
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.offset..end))
+            .ok_or_else(|| self.malformed())?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CacheError> {
+        Ok(self
+            .take(N)?
+            .try_into()
+            .expect("exactly N bytes were taken"))
+    }
+
+    /// Reads a sequence length, which cannot exceed the remaining bytes
+    /// because every encoded item occupies at least one.
+    fn len(&mut self) -> Result<usize, CacheError> {
+        let offset = self.offset;
+        let len = u32::decode(self)? as usize;
+        if len <= self.bytes.len() - self.offset {
+            Ok(len)
+        } else {
+            Err(self.malformed_at(offset))
+        }
+    }
+
+    /// Reads an index into a table of `len` items.
+    fn index(&mut self, len: u32) -> Result<u32, CacheError> {
+        let offset = self.offset;
+        let index = u32::decode(self)?;
+        if index < len {
+            Ok(index)
+        } else {
+            Err(self.malformed_at(offset))
+        }
+    }
+
+    /// Reads a range of indexes into a table of `len` items.
+    fn range(&mut self, len: u32) -> Result<Range<u32>, CacheError> {
+        let offset = self.offset;
+        let (start, end) = (u32::decode(self)?, u32::decode(self)?);
+        if start <= end && end <= len {
+            Ok(start..end)
+        } else {
+            Err(self.malformed_at(offset))
+        }
+    }
+
+    fn indexes(&mut self, len: u32) -> Result<Box<[u32]>, CacheError> {
+        let count = self.len()?;
+        (0..count).map(|_index| self.index(len)).collect()
+    }
+
+    fn optional_index(&mut self, len: u32) -> Result<Option<u32>, CacheError> {
+        match self.tag(2)? {
+            0 => Ok(None),
+            _ => self.index(len).map(Some),
+        }
+    }
+
+    /// Reads an enum tag below `variants`.
+    fn tag(&mut self, variants: u8) -> Result<u8, CacheError> {
+        let offset = self.offset;
+        let tag = u8::decode(self)?;
+        if tag < variants {
+            Ok(tag)
+        } else {
+            Err(self.malformed_at(offset))
+        }
+    }
+
+    /// Reads a table whose length was read with the counts.
+    fn table<T: Cached>(&mut self, count: u32) -> Result<Box<[T]>, CacheError> {
+        if count as usize > self.bytes.len() - self.offset {
+            return Err(self.malformed());
+        }
+        (0..count).map(|_index| T::decode(self)).collect()
+    }
+
+    /// Converts a constructor's rejection of a decoded number.
+    fn valid<T, E>(&self, offset: usize, value: Result<T, E>) -> Result<T, CacheError> {
+        value.map_err(|_error| self.malformed_at(offset))
+    }
+}
+
+/// A value with a fixed little-endian cache encoding.
+trait Cached: Sized {
+    fn encode(&self, out: &mut Encoder);
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError>;
+}
+
+macro_rules! cached_number {
+    ($($type:ty),+) => {$(
+        impl Cached for $type {
+            fn encode(&self, out: &mut Encoder) {
+                out.0.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+                input.array().map(Self::from_le_bytes)
+            }
+        }
+    )+};
+}
+
+cached_number!(u8, u32, i32, u64, i64, f32, f64);
+
+/// Encodes a field-less enum as its position in the listed variant order.
+///
+/// The exhaustive match makes a new variant fail to compile until it is
+/// listed, which is also when [`SKELETON_CACHE_VERSION`] must change.
+macro_rules! cached_enum {
+    ($type:ident { $($variant:ident),+ $(,)? }) => {
+        impl Cached for $type {
+            fn encode(&self, out: &mut Encoder) {
+                const VARIANTS: &[$type] = &[$($type::$variant),+];
+                match self {
+                    $($type::$variant)|+ => {}
+                }
+                let tag = VARIANTS
+                    .iter()
+                    .position(|variant| variant == self)
+                    .expect("every variant is listed");
+                (tag as u8).encode(out);
+            }
+
+            fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+                const VARIANTS: &[$type] = &[$($type::$variant),+];
+                let tag = input.tag(VARIANTS.len() as u8)?;
+                Ok(VARIANTS[usize::from(tag)])
+            }
+        }
+    };
+}
+
+cached_enum!(SlotBlendMode {
+    Normal,
+    Additive,
+    Multiply,
+    Screen,
+    Unknown
+});
+cached_enum!(BendDirection { Positive, Negative });
+cached_enum!(AlphaEncoding {
+    Straight,
+    Premultiplied
+});
+cached_enum!(TextureFilter {
+    Nearest,
+    Linear,
+    MipMap,
+    MipMapNearestNearest,
+    MipMapLinearNearest,
+    MipMapNearestLinear,
+    MipMapLinearLinear,
+    Unknown,
+});
+cached_enum!(TextureFormat {
+    Alpha,
+    Intensity,
+    LuminanceAlpha,
+    Rgb565,
+    Rgba4444,
+    Rgb888,
+    Rgba8888,
+    Unknown,
+});
+cached_enum!(TransformMixChannel {
+    Rotate,
+    X,
+    Y,
+    ScaleX,
+    ScaleY,
+    ShearY
+});
+cached_enum!(AtlasIssueKind {
+    PremultipliedAlpha,
+    UnsupportedRotation,
+    UnsupportedPageSetting
+});
+cached_enum!(DiagnosticSeverity { Warning, Degraded });
+cached_enum!(DiagnosticCode {
+    UnsupportedAttachmentType,
+    UnsupportedConstraintType,
+    UnsupportedConstraintOption,
+    UnsupportedBoneTransformMode,
+    UnsupportedTimelineType,
+    UnsupportedBlendMode,
+    UnsupportedTwoColourTint,
+    IgnoredSkinBones,
+    IgnoredSkinConstraints,
+    UnknownField,
+    UntestedPatchVersion,
+    AlphaEncodingMismatch,
+    UnsupportedAtlasSetting,
+    UnsupportedAtlasRotation,
+    ShadowedAtlasRegion,
+    DiagnosticsTruncated,
+    UnmappedBone,
+    UnmappedSlot,
+    UnmappedConstraint,
+    UnmappedEvent,
+    UnmappedAttachment,
+});
+
+impl Cached for bool {
+    fn encode(&self, out: &mut Encoder) {
+        u8::from(*self).encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(input.tag(2)? == 1)
+    }
+}
+
+impl Cached for Box<str> {
+    fn encode(&self, out: &mut Encoder) {
+        out.len(self.len());
+        out.0.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let len = input.len()?;
+        let offset = input.offset;
+        let bytes = input.take(len)?;
+        let text = std::str::from_utf8(bytes);
+        input.valid(offset, text).map(Box::from)
+    }
+}
+
+impl<T: Cached> Cached for Option<T> {
+    fn encode(&self, out: &mut Encoder) {
+        match self {
+            None => 0_u8.encode(out),
+            Some(value) => {
+                1_u8.encode(out);
+                value.encode(out);
+            }
+        }
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        match input.tag(2)? {
+            0 => Ok(None),
+            _ => T::decode(input).map(Some),
+        }
+    }
+}
+
+impl<T: Cached> Cached for Box<[T]> {
+    fn encode(&self, out: &mut Encoder) {
+        out.len(self.len());
+        out.table(self);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let len = input.len()?;
+        (0..len).map(|_index| T::decode(input)).collect()
+    }
+}
+
+impl<T: Cached, const N: usize> Cached for [T; N] {
+    fn encode(&self, out: &mut Encoder) {
+        out.table(self);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let items = (0..N)
+            .map(|_index| T::decode(input))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_items| unreachable!("exactly N items were decoded")))
+    }
+}
+
+impl Cached for Vec2 {
+    fn encode(&self, out: &mut Encoder) {
+        self.to_array().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        <[f32; 2]>::decode(input).map(Self::from_array)
+    }
+}
+
+impl Cached for Counts {
+    fn encode(&self, out: &mut Encoder) {
+        self.as_array().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let [
+            bones,
+            slots,
+            skins,
+            attachments,
+            mesh_geometries,
+            animations,
+            ik_constraints,
+            transform_constraints,
+            constraints,
+            atlas_pages,
+            atlas_regions,
+            events,
+        ] = <[u32; 12]>::decode(input)?;
+        Ok(Self {
+            bones,
+            slots,
+            skins,
+            attachments,
+            mesh_geometries,
+            animations,
+            ik_constraints,
+            transform_constraints,
+            constraints,
+            atlas_pages,
+            atlas_regions,
+            events,
+        })
+    }
+}
+
+impl Cached for JsonValue {
+    fn encode(&self, out: &mut Encoder) {
+        match self {
+            Self::Null => 0_u8.encode(out),
+            Self::Bool(value) => {
+                1_u8.encode(out);
+                value.encode(out);
+            }
+            Self::I64(value) => {
+                2_u8.encode(out);
+                value.encode(out);
+            }
+            Self::U64(value) => {
+                3_u8.encode(out);
+                value.encode(out);
+            }
+            Self::F64(value) => {
+                4_u8.encode(out);
+                value.encode(out);
+            }
+            Self::String(value) => {
+                5_u8.encode(out);
+                value.encode(out);
+            }
+            Self::Array(values) => {
+                6_u8.encode(out);
+                values.encode(out);
+            }
+            Self::Object(members) => {
+                7_u8.encode(out);
+                members.encode(out);
+            }
+        }
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let offset = input.offset;
+        let value = match input.tag(8)? {
+            0 => Self::Null,
+            1 => Self::Bool(Cached::decode(input)?),
+            2 => Self::I64(Cached::decode(input)?),
+            3 => Self::U64(Cached::decode(input)?),
+            4 => Self::F64(Cached::decode(input)?),
+            5 => Self::String(Cached::decode(input)?),
+            tag => {
+                if input.depth == MAX_JSON_DEPTH {
+                    return Err(input.malformed_at(offset));
+                }
+                input.depth += 1;
+                let value = if tag == 6 {
+                    Cached::decode(input).map(Self::Array)
+                } else {
+                    Cached::decode(input).map(Self::Object)
+                };
+                input.depth -= 1;
+                value?
+            }
+        };
+        Ok(value)
+    }
+}
+
+impl Cached for JsonMember {
+    fn encode(&self, out: &mut Encoder) {
+        Box::<str>::from(self.name()).encode(out);
+        self.value().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let name = Box::<str>::decode(input)?;
+        JsonValue::decode(input).map(|value| Self::new(name, value))
+    }
+}
+
+impl Cached for Rgba8 {
+    fn encode(&self, out: &mut Encoder) {
+        self.to_array().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let [red, green, blue, alpha] = input.array()?;
+        Ok(Self::new(red, green, blue, alpha))
+    }
+}
+
+impl Cached for PixelSize {
+    fn encode(&self, out: &mut Encoder) {
+        [self.width(), self.height()].encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let [width, height] = <[u32; 2]>::decode(input)?;
+        Ok(Self::new(width, height))
+    }
+}
+
+impl Cached for PixelRect {
+    fn encode(&self, out: &mut Encoder) {
+        [self.x(), self.y(), self.width(), self.height()].encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let [x, y, width, height] = <[u32; 4]>::decode(input)?;
+        Ok(Self::new(x, y, width, height))
+    }
+}
+
+impl Cached for Trim {
+    fn encode(&self, out: &mut Encoder) {
+        let original = self.original_size();
+        [
+            self.left(),
+            self.bottom(),
+            original.width(),
+            original.height(),
+        ]
+        .encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let [left, bottom, width, height] = <[u32; 4]>::decode(input)?;
+        Ok(Self::new(left, bottom, width, height))
+    }
+}
+
+impl Cached for WrapMode {
+    fn encode(&self, out: &mut Encoder) {
+        [self.x(), self.y()].encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let [x, y] = <[bool; 2]>::decode(input)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl Cached for AtlasRotation {
+    fn encode(&self, out: &mut Encoder) {
+        self.as_degrees().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let offset = input.offset;
+        let rotation = Self::new(f32::decode(input)?);
+        input.valid(offset, rotation.ok_or(()))
+    }
+}
+
+impl Cached for Angle {
+    fn encode(&self, out: &mut Encoder) {
+        self.as_radians().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let offset = input.offset;
+        let angle = Self::from_radians(f32::decode(input)?);
+        input.valid(offset, angle)
+    }
+}
+
+impl Cached for Mix {
+    fn encode(&self, out: &mut Encoder) {
+        self.get().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let offset = input.offset;
+        let mix = Self::new(f32::decode(input)?);
+        input.valid(offset, mix)
+    }
+}
+
+impl Cached for TransformMix {
+    fn encode(&self, out: &mut Encoder) {
+        self.get().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let offset = input.offset;
+        let mix = Self::new(f32::decode(input)?);
+        input.valid(offset, mix)
+    }
+}
+
+impl Cached for BoneTransform {
+    fn encode(&self, out: &mut Encoder) {
+        self.translation().encode(out);
+        self.rotation().encode(out);
+        self.scale().encode(out);
+        self.shear().x().encode(out);
+        self.shear().y().encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let offset = input.offset;
+        let translation = Vec2::decode(input)?;
+        let rotation = Angle::decode(input)?;
+        let scale = Vec2::decode(input)?;
+        let shear = Shear::new(Angle::decode(input)?, Angle::decode(input)?);
+        let transform = Self::new(translation, rotation, scale, shear);
+        input.valid(offset, transform)
+    }
+}
+
+impl Cached for BoneData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.parent.encode(out);
+        self.length.encode(out);
+        self.setup_transform.encode(out);
+        self.retained.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            name: Cached::decode(input)?,
+            parent: input.optional_index(input.counts.bones)?,
+            length: Cached::decode(input)?,
+            setup_transform: Cached::decode(input)?,
+            retained: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for SlotData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.bone.encode(out);
+        self.setup_attachment_name.encode(out);
+        self.colour.encode(out);
+        self.blend_mode.encode(out);
+        self.blend_token.encode(out);
+        self.retained.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            name: Cached::decode(input)?,
+            bone: input.index(input.counts.bones)?,
+            setup_attachment_name: Cached::decode(input)?,
+            colour: Cached::decode(input)?,
+            blend_mode: Cached::decode(input)?,
+            blend_token: Cached::decode(input)?,
+            retained: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for Range<u32> {
+    fn encode(&self, out: &mut Encoder) {
+        [self.start, self.end].encode(out);
+    }
+
+    /// Ranges are always read with [`Decoder::range`] against their table.
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        input.range(u32::MAX)
+    }
+}
+
+impl Cached for SkinData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.attachments.encode(out);
+        self.retained.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            name: Cached::decode(input)?,
+            attachments: input.range(input.counts.attachments)?,
+            retained: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for AttachmentData {
+    fn encode(&self, out: &mut Encoder) {
+        self.placeholder_name.encode(out);
+        self.name.encode(out);
+        self.atlas_path.encode(out);
+        self.skin.encode(out);
+        self.slot.encode(out);
+        match &self.kind {
+            AttachmentDataKind::Region(region) => {
+                0_u8.encode(out);
+                region.transform.encode(out);
+                region.size.encode(out);
+                region.colour.encode(out);
+                region.atlas_region.encode(out);
+            }
+            AttachmentDataKind::Mesh(mesh) => {
+                1_u8.encode(out);
+                mesh.colour.encode(out);
+                mesh.atlas_region.encode(out);
+                mesh.geometry.encode(out);
+                mesh.source_mesh.encode(out);
+                mesh.inherits_deform.encode(out);
+            }
+            AttachmentDataKind::BoundingBox => 2_u8.encode(out),
+            AttachmentDataKind::Point => 3_u8.encode(out),
+            AttachmentDataKind::Unsupported { source_type } => {
+                4_u8.encode(out);
+                source_type.encode(out);
+            }
+        }
+        self.retained.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        let placeholder_name = Cached::decode(input)?;
+        let name = Cached::decode(input)?;
+        let atlas_path = Cached::decode(input)?;
+        let skin = input.index(counts.skins)?;
+        let slot = input.index(counts.slots)?;
+        let kind = match input.tag(5)? {
+            0 => AttachmentDataKind::Region(RegionAttachmentData {
+                transform: Cached::decode(input)?,
+                size: Cached::decode(input)?,
+                colour: Cached::decode(input)?,
+                atlas_region: input.index(counts.atlas_regions)?,
+            }),
+            1 => AttachmentDataKind::Mesh(MeshAttachmentData {
+                colour: Cached::decode(input)?,
+                atlas_region: input.index(counts.atlas_regions)?,
+                geometry: input.index(counts.mesh_geometries)?,
+                source_mesh: input.optional_index(counts.attachments)?,
+                inherits_deform: Cached::decode(input)?,
+            }),
+            2 => AttachmentDataKind::BoundingBox,
+            3 => AttachmentDataKind::Point,
+            _ => AttachmentDataKind::Unsupported {
+                source_type: Cached::decode(input)?,
+            },
+        };
+        Ok(Self {
+            placeholder_name,
+            name,
+            atlas_path,
+            skin,
+            slot,
+            kind,
+            retained: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for MeshGeometryData {
+    fn encode(&self, out: &mut Encoder) {
+        self.uvs.encode(out);
+        self.triangles.encode(out);
+        match &self.vertices {
+            MeshVerticesData::Unweighted(vertices) => {
+                0_u8.encode(out);
+                vertices.encode(out);
+            }
+            MeshVerticesData::Weighted {
+                vertices,
+                influences,
+            } => {
+                1_u8.encode(out);
+                influences.encode(out);
+                vertices.encode(out);
+            }
+        }
+        self.hull.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let uvs = Box::<[Vec2]>::decode(input)?;
+        let triangles_offset = input.offset;
+        let triangles = Box::<[u32]>::decode(input)?;
+        let vertices = match input.tag(2)? {
+            0 => MeshVerticesData::Unweighted(Cached::decode(input)?),
+            _ => {
+                let influences = Box::<[MeshInfluenceData]>::decode(input)?;
+                let len = u32::try_from(influences.len()).map_err(|_error| input.malformed())?;
+                let count = input.len()?;
+                let vertices = (0..count)
+                    .map(|_index| input.range(len))
+                    .collect::<Result<_, _>>()?;
+                MeshVerticesData::Weighted {
+                    vertices,
+                    influences,
+                }
+            }
+        };
+        let hull = u32::decode(input)?;
+        if vertices.len() != uvs.len()
+            || hull as usize > uvs.len()
+            || triangles.iter().any(|index| *index as usize >= uvs.len())
+        {
+            return Err(input.malformed_at(triangles_offset));
+        }
+        Ok(Self {
+            uvs,
+            triangles,
+            vertices,
+            hull,
+        })
+    }
+}
+
+impl Cached for MeshInfluenceData {
+    fn encode(&self, out: &mut Encoder) {
+        self.bone.encode(out);
+        self.bind_position.encode(out);
+        self.weight.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            bone: input.index(input.counts.bones)?,
+            bind_position: Cached::decode(input)?,
+            weight: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for AtlasExtension {
+    fn encode(&self, out: &mut Encoder) {
+        self.key.encode(out);
+        self.value.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            key: Cached::decode(input)?,
+            value: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for AtlasPageData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.size.encode(out);
+        self.format.encode(out);
+        self.format_token.encode(out);
+        self.min_filter.encode(out);
+        self.min_filter_token.encode(out);
+        self.mag_filter.encode(out);
+        self.mag_filter_token.encode(out);
+        self.wrap.encode(out);
+        self.alpha_encoding.encode(out);
+        self.scale.encode(out);
+        self.regions.encode(out);
+        self.extensions.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            name: Cached::decode(input)?,
+            size: Cached::decode(input)?,
+            format: Cached::decode(input)?,
+            format_token: Cached::decode(input)?,
+            min_filter: Cached::decode(input)?,
+            min_filter_token: Cached::decode(input)?,
+            mag_filter: Cached::decode(input)?,
+            mag_filter_token: Cached::decode(input)?,
+            wrap: Cached::decode(input)?,
+            alpha_encoding: Cached::decode(input)?,
+            scale: Cached::decode(input)?,
+            regions: input.range(input.counts.atlas_regions)?,
+            extensions: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for AtlasRegionData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.page.encode(out);
+        self.index.encode(out);
+        self.bounds.encode(out);
+        self.trim.encode(out);
+        self.rotation.encode(out);
+        self.split.encode(out);
+        self.pad.encode(out);
+        self.extensions.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            name: Cached::decode(input)?,
+            page: input.index(input.counts.atlas_pages)?,
+            index: Cached::decode(input)?,
+            bounds: Cached::decode(input)?,
+            trim: Cached::decode(input)?,
+            rotation: Cached::decode(input)?,
+            split: Cached::decode(input)?,
+            pad: Cached::decode(input)?,
+            extensions: Cached::decode(input)?,
+        })
+    }
+}
+
+/// Each atlas is written with its own table lengths, and its pages and
+/// regions refer to each other by their indexes within it.
+impl Cached for Arc<TextAtlas> {
+    fn encode(&self, out: &mut Encoder) {
+        out.len(self.pages.len());
+        out.len(self.regions.len());
+        out.table(&self.pages);
+        out.table(&self.regions);
+        self.issues.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let asset_counts = input.counts;
+        let pages = u32::try_from(input.len()?).expect("lengths are read as u32");
+        let regions = u32::try_from(input.len()?).expect("lengths are read as u32");
+        input.counts.atlas_pages = pages;
+        input.counts.atlas_regions = regions;
+        let atlas = input.table(pages).and_then(|pages| {
+            Ok(TextAtlas::from_tables(
+                pages,
+                input.table(regions)?,
+                Cached::decode(input)?,
+            ))
+        });
+        input.counts = asset_counts;
+        atlas.map(Self::new)
+    }
+}
+
+impl Cached for AtlasIssue {
+    fn encode(&self, out: &mut Encoder) {
+        match self.target() {
+            AtlasIssueTarget::Page(index) => (0_u8, index as u32).encode(out),
+            AtlasIssueTarget::Region(index) => (1_u8, index as u32).encode(out),
+        }
+        self.kind().encode(out);
+        Box::<str>::from(self.message()).encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        let target = match input.tag(2)? {
+            0 => AtlasIssueTarget::Page(input.index(counts.atlas_pages)? as usize),
+            _ => AtlasIssueTarget::Region(input.index(counts.atlas_regions)? as usize),
+        };
+        Ok(Self::new(
+            target,
+            Cached::decode(input)?,
+            Cached::decode(input)?,
+        ))
+    }
+}
+
+impl Cached for IkConstraintData {
+    fn encode(&self, out: &mut Encoder) {
+        self.constraint.encode(out);
+        self.name.encode(out);
+        self.order.encode(out);
+        self.bones.encode(out);
+        self.target.encode(out);
+        self.mix.encode(out);
+        self.bend_direction.encode(out);
+        self.softness.encode(out);
+        [self.compress, self.stretch, self.uniform].encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        let constraint = input.index(counts.constraints)?;
+        let name = Cached::decode(input)?;
+        let order = Cached::decode(input)?;
+        let bones = input.indexes(counts.bones)?;
+        let target = input.index(counts.bones)?;
+        let mix = Cached::decode(input)?;
+        let bend_direction = Cached::decode(input)?;
+        let softness = Cached::decode(input)?;
+        let [compress, stretch, uniform] = Cached::decode(input)?;
+        Ok(Self {
+            constraint,
+            name,
+            order,
+            bones,
+            target,
+            mix,
+            bend_direction,
+            softness,
+            compress,
+            stretch,
+            uniform,
+        })
+    }
+}
+
+impl Cached for TransformConstraintPoseData {
+    fn encode(&self, out: &mut Encoder) {
+        [
+            self.mix_rotate,
+            self.mix_x,
+            self.mix_y,
+            self.mix_scale_x,
+            self.mix_scale_y,
+            self.mix_shear_y,
+        ]
+        .encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let [
+            mix_rotate,
+            mix_x,
+            mix_y,
+            mix_scale_x,
+            mix_scale_y,
+            mix_shear_y,
+        ] = Cached::decode(input)?;
+        Ok(Self {
+            mix_rotate,
+            mix_x,
+            mix_y,
+            mix_scale_x,
+            mix_scale_y,
+            mix_shear_y,
+        })
+    }
+}
+
+impl Cached for TransformConstraintData {
+    fn encode(&self, out: &mut Encoder) {
+        self.constraint.encode(out);
+        self.name.encode(out);
+        self.order.encode(out);
+        self.bones.encode(out);
+        self.source.encode(out);
+        self.rotation_offset.encode(out);
+        [
+            self.copies_rotation,
+            self.local_source,
+            self.local_target,
+            self.additive,
+            self.clamped,
+        ]
+        .encode(out);
+        self.setup_pose.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        let constraint = input.index(counts.constraints)?;
+        let name = Cached::decode(input)?;
+        let order = Cached::decode(input)?;
+        let bones = input.indexes(counts.bones)?;
+        let source = input.index(counts.bones)?;
+        let rotation_offset = Cached::decode(input)?;
+        let [
+            copies_rotation,
+            local_source,
+            local_target,
+            additive,
+            clamped,
+        ] = Cached::decode(input)?;
+        Ok(Self {
+            constraint,
+            name,
+            order,
+            bones,
+            source,
+            rotation_offset,
+            copies_rotation,
+            local_source,
+            local_target,
+            additive,
+            clamped,
+            setup_pose: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for ConstraintData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.source_type.encode(out);
+        self.order.encode(out);
+        self.ik_constraint.encode(out);
+        self.transform_constraint.encode(out);
+        self.retained.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        Ok(Self {
+            name: Cached::decode(input)?,
+            source_type: Cached::decode(input)?,
+            order: Cached::decode(input)?,
+            ik_constraint: input.optional_index(counts.ik_constraints)?,
+            transform_constraint: input.optional_index(counts.transform_constraints)?,
+            retained: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for EventPayload {
+    fn encode(&self, out: &mut Encoder) {
+        self.integer.encode(out);
+        self.float.encode(out);
+        self.string.encode(out);
+        self.volume.encode(out);
+        self.balance.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            integer: Cached::decode(input)?,
+            float: Cached::decode(input)?,
+            string: Cached::decode(input)?,
+            volume: Cached::decode(input)?,
+            balance: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for EventDefinitionData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.payload.encode(out);
+        self.audio.encode(out);
+        self.retained.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            name: Cached::decode(input)?,
+            payload: Cached::decode(input)?,
+            audio: Cached::decode(input)?,
+            retained: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for TimelineTime {
+    fn encode(&self, out: &mut Encoder) {
+        self.ticks.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        u64::decode(input).map(|ticks| Self { ticks })
+    }
+}
+
+impl<const CHANNELS: usize> Cached for FrameCurve<CHANNELS> {
+    fn encode(&self, out: &mut Encoder) {
+        match self {
+            Self::Linear => 0_u8.encode(out),
+            Self::Stepped => 1_u8.encode(out),
+            Self::Bezier(channels) => {
+                2_u8.encode(out);
+                channels.encode(out);
+            }
+        }
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(match input.tag(3)? {
+            0 => Self::Linear,
+            1 => Self::Stepped,
+            _ => Self::Bezier(Cached::decode(input)?),
+        })
+    }
+}
+
+impl Cached for ScalarFrame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        self.value.encode(out);
+        self.curve.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            time: Cached::decode(input)?,
+            value: Cached::decode(input)?,
+            curve: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for Vec2Frame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        [self.x, self.y].encode(out);
+        self.curve.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let time = Cached::decode(input)?;
+        let [x, y] = Cached::decode(input)?;
+        Ok(Self {
+            time,
+            x,
+            y,
+            curve: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for ColourFrame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        self.colour.encode(out);
+        self.curve.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            time: Cached::decode(input)?,
+            colour: Cached::decode(input)?,
+            curve: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for AttachmentFrame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        self.placeholder_name.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            time: Cached::decode(input)?,
+            placeholder_name: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for IkFrame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        self.mix.encode(out);
+        self.bend_direction.encode(out);
+        self.curve.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            time: Cached::decode(input)?,
+            mix: Cached::decode(input)?,
+            bend_direction: Cached::decode(input)?,
+            curve: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for TransformFrame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        self.pose.encode(out);
+        self.curve.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            time: Cached::decode(input)?,
+            pose: Cached::decode(input)?,
+            curve: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for DrawOrderOffset {
+    fn encode(&self, out: &mut Encoder) {
+        self.slot.encode(out);
+        self.offset.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            slot: input.index(input.counts.slots)?,
+            offset: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for DrawOrderFrame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        self.offsets.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            time: Cached::decode(input)?,
+            offsets: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for EventFrame {
+    fn encode(&self, out: &mut Encoder) {
+        self.time.encode(out);
+        self.event.encode(out);
+        self.payload.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            time: Cached::decode(input)?,
+            event: input.index(input.counts.events)?,
+            payload: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for TimelineData {
+    fn encode(&self, out: &mut Encoder) {
+        match self {
+            Self::BoneRotate { bone, frames } => {
+                0_u8.encode(out);
+                bone.encode(out);
+                frames.encode(out);
+            }
+            Self::BoneTranslate { bone, frames } => {
+                1_u8.encode(out);
+                bone.encode(out);
+                frames.encode(out);
+            }
+            Self::BoneScale { bone, frames } => {
+                2_u8.encode(out);
+                bone.encode(out);
+                frames.encode(out);
+            }
+            Self::BoneShear { bone, frames } => {
+                3_u8.encode(out);
+                bone.encode(out);
+                frames.encode(out);
+            }
+            Self::SlotAttachment { slot, frames } => {
+                4_u8.encode(out);
+                slot.encode(out);
+                frames.encode(out);
+            }
+            Self::SlotColour { slot, frames } => {
+                5_u8.encode(out);
+                slot.encode(out);
+                frames.encode(out);
+            }
+            Self::Ik { constraint, frames } => {
+                6_u8.encode(out);
+                constraint.encode(out);
+                frames.encode(out);
+            }
+            Self::Transform { constraint, frames } => {
+                7_u8.encode(out);
+                constraint.encode(out);
+                frames.encode(out);
+            }
+            Self::DrawOrder { frames } => {
+                8_u8.encode(out);
+                frames.encode(out);
+            }
+            Self::Events { frames } => {
+                9_u8.encode(out);
+                frames.encode(out);
+            }
+            Self::Unsupported { name, source } => {
+                10_u8.encode(out);
+                name.encode(out);
+                source.path.encode(out);
+                source.value.encode(out);
+            }
+        }
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        let offset = input.offset;
+        let timeline = match input.tag(11)? {
+            0 => Self::BoneRotate {
+                bone: input.index(counts.bones)?,
+                frames: Cached::decode(input)?,
+            },
+            1 => Self::BoneTranslate {
+                bone: input.index(counts.bones)?,
+                frames: Cached::decode(input)?,
+            },
+            2 => Self::BoneScale {
+                bone: input.index(counts.bones)?,
+                frames: Cached::decode(input)?,
+            },
+            3 => Self::BoneShear {
+                bone: input.index(counts.bones)?,
+                frames: Cached::decode(input)?,
+            },
+            4 => Self::SlotAttachment {
+                slot: input.index(counts.slots)?,
+                frames: Cached::decode(input)?,
+            },
+            5 => Self::SlotColour {
+                slot: input.index(counts.slots)?,
+                frames: Cached::decode(input)?,
+            },
+            6 => Self::Ik {
+                constraint: input.index(counts.ik_constraints)?,
+                frames: Cached::decode(input)?,
+            },
+            7 => Self::Transform {
+                constraint: input.index(counts.transform_constraints)?,
+                frames: Cached::decode(input)?,
+            },
+            8 => Self::DrawOrder {
+                frames: Cached::decode(input)?,
+            },
+            9 => Self::Events {
+                frames: Cached::decode(input)?,
+            },
+            _ => {
+                return Ok(Self::Unsupported {
+                    name: Cached::decode(input)?,
+                    source: RetainedTimeline {
+                        path: Cached::decode(input)?,
+                        value: Cached::decode(input)?,
+                    },
+                });
+            }
+        };
+        if timeline.is_empty() {
+            return Err(input.malformed_at(offset));
+        }
+        Ok(timeline)
+    }
+}
+
+impl TimelineData {
+    /// Returns whether a sampled timeline has no keys, which the loader
+    /// never produces and samplers do not expect.
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::BoneRotate { frames, .. } => frames.is_empty(),
+            Self::BoneTranslate { frames, .. }
+            | Self::BoneScale { frames, .. }
+            | Self::BoneShear { frames, .. } => frames.is_empty(),
+            Self::SlotAttachment { frames, .. } => frames.is_empty(),
+            Self::SlotColour { frames, .. } => frames.is_empty(),
+            Self::Ik { frames, .. } => frames.is_empty(),
+            Self::Transform { frames, .. } => frames.is_empty(),
+            Self::DrawOrder { frames } => frames.is_empty(),
+            Self::Events { frames } => frames.is_empty(),
+            Self::Unsupported { .. } => false,
+        }
+    }
+}
+
+impl Cached for PropertyData {
+    fn encode(&self, out: &mut Encoder) {
+        match *self {
+            Self::BoneTranslation(bone) => (0_u8, bone).encode(out),
+            Self::BoneRotation(bone) => (1_u8, bone).encode(out),
+            Self::BoneScaleMagnitude(bone) => (2_u8, bone).encode(out),
+            Self::BoneScaleSign(bone) => (3_u8, bone).encode(out),
+            Self::BoneShear(bone) => (4_u8, bone).encode(out),
+            Self::SlotColor(slot) => (5_u8, slot).encode(out),
+            Self::SlotAttachment(slot) => (6_u8, slot).encode(out),
+            Self::IkMix(constraint) => (7_u8, constraint).encode(out),
+            Self::IkBendDirection(constraint) => (8_u8, constraint).encode(out),
+            Self::TransformMix(constraint, channel) => {
+                (9_u8, constraint).encode(out);
+                channel.encode(out);
+            }
+            Self::DrawOrder => 10_u8.encode(out),
+        }
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        Ok(match input.tag(11)? {
+            0 => Self::BoneTranslation(input.index(counts.bones)?),
+            1 => Self::BoneRotation(input.index(counts.bones)?),
+            2 => Self::BoneScaleMagnitude(input.index(counts.bones)?),
+            3 => Self::BoneScaleSign(input.index(counts.bones)?),
+            4 => Self::BoneShear(input.index(counts.bones)?),
+            5 => Self::SlotColor(input.index(counts.slots)?),
+            6 => Self::SlotAttachment(input.index(counts.slots)?),
+            7 => Self::IkMix(input.index(counts.ik_constraints)?),
+            8 => Self::IkBendDirection(input.index(counts.ik_constraints)?),
+            9 => Self::TransformMix(
+                input.index(counts.transform_constraints)?,
+                Cached::decode(input)?,
+            ),
+            _ => Self::DrawOrder,
+        })
+    }
+}
+
+/// Encodes a tag and the index that follows it.
+impl Cached for (u8, u32) {
+    fn encode(&self, out: &mut Encoder) {
+        self.0.encode(out);
+        self.1.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok((Cached::decode(input)?, Cached::decode(input)?))
+    }
+}
+
+impl Cached for AnimationData {
+    fn encode(&self, out: &mut Encoder) {
+        self.name.encode(out);
+        self.duration.encode(out);
+        self.timelines.encode(out);
+        self.properties.encode(out);
+        self.deferred_override_properties.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        Ok(Self {
+            name: Cached::decode(input)?,
+            duration: Cached::decode(input)?,
+            timelines: Cached::decode(input)?,
+            properties: Cached::decode(input)?,
+            deferred_override_properties: Cached::decode(input)?,
+        })
+    }
+}
+
+impl Cached for Diagnostic {
+    fn encode(&self, out: &mut Encoder) {
+        self.severity.encode(out);
+        self.code.encode(out);
+        match self.scope {
+            DiagnosticScope::Asset => 0_u8.encode(out),
+            DiagnosticScope::Bone(id) => (1_u8, id.index()).encode(out),
+            DiagnosticScope::Slot(id) => (2_u8, id.index()).encode(out),
+            DiagnosticScope::Skin(id) => (3_u8, id.index()).encode(out),
+            DiagnosticScope::Animation(id) => (4_u8, id.index()).encode(out),
+            DiagnosticScope::Event(id) => (5_u8, id.index()).encode(out),
+            DiagnosticScope::Attachment(id) => (6_u8, id.index()).encode(out),
+            DiagnosticScope::IkConstraint(id) => (7_u8, id.index()).encode(out),
+            DiagnosticScope::Constraint(id) => (8_u8, id.index()).encode(out),
+            DiagnosticScope::AtlasPage(id) => (9_u8, id.index()).encode(out),
+            DiagnosticScope::AtlasRegion(id) => (10_u8, id.index()).encode(out),
+        }
+        self.message.encode(out);
+    }
+
+    fn decode(input: &mut Decoder<'_>) -> Result<Self, CacheError> {
+        let counts = input.counts;
+        let severity = Cached::decode(input)?;
+        let code = Cached::decode(input)?;
+        let scope = match input.tag(11)? {
+            0 => PendingScope::Asset,
+            1 => PendingScope::Bone(input.index(counts.bones)?),
+            2 => PendingScope::Slot(input.index(counts.slots)?),
+            3 => PendingScope::Skin(input.index(counts.skins)?),
+            4 => PendingScope::Animation(input.index(counts.animations)?),
+            5 => PendingScope::Event(input.index(counts.events)?),
+            6 => PendingScope::Attachment(input.index(counts.attachments)?),
+            7 => PendingScope::IkConstraint(input.index(counts.ik_constraints)?),
+            8 => PendingScope::Constraint(input.index(counts.constraints)?),
+            9 => PendingScope::AtlasPage(input.index(counts.atlas_pages)?),
+            _ => PendingScope::AtlasRegion(input.index(counts.atlas_regions)?),
+        };
+        let diagnostic = PendingDiagnostic {
+            severity,
+            code,
+            scope,
+            message: Cached::decode(input)?,
+        };
+        Ok(diagnostic.materialize(input.key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheError, HEADER_LEN, read_cache, write_cache};
+    use crate::load_json;
+
+    #[test]
+    fn every_truncation_is_rejected_without_panicking() {
+        let json = br#"{
+          "skeleton":{"spine":"4.3.23"},
+          "bones":[{"name":"root"},{"name":"tail","parent":"root","rotation":15}],
+          "slots":[{"name":"tail","bone":"tail","attachment":"tail","x-tint":[1,{"a":null}]}],
+          "skins":[{"name":"default","attachments":{"tail":{"tail":{"width":4,"height":4}}}}],
+          "animations":{"wag":{"bones":{"tail":{"rotate":[{"value":10},{"time":1,"value":-10}]}}}}
+        }"#;
+        let asset = load_json(json, b"cat.png\ntail\n\tbounds: 0, 0, 4, 4\n")
+            .expect("the fixture loads")
+            .into_asset();
+        let cache = write_cache(&asset);
+
+        for len in 0..cache.len() {
+            assert!(read_cache(&cache[..len]).is_err(), "{len} bytes");
+        }
+        let mut trailing = cache.clone();
+        trailing.push(0);
+        assert!(matches!(
+            read_cache(&trailing),
+            Err(CacheError::Malformed { .. })
+        ));
+        let mut flipped = cache;
+        flipped[HEADER_LEN + 3] ^= 1;
+        assert_eq!(
+            read_cache(&flipped).expect_err("the payload changed"),
+            CacheError::ChecksumMismatch
+        );
+    }
+}
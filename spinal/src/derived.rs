@@ -0,0 +1,181 @@
+//! Immutable assets derived from another asset with generated animations.
+
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    AnimationId, Diagnostic, IdError, SkeletonAsset,
+    animation::{
+        AnimationData, DrawOrderFrame, DrawOrderOffset, FrameCurve, ScalarFrame, Vec2Frame,
+    },
+    id::AssetKey,
+    load::PendingDiagnostic,
+};
+
+/// A failure to derive an asset with generated animations.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum DeriveError {
+    /// No process-local asset identity remains for the derived asset.
+    #[error("process-local asset identity space is exhausted")]
+    CapacityExceeded,
+    /// A source animation belongs to a different asset.
+    #[error(transparent)]
+    Id(#[from] IdError),
+}
+
+/// A new immutable asset holding a base asset's rig, its animations, and
+/// generated animations that were added or replaced same-named clips.
+///
+/// IDs of the base asset do not resolve against the derived one; create
+/// skeletons from [`Self::asset`] to play the generated clips.
+#[derive(Debug)]
+pub struct DerivedAsset {
+    asset: Arc<SkeletonAsset>,
+    animations: Box<[AnimationId]>,
+    diagnostics: usize,
+}
+
+impl DerivedAsset {
+    /// Returns the shared derived asset.
+    #[must_use]
+    pub fn asset(&self) -> &Arc<SkeletonAsset> {
+        &self.asset
+    }
+
+    /// Returns the generated animations in input order.
+    #[must_use]
+    pub fn animations(&self) -> &[AnimationId] {
+        &self.animations
+    }
+
+    /// Returns the diagnostics raised while generating the animations.
+    ///
+    /// They are also retained by the derived asset, scoped to their
+    /// animation, so they surface as active diagnostics while it plays.
+    #[must_use]
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        let retained = self.asset.diagnostics();
+        &retained[retained.len() - self.diagnostics..]
+    }
+
+    /// Consumes the report and returns its shared derived asset.
+    #[must_use]
+    pub fn into_asset(self) -> Arc<SkeletonAsset> {
+        self.asset
+    }
+}
+
+/// Copies `base` under a fresh identity with `animations` added.
+pub(crate) fn derive_asset(
+    base: &SkeletonAsset,
+    animations: Vec<AnimationData>,
+    pending: Vec<PendingDiagnostic>,
+) -> Result<DerivedAsset, DeriveError> {
+    let key = AssetKey::try_fresh().ok_or(DeriveError::CapacityExceeded)?;
+    let diagnostics = pending.len();
+    let (asset, indexes) = base.with_animations(key, animations, pending);
+    Ok(DerivedAsset {
+        asset: Arc::new(asset),
+        animations: indexes
+            .into_iter()
+            .map(|index| AnimationId::new(key, index))
+            .collect(),
+        diagnostics,
+    })
+}
+
+/// Remaps the slots of every draw-order key, or returns `None` when a slot is
+/// unmapped or the offsets no longer form a valid order of `slot_count`
+/// slots.
+pub(crate) fn remap_draw_order(
+    frames: &[DrawOrderFrame],
+    slot_count: usize,
+    mut slot: impl FnMut(u32) -> Option<u32>,
+) -> Option<Box<[DrawOrderFrame]>> {
+    let slot_count = i64::try_from(slot_count).unwrap_or(i64::MAX);
+    let mut remapped = Vec::with_capacity(frames.len());
+    for frame in frames {
+        let mut offsets = Vec::with_capacity(frame.offsets.len());
+        for offset in &frame.offsets {
+            offsets.push(DrawOrderOffset {
+                slot: slot(offset.slot)?,
+                offset: offset.offset,
+            });
+        }
+        offsets.sort_by_key(|offset| offset.slot);
+        let mut destinations = offsets
+            .iter()
+            .map(|offset| i64::from(offset.slot) + i64::from(offset.offset))
+            .collect::<Vec<_>>();
+        destinations.sort_unstable();
+        let valid = offsets.windows(2).all(|pair| pair[0].slot != pair[1].slot)
+            && destinations.windows(2).all(|pair| pair[0] != pair[1])
+            && destinations
+                .iter()
+                .all(|destination| (0..slot_count).contains(destination));
+        if !valid {
+            return None;
+        }
+        remapped.push(DrawOrderFrame {
+            time: frame.time,
+            offsets: offsets.into_boxed_slice(),
+        });
+    }
+    Some(remapped.into_boxed_slice())
+}
+
+/// Maps every key value and Bézier control value of a scalar timeline
+/// through `value * scale + offset`.
+pub(crate) fn map_scalar_frames(
+    frames: &[ScalarFrame],
+    scale: f32,
+    offset: f32,
+) -> Box<[ScalarFrame]> {
+    frames
+        .iter()
+        .map(|frame| ScalarFrame {
+            time: frame.time,
+            value: frame.value * scale + offset,
+            curve: map_curve(&frame.curve, [scale], [offset]),
+        })
+        .collect()
+}
+
+/// Maps every key value and Bézier control value of a two-channel timeline
+/// through `value * scale + offset`, per channel.
+pub(crate) fn map_vec2_frames(
+    frames: &[Vec2Frame],
+    scale: [f32; 2],
+    offset: [f32; 2],
+) -> Box<[Vec2Frame]> {
+    frames
+        .iter()
+        .map(|frame| Vec2Frame {
+            time: frame.time,
+            x: frame.x * scale[0] + offset[0],
+            y: frame.y * scale[1] + offset[1],
+            curve: map_curve(&frame.curve, scale, offset),
+        })
+        .collect()
+}
+
+fn map_curve<const CHANNELS: usize>(
+    curve: &FrameCurve<CHANNELS>,
+    scale: [f32; CHANNELS],
+    offset: [f32; CHANNELS],
+) -> FrameCurve<CHANNELS> {
+    match curve {
+        FrameCurve::Bezier(channels) => {
+            let mut channels = *channels;
+            for (channel, (scale, offset)) in channels.iter_mut().zip(scale.into_iter().zip(offset))
+            {
+                channel[1] = channel[1] * scale + offset;
+                channel[3] = channel[3] * scale + offset;
+            }
+            FrameCurve::Bezier(channels)
+        }
+        curve => curve.clone(),
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     AnimationId, AtlasPageId, AtlasRegionId, AttachmentId, BoneId, ConstraintId, EventId,
-    IkConstraintId, SkinId, SlotId,
+    IkConstraintId, SkinId, SlotId, id::AssetKey,
 };
 
 /// The runtime impact of a non-fatal asset diagnostic.
@@ -45,8 +45,21 @@ pub enum DiagnosticCode {
     UnsupportedAtlasSetting,
     /// An atlas region uses a packed rotation outside the active profile.
     UnsupportedAtlasRotation,
+    /// An attachment's region name also appears in a later linked atlas, whose
+    /// region is not drawn.
+    ShadowedAtlasRegion,
     /// Further loader diagnostics were omitted after reaching the retention limit.
     DiagnosticsTruncated,
+    /// A retargeted timeline's bone has no counterpart in the target skeleton.
+    UnmappedBone,
+    /// A retargeted timeline's slot has no counterpart in the target skeleton.
+    UnmappedSlot,
+    /// A retargeted timeline's constraint has no counterpart in the target skeleton.
+    UnmappedConstraint,
+    /// A retargeted event key has no counterpart in the target skeleton.
+    UnmappedEvent,
+    /// A retargeted attachment key names an attachment the target slot lacks.
+    UnmappedAttachment,
 }
 
 /// The asset element affected by a diagnostic.
@@ -77,6 +90,25 @@ pub enum DiagnosticScope {
     AtlasRegion(AtlasRegionId),
 }
 
+impl DiagnosticScope {
+    /// Returns the same element scoped to a copy of its asset.
+    pub(crate) const fn with_asset(self, key: AssetKey) -> Self {
+        match self {
+            Self::Asset => Self::Asset,
+            Self::Bone(id) => Self::Bone(BoneId::new(key, id.index())),
+            Self::Slot(id) => Self::Slot(SlotId::new(key, id.index())),
+            Self::Skin(id) => Self::Skin(SkinId::new(key, id.index())),
+            Self::Animation(id) => Self::Animation(AnimationId::new(key, id.index())),
+            Self::Event(id) => Self::Event(EventId::new(key, id.index())),
+            Self::Attachment(id) => Self::Attachment(AttachmentId::new(key, id.index())),
+            Self::IkConstraint(id) => Self::IkConstraint(IkConstraintId::new(key, id.index())),
+            Self::Constraint(id) => Self::Constraint(ConstraintId::new(key, id.index())),
+            Self::AtlasPage(id) => Self::AtlasPage(AtlasPageId::new(key, id.index())),
+            Self::AtlasRegion(id) => Self::AtlasRegion(AtlasRegionId::new(key, id.index())),
+        }
+    }
+}
+
 /// A non-fatal issue discovered while linking an asset.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Diagnostic {
@@ -119,4 +151,9 @@ impl Diagnostic {
     pub const fn is_degraded(&self) -> bool {
         matches!(self.severity, DiagnosticSeverity::Degraded)
     }
+
+    pub(crate) fn with_asset(mut self, key: AssetKey) -> Self {
+        self.scope = self.scope.with_asset(key);
+        self
+    }
 }
@@ -7,7 +7,7 @@ use glam::Vec2;
 use crate::{
     AtlasPageId, AtlasPageRef, AtlasRegionId, AtlasRegionRef, AtlasRotation, AttachmentId, IdError,
     MeshAttachmentRef, PixelRect, PixelSize, RegionAttachmentRef, Rgba, SkeletonAsset,
-    SlotBlendMode, SlotId, SlotRef, Trim,
+    SlotBlendMode, SlotId, SlotRef, TextureOverride, Trim,
     world::{WorldTransform, normal_local_to_world},
 };
 
@@ -47,6 +47,7 @@ pub struct RegionDrawItemRef<'a> {
     attachment: RegionAttachmentRef<'a>,
     atlas_page: AtlasPageRef<'a>,
     atlas_region: AtlasRegionRef<'a>,
+    texture: Option<TextureOverride>,
     positions: [Vec2; 4],
     uvs: Option<[Vec2; 4]>,
     color: Rgba,
@@ -77,6 +78,15 @@ impl<'a> RegionDrawItemRef<'a> {
         self.atlas_region.id()
     }
 
+    /// Returns the custom texture drawn instead of the atlas region, if any.
+    ///
+    /// When present, [`Self::uvs`] address its page and the positions span
+    /// the attachment's untrimmed size.
+    #[must_use]
+    pub const fn texture_override(self) -> Option<TextureOverride> {
+        self.texture
+    }
+
     /// Returns the four skeleton-space positions.
     ///
     /// Vertex order is bottom-left, top-left, top-right, bottom-right before
@@ -118,17 +128,13 @@ impl<'a> RegionDrawItemRef<'a> {
         attachment: RegionAttachmentRef<'_>,
         bone_world: WorldTransform,
         slot_color: Rgba,
+        texture: Option<TextureOverride>,
     ) -> Result<Self, IdError> {
         let slot = asset.slot(slot.id())?;
         let attachment = asset
             .attachment(attachment.attachment().id())?
             .as_region()
             .expect("a RegionAttachmentRef always identifies a region attachment");
-        debug_assert_eq!(
-            attachment.attachment().slot(),
-            slot.id(),
-            "a draw attachment must belong to its slot"
-        );
         let atlas_region = asset.atlas_region(attachment.atlas_region())?;
         let atlas_page = asset.atlas_page(atlas_region.page())?;
         Ok(Self::from_linked(
@@ -138,6 +144,7 @@ impl<'a> RegionDrawItemRef<'a> {
             atlas_page,
             bone_world,
             slot_color,
+            texture,
         ))
     }
 
@@ -148,12 +155,8 @@ impl<'a> RegionDrawItemRef<'a> {
         atlas_page: AtlasPageRef<'a>,
         bone_world: WorldTransform,
         slot_color: Rgba,
+        texture: Option<TextureOverride>,
     ) -> Self {
-        debug_assert_eq!(
-            attachment.attachment().slot(),
-            slot.id(),
-            "a draw attachment must belong to its slot"
-        );
         debug_assert_eq!(
             attachment.atlas_region(),
             atlas_region.id(),
@@ -165,19 +168,27 @@ impl<'a> RegionDrawItemRef<'a> {
             "a draw region must use its containing atlas page"
         );
 
+        // A custom texture holds the whole image, so the atlas trim no longer
+        // offsets the corners.
+        let trim = if texture.is_some() {
+            Trim::default()
+        } else {
+            atlas_region.trim()
+        };
         let positions = transform_region_positions(
             bone_world,
             attachment.local_transform(),
-            region_local_corners(
-                attachment.size(),
-                atlas_region.bounds(),
-                atlas_region.trim(),
-            ),
+            region_local_corners(attachment.size(), atlas_region.bounds(), trim),
         );
-        let uvs = normalized_uvs(
-            atlas_page.size(),
-            atlas_region.bounds(),
-            atlas_region.rotation(),
+        let uvs = texture.map_or_else(
+            || {
+                normalized_uvs(
+                    atlas_page.size(),
+                    atlas_region.bounds(),
+                    atlas_region.rotation(),
+                )
+            },
+            |texture| Some(texture.corners()),
         );
         let color = modulate_color(slot_color, Rgba::from_rgba8(attachment.color()));
 
@@ -186,6 +197,7 @@ impl<'a> RegionDrawItemRef<'a> {
             attachment,
             atlas_page,
             atlas_region,
+            texture,
             positions,
             uvs,
             color,
@@ -203,6 +215,7 @@ pub struct MeshDrawItemRef<'a> {
     attachment: MeshAttachmentRef<'a>,
     atlas_page: AtlasPageRef<'a>,
     atlas_region: AtlasRegionRef<'a>,
+    texture: Option<TextureOverride>,
     positions: &'a [Vec2],
     color: Rgba,
 }
@@ -232,6 +245,14 @@ impl<'a> MeshDrawItemRef<'a> {
         self.atlas_region.id()
     }
 
+    /// Returns the custom texture drawn instead of the atlas region, if any.
+    ///
+    /// When present, [`Self::uvs`] address its page.
+    #[must_use]
+    pub const fn texture_override(self) -> Option<TextureOverride> {
+        self.texture
+    }
+
     /// Returns solved skeleton-space positions in source vertex order.
     #[must_use]
     pub const fn positions(self) -> &'a [Vec2] {
@@ -259,6 +280,17 @@ impl<'a> MeshDrawItemRef<'a> {
     /// [`Self::source_uvs`] itself.
     #[must_use]
     pub fn uvs(self) -> Option<MeshUvIter<'a>> {
+        if let Some(texture) = self.texture {
+            // A unit region without trim leaves source UVs unchanged before
+            // they are placed in the custom rectangle.
+            return Some(MeshUvIter {
+                source: self.attachment.uvs(),
+                remaining: 0..self.attachment.vertex_count(),
+                corners: texture.corners(),
+                bounds: PixelRect::new(0, 0, 1, 1),
+                trim: Trim::new(0, 0, 1, 1),
+            });
+        }
         let corners = normalized_uvs(
             self.atlas_page.size(),
             self.atlas_region.bounds(),
@@ -291,13 +323,13 @@ impl<'a> MeshDrawItemRef<'a> {
         attachment: MeshAttachmentRef<'_>,
         positions: &'a [Vec2],
         slot_color: Rgba,
+        texture: Option<TextureOverride>,
     ) -> Result<Self, IdError> {
         let slot = asset.slot(slot.id())?;
         let attachment = asset
             .attachment(attachment.attachment().id())?
             .as_mesh()
             .expect("a MeshAttachmentRef always identifies a mesh attachment");
-        debug_assert_eq!(attachment.attachment().slot(), slot.id());
         debug_assert_eq!(attachment.vertex_count(), positions.len());
         let atlas_region = asset.atlas_region(attachment.atlas_region())?;
         let atlas_page = asset.atlas_page(atlas_region.page())?;
@@ -307,6 +339,7 @@ impl<'a> MeshDrawItemRef<'a> {
             attachment,
             atlas_page,
             atlas_region,
+            texture,
             positions,
             color,
         })
@@ -669,7 +702,7 @@ body
         .expect("fixture transform is finite");
         let slot_color = Rgba::new(0.5, 0.25, 1.0, 0.75).expect("fixture colour is normalized");
 
-        let item = RegionDrawItemRef::from_asset(&asset, slot, attachment, world, slot_color)
+        let item = RegionDrawItemRef::from_asset(&asset, slot, attachment, world, slot_color, None)
             .expect("all linked refs belong to the fixture");
 
         assert_eq!(item.slot(), slot.id());
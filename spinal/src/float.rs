@@ -0,0 +1,112 @@
+//! Transcendental float operations used by pose solving and mixing.
+//!
+//! The default build calls the platform implementations from `std`, whose
+//! last bits may differ between targets. With the `portable-math` feature
+//! every function here is computed in software by `libm`, so solved frames
+//! are bit-identical on every target. Square roots and basic arithmetic are
+//! correctly rounded by IEEE 754 and need no replacement, and Rust never
+//! contracts a multiply and add into a fused operation on its own.
+
+#[cfg(feature = "portable-math")]
+mod imp {
+    pub(super) fn sin_cos(x: f64) -> (f64, f64) {
+        libm::sincos(x)
+    }
+
+    pub(super) fn sin(x: f64) -> f64 {
+        libm::sin(x)
+    }
+
+    pub(super) fn acos(x: f64) -> f64 {
+        libm::acos(x)
+    }
+
+    pub(super) fn atan2(y: f64, x: f64) -> f64 {
+        libm::atan2(y, x)
+    }
+
+    pub(super) fn atan2_f32(y: f32, x: f32) -> f32 {
+        libm::atan2f(y, x)
+    }
+
+    pub(super) fn hypot(x: f64, y: f64) -> f64 {
+        libm::hypot(x, y)
+    }
+
+    pub(super) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+        libm::fma(a, b, c)
+    }
+}
+
+#[cfg(not(feature = "portable-math"))]
+mod imp {
+    pub(super) fn sin_cos(x: f64) -> (f64, f64) {
+        x.sin_cos()
+    }
+
+    pub(super) fn sin(x: f64) -> f64 {
+        x.sin()
+    }
+
+    pub(super) fn acos(x: f64) -> f64 {
+        x.acos()
+    }
+
+    pub(super) fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+
+    pub(super) fn atan2_f32(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+
+    pub(super) fn hypot(x: f64, y: f64) -> f64 {
+        x.hypot(y)
+    }
+
+    pub(super) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+        a.mul_add(b, c)
+    }
+}
+
+/// Returns the sine and cosine of `x` radians.
+#[inline]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    imp::sin_cos(x)
+}
+
+/// Returns the sine of `x` radians.
+#[inline]
+pub(crate) fn sin(x: f64) -> f64 {
+    imp::sin(x)
+}
+
+/// Returns the arccosine of `x` in radians.
+#[inline]
+pub(crate) fn acos(x: f64) -> f64 {
+    imp::acos(x)
+}
+
+/// Returns the four-quadrant arctangent of `y / x` in radians.
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    imp::atan2(y, x)
+}
+
+/// Single-precision [`atan2`].
+#[inline]
+pub(crate) fn atan2_f32(y: f32, x: f32) -> f32 {
+    imp::atan2_f32(y, x)
+}
+
+/// Returns `sqrt(x * x + y * y)` without intermediate overflow.
+#[inline]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    imp::hypot(x, y)
+}
+
+/// Returns `a * b + c` with a single rounding.
+#[inline]
+pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    imp::mul_add(a, b, c)
+}
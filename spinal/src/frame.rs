@@ -1,11 +1,17 @@
+use std::time::Duration;
+
 use glam::Vec2;
 use thiserror::Error;
 
 use crate::{
     Angle, AtlasPageId, AtlasRegionId, AttachmentId, BendDirection, BoneId, BonePoseRef,
     BoneTransform, ConstraintId, Diagnostic, DiagnosticScope, DrawItemRef, IdError, IkConstraintId,
-    IkConstraintPoseRef, MeshDrawItemRef, Mix, RegionDrawItemRef, Shear, Skeleton, SkinId, SlotId,
-    SlotPoseRef, TransformConstraintId, TransformConstraintPoseRef, TransformMix, UpdateReport,
+    IkConstraintPoseRef, InvalidRgba, MeshDrawItemRef, Mix, RegionDrawItemRef, Rgba,
+    RuntimeConstraintError, RuntimeConstraintId, RuntimeConstraintStatus, Shear, Skeleton, SkinId,
+    SlotId, SlotPoseRef, TransformConstraintId, TransformConstraintPoseRef, TransformMix,
+    UpdateReport, float,
+    runtime_constraint::solve_runtime_constraints,
+    runtime_skin::follows_any_slot,
     world::{
         IkReach, OneBoneIkSolution, WorldTransform, normal_local_to_world, shortest_angle_delta,
         solve_one_bone_ik, solve_two_bone_ik, solve_world_rotation,
@@ -14,19 +20,29 @@ use crate::{
 
 /// A sampled local pose that may receive procedural edits before constraints.
 ///
-/// This state deliberately exposes no world transforms or draw data. Consuming
-/// it with [`EditablePose::solve`] applies constraints and produces a
+/// This state deliberately exposes no solved world transforms or draw data;
+/// [`EditablePose::bone_space`] converts against the unconstrained pose.
+/// Consuming it with [`EditablePose::solve`] applies constraints and produces a
 /// [`SolvedFrame`].
 #[derive(Debug)]
 #[must_use = "apply procedural edits, then call solve to obtain renderer output"]
 pub struct EditablePose<'a> {
-    skeleton: &'a mut Skeleton,
+    pub(crate) skeleton: &'a mut Skeleton,
     report: UpdateReport,
+    elapsed: Duration,
 }
 
 impl<'a> EditablePose<'a> {
-    pub(crate) const fn new(skeleton: &'a mut Skeleton, report: UpdateReport) -> Self {
-        Self { skeleton, report }
+    pub(crate) const fn new(
+        skeleton: &'a mut Skeleton,
+        report: UpdateReport,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            skeleton,
+            report,
+            elapsed,
+        }
     }
 
     /// Returns the lifecycle facts from the player update that produced this
@@ -36,10 +52,30 @@ impl<'a> EditablePose<'a> {
         self.report
     }
 
+    /// Returns the unscaled time since the previous solve that runtime
+    /// constraints integrate over.
+    ///
+    /// Player and mixer updates use their update delta. A standalone
+    /// [`Skeleton::editable_pose`] has no elapsed time.
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Replaces the time runtime constraints integrate over, for example
+    /// after [`Skeleton::sample_animation`].
+    #[must_use = "the builder returns the pose with the new elapsed time"]
+    pub const fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+
     /// Opens a short-lived procedural editing view.
     ///
-    /// Edits operate in bone-local space and are applied after animation
-    /// sampling and crossfading, but before world transforms and IK.
+    /// Bone edits operate in bone-local space and are applied after animation
+    /// sampling and crossfading, but before world transforms and IK. Slot
+    /// colour, attachment and draw-order edits appear in the solved draw
+    /// stream.
     pub fn edit(&mut self) -> PoseEditor<'_> {
         PoseEditor {
             skeleton: self.skeleton,
@@ -59,7 +95,7 @@ impl<'a> EditablePose<'a> {
     /// Applies world transforms and all supported constraints in authored
     /// evaluation order.
     pub fn solve(self) -> SolvedFrame<'a> {
-        solve_world_and_constraints(self.skeleton);
+        solve_world_and_constraints(self.skeleton, self.elapsed);
         SolvedFrame {
             skeleton: self.skeleton,
             report: self.report,
@@ -75,7 +111,7 @@ impl Skeleton {
     /// [`Skeleton::reset_to_setup_pose`]. The resulting update report is
     /// empty because no player clock was advanced.
     pub fn editable_pose(&mut self) -> EditablePose<'_> {
-        EditablePose::new(self, UpdateReport::default())
+        EditablePose::new(self, UpdateReport::default(), Duration::ZERO)
     }
 }
 
@@ -154,7 +190,10 @@ pub enum ControlTargetError {
     },
 }
 
-fn current_unconstrained_world(skeleton: &mut Skeleton, through: usize) -> WorldTransform {
+pub(crate) fn current_unconstrained_world(
+    skeleton: &mut Skeleton,
+    through: usize,
+) -> WorldTransform {
     for index in 0..=through {
         let parent = skeleton.asset().bone_data(index).parent;
         let parent_world = parent.map(|parent| skeleton.world_transforms[parent as usize]);
@@ -238,6 +277,178 @@ impl PoseEditor<'_> {
         self.skeleton.pose.transform_constraints[index].mix_rotate = mix;
         Ok(())
     }
+
+    /// Returns one slot's current modulation colour.
+    pub fn slot_color(&self, slot: SlotId) -> Result<Rgba, IdError> {
+        self.skeleton.slot_pose(slot).map(SlotPoseRef::color)
+    }
+
+    /// Replaces one slot's modulation colour, for example for a damage
+    /// flash.
+    pub fn set_slot_color(&mut self, slot: SlotId, color: Rgba) -> Result<(), IdError> {
+        let index = self.skeleton.asset().slot_index(slot)?;
+        self.skeleton.pose.slots[index].color = color;
+        Ok(())
+    }
+
+    /// Replaces only one slot's alpha, keeping its current RGB channels.
+    pub fn set_slot_alpha(&mut self, slot: SlotId, alpha: f32) -> Result<(), SlotEditError> {
+        let index = self
+            .skeleton
+            .asset()
+            .slot_index(slot)
+            .map_err(SlotEditError::InvalidSlot)?;
+        let pose = &mut self.skeleton.pose.slots[index];
+        pose.color = pose
+            .color
+            .with_alpha(alpha)
+            .map_err(SlotEditError::InvalidColor)?;
+        Ok(())
+    }
+
+    /// Returns the attachment one slot currently shows.
+    pub fn slot_attachment(&self, slot: SlotId) -> Result<Option<AttachmentId>, IdError> {
+        self.skeleton.slot_pose(slot).map(SlotPoseRef::attachment)
+    }
+
+    /// Shows the attachment the active skins provide for `placeholder`, as an
+    /// attachment timeline key would, or hides the slot for `None`.
+    ///
+    /// A placeholder no skin provides leaves the slot empty.
+    pub fn set_slot_attachment(
+        &mut self,
+        slot: SlotId,
+        placeholder: Option<&str>,
+    ) -> Result<(), SlotEditError> {
+        let asset = self.skeleton.asset();
+        let index = asset.slot_index(slot).map_err(SlotEditError::InvalidSlot)? as u32;
+        let (placeholder_index, attachment) = match placeholder {
+            None => (None, None),
+            Some(placeholder) => {
+                let placeholder_index = asset
+                    .attachment_placeholder_index(index, placeholder)
+                    .ok_or(SlotEditError::UnknownPlaceholder)?;
+                let attachment = self.skeleton.skin_stack.resolve(asset, index, placeholder);
+                (Some(placeholder_index), attachment)
+            }
+        };
+        let pose = &mut self.skeleton.pose.slots[index as usize];
+        pose.attachment_placeholder = placeholder_index;
+        pose.attachment = attachment;
+        Ok(())
+    }
+
+    /// Shows a concrete attachment of any skin on one slot, or hides the
+    /// slot for `None`.
+    ///
+    /// An unweighted mesh can only be shown on slots of its authored bone.
+    pub fn set_slot_attachment_id(
+        &mut self,
+        slot: SlotId,
+        attachment: Option<AttachmentId>,
+    ) -> Result<(), SlotEditError> {
+        let asset = self.skeleton.asset();
+        let index = asset.slot_index(slot).map_err(SlotEditError::InvalidSlot)?;
+        let (placeholder_index, attachment) = match attachment {
+            None => (None, None),
+            Some(id) => {
+                let attachment = asset
+                    .attachment(id)
+                    .map_err(SlotEditError::InvalidAttachment)?
+                    .ordinal();
+                let data = asset.attachment_data(attachment);
+                if !follows_any_slot(asset, attachment)
+                    && asset.slot_data(index).bone != asset.slot_data(data.slot as usize).bone
+                {
+                    return Err(SlotEditError::IncompatibleAttachment {
+                        attachment: id,
+                        slot,
+                    });
+                }
+                (
+                    asset.attachment_placeholder_index(index as u32, &data.placeholder_name),
+                    Some(attachment as u32),
+                )
+            }
+        };
+        let pose = &mut self.skeleton.pose.slots[index];
+        pose.attachment_placeholder = placeholder_index;
+        pose.attachment = attachment;
+        Ok(())
+    }
+
+    /// Moves `slot` in the draw order to directly in front of `other`.
+    pub fn draw_slot_in_front_of(&mut self, slot: SlotId, other: SlotId) -> Result<(), IdError> {
+        self.move_in_draw_order(slot, other, true)
+    }
+
+    /// Moves `slot` in the draw order to directly behind `other`.
+    pub fn draw_slot_behind(&mut self, slot: SlotId, other: SlotId) -> Result<(), IdError> {
+        self.move_in_draw_order(slot, other, false)
+    }
+
+    fn move_in_draw_order(
+        &mut self,
+        slot: SlotId,
+        other: SlotId,
+        in_front: bool,
+    ) -> Result<(), IdError> {
+        let asset = self.skeleton.asset();
+        let slot = asset.slot_index(slot)? as u32;
+        let other = asset.slot_index(other)? as u32;
+        let order = &mut self.skeleton.pose.draw_order;
+        let position = |slot| {
+            order
+                .iter()
+                .position(|candidate| *candidate == slot)
+                .expect("the draw order lists every slot once")
+        };
+        let (from, to) = (position(slot), position(other));
+        // Draw order runs back to front, so in front means a later position.
+        if from < to {
+            let target = if in_front { to } else { to - 1 };
+            order[from..=target].rotate_left(1);
+        } else if from > to {
+            let target = if in_front { to + 1 } else { to };
+            order[target..=from].rotate_right(1);
+        }
+        Ok(())
+    }
+}
+
+/// A failure to edit a slot's procedural state.
+#[derive(Clone, Copy, Debug, Error, PartialEq)]
+#[non_exhaustive]
+pub enum SlotEditError {
+    /// The slot ID belongs to another loaded asset.
+    #[error("the edited slot is invalid: {0}")]
+    InvalidSlot(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// The attachment ID belongs to another loaded asset.
+    #[error("the shown attachment is invalid: {0}")]
+    InvalidAttachment(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// A colour channel is non-finite or outside `0.0..=1.0`.
+    #[error(transparent)]
+    InvalidColor(InvalidRgba),
+    /// No skin or animation of the slot refers to the placeholder.
+    #[error("the slot has no such attachment placeholder")]
+    UnknownPlaceholder,
+    /// An unweighted mesh was shown on a slot of a different bone, where its
+    /// vertices would still follow the authored bone.
+    #[error("attachment {attachment:?} cannot be drawn on slot {slot:?}")]
+    IncompatibleAttachment {
+        /// The mesh attachment.
+        attachment: AttachmentId,
+        /// The rejected slot.
+        slot: SlotId,
+    },
 }
 
 /// Whether a full-influence two-bone IK solution can reach its target.
@@ -526,6 +737,7 @@ impl SolvedFrame<'_> {
                         region,
                         self.skeleton.world_transforms[bone.ordinal()],
                         slot_pose.color(),
+                        self.skeleton.texture_overrides[attachment.ordinal()],
                     )
                     .expect("linked draw references belong to one immutable asset"),
                 ));
@@ -534,8 +746,15 @@ impl SolvedFrame<'_> {
                 let positions = &self.skeleton.mesh_world_positions
                     [self.skeleton.mesh_vertex_ranges[attachment.ordinal()].clone()];
                 DrawItemRef::from(
-                    MeshDrawItemRef::from_asset(asset, slot, mesh, positions, slot_pose.color())
-                        .expect("linked mesh draw references belong to one immutable asset"),
+                    MeshDrawItemRef::from_asset(
+                        asset,
+                        slot,
+                        mesh,
+                        positions,
+                        slot_pose.color(),
+                        self.skeleton.texture_overrides[attachment.ordinal()],
+                    )
+                    .expect("linked mesh draw references belong to one immutable asset"),
                 )
             })
         })
@@ -585,6 +804,31 @@ impl SolvedFrame<'_> {
             .map(|(constraint, status)| (constraint.id(), status))
     }
 
+    /// Returns the result one runtime constraint reported for this frame.
+    pub fn runtime_constraint_status(
+        &self,
+        constraint: RuntimeConstraintId,
+    ) -> Result<RuntimeConstraintStatus, RuntimeConstraintError> {
+        self.skeleton
+            .runtime_constraints
+            .iter()
+            .find(|entry| entry.id == constraint)
+            .map(|entry| entry.status)
+            .ok_or(RuntimeConstraintError::UnknownConstraint)
+    }
+
+    /// Iterates runtime constraint results in solve order.
+    pub fn runtime_constraint_statuses(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (RuntimeConstraintId, RuntimeConstraintStatus)>
+    + ExactSizeIterator
+    + '_ {
+        self.skeleton
+            .runtime_constraints
+            .iter()
+            .map(|entry| (entry.id, entry.status))
+    }
+
     /// Iterates retained asset diagnostics that affect this evaluated frame.
     ///
     /// For example, an unsupported attachment is active only while that
@@ -603,26 +847,15 @@ impl SolvedFrame<'_> {
             .filter(|diagnostic| self.diagnostic_is_active(diagnostic.scope()))
     }
 
-    /// Returns whether an active asset fallback or runtime IK safety fallback
+    /// Returns whether an active asset fallback or runtime safety fallback
     /// changed this frame.
     #[must_use]
     pub fn has_degradations(&self) -> bool {
-        self.active_diagnostics().any(Diagnostic::is_degraded)
-            || self
-                .skeleton
-                .ik_solve_statuses
-                .iter()
-                .copied()
-                .any(IkSolveStatus::is_degraded)
-            || self
-                .skeleton
-                .transform_solve_statuses
-                .iter()
-                .copied()
-                .any(TransformConstraintSolveStatus::is_degraded)
+        self.active_diagnostics().any(Diagnostic::is_degraded) || self.has_runtime_degradations()
     }
 
-    /// Returns whether a runtime IK safety fallback changed this frame.
+    /// Returns whether a runtime IK, transform or runtime constraint safety
+    /// fallback changed this frame.
     #[must_use]
     pub fn has_runtime_degradations(&self) -> bool {
         self.skeleton
@@ -636,6 +869,11 @@ impl SolvedFrame<'_> {
                 .iter()
                 .copied()
                 .any(TransformConstraintSolveStatus::is_degraded)
+            || self
+                .skeleton
+                .runtime_constraints
+                .iter()
+                .any(|entry| entry.status.is_degraded())
     }
 
     fn diagnostic_is_active(&self, scope: DiagnosticScope) -> bool {
@@ -749,7 +987,7 @@ impl SolvedFrame<'_> {
     }
 }
 
-fn solve_world_and_constraints(skeleton: &mut Skeleton) {
+fn solve_world_and_constraints(skeleton: &mut Skeleton, elapsed: Duration) {
     skeleton.applied_bones.copy_from_slice(&skeleton.pose.bones);
     skeleton.ik_solve_statuses.fill(IkSolveStatus::INACTIVE);
     skeleton
@@ -757,7 +995,13 @@ fn solve_world_and_constraints(skeleton: &mut Skeleton) {
         .fill(TransformConstraintSolveStatus::INACTIVE);
     recompute_world_transforms(skeleton);
 
-    for order_index in 0..skeleton.asset().constraint_evaluation_order().len() {
+    // The entries are moved out so each constraint can borrow the skeleton.
+    let mut runtime = std::mem::take(&mut skeleton.runtime_constraints);
+    let mut next_runtime = 0;
+    let order_len = skeleton.asset().constraint_evaluation_order().len();
+    for order_index in 0..order_len {
+        next_runtime =
+            solve_runtime_constraints(skeleton, elapsed, &mut runtime, next_runtime, order_index);
         let constraint_index = skeleton.asset().constraint_evaluation_order()[order_index] as usize;
         let (ik_constraint, transform_constraint) = {
             let constraint = skeleton.asset().constraint_data(constraint_index);
@@ -769,6 +1013,8 @@ fn solve_world_and_constraints(skeleton: &mut Skeleton) {
             solve_transform_constraint(skeleton, index as usize);
         }
     }
+    solve_runtime_constraints(skeleton, elapsed, &mut runtime, next_runtime, order_len);
+    skeleton.runtime_constraints = runtime;
     skeleton.update_mesh_world_positions();
 }
 
@@ -856,7 +1102,7 @@ fn solve_transform_constraint(skeleton: &mut Skeleton, constraint_index: usize)
     );
 }
 
-fn apply_one_bone_ik(
+pub(crate) fn apply_one_bone_ik(
     skeleton: &mut Skeleton,
     bone: usize,
     target_world: glam::Vec2,
@@ -880,7 +1126,7 @@ fn apply_one_bone_ik(
     IkSolveStatus::applied(None, false)
 }
 
-fn apply_two_bone_ik(
+pub(crate) fn apply_two_bone_ik(
     skeleton: &mut Skeleton,
     parent: usize,
     child: usize,
@@ -945,7 +1191,7 @@ fn apply_two_bone_ik(
     IkSolveStatus::applied(Some(target_reach), solution.child_y_was_zeroed)
 }
 
-fn recompute_world_transforms(skeleton: &mut Skeleton) {
+pub(crate) fn recompute_world_transforms(skeleton: &mut Skeleton) {
     for bone in 0..skeleton.applied_bones.len() {
         let parent = skeleton
             .asset()
@@ -957,6 +1203,34 @@ fn recompute_world_transforms(skeleton: &mut Skeleton) {
     }
 }
 
+/// Recomputes the world transforms of one bone and its descendants after its
+/// local transform changed; every other bone keeps its world transform.
+pub(crate) fn recompute_subtree_world_transforms(skeleton: &mut Skeleton, root: usize) {
+    let parent_of = |skeleton: &Skeleton, bone: usize| {
+        skeleton
+            .asset()
+            .bone_data(bone)
+            .parent
+            .map(|parent| parent as usize)
+    };
+    for bone in root..skeleton.applied_bones.len() {
+        // Parents precede their children, so an ancestor walk can stop at the
+        // first bone before the root.
+        let mut ancestor = Some(bone);
+        while let Some(index) = ancestor
+            && index > root
+        {
+            ancestor = parent_of(skeleton, index);
+        }
+        if ancestor != Some(root) {
+            continue;
+        }
+        let parent = parent_of(skeleton, bone).map(|parent| skeleton.world_transforms[parent]);
+        skeleton.world_transforms[bone] =
+            normal_local_to_world(parent, skeleton.applied_bones[bone].local_transform);
+    }
+}
+
 fn mixed_angle(current: Angle, desired: Angle, mix: f32) -> Angle {
     let radians = f64::from(current.as_radians())
         + f64::from(shortest_angle_delta(current, desired)) * f64::from(mix);
@@ -967,11 +1241,11 @@ fn mixed_angle(current: Angle, desired: Angle, mix: f32) -> Angle {
 fn world_rotation(transform: WorldTransform) -> Option<Angle> {
     let axis = transform.x_axis();
     (axis.length_squared() > f32::EPSILON)
-        .then(|| Angle::from_radians(axis.y.atan2(axis.x)).ok())
+        .then(|| Angle::from_radians(float::atan2_f32(axis.y, axis.x)).ok())
         .flatten()
 }
 
-fn replace_rotation(transform: BoneTransform, rotation: Angle) -> BoneTransform {
+pub(crate) fn replace_rotation(transform: BoneTransform, rotation: Angle) -> BoneTransform {
     BoneTransform::new(
         transform.translation(),
         rotation,
@@ -124,6 +124,9 @@ impl AtlasRotation {
     /// No packed rotation.
     pub const ZERO: Self = Self(0.0);
 
+    /// The quarter turn written as `rotate: true`.
+    pub(crate) const QUARTER: Self = Self(90.0);
+
     pub(crate) fn new(degrees: f32) -> Option<Self> {
         (degrees.is_finite() && (0.0..=360.0).contains(&degrees)).then_some(Self(degrees))
     }
@@ -305,6 +308,11 @@ impl Rgba {
         [self.red, self.green, self.blue, self.alpha]
     }
 
+    /// Returns this colour with a replaced alpha channel.
+    pub fn with_alpha(self, alpha: f32) -> Result<Self, InvalidRgba> {
+        Self::new(self.red, self.green, self.blue, alpha)
+    }
+
     pub(crate) fn lerp(self, other: Self, amounts: [f32; 4]) -> Self {
         let start = self.to_array();
         let end = other.to_array();
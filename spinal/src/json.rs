@@ -3,13 +3,15 @@
 //! `serde_json::Value` stores objects in a map, which discards duplicate names
 //! and may discard source order. Both are useful when producing precise schema
 //! diagnostics, so this module deserializes into an ordered list of members
-//! instead.
+//! instead. The JSON writer serializes the same tree, so retained records keep
+//! their member order when written back out.
 
 use core::fmt;
 use core::str;
 
-use serde::Deserialize;
 use serde::de::{Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
 
 use crate::load::error::{LoadDocument, LoadError, LoadErrorKind, SourceLocation};
 
@@ -151,6 +153,14 @@ pub(crate) struct JsonMember {
 }
 
 impl JsonMember {
+    /// Creates a member for a document assembled in memory.
+    pub(crate) fn new(name: impl Into<Box<str>>, value: JsonValue) -> Self {
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn test_fixture(name: &str, value: JsonValue) -> Self {
         Self {
@@ -170,6 +180,32 @@ impl JsonMember {
     }
 }
 
+impl Serialize for JsonValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::I64(value) => serializer.serialize_i64(*value),
+            Self::U64(value) => serializer.serialize_u64(*value),
+            Self::F64(value) => serializer.serialize_f64(*value),
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Array(values) => values.serialize(serializer),
+            Self::Object(members) => {
+                // Members are written in order, so a reparse sees the same
+                // sequence the loader originally validated.
+                let mut map = serializer.serialize_map(Some(members.len()))?;
+                for member in members {
+                    map.serialize_entry(member.name(), member.value())?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for JsonValue {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
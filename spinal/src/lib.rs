@@ -3,10 +3,17 @@
 #![doc = include_str!("../README.md")]
 
 mod animation;
+mod animation_builder;
 mod asset;
 mod atlas;
+mod bake;
+mod batch;
+mod bone_space;
+mod cache;
+mod derived;
 mod diagnostic;
 mod draw;
+mod float;
 mod frame;
 mod geometry;
 mod id;
@@ -14,24 +21,48 @@ mod json;
 mod load;
 mod math;
 mod mesh;
+mod mirror;
+mod mix_table;
 mod mixer;
 mod player;
 mod pose;
+mod retarget;
+mod root_motion;
+mod runtime_constraint;
+mod runtime_ik;
+mod runtime_skin;
+mod save_state;
 mod skeleton;
+mod skeleton_builder;
+mod snapshot;
+mod spring;
+mod state_machine;
+mod sync_group;
+mod texture_override;
+mod timeline;
 mod world;
+mod write;
 
 pub use animation::{OverrideSupport, PlaybackMode, PropertyKey, TransformMixChannel};
+pub use animation_builder::{AnimationBuilder, EventKey, TransformKey, build_animations};
 pub use asset::{
-    AnimationRef, AtlasPageRef, AtlasPropertyRef, AtlasRegionRef, AttachmentKind, AttachmentRef,
-    BendDirection, BoneRef, ConstraintRef, EventDefinitionRef, IkConstraintRef,
+    AnimationMarker, AnimationRef, AtlasPageRef, AtlasPropertyRef, AtlasRegionRef, AttachmentKind,
+    AttachmentRef, BendDirection, BoneRef, ConstraintRef, EventDefinitionRef, IkConstraintRef,
     OverrideCompatibility, RegionAttachmentRef, SkeletonAsset, SkinRef, SlotBlendMode, SlotRef,
     TransformConstraintRef, TransformConstraintSetupPose,
 };
+pub use atlas::TextAtlas;
+pub use bake::{BakeOptions, BakeReport, BakedAnimation, BakedPlayer};
+pub use batch::{BatchContext, BatchInstance, BatchParts, update_batch};
+pub use bone_space::BoneSpace;
+pub use cache::{CacheError, SKELETON_CACHE_VERSION, read_cache, write_cache};
+pub use derived::{DeriveError, DerivedAsset};
 pub use diagnostic::{Diagnostic, DiagnosticCode, DiagnosticScope, DiagnosticSeverity};
 pub use draw::{DrawItemRef, MeshDrawItemRef, MeshUvIter, RegionDrawItemRef};
 pub use frame::{
     ControlTargetError, EditablePose, IkSolveIssue, IkSolveStatus, IkTargetReach, PoseEditor,
-    PoseTargets, SolvedBoneRef, SolvedFrame, TransformConstraintSolveStatus, TransformSolveIssue,
+    PoseTargets, SlotEditError, SolvedBoneRef, SolvedFrame, TransformConstraintSolveStatus,
+    TransformSolveIssue,
 };
 pub use geometry::{
     AlphaEncoding, AtlasRotation, InvalidRgba, PixelRect, PixelSize, Rgba, Rgba8, TextureFilter,
@@ -42,12 +73,17 @@ pub use id::{
     AnimationId, AtlasPageId, AtlasRegionId, AttachmentId, BoneId, ConstraintId, EventId, IdError,
     IdErrorKind, IkConstraintId, SkinId, SlotId, TransformConstraintId,
 };
-pub use load::{LoadDocument, LoadError, LoadErrorKind, LoadReport, SourceLocation, load_json};
+pub use load::{
+    LoadDocument, LoadError, LoadErrorKind, LoadReport, SourceLocation, load_json,
+    load_json_with_atlases,
+};
 pub use math::{
     Angle, BoneTransform, InvalidAngle, InvalidBoneTransform, InvalidMix, InvalidTransformMix, Mix,
     Shear, TransformMix,
 };
 pub use mesh::{MeshAttachmentRef, MeshInfluenceRef, MeshVertexRef};
+pub use mirror::{MirrorOptions, mirror_animations};
+pub use mix_table::{MixTable, MixTableError};
 pub use mixer::{
     AnimationMixer, BaseTrackMut, BaseTrackRef, InvalidPlaybackSpeed, TrackAnimationEvent,
     TrackError, TrackErrorKind, TrackEventSink, TrackId, TrackMut, TrackOptions,
@@ -57,10 +93,44 @@ pub use player::{
     AnimationEvent, AnimationPlayer, Crossfade, DiscreteSwitches, EventSink, MixCurve, PlayOptions,
     PlayOutcome, PlaybackId, PlayerError, PlayerStatus, RotationPath, Transition, UpdateReport,
 };
+pub use retarget::{RetargetOptions, TranslationRetarget, retarget_animations};
+pub use root_motion::{RootMotion, RootMotionDelta};
+pub use runtime_constraint::{
+    ConstraintPose, ConstraintPosition, RuntimeConstraint, RuntimeConstraintError,
+    RuntimeConstraintId, RuntimeConstraintStatus,
+};
+pub use runtime_ik::{IkTarget, RuntimeIk, RuntimeIkError};
+pub use runtime_skin::{RuntimeSkin, RuntimeSkinError, RuntimeSkinId, SkinAttachment, SkinLayer};
+pub use save_state::{
+    ANIMATION_SAVE_STATE_VERSION, AnimationSaveState, PlaybackSaveState, SaveStateError,
+    SaveStateMismatch, SavedTrack, TrackSaveState,
+};
 pub use skeleton::{
     BonePoseRef, IkConstraintPoseRef, Skeleton, SlotPoseRef, TransformConstraintPoseRef,
 };
+pub use skeleton_builder::{
+    AtlasPageDefinition, AtlasRegionDefinition, AttachmentDefinition, BoneDefinition,
+    IkConstraintDefinition, MeshDefinition, RegionDefinition, SkeletonBuilder, SkinDefinition,
+    SlotDefinition, TransformConstraintDefinition,
+};
+pub use snapshot::{MixerSnapshot, PlayerSnapshot, SkeletonSnapshot, SnapshotError};
+pub use spring::{SpringChain, SpringChainError, SpringOptions};
+pub use state_machine::{
+    Comparison, ConditionDefinition, CrossfadeDefinition, ParameterDefinition, ParameterId,
+    ParameterKind, ParameterValue, StateDefinition, StateId, StateMachine, StateMachineDefinition,
+    StateMachineDefinitionError, StateMachineError, StateMachineUpdateError, StateTransition,
+    TransitionDefinition,
+};
+pub use sync_group::{SyncGroupId, SyncPolicy};
+pub use texture_override::{CustomPageKey, TextureOverride, TextureOverrideError};
+pub use timeline::{
+    AttachmentKeyRef, AttachmentTimelineRef, BezierCurve, ColorKeyRef, ColorTimelineRef,
+    DrawOrderKeyRef, DrawOrderTimelineRef, EventKeyRef, EventTimelineRef, IkKeyRef, IkTimelineRef,
+    KeyCurve, ScalarKeyRef, ScalarTimelineRef, TimelineRef, TransformKeyRef, TransformTimelineRef,
+    Vec2KeyRef, Vec2TimelineRef,
+};
 pub use world::{InvalidWorldTransform, WorldTransform};
+pub use write::write_json;
 
 /// The Spine major version targeted by the first Spinal wire-format loader.
 pub const TARGET_SPINE_MAJOR: u16 = 4;
@@ -5,7 +5,7 @@ use crate::{
     animation::{
         AnimationData, AttachmentFrame, ColourFrame, DrawOrderFrame, DrawOrderOffset,
         EventDefinitionData, EventFrame, EventPayload, FrameCurve, IkFrame, NANOS_PER_SECOND,
-        ScalarFrame, TimelineData, TimelineTime, TransformFrame, Vec2Frame,
+        RetainedTimeline, ScalarFrame, TimelineData, TimelineTime, TransformFrame, Vec2Frame,
         animation_deferred_override_properties, animation_properties, transform_pose_values,
     },
     asset::{IkConstraintData, TransformConstraintData, TransformConstraintPoseData},
@@ -118,6 +118,7 @@ pub(crate) fn parse_animations(
                 if !retain_draw_order_with_unknown_fields(
                     value,
                     &timeline_path,
+                    "drawOrder",
                     animation.name(),
                     animation_index,
                     &mut timelines,
@@ -134,6 +135,7 @@ pub(crate) fn parse_animations(
                 if !retain_draw_order_with_unknown_fields(
                     value,
                     &timeline_path,
+                    "draworder",
                     animation.name(),
                     animation_index,
                     &mut timelines,
@@ -152,6 +154,7 @@ pub(crate) fn parse_animations(
             if !retain_timeline_with_unknown_fields(
                 value,
                 &timeline_path,
+                &["events"],
                 &[
                     "time", "name", "int", "float", "string", "volume", "balance",
                 ],
@@ -177,6 +180,7 @@ pub(crate) fn parse_animations(
             duration = duration.max(maximum_nested_time(section.value()));
             retain_unsupported(
                 section.name(),
+                retained(&[section.name()], section.value()),
                 animation.name(),
                 animation_index,
                 &mut timelines,
@@ -235,6 +239,7 @@ fn parse_bone_timelines(
                 && retain_timeline_with_unknown_fields(
                     timeline.value(),
                     &timeline_path,
+                    &["bones", bone.name(), timeline.name()],
                     known_fields,
                     &format!("bones/{}", timeline.name()),
                     animation_name,
@@ -287,6 +292,7 @@ fn parse_bone_timelines(
                     *duration = (*duration).max(maximum_nested_time(timeline.value()));
                     retain_unsupported(
                         &format!("bones/{unsupported}"),
+                        retained(&["bones", bone.name(), unsupported], timeline.value()),
                         animation_name,
                         animation_index,
                         output,
@@ -335,6 +341,7 @@ fn parse_slot_timelines(
                 && retain_timeline_with_unknown_fields(
                     timeline.value(),
                     &timeline_path,
+                    &["slots", slot.name(), timeline.name()],
                     known_fields,
                     &format!("slots/{}", timeline.name()),
                     animation_name,
@@ -374,6 +381,7 @@ fn parse_slot_timelines(
                     *duration = (*duration).max(maximum_nested_time(timeline.value()));
                     retain_unsupported(
                         &format!("slots/{unsupported}"),
+                        retained(&["slots", slot.name(), unsupported], timeline.value()),
                         animation_name,
                         animation_index,
                         output,
@@ -418,6 +426,7 @@ fn parse_ik_timelines(
         if retain_timeline_with_unknown_fields(
             constraint.value(),
             &constraint_path,
+            &["ik", constraint.name()],
             &[
                 "time",
                 "mix",
@@ -443,6 +452,7 @@ fn parse_ik_timelines(
         if advanced {
             retain_unsupported(
                 "ik/options",
+                retained(&["ik", constraint.name()], constraint.value()),
                 animation_name,
                 animation_index,
                 output,
@@ -490,6 +500,7 @@ fn parse_transform_timelines(
         if retain_timeline_with_unknown_fields(
             constraint.value(),
             &constraint_path,
+            &["transform", constraint.name()],
             &[
                 "time",
                 "mixRotate",
@@ -1198,7 +1209,7 @@ fn validate_curve_x<const CHANNELS: usize>(
     }
 }
 
-fn require_strict_time(
+pub(crate) fn require_strict_time(
     previous: Option<TimelineTime>,
     current: TimelineTime,
     path: &str,
@@ -1214,7 +1225,7 @@ fn require_strict_time(
     }
 }
 
-fn require_nondecreasing_time(
+pub(crate) fn require_nondecreasing_time(
     previous: Option<TimelineTime>,
     current: TimelineTime,
     path: &str,
@@ -1230,14 +1241,25 @@ fn require_nondecreasing_time(
     }
 }
 
+fn retained(path: &[&str], value: &JsonValue) -> RetainedTimeline {
+    RetainedTimeline {
+        path: path.iter().map(|&segment| Box::from(segment)).collect(),
+        value: value.clone(),
+    }
+}
+
 fn retain_unsupported(
     name: &str,
+    source: RetainedTimeline,
     animation_name: &str,
     animation_index: u32,
     output: &mut Vec<TimelineData>,
     pending: &mut PendingDiagnostics,
 ) {
-    output.push(TimelineData::Unsupported { name: name.into() });
+    output.push(TimelineData::Unsupported {
+        name: name.into(),
+        source,
+    });
     pending.push(PendingDiagnostic::degraded(
         DiagnosticCode::UnsupportedTimelineType,
         PendingScope::Animation(animation_index),
@@ -1248,13 +1270,17 @@ fn retain_unsupported(
 #[allow(clippy::too_many_arguments)]
 fn retain_unsupported_with_detail(
     name: &str,
+    source: RetainedTimeline,
     animation_name: &str,
     detail: &str,
     animation_index: u32,
     output: &mut Vec<TimelineData>,
     pending: &mut PendingDiagnostics,
 ) {
-    output.push(TimelineData::Unsupported { name: name.into() });
+    output.push(TimelineData::Unsupported {
+        name: name.into(),
+        source,
+    });
     pending.push(PendingDiagnostic::degraded(
         DiagnosticCode::UnsupportedTimelineType,
         PendingScope::Animation(animation_index),
@@ -1266,6 +1292,7 @@ fn retain_unsupported_with_detail(
 fn retain_timeline_with_unknown_fields(
     value: &JsonValue,
     path: &str,
+    location: &[&str],
     known: &[&str],
     name: &str,
     animation_name: &str,
@@ -1275,14 +1302,15 @@ fn retain_timeline_with_unknown_fields(
     pending: &mut PendingDiagnostics,
 ) -> Result<bool, LoadError> {
     let values = frame_values(value, path)?;
-    for (index, value) in values.iter().enumerate() {
+    for (index, frame_value) in values.iter().enumerate() {
         let frame_path = index_pointer(path, index);
-        let frame = frame_object(value, &frame_path)?;
+        let frame = frame_object(frame_value, &frame_path)?;
         if let Some(unknown) = frame.iter().find(|member| !known.contains(&member.name())) {
-            *duration = (*duration).max(maximum_nested_time(value));
+            *duration = (*duration).max(maximum_nested_time(frame_value));
             let unknown_path = pointer(&frame_path, unknown.name());
             retain_unsupported_with_detail(
                 name,
+                retained(location, value),
                 animation_name,
                 &format!("unknown field {:?} at {unknown_path}", unknown.name()),
                 animation_index,
@@ -1299,6 +1327,7 @@ fn retain_timeline_with_unknown_fields(
 fn retain_draw_order_with_unknown_fields(
     value: &JsonValue,
     path: &str,
+    section: &str,
     animation_name: &str,
     animation_index: u32,
     output: &mut Vec<TimelineData>,
@@ -1308,6 +1337,7 @@ fn retain_draw_order_with_unknown_fields(
     if retain_timeline_with_unknown_fields(
         value,
         path,
+        &[section],
         &["time", "offsets"],
         "drawOrder/options",
         animation_name,
@@ -1337,6 +1367,7 @@ fn retain_draw_order_with_unknown_fields(
                 let unknown_path = pointer(&offset_path, unknown.name());
                 retain_unsupported_with_detail(
                     "drawOrder/offsets",
+                    retained(&[section], value),
                     animation_name,
                     &format!("unknown field {:?} at {unknown_path}", unknown.name()),
                     animation_index,
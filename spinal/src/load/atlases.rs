@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use crate::{
+    DiagnosticCode,
+    atlas::{AtlasIssueKind, AtlasIssueTarget, TextAtlas},
+};
+
+use super::{
+    LoadDocument, LoadError, LoadErrorKind, PendingDiagnostic, PendingDiagnostics, PendingScope,
+    SourceLocation, schema::error,
+};
+
+/// The ordered atlases one skeleton links against.
+///
+/// Pages and regions are numbered across the atlases in order, so the first
+/// atlas keeps the indexes it would have when linked on its own.
+pub(super) struct LinkedAtlases<'a> {
+    atlases: &'a [Arc<TextAtlas>],
+    region_starts: Box<[u32]>,
+}
+
+impl<'a> LinkedAtlases<'a> {
+    /// Numbers the atlas tables and reports each atlas's renderer-profile
+    /// issues against this skeleton.
+    pub(super) fn new(
+        atlases: &'a [Arc<TextAtlas>],
+        pending: &mut PendingDiagnostics,
+    ) -> Result<Self, LoadError> {
+        let mut region_starts = Vec::with_capacity(atlases.len());
+        let mut pages = 0_u32;
+        let mut regions = 0_u32;
+        for atlas in atlases {
+            let page_start = pages;
+            let region_start = regions;
+            pages = append(pages, atlas.pages.len(), "/pages")?;
+            regions = append(regions, atlas.regions.len(), "/regions")?;
+            region_starts.push(region_start);
+
+            for issue in &atlas.issues {
+                let (code, scope) = match (issue.kind(), issue.target()) {
+                    (AtlasIssueKind::PremultipliedAlpha, AtlasIssueTarget::Page(index)) => (
+                        DiagnosticCode::AlphaEncodingMismatch,
+                        PendingScope::AtlasPage(offset(page_start, index, pages)?),
+                    ),
+                    (AtlasIssueKind::UnsupportedPageSetting, AtlasIssueTarget::Page(index)) => (
+                        DiagnosticCode::UnsupportedAtlasSetting,
+                        PendingScope::AtlasPage(offset(page_start, index, pages)?),
+                    ),
+                    (AtlasIssueKind::UnsupportedRotation, AtlasIssueTarget::Region(index)) => (
+                        DiagnosticCode::UnsupportedAtlasRotation,
+                        PendingScope::AtlasRegion(offset(region_start, index, regions)?),
+                    ),
+                    (AtlasIssueKind::PremultipliedAlpha, AtlasIssueTarget::Region(index))
+                    | (AtlasIssueKind::UnsupportedPageSetting, AtlasIssueTarget::Region(index))
+                    | (AtlasIssueKind::UnsupportedRotation, AtlasIssueTarget::Page(index)) => {
+                        return Err(invalid_issue_target(index));
+                    }
+                };
+                pending.push(PendingDiagnostic::degraded(code, scope, issue.message()));
+            }
+        }
+        Ok(Self {
+            atlases,
+            region_starts: region_starts.into_boxed_slice(),
+        })
+    }
+
+    /// Resolves a region attachment's image to an asset-scoped region.
+    pub(super) fn region(
+        &self,
+        lookup_name: &str,
+        attachment_kind: &str,
+        path: &str,
+        attachment: u32,
+        pending: &mut PendingDiagnostics,
+    ) -> Result<u32, LoadError> {
+        let (atlas, local) =
+            self.lookup(lookup_name, attachment_kind, path, attachment, pending)?;
+        Ok(self.region_starts[atlas] + local)
+    }
+
+    /// Resolves a mesh attachment's image, which must have positive packed
+    /// bounds for its UVs to map onto.
+    pub(super) fn mesh_region(
+        &self,
+        lookup_name: &str,
+        attachment_kind: &str,
+        path: &str,
+        attachment: u32,
+        pending: &mut PendingDiagnostics,
+    ) -> Result<u32, LoadError> {
+        let (atlas, local) =
+            self.lookup(lookup_name, attachment_kind, path, attachment, pending)?;
+        let bounds = self.atlases[atlas].regions[local as usize].bounds;
+        if bounds.width() == 0 || bounds.height() == 0 {
+            let message = if self.atlases.len() > 1 {
+                format!(
+                    "{attachment_kind} attachment requires positive packed bounds in atlas {atlas}"
+                )
+            } else {
+                format!("{attachment_kind} attachment requires positive packed bounds")
+            };
+            return Err(LoadError::new(
+                LoadErrorKind::SchemaViolation,
+                message,
+                SourceLocation::for_document(LoadDocument::Atlas)
+                    .with_path(format!("/regions/{local}/bounds")),
+            ));
+        }
+        Ok(self.region_starts[atlas] + local)
+    }
+
+    /// Finds the first atlas with regions of the given name, in link order.
+    ///
+    /// Several such regions in that atlas are ambiguous, as they are when one
+    /// atlas is linked alone. The same name in a later atlas is shadowed and
+    /// reported as a warning on the attachment.
+    fn lookup(
+        &self,
+        lookup_name: &str,
+        attachment_kind: &str,
+        path: &str,
+        attachment: u32,
+        pending: &mut PendingDiagnostics,
+    ) -> Result<(usize, u32), LoadError> {
+        let mut owners = self
+            .atlases
+            .iter()
+            .enumerate()
+            .filter(|(_, atlas)| !atlas.regions_named(lookup_name).is_empty());
+        let Some((atlas, text_atlas)) = owners.next() else {
+            return Err(error(
+                LoadErrorKind::MissingAtlasRegion,
+                path,
+                format!("{attachment_kind} attachment requires atlas region {lookup_name:?}"),
+            ));
+        };
+        let [local] = *text_atlas.regions_named(lookup_name) else {
+            return Err(error(
+                LoadErrorKind::AmbiguousAtlasRegion,
+                path,
+                format!(
+                    "{attachment_kind} attachment {lookup_name:?} matches multiple atlas regions"
+                ),
+            ));
+        };
+        for (shadowed, _) in owners {
+            pending.push(PendingDiagnostic::warning(
+                DiagnosticCode::ShadowedAtlasRegion,
+                PendingScope::Attachment(attachment),
+                format!(
+                    "{attachment_kind} attachment {lookup_name:?} uses atlas {atlas} and ignores \
+                     the region of the same name in atlas {shadowed}"
+                ),
+            ));
+        }
+        Ok((atlas, local))
+    }
+}
+
+fn append(total: u32, len: usize, path: &str) -> Result<u32, LoadError> {
+    u32::try_from(len)
+        .ok()
+        .and_then(|len| total.checked_add(len))
+        .ok_or_else(|| {
+            error(
+                LoadErrorKind::CapacityExceeded,
+                path,
+                "linked atlases contain more entries than asset-scoped IDs can represent",
+            )
+        })
+}
+
+fn offset(start: u32, index: usize, end: u32) -> Result<u32, LoadError> {
+    u32::try_from(index)
+        .ok()
+        .and_then(|index| start.checked_add(index))
+        .filter(|index| *index < end)
+        .ok_or_else(|| invalid_issue_target(index))
+}
+
+fn invalid_issue_target(index: usize) -> LoadError {
+    error(
+        LoadErrorKind::SchemaViolation,
+        "/",
+        format!("atlas parser produced an invalid issue target at index {index}"),
+    )
+}
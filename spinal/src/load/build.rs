@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use glam::Vec2;
 
@@ -7,12 +10,11 @@ use crate::{
     SlotBlendMode, TARGET_SPINE_MAJOR, TARGET_SPINE_MINOR, TARGET_SPINE_VERSION, TransformMix,
     animation::{EventDefinitionData, EventPayload},
     asset::{
-        AssetData, AtlasExtension as AssetAtlasExtension, AtlasPageData, AtlasRegionData,
-        AttachmentData, AttachmentDataKind, BoneData, ConstraintData, IkConstraintData,
+        AssetData, AttachmentData, AttachmentDataKind, BoneData, ConstraintData, IkConstraintData,
         RegionAttachmentData, SkinData, SlotData, TransformConstraintData,
         TransformConstraintPoseData,
     },
-    atlas::{AtlasIssueKind, AtlasIssueTarget, ParsedAtlas, ParsedAtlasPage, ParsedAtlasRegion},
+    atlas::TextAtlas,
     id::AssetKey,
     json::{JsonMember, JsonValue},
     mesh::{MeshAttachmentData, MeshGeometryData},
@@ -22,20 +24,18 @@ use super::{
     LoadDocument, LoadError, LoadErrorKind, PendingDiagnostic, PendingDiagnostics, PendingScope,
     SourceLocation,
     animation::{AnimationLinks, parse_animations},
-    mesh::{
-        PendingLinkedMesh, parse_mesh_geometry, resolve_attachment_atlas_region,
-        resolve_linked_meshes,
-    },
+    atlases::LinkedAtlases,
+    mesh::{PendingLinkedMesh, parse_mesh_geometry, resolve_linked_meshes},
     schema::{
         array, bool_or, colour_or, error, f32_or, finite_f32, i32_value, index_pointer, member,
         nonempty_string, object, optional_nonempty_string, optional_string, pointer,
-        required_member, schema_error, string, u32_or, u32_value, unique_members,
+        required_member, retained_members, schema_error, string, u32_or, u32_value, unique_members,
     },
 };
 
 pub(crate) fn build_asset(
     root: &JsonValue,
-    atlas: ParsedAtlas,
+    atlases: &[Arc<TextAtlas>],
 ) -> Result<(AssetKey, AssetData), LoadError> {
     let root = object(root, "")?;
     unique_members(root, "")?;
@@ -43,15 +43,19 @@ pub(crate) fn build_asset(
 
     let spine_version = parse_version(root, &mut pending)?;
     diagnose_unknown_root_fields(root, &mut pending);
-    let (atlas_pages, atlas_regions, atlas_by_name) = convert_atlas(atlas, &mut pending)?;
+    let retained_metadata = retained_members(
+        object(required_member(root, "skeleton", "")?, "/skeleton")?,
+        &["spine", "version"],
+    );
+    let retained_sections = retained_members(root, ROOT_SECTIONS);
+    let linked_atlases = LinkedAtlases::new(atlases, &mut pending)?;
     let (bones, bone_by_name) = parse_bones(root, &mut pending)?;
     let (slots, slot_by_name) = parse_slots(root, &bone_by_name, &mut pending)?;
     let (skins, attachments, mesh_geometries, _skin_by_name) = parse_skins(
         root,
         &slot_by_name,
         bones.len(),
-        &atlas_by_name,
-        &atlas_regions,
+        &linked_atlases,
         &mut pending,
     )?;
     let (_attachments_by_skin_slot, attachment_names_by_slot) = index_attachments(&attachments)?;
@@ -84,8 +88,6 @@ pub(crate) fn build_asset(
     ensure_capacity(ik_constraints.len(), "/constraints")?;
     ensure_capacity(transform_constraints.len(), "/constraints")?;
     ensure_capacity(constraints.len(), "/constraints")?;
-    ensure_capacity(atlas_pages.len(), "/pages")?;
-    ensure_capacity(atlas_regions.len(), "/regions")?;
     ensure_capacity(events.len(), "/events")?;
 
     let key = AssetKey::try_fresh().ok_or_else(|| {
@@ -113,30 +115,32 @@ pub(crate) fn build_asset(
             ik_constraints,
             transform_constraints,
             constraints,
-            atlas_pages,
-            atlas_regions,
+            atlases: atlases.into(),
             events,
             diagnostics,
+            retained_metadata,
+            retained_sections,
         },
     ))
 }
 
+const ROOT_SECTIONS: &[&str] = &[
+    "skeleton",
+    "bones",
+    "slots",
+    "skins",
+    "constraints",
+    "ik",
+    "transform",
+    "path",
+    "physics",
+    "events",
+    "animations",
+];
+
 fn diagnose_unknown_root_fields(root: &[JsonMember], pending: &mut PendingDiagnostics) {
     for field in root {
-        if matches!(
-            field.name(),
-            "skeleton"
-                | "bones"
-                | "slots"
-                | "skins"
-                | "constraints"
-                | "ik"
-                | "transform"
-                | "path"
-                | "physics"
-                | "events"
-                | "animations"
-        ) {
+        if ROOT_SECTIONS.contains(&field.name()) {
             continue;
         }
         pending.push(PendingDiagnostic::degraded(
@@ -270,108 +274,6 @@ fn parse_version(
     Ok(version.into())
 }
 
-type AtlasLookup = HashMap<Box<str>, Vec<u32>>;
-type AtlasParse = (Box<[AtlasPageData]>, Box<[AtlasRegionData]>, AtlasLookup);
-
-fn convert_atlas(
-    atlas: ParsedAtlas,
-    pending: &mut PendingDiagnostics,
-) -> Result<AtlasParse, LoadError> {
-    ensure_capacity(atlas.pages.len(), "/pages")?;
-    ensure_capacity(atlas.regions.len(), "/regions")?;
-
-    for issue in &atlas.issues {
-        let (code, scope) = match (issue.kind(), issue.target()) {
-            (AtlasIssueKind::PremultipliedAlpha, AtlasIssueTarget::Page(index)) => (
-                DiagnosticCode::AlphaEncodingMismatch,
-                PendingScope::AtlasPage(index_u32(index, "/pages")?),
-            ),
-            (AtlasIssueKind::UnsupportedPageSetting, AtlasIssueTarget::Page(index)) => (
-                DiagnosticCode::UnsupportedAtlasSetting,
-                PendingScope::AtlasPage(index_u32(index, "/pages")?),
-            ),
-            (AtlasIssueKind::UnsupportedRotation, AtlasIssueTarget::Region(index)) => (
-                DiagnosticCode::UnsupportedAtlasRotation,
-                PendingScope::AtlasRegion(index_u32(index, "/regions")?),
-            ),
-            (AtlasIssueKind::PremultipliedAlpha, AtlasIssueTarget::Region(index))
-            | (AtlasIssueKind::UnsupportedPageSetting, AtlasIssueTarget::Region(index))
-            | (AtlasIssueKind::UnsupportedRotation, AtlasIssueTarget::Page(index)) => {
-                return Err(error(
-                    LoadErrorKind::SchemaViolation,
-                    "/",
-                    format!("atlas parser produced an invalid issue target at index {index}"),
-                ));
-            }
-        };
-        pending.push(PendingDiagnostic::degraded(code, scope, issue.message()));
-    }
-
-    let pages = atlas
-        .pages
-        .into_iter()
-        .map(convert_atlas_page)
-        .collect::<Result<Box<_>, _>>()?;
-    let mut lookup = HashMap::<Box<str>, Vec<u32>>::new();
-    let mut regions = Vec::with_capacity(atlas.regions.len());
-    for (index, region) in atlas.regions.into_iter().enumerate() {
-        let index_u32 = index_u32(index, "/regions")?;
-        lookup
-            .entry(region.name.clone())
-            .or_default()
-            .push(index_u32);
-        regions.push(convert_atlas_region(region)?);
-    }
-    Ok((pages, regions.into_boxed_slice(), lookup))
-}
-
-fn convert_atlas_page(page: ParsedAtlasPage) -> Result<AtlasPageData, LoadError> {
-    Ok(AtlasPageData {
-        name: page.name,
-        size: page.size,
-        format: page.format,
-        format_token: page.format_token,
-        min_filter: page.min_filter,
-        min_filter_token: page.min_filter_token,
-        mag_filter: page.mag_filter,
-        mag_filter_token: page.mag_filter_token,
-        wrap: page.wrap,
-        alpha_encoding: page.alpha_encoding,
-        scale: page.scale,
-        regions: index_u32(page.region_range.start, "/pages")?
-            ..index_u32(page.region_range.end, "/pages")?,
-        extensions: page
-            .extensions
-            .into_iter()
-            .map(|extension| AssetAtlasExtension {
-                key: extension.key,
-                value: extension.value,
-            })
-            .collect(),
-    })
-}
-
-fn convert_atlas_region(region: ParsedAtlasRegion) -> Result<AtlasRegionData, LoadError> {
-    Ok(AtlasRegionData {
-        name: region.name,
-        page: index_u32(region.page, "/regions")?,
-        index: region.index,
-        bounds: region.bounds,
-        trim: region.offsets,
-        rotation: region.rotation,
-        split: region.split,
-        pad: region.pad,
-        extensions: region
-            .extensions
-            .into_iter()
-            .map(|extension| AssetAtlasExtension {
-                key: extension.key,
-                value: extension.value,
-            })
-            .collect(),
-    })
-}
-
 type BoneParse = (Box<[BoneData]>, HashMap<Box<str>, u32>);
 
 fn parse_bones(
@@ -518,6 +420,13 @@ fn parse_bones(
             parent,
             length: f32_or(bone, "length", &path, 0.0)?,
             setup_transform,
+            retained: retained_members(
+                bone,
+                &[
+                    "name", "parent", "length", "x", "y", "rotation", "scaleX", "scaleY", "shearX",
+                    "shearY",
+                ],
+            ),
         });
     }
     Ok((bones.into_boxed_slice(), names))
@@ -615,6 +524,7 @@ fn parse_slots(
             colour: colour_or(slot, "color", &path, Rgba8::WHITE)?,
             blend_mode,
             blend_token: blend_token.into(),
+            retained: retained_members(slot, &["name", "bone", "attachment", "color", "blend"]),
         });
     }
     Ok((slots.into_boxed_slice(), names))
@@ -631,8 +541,7 @@ fn parse_skins(
     root: &[JsonMember],
     slots: &HashMap<Box<str>, u32>,
     bone_count: usize,
-    atlas: &AtlasLookup,
-    atlas_regions: &[AtlasRegionData],
+    atlases: &LinkedAtlases<'_>,
     pending: &mut PendingDiagnostics,
 ) -> Result<SkinParse, LoadError> {
     let Some(value) = member(root, "skins", "")? else {
@@ -717,8 +626,7 @@ fn parse_skins(
                         skin_index_u32,
                         slot,
                         bone_count,
-                        atlas,
-                        atlas_regions,
+                        atlases,
                         attachment_index,
                         &mut mesh_geometries,
                         &mut linked_meshes,
@@ -732,6 +640,7 @@ fn parse_skins(
         skins.push(SkinData {
             name: name.into(),
             attachments: start..end,
+            retained: retained_members(skin, &["name", "attachments"]),
         });
     }
     resolve_linked_meshes(&mut attachments, &names, &linked_meshes)?;
@@ -814,8 +723,7 @@ fn parse_attachment(
     skin: u32,
     slot: u32,
     bone_count: usize,
-    atlas: &AtlasLookup,
-    atlas_regions: &[AtlasRegionData],
+    atlases: &LinkedAtlases<'_>,
     attachment_index: u32,
     mesh_geometries: &mut Vec<MeshGeometryData>,
     linked_meshes: &mut Vec<PendingLinkedMesh>,
@@ -879,33 +787,15 @@ fn parse_attachment(
     });
     let sequence = member(attachment, "sequence", path)?;
 
+    let mut regenerated = &["type", "name", "path"][..];
     let kind = match source_type {
         "region" if sequence.is_none() && unknown_region_field.is_none() => {
-            let matches = atlas.get(lookup_name).map_or(&[][..], Vec::as_slice);
-            let atlas_region = match matches {
-                [] => {
-                    return Err(error(
-                        LoadErrorKind::MissingAtlasRegion,
-                        path,
-                        format!("region attachment requires atlas region {lookup_name:?}"),
-                    ));
-                }
-                [index] => *index,
-                _ => {
-                    return Err(error(
-                        LoadErrorKind::AmbiguousAtlasRegion,
-                        path,
-                        format!("region attachment {lookup_name:?} matches multiple atlas regions"),
-                    ));
-                }
-            };
-            if atlas_regions.get(atlas_region as usize).is_none() {
-                return Err(error(
-                    LoadErrorKind::SchemaViolation,
-                    path,
-                    "atlas lookup produced an invalid region index",
-                ));
-            }
+            regenerated = &[
+                "type", "name", "path", "x", "y", "rotation", "scaleX", "scaleY", "width",
+                "height", "color",
+            ];
+            let atlas_region =
+                atlases.region(lookup_name, "region", path, attachment_index, pending)?;
             let width = attachment_pixel_size(attachment, "width", path)?;
             let height = attachment_pixel_size(attachment, "height", path)?;
             let transform = BoneTransform::new(
@@ -950,8 +840,18 @@ fn parse_attachment(
             }
         }
         "mesh" if sequence.is_none() && unknown_mesh_field.is_none() => {
+            regenerated = &[
+                "type",
+                "name",
+                "path",
+                "color",
+                "uvs",
+                "triangles",
+                "vertices",
+                "hull",
+            ];
             let atlas_region =
-                resolve_attachment_atlas_region(atlas, atlas_regions, lookup_name, "mesh", path)?;
+                atlases.mesh_region(lookup_name, "mesh", path, attachment_index, pending)?;
             let geometry = parse_mesh_geometry(attachment, path, bone_count)?;
             let geometry_index = index_u32(mesh_geometries.len(), path)?;
             mesh_geometries.push(geometry);
@@ -964,13 +864,9 @@ fn parse_attachment(
             })
         }
         "linkedmesh" if sequence.is_none() && unknown_linked_mesh_field.is_none() => {
-            let atlas_region = resolve_attachment_atlas_region(
-                atlas,
-                atlas_regions,
-                lookup_name,
-                "linked mesh",
-                path,
-            )?;
+            regenerated = &["type", "name", "path", "color", "skin", "parent", "deform"];
+            let atlas_region =
+                atlases.mesh_region(lookup_name, "linked mesh", path, attachment_index, pending)?;
             let parent = nonempty_string(
                 required_member(attachment, "parent", path)?,
                 &pointer(path, "parent"),
@@ -1049,6 +945,7 @@ fn parse_attachment(
         skin,
         slot,
         kind,
+        retained: retained_members(attachment, regenerated),
     })
 }
 
@@ -1218,6 +1115,14 @@ fn parse_constraints(
             order,
             ik_constraint: None,
             transform_constraint: None,
+            retained: retained_members(
+                constraint,
+                match source_type {
+                    "ik" => IK_REGENERATED,
+                    "transform" => TRANSFORM_REGENERATED,
+                    _ => &["type", "name", "order"],
+                },
+            ),
         });
 
         match source_type {
@@ -1298,6 +1203,42 @@ fn parse_constraints(
     ))
 }
 
+const IK_REGENERATED: &[&str] = &[
+    "type",
+    "name",
+    "order",
+    "bones",
+    "target",
+    "mix",
+    "softness",
+    "bendPositive",
+    "compress",
+    "stretch",
+    "uniform",
+];
+
+const TRANSFORM_REGENERATED: &[&str] = &[
+    "type",
+    "name",
+    "order",
+    "bones",
+    "source",
+    "target",
+    "rotation",
+    "mixRotate",
+    "mixX",
+    "mixY",
+    "mixScaleX",
+    "mixScaleY",
+    "mixShearY",
+    "localSource",
+    "localTarget",
+    "local",
+    "additive",
+    "relative",
+    "clamp",
+];
+
 fn parse_ik_constraint(
     constraint: &[JsonMember],
     name: &str,
@@ -1942,6 +1883,10 @@ fn parse_events(
             name: event.name().into(),
             payload,
             audio: optional_string(data, "audio", &path)?.map(Box::from),
+            retained: retained_members(
+                data,
+                &["int", "float", "string", "volume", "balance", "audio"],
+            ),
         });
     }
     Ok((definitions.into_boxed_slice(), names))
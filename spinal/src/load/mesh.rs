@@ -3,13 +3,13 @@ use std::{collections::HashMap, ops::Range};
 use glam::Vec2;
 
 use crate::{
-    asset::{AtlasRegionData, AttachmentData, AttachmentDataKind},
+    asset::{AttachmentData, AttachmentDataKind},
     json::{JsonMember, JsonValue},
     mesh::{MeshGeometryData, MeshInfluenceData, MeshVerticesData},
 };
 
 use super::{
-    LoadDocument, LoadError, LoadErrorKind, SourceLocation,
+    LoadError, LoadErrorKind,
     schema::{
         array, error, finite_f32, index_pointer, pointer, required_member, schema_error, u32_value,
     },
@@ -23,47 +23,6 @@ pub(super) struct PendingLinkedMesh {
     pub(super) path: Box<str>,
 }
 
-pub(super) fn resolve_attachment_atlas_region(
-    atlas: &HashMap<Box<str>, Vec<u32>>,
-    atlas_regions: &[AtlasRegionData],
-    lookup_name: &str,
-    attachment_kind: &str,
-    path: &str,
-) -> Result<u32, LoadError> {
-    let matches = atlas.get(lookup_name).map_or(&[][..], Vec::as_slice);
-    let atlas_region = match matches {
-        [] => {
-            return Err(error(
-                LoadErrorKind::MissingAtlasRegion,
-                path,
-                format!("{attachment_kind} attachment requires atlas region {lookup_name:?}"),
-            ));
-        }
-        [index] => *index,
-        _ => {
-            return Err(error(
-                LoadErrorKind::AmbiguousAtlasRegion,
-                path,
-                format!(
-                    "{attachment_kind} attachment {lookup_name:?} matches multiple atlas regions"
-                ),
-            ));
-        }
-    };
-    let region = atlas_regions
-        .get(atlas_region as usize)
-        .ok_or_else(|| schema_error(path, "atlas lookup produced an invalid region index"))?;
-    if region.bounds.width() == 0 || region.bounds.height() == 0 {
-        return Err(LoadError::new(
-            LoadErrorKind::SchemaViolation,
-            format!("{attachment_kind} attachment requires positive packed bounds"),
-            SourceLocation::for_document(LoadDocument::Atlas)
-                .with_path(format!("/regions/{atlas_region}/bounds")),
-        ));
-    }
-    Ok(atlas_region)
-}
-
 pub(super) fn parse_mesh_geometry(
     attachment: &[JsonMember],
     path: &str,
@@ -1,8 +1,9 @@
-mod animation;
+pub(crate) mod animation;
+mod atlases;
 mod build;
 pub(crate) mod error;
 mod mesh;
-mod schema;
+pub(crate) mod schema;
 
 pub use error::{LoadDocument, LoadError, LoadErrorKind, SourceLocation};
 
@@ -11,7 +12,7 @@ use std::sync::Arc;
 use crate::{
     AnimationId, AtlasPageId, AtlasRegionId, AttachmentId, BoneId, ConstraintId, Diagnostic,
     DiagnosticCode, DiagnosticScope, DiagnosticSeverity, EventId, IkConstraintId, SkeletonAsset,
-    SkinId, SlotId, id::AssetKey,
+    SkinId, SlotId, TextAtlas, id::AssetKey, json::JsonValue,
 };
 
 /// Loads and links Spine skeleton JSON with its text texture atlas.
@@ -20,8 +21,31 @@ use crate::{
 /// over byte acquisition, page-image resolution, and rendering.
 pub fn load_json(skeleton_json: &[u8], atlas_text: &[u8]) -> Result<LoadReport, LoadError> {
     let root = crate::json::parse_json(skeleton_json)?;
-    let atlas = crate::atlas::parse_atlas(atlas_text)?;
-    let (key, data) = build::build_asset(&root, atlas)?;
+    let atlas = TextAtlas::parse(atlas_text)?;
+    link(&root, &[Arc::new(atlas)])
+}
+
+/// Loads Spine skeleton JSON and links it against an ordered set of atlases.
+///
+/// Each attachment draws the region named by its path from the first atlas
+/// that has one, so listing a skeleton's own atlas before a shared one lets it
+/// replace shared regions. A name found again in a later atlas is reported as
+/// a [`DiagnosticCode::ShadowedAtlasRegion`] warning, and two regions of that
+/// name in the winning atlas are an [`LoadErrorKind::AmbiguousAtlasRegion`]
+/// error. Pages and regions are numbered across the atlases in order.
+///
+/// The linked asset shares each atlas through its `Arc` instead of copying it.
+pub fn load_json_with_atlases(
+    skeleton_json: &[u8],
+    atlases: &[Arc<TextAtlas>],
+) -> Result<LoadReport, LoadError> {
+    let root = crate::json::parse_json(skeleton_json)?;
+    link(&root, atlases)
+}
+
+/// Links a decoded skeleton document with validated atlases.
+pub(crate) fn link(root: &JsonValue, atlases: &[Arc<TextAtlas>]) -> Result<LoadReport, LoadError> {
+    let (key, data) = build::build_asset(root, atlases)?;
     Ok(LoadReport::new(SkeletonAsset::from_data(key, data)))
 }
 
@@ -36,6 +36,15 @@ pub(crate) fn unique_members(members: &[JsonMember], path: &str) -> Result<(), L
     Ok(())
 }
 
+/// Copies the members the JSON writer does not regenerate from typed data.
+pub(crate) fn retained_members(members: &[JsonMember], regenerated: &[&str]) -> Box<[JsonMember]> {
+    members
+        .iter()
+        .filter(|member| !regenerated.contains(&member.name()))
+        .cloned()
+        .collect()
+}
+
 pub(crate) fn member<'a>(
     members: &'a [JsonMember],
     name: &str,
@@ -4,7 +4,7 @@ use glam::Vec2;
 
 use crate::{AtlasRegionId, AttachmentRef, BoneId, Rgba8, SkeletonAsset};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct MeshAttachmentData {
     pub(crate) colour: Rgba8,
     pub(crate) atlas_region: u32,
@@ -13,7 +13,7 @@ pub(crate) struct MeshAttachmentData {
     pub(crate) inherits_deform: bool,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct MeshGeometryData {
     pub(crate) uvs: Box<[Vec2]>,
     pub(crate) triangles: Box<[u32]>,
@@ -21,7 +21,7 @@ pub(crate) struct MeshGeometryData {
     pub(crate) hull: u32,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) enum MeshVerticesData {
     Unweighted(Box<[Vec2]>),
     Weighted {
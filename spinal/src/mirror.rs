@@ -0,0 +1,608 @@
+//! Left/right mirroring of animations about the skeleton's vertical axis.
+
+use std::collections::HashMap;
+
+use crate::{
+    AnimationId, BendDirection, DeriveError, DerivedAsset, DiagnosticCode, SkeletonAsset,
+    animation::{
+        AnimationData, AttachmentFrame, ColourFrame, FrameCurve, IkFrame, ScalarFrame,
+        TimelineData, TimelineTime, TransformFrame, Vec2Frame,
+    },
+    derived::{derive_asset, map_scalar_frames, map_vec2_frames, remap_draw_order},
+    load::{PendingDiagnostic, PendingScope},
+};
+
+const DEFAULT_ANIMATION_SUFFIX: &str = "-mirrored";
+
+/// Left/right pairing rules for [`mirror_animations`].
+///
+/// Each bone, slot and constraint mirrors onto its counterpart: an explicit
+/// pair if one was given, otherwise the name with its side suffix swapped, and
+/// otherwise itself. Attachment placeholders follow the side suffixes when the
+/// mirrored slot has a placeholder of the swapped name.
+#[derive(Clone, Debug)]
+pub struct MirrorOptions {
+    sides: Option<(Box<str>, Box<str>)>,
+    bones: HashMap<Box<str>, Box<str>>,
+    slots: HashMap<Box<str>, Box<str>>,
+    animation_suffix: Box<str>,
+}
+
+impl MirrorOptions {
+    /// Pairs only explicitly listed bones and slots and names mirrored clips
+    /// with a `-mirrored` suffix.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sides: None,
+            bones: HashMap::new(),
+            slots: HashMap::new(),
+            animation_suffix: DEFAULT_ANIMATION_SUFFIX.into(),
+        }
+    }
+
+    /// Pairs every name ending in `left` with the same name ending in
+    /// `right`, such as `_l` and `_r`.
+    #[must_use]
+    pub fn by_suffix(left: &str, right: &str) -> Self {
+        Self {
+            sides: Some((left.into(), right.into())),
+            ..Self::new()
+        }
+    }
+
+    /// Pairs two bones in both directions.
+    #[must_use]
+    pub fn with_bone_pair(mut self, first: &str, second: &str) -> Self {
+        self.bones.insert(first.into(), second.into());
+        self.bones.insert(second.into(), first.into());
+        self
+    }
+
+    /// Pairs two slots in both directions.
+    #[must_use]
+    pub fn with_slot_pair(mut self, first: &str, second: &str) -> Self {
+        self.slots.insert(first.into(), second.into());
+        self.slots.insert(second.into(), first.into());
+        self
+    }
+
+    /// Sets the suffix appended to mirrored clip names.
+    ///
+    /// An empty suffix replaces each source clip with its mirror.
+    #[must_use]
+    pub fn with_animation_suffix(mut self, suffix: &str) -> Self {
+        self.animation_suffix = suffix.into();
+        self
+    }
+
+    /// Returns the suffix appended to mirrored clip names.
+    #[must_use]
+    pub fn animation_suffix(&self) -> &str {
+        &self.animation_suffix
+    }
+
+    /// Returns `name` with its side suffix swapped, if it has one.
+    fn swap_side(&self, name: &str) -> Option<String> {
+        let (left, right) = self.sides.as_ref()?;
+        if let Some(stem) = name.strip_suffix(&**left) {
+            Some(format!("{stem}{right}"))
+        } else {
+            name.strip_suffix(&**right)
+                .map(|stem| format!("{stem}{left}"))
+        }
+    }
+}
+
+impl Default for MirrorOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Mirrors `animations` of `asset` left to right about the skeleton's
+/// vertical axis.
+///
+/// Every bone's mirrored world transform is the reflection of its source
+/// counterpart's, expressed as a proper rotation so attachments keep their
+/// authored art instead of being flipped. Below the root that negates local
+/// rotation, local y-translation and shear; root bones instead negate
+/// x-translation and turn half a revolution. Keys are rewritten relative to
+/// the counterpart's setup pose, and properties whose mirrored setup differs
+/// from the counterpart's gain a constant key, so an asymmetric setup pose
+/// mirrors exactly. IK bend directions flip. Names without a counterpart in
+/// the asset mirror onto themselves and are reported as degraded diagnostics.
+///
+/// The mirrored clips are added to a derived asset under their source name
+/// plus [`MirrorOptions::animation_suffix`].
+pub fn mirror_animations(
+    asset: &SkeletonAsset,
+    animations: impl IntoIterator<Item = AnimationId>,
+    options: &MirrorOptions,
+) -> Result<DerivedAsset, DeriveError> {
+    let mut pairs = Pairs {
+        asset,
+        options,
+        missing: Vec::new(),
+    };
+    let bones = pairs.bones();
+    let slots = pairs.slots();
+    let ik_constraints = pairs.ik_constraints();
+    let transform_constraints = pairs.transform_constraints();
+    let mirror = Mirror {
+        asset,
+        options,
+        bones,
+        slots,
+        ik_constraints,
+        transform_constraints,
+    };
+
+    let mut mirrored = Vec::new();
+    let mut pending = Vec::new();
+    for (position, animation) in animations.into_iter().enumerate() {
+        let source = asset.animation_data(asset.animation_index(animation)?);
+        let scope = PendingScope::Animation(
+            u32::try_from(position)
+                .expect("mirrored animations fit the asset-scoped ID representation"),
+        );
+        for (code, message) in &pairs.missing {
+            pending.push(PendingDiagnostic::degraded(
+                *code,
+                scope,
+                format!("{message} while mirroring animation {:?}", source.name),
+            ));
+        }
+        mirrored.push(mirror.animation(source, scope, &mut pending));
+    }
+    derive_asset(asset, mirrored, pending)
+}
+
+/// Resolves every element's counterpart index once per call.
+struct Pairs<'a> {
+    asset: &'a SkeletonAsset,
+    options: &'a MirrorOptions,
+    missing: Vec<(DiagnosticCode, String)>,
+}
+
+impl<'a> Pairs<'a> {
+    fn bones(&mut self) -> Box<[u32]> {
+        let asset = self.asset;
+        self.resolve(
+            asset.bones().map(|bone| bone.name()),
+            Some(&self.options.bones),
+            |name| asset.bone_id(name).map(|id| id.index()),
+            DiagnosticCode::UnmappedBone,
+            "bone",
+        )
+    }
+
+    fn slots(&mut self) -> Box<[u32]> {
+        let asset = self.asset;
+        self.resolve(
+            asset.slots().map(|slot| slot.name()),
+            Some(&self.options.slots),
+            |name| asset.slot_id(name).map(|id| id.index()),
+            DiagnosticCode::UnmappedSlot,
+            "slot",
+        )
+    }
+
+    fn ik_constraints(&mut self) -> Box<[u32]> {
+        let asset = self.asset;
+        self.resolve(
+            asset.ik_constraints().map(|constraint| constraint.name()),
+            None,
+            |name| asset.ik_constraint_id(name).map(|id| id.index()),
+            DiagnosticCode::UnmappedConstraint,
+            "IK constraint",
+        )
+    }
+
+    fn transform_constraints(&mut self) -> Box<[u32]> {
+        let asset = self.asset;
+        self.resolve(
+            asset
+                .transform_constraints()
+                .map(|constraint| constraint.name()),
+            None,
+            |name| asset.transform_constraint_id(name).map(|id| id.index()),
+            DiagnosticCode::UnmappedConstraint,
+            "transform constraint",
+        )
+    }
+
+    fn resolve(
+        &mut self,
+        names: impl Iterator<Item = &'a str>,
+        explicit: Option<&HashMap<Box<str>, Box<str>>>,
+        lookup: impl Fn(&str) -> Option<u32>,
+        code: DiagnosticCode,
+        kind: &str,
+    ) -> Box<[u32]> {
+        names
+            .enumerate()
+            .map(|(index, name)| {
+                let index = u32::try_from(index)
+                    .expect("asset tables fit the asset-scoped ID representation");
+                let counterpart = explicit
+                    .and_then(|pairs| pairs.get(name))
+                    .map(ToString::to_string)
+                    .or_else(|| self.options.swap_side(name));
+                let Some(counterpart) = counterpart else {
+                    return index;
+                };
+                lookup(&counterpart).unwrap_or_else(|| {
+                    self.missing.push((
+                        code,
+                        format!(
+                            "{kind} {name:?} has no counterpart {counterpart:?} and mirrors onto itself"
+                        ),
+                    ));
+                    index
+                })
+            })
+            .collect()
+    }
+}
+
+struct Mirror<'a> {
+    asset: &'a SkeletonAsset,
+    options: &'a MirrorOptions,
+    bones: Box<[u32]>,
+    slots: Box<[u32]>,
+    ik_constraints: Box<[u32]>,
+    transform_constraints: Box<[u32]>,
+}
+
+impl Mirror<'_> {
+    fn animation(
+        &self,
+        source: &AnimationData,
+        scope: PendingScope,
+        pending: &mut Vec<PendingDiagnostic>,
+    ) -> AnimationData {
+        let mut timelines = Vec::new();
+        for (bone, pair) in self.bones.iter().enumerate() {
+            self.bone(source, bone as u32, *pair, &mut timelines);
+        }
+        for (slot, pair) in self.slots.iter().enumerate() {
+            self.slot(source, slot as u32, *pair, &mut timelines);
+        }
+        for (constraint, pair) in self.ik_constraints.iter().enumerate() {
+            self.ik_constraint(source, constraint as u32, *pair, &mut timelines);
+        }
+        for (constraint, pair) in self.transform_constraints.iter().enumerate() {
+            self.transform_constraint(source, constraint as u32, *pair, &mut timelines);
+        }
+        for timeline in &source.timelines {
+            match timeline {
+                TimelineData::DrawOrder { frames } => {
+                    let slot_count = self.slots.len();
+                    match remap_draw_order(frames, slot_count, |slot| {
+                        Some(self.slots[slot as usize])
+                    }) {
+                        Some(frames) => timelines.push(TimelineData::DrawOrder { frames }),
+                        None => pending.push(PendingDiagnostic::degraded(
+                            DiagnosticCode::UnmappedSlot,
+                            scope,
+                            format!(
+                                "the mirrored draw-order timeline of animation {:?} is not a valid slot order and was dropped",
+                                source.name
+                            ),
+                        )),
+                    }
+                }
+                TimelineData::Events { .. } | TimelineData::Unsupported { .. } => {
+                    timelines.push(timeline.clone());
+                }
+                _ => {}
+            }
+        }
+        AnimationData {
+            name: format!("{}{}", source.name, self.options.animation_suffix).into(),
+            duration: source.duration,
+            timelines: timelines.into_boxed_slice(),
+            properties: Box::default(),
+            deferred_override_properties: Box::default(),
+        }
+    }
+
+    /// Writes `pair`'s timelines from the reflection of `bone`'s pose.
+    fn bone(&self, source: &AnimationData, bone: u32, pair: u32, out: &mut Vec<TimelineData>) {
+        let from = self.asset.bone_data(bone as usize).setup_transform;
+        let to_data = self.asset.bone_data(pair as usize);
+        let to = to_data.setup_transform;
+        let root = to_data.parent.is_none();
+
+        let half_turn = if root { 180.0 } else { 0.0 };
+        let rotation = affine_scalar(
+            source.timelines.iter().find_map(|timeline| match timeline {
+                TimelineData::BoneRotate {
+                    bone: keyed,
+                    frames,
+                } if *keyed == bone => Some(&**frames),
+                _ => None,
+            }),
+            -1.0,
+            half_turn - from.rotation().as_degrees() - to.rotation().as_degrees(),
+            0.0,
+        );
+        if let Some(frames) = rotation {
+            out.push(TimelineData::BoneRotate { bone: pair, frames });
+        }
+
+        let (from_offset, to_offset) = (from.translation(), to.translation());
+        let (sign, offset) = if root {
+            (
+                [-1.0, 1.0],
+                [-from_offset.x - to_offset.x, from_offset.y - to_offset.y],
+            )
+        } else {
+            (
+                [1.0, -1.0],
+                [from_offset.x - to_offset.x, -from_offset.y - to_offset.y],
+            )
+        };
+        let translation = affine_vec2(
+            find_vec2(source, bone, |timeline| match timeline {
+                TimelineData::BoneTranslate { bone, frames } => Some((*bone, &**frames)),
+                _ => None,
+            }),
+            sign,
+            offset,
+            0.0,
+        );
+        if let Some(frames) = translation {
+            out.push(TimelineData::BoneTranslate { bone: pair, frames });
+        }
+
+        let ratio = |from: f32, to: f32| if to == 0.0 { 1.0 } else { from / to };
+        let scale = affine_vec2(
+            find_vec2(source, bone, |timeline| match timeline {
+                TimelineData::BoneScale { bone, frames } => Some((*bone, &**frames)),
+                _ => None,
+            }),
+            [
+                ratio(from.scale().x, to.scale().x),
+                ratio(from.scale().y, to.scale().y),
+            ],
+            [0.0; 2],
+            1.0,
+        );
+        if let Some(frames) = scale {
+            out.push(TimelineData::BoneScale { bone: pair, frames });
+        }
+
+        let shear = affine_vec2(
+            find_vec2(source, bone, |timeline| match timeline {
+                TimelineData::BoneShear { bone, frames } => Some((*bone, &**frames)),
+                _ => None,
+            }),
+            [-1.0; 2],
+            [
+                -from.shear().x().as_degrees() - to.shear().x().as_degrees(),
+                -from.shear().y().as_degrees() - to.shear().y().as_degrees(),
+            ],
+            0.0,
+        );
+        if let Some(frames) = shear {
+            out.push(TimelineData::BoneShear { bone: pair, frames });
+        }
+    }
+
+    fn slot(&self, source: &AnimationData, slot: u32, pair: u32, out: &mut Vec<TimelineData>) {
+        let from = self.asset.slot_data(slot as usize);
+        let to = self.asset.slot_data(pair as usize);
+
+        let colour = source.timelines.iter().find_map(|timeline| match timeline {
+            TimelineData::SlotColour {
+                slot: keyed,
+                frames,
+            } if *keyed == slot => Some(frames.clone()),
+            _ => None,
+        });
+        let hold = (from.colour != to.colour).then_some(ColourFrame {
+            time: TimelineTime::ZERO,
+            colour: from.colour,
+            curve: FrameCurve::Stepped,
+        });
+        if let Some(frames) = hold_setup(colour, hold, |frame| frame.time) {
+            out.push(TimelineData::SlotColour { slot: pair, frames });
+        }
+
+        let attachment = source.timelines.iter().find_map(|timeline| match timeline {
+            TimelineData::SlotAttachment {
+                slot: keyed,
+                frames,
+            } if *keyed == slot => Some(
+                frames
+                    .iter()
+                    .map(|frame| AttachmentFrame {
+                        time: frame.time,
+                        placeholder_name: frame
+                            .placeholder_name
+                            .as_deref()
+                            .map(|name| self.placeholder(pair, name)),
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        });
+        let mirrored_setup = from
+            .setup_attachment_name
+            .as_deref()
+            .map(|name| self.placeholder(pair, name));
+        let hold = (mirrored_setup != to.setup_attachment_name).then_some(AttachmentFrame {
+            time: TimelineTime::ZERO,
+            placeholder_name: mirrored_setup,
+        });
+        if let Some(frames) = hold_setup(attachment, hold, |frame| frame.time) {
+            out.push(TimelineData::SlotAttachment { slot: pair, frames });
+        }
+    }
+
+    fn ik_constraint(
+        &self,
+        source: &AnimationData,
+        constraint: u32,
+        pair: u32,
+        out: &mut Vec<TimelineData>,
+    ) {
+        let from = self.asset.ik_constraint_data(constraint as usize);
+        let to = self.asset.ik_constraint_data(pair as usize);
+        let keyed = source.timelines.iter().find_map(|timeline| match timeline {
+            TimelineData::Ik {
+                constraint: keyed,
+                frames,
+            } if *keyed == constraint => Some(
+                frames
+                    .iter()
+                    .map(|frame| IkFrame {
+                        bend_direction: flip(frame.bend_direction),
+                        ..frame.clone()
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        });
+        let mirrored_bend = flip(from.bend_direction);
+        let hold = (from.mix != to.mix || mirrored_bend != to.bend_direction).then_some(IkFrame {
+            time: TimelineTime::ZERO,
+            mix: from.mix,
+            bend_direction: mirrored_bend,
+            curve: FrameCurve::Stepped,
+        });
+        if let Some(frames) = hold_setup(keyed, hold, |frame| frame.time) {
+            out.push(TimelineData::Ik {
+                constraint: pair,
+                frames,
+            });
+        }
+    }
+
+    fn transform_constraint(
+        &self,
+        source: &AnimationData,
+        constraint: u32,
+        pair: u32,
+        out: &mut Vec<TimelineData>,
+    ) {
+        let from = self.asset.transform_constraint_data(constraint as usize);
+        let to = self.asset.transform_constraint_data(pair as usize);
+        let keyed = source.timelines.iter().find_map(|timeline| match timeline {
+            TimelineData::Transform {
+                constraint: keyed,
+                frames,
+            } if *keyed == constraint => Some(frames.clone()),
+            _ => None,
+        });
+        let hold = (from.setup_pose != to.setup_pose).then_some(TransformFrame {
+            time: TimelineTime::ZERO,
+            pose: from.setup_pose,
+            curve: FrameCurve::Stepped,
+        });
+        if let Some(frames) = hold_setup(keyed, hold, |frame| frame.time) {
+            out.push(TimelineData::Transform {
+                constraint: pair,
+                frames,
+            });
+        }
+    }
+
+    /// Returns the side-swapped placeholder name when `slot` has one.
+    fn placeholder(&self, slot: u32, name: &str) -> Box<str> {
+        self.options
+            .swap_side(name)
+            .filter(|swapped| {
+                self.asset
+                    .attachment_placeholder_index(slot, swapped)
+                    .is_some()
+            })
+            .map_or_else(|| name.into(), String::into_boxed_str)
+    }
+}
+
+const fn flip(direction: BendDirection) -> BendDirection {
+    match direction {
+        BendDirection::Positive => BendDirection::Negative,
+        BendDirection::Negative => BendDirection::Positive,
+    }
+}
+
+fn find_vec2<'s>(
+    source: &'s AnimationData,
+    bone: u32,
+    pick: impl Fn(&'s TimelineData) -> Option<(u32, &'s [Vec2Frame])>,
+) -> Option<&'s [Vec2Frame]> {
+    source
+        .timelines
+        .iter()
+        .filter_map(pick)
+        .find_map(|(keyed, frames)| (keyed == bone).then_some(frames))
+}
+
+/// Maps a keyed scalar timeline, or the unkeyed setup offset `neutral`,
+/// through `value * scale + offset`.
+fn affine_scalar(
+    frames: Option<&[ScalarFrame]>,
+    scale: f32,
+    offset: f32,
+    neutral: f32,
+) -> Option<Box<[ScalarFrame]>> {
+    let value = neutral * scale + offset;
+    let hold = (value != neutral).then_some(ScalarFrame {
+        time: TimelineTime::ZERO,
+        value,
+        curve: FrameCurve::Stepped,
+    });
+    hold_setup(
+        frames.map(|frames| map_scalar_frames(frames, scale, offset)),
+        hold,
+        |frame| frame.time,
+    )
+}
+
+/// Maps a keyed two-channel timeline, or the unkeyed setup offset
+/// `neutral`, through `value * scale + offset` per channel.
+fn affine_vec2(
+    frames: Option<&[Vec2Frame]>,
+    scale: [f32; 2],
+    offset: [f32; 2],
+    neutral: f32,
+) -> Option<Box<[Vec2Frame]>> {
+    let x = neutral * scale[0] + offset[0];
+    let y = neutral * scale[1] + offset[1];
+    let hold = (x != neutral || y != neutral).then_some(Vec2Frame {
+        time: TimelineTime::ZERO,
+        x,
+        y,
+        curve: FrameCurve::Stepped,
+    });
+    hold_setup(
+        frames.map(|frames| map_vec2_frames(frames, scale, offset)),
+        hold,
+        |frame| frame.time,
+    )
+}
+
+/// Prepends `hold` to keys that start after time zero, so the mirrored
+/// property leaves the counterpart's setup pose before its first key; uses
+/// `hold` alone for unkeyed properties.
+fn hold_setup<T>(
+    keyed: Option<Box<[T]>>,
+    hold: Option<T>,
+    time: impl Fn(&T) -> TimelineTime,
+) -> Option<Box<[T]>> {
+    match (keyed, hold) {
+        (None, hold) => hold.map(|hold| Box::new([hold]) as Box<[T]>),
+        (Some(keyed), Some(hold))
+            if keyed
+                .first()
+                .is_some_and(|first| time(first) > TimelineTime::ZERO) =>
+        {
+            Some(std::iter::once(hold).chain(keyed).collect())
+        }
+        (Some(keyed), _) => Some(keyed),
+    }
+}
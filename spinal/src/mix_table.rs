@@ -0,0 +1,214 @@
+//! Default crossfades chosen by animation pair.
+
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::{
+    AnimationId, Crossfade, IdError, MixCurve, PlayOptions, SkeletonAsset, Transition, id::AssetKey,
+};
+
+/// Default crossfade durations keyed by ordered animation pairs.
+///
+/// A table is attached to an [`crate::AnimationPlayer`] or one mixer track and
+/// chooses the transition for every play request whose [`crate::PlayOptions`]
+/// carries no explicit transition. Pair lookups allocate nothing; editing the
+/// table may.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MixTable {
+    asset: AssetKey,
+    default_duration: Duration,
+    curve: MixCurve,
+    // Sorted by `(from, to)` animation index for binary search.
+    pairs: Vec<MixPair>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct MixPair {
+    from: u32,
+    to: u32,
+    duration: Duration,
+}
+
+impl MixTable {
+    /// Creates an empty table whose default duration is zero.
+    ///
+    /// A zero-duration mix is an immediate switch, so an empty table behaves
+    /// exactly like having no table.
+    #[must_use]
+    pub fn new(asset: &SkeletonAsset) -> Self {
+        Self {
+            asset: asset.key(),
+            default_duration: Duration::ZERO,
+            curve: MixCurve::Linear,
+            pairs: Vec::new(),
+        }
+    }
+
+    /// Builds a table from stable animation names.
+    ///
+    /// Later entries for the same ordered pair replace earlier ones.
+    pub fn from_names<'n, I>(
+        asset: &SkeletonAsset,
+        default_duration: Duration,
+        curve: MixCurve,
+        pairs: I,
+    ) -> Result<Self, MixTableError>
+    where
+        I: IntoIterator<Item = (&'n str, &'n str, Duration)>,
+    {
+        let mut table = Self::new(asset)
+            .with_default_duration(default_duration)
+            .with_curve(curve);
+        for (from, to, duration) in pairs {
+            let resolve = |name: &str| {
+                asset
+                    .animation_id(name)
+                    .ok_or_else(|| MixTableError::UnknownAnimation(name.into()))
+            };
+            table
+                .set_mix(resolve(from)?, resolve(to)?, duration)
+                .expect("names resolved against the table asset produce local IDs");
+        }
+        Ok(table)
+    }
+
+    /// Replaces the duration used by pairs without an override.
+    #[must_use]
+    pub const fn with_default_duration(mut self, duration: Duration) -> Self {
+        self.default_duration = duration;
+        self
+    }
+
+    /// Replaces the interpolation used by every crossfade from this table.
+    #[must_use]
+    pub const fn with_curve(mut self, curve: MixCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Inserts or replaces the duration used from `from` to `to`.
+    pub fn set_mix(
+        &mut self,
+        from: AnimationId,
+        to: AnimationId,
+        duration: Duration,
+    ) -> Result<(), IdError> {
+        let pair = MixPair {
+            from: self.local_index(from)?,
+            to: self.local_index(to)?,
+            duration,
+        };
+        match self.search(pair.from, pair.to) {
+            Ok(index) => self.pairs[index] = pair,
+            Err(index) => self.pairs.insert(index, pair),
+        }
+        Ok(())
+    }
+
+    /// Removes one pair override and returns its duration.
+    pub fn remove_mix(
+        &mut self,
+        from: AnimationId,
+        to: AnimationId,
+    ) -> Result<Option<Duration>, IdError> {
+        let from = self.local_index(from)?;
+        let to = self.local_index(to)?;
+        Ok(self
+            .search(from, to)
+            .ok()
+            .map(|index| self.pairs.remove(index).duration))
+    }
+
+    /// Returns the duration used from `from` to `to`.
+    pub fn mix(&self, from: AnimationId, to: AnimationId) -> Result<Duration, IdError> {
+        let from = self.local_index(from)?;
+        let to = self.local_index(to)?;
+        Ok(self
+            .search(from, to)
+            .map_or(self.default_duration, |index| self.pairs[index].duration))
+    }
+
+    /// Returns the duration used by pairs without an override.
+    #[must_use]
+    pub const fn default_duration(&self) -> Duration {
+        self.default_duration
+    }
+
+    /// Returns the crossfade interpolation.
+    #[must_use]
+    pub const fn curve(&self) -> MixCurve {
+        self.curve
+    }
+
+    /// Returns the number of pair overrides.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns whether the table has no pair overrides.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    pub(crate) fn validate_asset(&self, asset: &SkeletonAsset) -> Result<(), IdError> {
+        if self.asset != asset.key() {
+            return Err(IdError::foreign_asset());
+        }
+        Ok(())
+    }
+
+    /// Chooses the transition into `to`; nothing playing switches immediately.
+    ///
+    /// Both IDs must already be validated against the table asset.
+    pub(crate) fn transition(&self, from: Option<AnimationId>, to: AnimationId) -> Transition {
+        let Some(from) = from else {
+            return Transition::Immediate;
+        };
+        let duration = self
+            .search(from.index(), to.index())
+            .map_or(self.default_duration, |index| self.pairs[index].duration);
+        if duration.is_zero() {
+            return Transition::Immediate;
+        }
+        Transition::Crossfade(Crossfade::new(duration).with_curve(self.curve))
+    }
+
+    fn local_index(&self, animation: AnimationId) -> Result<u32, IdError> {
+        if animation.asset() != self.asset {
+            return Err(IdError::foreign_asset());
+        }
+        Ok(animation.index())
+    }
+
+    fn search(&self, from: u32, to: u32) -> Result<usize, usize> {
+        self.pairs
+            .binary_search_by(|pair| (pair.from, pair.to).cmp(&(from, to)))
+    }
+}
+
+/// Resolves the transition for one play request.
+///
+/// An explicit option always wins; otherwise the attached table, if any,
+/// chooses by pair. Both IDs must already be validated against the table asset.
+pub(crate) fn play_transition(
+    options: PlayOptions,
+    table: Option<&MixTable>,
+    from: Option<AnimationId>,
+    to: AnimationId,
+) -> Transition {
+    options
+        .explicit_transition()
+        .unwrap_or_else(|| table.map_or(Transition::Immediate, |table| table.transition(from, to)))
+}
+
+/// A failure to build a [`MixTable`] from animation names.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum MixTableError {
+    /// A pair names an animation missing from the asset.
+    #[error("animation `{0}` does not exist in the skeleton asset")]
+    UnknownAnimation(Box<str>),
+}
@@ -7,16 +7,22 @@ use std::{
     time::Duration,
 };
 
+use glam::Vec2;
 use thiserror::Error;
 
 use crate::{
-    AnimationEvent, AnimationId, AnimationPlayer, Crossfade, EventSink, Mix, MixCurve, PlayOptions,
-    PlayOutcome, PlaybackId, PlayerError, PlayerStatus, Skeleton, SkeletonAsset, Transition,
-    UpdateReport,
+    AnimationEvent, AnimationId, AnimationPlayer, Crossfade, EventSink, IdError, Mix, MixCurve,
+    MixTable, PlayOptions, PlayOutcome, PlaybackId, PlaybackMode, PlayerError, PlayerStatus,
+    RootMotion, RootMotionDelta, Skeleton, SkeletonAsset, Transition, UpdateReport, float,
     frame::EditablePose,
-    player::{Advance, Playback},
+    mix_table::play_transition,
+    player::{Advance, Playback, PlaybackWindow},
     pose::{AngleBranches, ContributionPose},
+    root_motion::ClipMotion,
+    save_state::{AnimationSaveState, PlaybackSaveState, SaveStateError, TrackSaveState},
     skeleton::SkeletonInstanceKey,
+    snapshot::{MixerSnapshot, SnapshotError},
+    sync_group::{SyncGroup, SyncGroupId, SyncMember, SyncPlan, SyncPolicy, SyncRate, plan},
 };
 
 static NEXT_MIXER_KEY: AtomicU64 = AtomicU64::new(1);
@@ -250,12 +256,15 @@ pub enum TrackErrorKind {
     /// The track was removed or was never created by this mixer.
     #[error("the track identifier is no longer present")]
     Removed,
-    /// The mixer exhausted its nonzero track identity space.
-    #[error("the mixer exhausted its track identity space")]
+    /// The mixer exhausted its nonzero track or sync group identity space.
+    #[error("the mixer exhausted its track or sync group identity space")]
     IdentityExhausted,
     /// The requested priority index is outside the current override-track set.
     #[error("the requested track priority index is out of bounds")]
     OrderOutOfBounds,
+    /// The sync group was removed or was never created by this mixer.
+    #[error("the sync group identifier is no longer present")]
+    SyncGroupRemoved,
 }
 
 /// A failure to resolve or create a mixer track.
@@ -285,7 +294,7 @@ pub struct BaseTrackRef<'a> {
     speed: f32,
 }
 
-impl BaseTrackRef<'_> {
+impl<'a> BaseTrackRef<'a> {
     /// Returns a copyable snapshot of base playback state.
     #[must_use]
     pub fn status(self) -> PlayerStatus {
@@ -303,6 +312,12 @@ impl BaseTrackRef<'_> {
     pub const fn speed(self) -> f32 {
         self.speed
     }
+
+    /// Returns the base track's mix table, if any.
+    #[must_use]
+    pub fn mix_table(self) -> Option<&'a MixTable> {
+        self.player.mix_table()
+    }
 }
 
 /// Mutable command access to the permanent base track.
@@ -314,7 +329,7 @@ pub struct BaseTrackMut<'a> {
 }
 
 impl BaseTrackMut<'_> {
-    /// Replaces the base animation and starts it at time zero.
+    /// Replaces the base animation and starts it at its window start.
     pub fn play(
         &mut self,
         animation: AnimationId,
@@ -337,6 +352,12 @@ impl BaseTrackMut<'_> {
         self.player.seek_to(elapsed)
     }
 
+    /// Releases the base playback's loop range so it runs on to its window
+    /// end.
+    pub fn release_loop(&mut self) -> Option<PlaybackId> {
+        self.player.release_loop()
+    }
+
     /// Stops the base playback and optionally crossfades to setup pose.
     pub fn stop(&mut self, transition: Transition) -> Option<PlaybackId> {
         self.player.stop(transition)
@@ -371,6 +392,18 @@ impl BaseTrackMut<'_> {
     pub const fn speed(&self) -> f32 {
         *self.speed
     }
+
+    /// Attaches or detaches the table consulted by base play requests without
+    /// an explicit transition.
+    pub fn set_mix_table(&mut self, table: Option<Arc<MixTable>>) -> Result<(), IdError> {
+        self.player.set_mix_table(table)
+    }
+
+    /// Returns the base track's mix table, if any.
+    #[must_use]
+    pub fn mix_table(&self) -> Option<&MixTable> {
+        self.player.mix_table()
+    }
 }
 
 /// Immutable observation of one ordered override track.
@@ -379,7 +412,7 @@ pub struct TrackRef<'a> {
     track: &'a OverrideTrack,
 }
 
-impl TrackRef<'_> {
+impl<'a> TrackRef<'a> {
     /// Returns the stable mixer-scoped track identifier.
     #[must_use]
     pub const fn id(self) -> TrackId {
@@ -424,6 +457,12 @@ impl TrackRef<'_> {
     pub const fn speed(self) -> f32 {
         self.track.speed
     }
+
+    /// Returns this track's mix table, if any.
+    #[must_use]
+    pub fn mix_table(self) -> Option<&'a MixTable> {
+        self.track.mix_table.as_deref()
+    }
 }
 
 /// Mutable command access to one ordered override track.
@@ -433,7 +472,7 @@ pub struct TrackMut<'a> {
 }
 
 impl TrackMut<'_> {
-    /// Replaces this track's animation and starts it at time zero.
+    /// Replaces this track's animation and starts it at its window start.
     pub fn play(
         &mut self,
         animation: AnimationId,
@@ -447,6 +486,11 @@ impl TrackMut<'_> {
         self.track.restart()
     }
 
+    /// Releases this track's loop range so it runs on to its window end.
+    pub fn release_loop(&mut self) -> Option<PlaybackId> {
+        self.track.release_loop()
+    }
+
     /// Stops this track and optionally fades out its sparse contribution.
     pub fn stop(&mut self, transition: Transition) -> Option<PlaybackId> {
         self.track.stop(transition)
@@ -498,6 +542,22 @@ impl TrackMut<'_> {
     pub const fn speed(&self) -> f32 {
         self.track.speed
     }
+
+    /// Attaches or detaches the table consulted by this track's play requests
+    /// without an explicit transition.
+    pub fn set_mix_table(&mut self, table: Option<Arc<MixTable>>) -> Result<(), IdError> {
+        if let Some(table) = &table {
+            table.validate_asset(&self.track.asset)?;
+        }
+        self.track.mix_table = table;
+        Ok(())
+    }
+
+    /// Returns this track's mix table, if any.
+    #[must_use]
+    pub fn mix_table(&self) -> Option<&MixTable> {
+        self.track.mix_table.as_deref()
+    }
 }
 
 /// A renderer-independent ordered animation-track mixer.
@@ -518,6 +578,11 @@ pub struct AnimationMixer {
     base_report: UpdateReport,
     tracks: Vec<OverrideTrack>,
     next_track_serial: u64,
+    root_motion: Option<RootMotion>,
+    root_motion_delta: RootMotionDelta,
+    sync_groups: Vec<SyncGroup>,
+    next_sync_serial: u64,
+    base_sync: TrackSync,
 }
 
 impl AnimationMixer {
@@ -540,7 +605,241 @@ impl AnimationMixer {
             base_report: UpdateReport::default(),
             tracks: Vec::new(),
             next_track_serial: 2,
+            root_motion: None,
+            root_motion_delta: RootMotionDelta::ZERO,
+            sync_groups: Vec::new(),
+            next_sync_serial: 1,
+            base_sync: TrackSync::default(),
+        }
+    }
+
+    /// Describes the tracks and the skeleton's skin layers by name for a save
+    /// game.
+    ///
+    /// Active crossfades and weight fades are saved at their presented
+    /// values: the incoming playback and the current weight. Mixer
+    /// configuration, including sync groups and the root-motion bone, is not
+    /// saved.
+    pub fn save_state(&self, skeleton: &Skeleton) -> Result<AnimationSaveState, SaveStateError> {
+        if skeleton.instance_key() != self.instance_key {
+            return Err(SaveStateError::ForeignSkeleton);
         }
+        let playback = |playback: Option<Playback>| {
+            playback.map(|playback| PlaybackSaveState::new(&self.asset, playback))
+        };
+        let skin_layers = skeleton
+            .skin_layers()
+            .map(|skin| {
+                self.asset
+                    .skin(skin)
+                    .expect("skin layers belong to the skeleton's asset")
+                    .name()
+                    .into()
+            })
+            .collect();
+        let base = TrackSaveState::new(
+            playback(self.base.active_playback()),
+            1.0,
+            self.base_paused,
+            self.base_speed,
+        );
+        let tracks = self
+            .tracks
+            .iter()
+            .map(|track| {
+                TrackSaveState::new(
+                    playback(track.active),
+                    track.weight.get(),
+                    track.paused,
+                    track.speed,
+                )
+            })
+            .collect();
+        Ok(AnimationSaveState::new(skin_layers, base, tracks))
+    }
+
+    /// Replaces the tracks and the skeleton's skin layers with a save state,
+    /// resolving names against this mixer's asset.
+    ///
+    /// Every mismatch is reported together and nothing changes unless the
+    /// whole state resolves. On success all override tracks are replaced by
+    /// new tracks, whose IDs are returned from low to high priority; clocks
+    /// resume at their saved positions without re-emitting earlier events.
+    ///
+    /// A save state records each track's playback, weight, pause and speed.
+    /// The remaining per-track configuration stays in place: each new track
+    /// takes over the sync-group membership, group leadership and mix table of
+    /// the previous track at its priority index. A leader without a successor
+    /// leaves its group weighted, and every group adopts the phase of the
+    /// restored clocks. Weight fades in progress are not saved and end. The
+    /// base track keeps its own mix table and group.
+    pub fn restore_save_state(
+        &mut self,
+        skeleton: &mut Skeleton,
+        state: &AnimationSaveState,
+    ) -> Result<Vec<TrackId>, SaveStateError> {
+        if skeleton.instance_key() != self.instance_key {
+            return Err(SaveStateError::ForeignSkeleton);
+        }
+        let resolved = state
+            .resolve(&self.asset)
+            .map_err(SaveStateError::Mismatch)?;
+        let identities = match resolved.tracks.len() {
+            0 => true,
+            count => {
+                self.next_track_serial != 0
+                    && self
+                        .next_track_serial
+                        .checked_add(count as u64 - 1)
+                        .is_some()
+            }
+        };
+        if !identities {
+            return Err(SaveStateError::Track(TrackError::new(
+                TrackErrorKind::IdentityExhausted,
+            )));
+        }
+
+        skeleton
+            .set_skin_layers(&resolved.skin_layers)
+            .expect("resolved skins belong to the mixer's asset");
+        match &resolved.base.playback {
+            Some(playback) => {
+                self.base
+                    .play(
+                        playback.animation,
+                        playback.options.with_transition(Transition::Immediate),
+                    )
+                    .expect("resolved playbacks are valid for the mixer's asset");
+                self.base
+                    .active_playback_mut()
+                    .expect("the base track is playing")
+                    .resume_at(playback.position_ticks, playback.loop_index);
+            }
+            None => {
+                self.base.stop(Transition::Immediate);
+            }
+        }
+        self.base_paused = resolved.base.paused;
+        self.base_speed = resolved.base.speed;
+
+        let mut previous = self
+            .tracks
+            .drain(..)
+            .map(|track| (track.id, track.sync.group, track.mix_table))
+            .collect::<Vec<_>>();
+        let mut ids = Vec::with_capacity(resolved.tracks.len());
+        for saved in &resolved.tracks {
+            let id = self
+                .insert_track(TrackOptions::override_track().with_weight(saved.weight))
+                .expect("track identity capacity was checked");
+            let track = self.tracks.last_mut().expect("the track was just inserted");
+            if let Some(playback) = &saved.playback {
+                track
+                    .play(
+                        playback.animation,
+                        playback.options.with_transition(Transition::Immediate),
+                    )
+                    .expect("resolved playbacks are valid for the mixer's asset");
+                track
+                    .active
+                    .as_mut()
+                    .expect("the track is playing")
+                    .resume_at(playback.position_ticks, playback.loop_index);
+            }
+            track.paused = saved.paused;
+            track.speed = saved.speed;
+            if let Some((_, group, mix_table)) = previous.get_mut(ids.len()) {
+                track.sync.group = *group;
+                track.mix_table = mix_table.take();
+            }
+            ids.push(id);
+        }
+        self.base_sync.aligned = false;
+        for group in &mut self.sync_groups {
+            if let SyncPolicy::Leader(leader) = group.policy
+                && let Some(index) = previous.iter().position(|(id, ..)| *id == leader)
+            {
+                group.policy = ids
+                    .get(index)
+                    .map_or(SyncPolicy::Weighted, |id| SyncPolicy::Leader(*id));
+            }
+            group.phase = None;
+        }
+        Ok(ids)
+    }
+
+    /// Captures the complete mixer state for a later [`Self::restore`].
+    #[must_use]
+    pub fn snapshot(&self) -> MixerSnapshot {
+        MixerSnapshot {
+            mixer: Self {
+                key: self.key,
+                instance_key: self.instance_key,
+                asset: Arc::clone(&self.asset),
+                base_id: self.base_id,
+                base: self.base.duplicate(),
+                base_paused: self.base_paused,
+                base_speed: self.base_speed,
+                base_report: self.base_report,
+                tracks: self.tracks.clone(),
+                next_track_serial: self.next_track_serial,
+                root_motion: self.root_motion,
+                root_motion_delta: self.root_motion_delta,
+                sync_groups: self.sync_groups.clone(),
+                next_sync_serial: self.next_sync_serial,
+                base_sync: self.base_sync,
+            },
+        }
+    }
+
+    /// Captures the complete mixer state into an existing snapshot.
+    ///
+    /// A snapshot previously taken from a mixer of the same asset is
+    /// overwritten in place; it allocates only when this mixer holds more
+    /// tracks or sync groups than the snapshot has room for.
+    pub fn snapshot_into(&self, snapshot: &mut MixerSnapshot) {
+        if Arc::ptr_eq(&snapshot.mixer.asset, &self.asset) {
+            snapshot.mixer.copy_state_from(self);
+        } else {
+            *snapshot = self.snapshot();
+        }
+    }
+
+    /// Returns this mixer to a snapshot of itself.
+    ///
+    /// Tracks inserted or removed since the snapshot are removed or recreated
+    /// with their captured IDs; restoring into the same track count does not
+    /// allocate. Restore the skeleton's own [`crate::SkeletonSnapshot`] from
+    /// the same frame so that re-simulated updates reproduce identical frames.
+    pub fn restore(&mut self, snapshot: &MixerSnapshot) -> Result<(), SnapshotError> {
+        if snapshot.mixer.key != self.key {
+            return Err(SnapshotError::ForeignMixer);
+        }
+        self.copy_state_from(&snapshot.mixer);
+        Ok(())
+    }
+
+    fn copy_state_from(&mut self, source: &Self) {
+        self.key = source.key;
+        self.instance_key = source.instance_key;
+        self.base_id = source.base_id;
+        self.base.copy_state_from(&source.base);
+        self.base_paused = source.base_paused;
+        self.base_speed = source.base_speed;
+        self.base_report = source.base_report;
+        self.tracks.truncate(source.tracks.len());
+        for (track, source) in self.tracks.iter_mut().zip(&source.tracks) {
+            track.copy_from(source);
+        }
+        let copied = self.tracks.len();
+        self.tracks.extend(source.tracks[copied..].iter().cloned());
+        self.next_track_serial = source.next_track_serial;
+        self.root_motion = source.root_motion;
+        self.root_motion_delta = source.root_motion_delta;
+        self.sync_groups.clone_from(&source.sync_groups);
+        self.next_sync_serial = source.next_sync_serial;
+        self.base_sync = source.base_sync;
     }
 
     /// Returns the permanent base track ID.
@@ -708,12 +1007,145 @@ impl AnimationMixer {
         self.active_deferred_properties().next().is_some()
     }
 
+    /// Extracts authored motion from one bone on every update, or stops
+    /// extracting with `None`.
+    ///
+    /// The bone must belong to this mixer's asset. Each override track blends
+    /// its playback's motion over the tracks below it with the same weight and
+    /// influence it applies to the bone's pose.
+    pub fn set_root_motion(&mut self, root_motion: Option<RootMotion>) -> Result<(), IdError> {
+        if let Some(root_motion) = root_motion {
+            root_motion.bone_index(&self.asset)?;
+        }
+        self.root_motion = root_motion;
+        self.root_motion_delta = RootMotionDelta::ZERO;
+        Ok(())
+    }
+
+    /// Returns the root-motion configuration, if any.
+    #[must_use]
+    pub const fn root_motion(&self) -> Option<RootMotion> {
+        self.root_motion
+    }
+
+    /// Returns the root motion extracted by the latest update.
+    #[must_use]
+    pub const fn root_motion_delta(&self) -> RootMotionDelta {
+        self.root_motion_delta
+    }
+
+    /// Creates an empty phase-synchronized track group.
+    ///
+    /// Members of one group share a normalized phase: every update advances
+    /// the phase once according to `policy`, and each member's clock moves by
+    /// that many cycles of its own animation duration. A new playback on a
+    /// member, or a track joining the group, starts at the current group phase
+    /// instead of time zero, so a crossfade from a long walk into a short trot
+    /// keeps feet aligned. Authored events still fire at each clip's local
+    /// times. Zero-duration playbacks keep their own clocks.
+    pub fn insert_sync_group(&mut self, policy: SyncPolicy) -> Result<SyncGroupId, TrackError> {
+        self.validate_sync_policy(policy)?;
+        let serial = NonZeroU64::new(self.next_sync_serial)
+            .ok_or_else(|| TrackError::new(TrackErrorKind::IdentityExhausted))?;
+        self.next_sync_serial = self.next_sync_serial.checked_add(1).unwrap_or(0);
+        let id = SyncGroupId {
+            mixer: self.key,
+            serial,
+        };
+        self.sync_groups.push(SyncGroup::new(id, policy));
+        Ok(id)
+    }
+
+    /// Removes a sync group; its members keep playing on their own clocks.
+    pub fn remove_sync_group(&mut self, id: SyncGroupId) -> Result<(), TrackError> {
+        let index = self.sync_group_index(id)?;
+        self.sync_groups.remove(index);
+        for sync in std::iter::once(&mut self.base_sync)
+            .chain(self.tracks.iter_mut().map(|track| &mut track.sync))
+        {
+            if sync.group == Some(id) {
+                *sync = TrackSync::default();
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces how a sync group advances its phase.
+    ///
+    /// A leader need not be a member yet; until it is an active member the
+    /// group advances as [`SyncPolicy::Weighted`].
+    pub fn set_sync_policy(
+        &mut self,
+        id: SyncGroupId,
+        policy: SyncPolicy,
+    ) -> Result<(), TrackError> {
+        self.validate_sync_policy(policy)?;
+        let index = self.sync_group_index(id)?;
+        self.sync_groups[index].policy = policy;
+        Ok(())
+    }
+
+    /// Returns how a sync group advances its phase.
+    pub fn sync_policy(&self, id: SyncGroupId) -> Result<SyncPolicy, TrackError> {
+        let index = self.sync_group_index(id)?;
+        Ok(self.sync_groups[index].policy)
+    }
+
+    /// Returns a sync group's normalized phase in `[0, 1)` after the latest
+    /// update, or `None` before any member has played.
+    pub fn sync_phase(&self, id: SyncGroupId) -> Result<Option<f32>, TrackError> {
+        let index = self.sync_group_index(id)?;
+        Ok(self.sync_groups[index].phase.map(|phase| phase as f32))
+    }
+
+    /// Moves the base or an override track into a sync group, or out of any
+    /// group with `None`.
+    ///
+    /// A joining track's current playback is realigned to the group phase on
+    /// the next update without emitting the events it skips. A paused or
+    /// zero-speed follower holds its own clock and is realigned the same way
+    /// once it moves again.
+    pub fn set_sync_group(
+        &mut self,
+        track: TrackId,
+        group: Option<SyncGroupId>,
+    ) -> Result<(), TrackError> {
+        if let Some(group) = group {
+            self.sync_group_index(group)?;
+        }
+        let sync = self.track_sync_mut(track)?;
+        if sync.group != group {
+            *sync = TrackSync {
+                group,
+                aligned: false,
+                plan: None,
+            };
+        }
+        Ok(())
+    }
+
+    /// Returns the sync group of the base or an override track.
+    pub fn sync_group(&self, track: TrackId) -> Result<Option<SyncGroupId>, TrackError> {
+        self.validate_track_scope(track)?;
+        if track == self.base_id {
+            return Ok(self.base_sync.group);
+        }
+        self.tracks
+            .iter()
+            .find(|candidate| candidate.id == track)
+            .map(|candidate| candidate.sync.group)
+            .ok_or_else(|| TrackError::new(TrackErrorKind::Removed))
+    }
+
     /// Advances every track and produces one procedurally editable local pose.
     ///
     /// Track clocks are validated before the base pose or any mixer state is
-    /// changed. Override tracks are evaluated in their observable low-to-high
+    /// changed, and sync-group phases are committed only once the base pose
+    /// updates, so a failed update leaves every clock and phase unchanged.
+    /// Override tracks are evaluated in their observable low-to-high
     /// priority order. Playback clocks use scaled time; crossfades and weight
-    /// fades use the unscaled `delta`.
+    /// fades use the unscaled `delta`. Sync group members instead move by
+    /// their group's phase advance.
     pub fn update<'s, S: TrackEventSink + ?Sized>(
         &mut self,
         skeleton: &'s mut Skeleton,
@@ -723,16 +1155,29 @@ impl AnimationMixer {
         if skeleton.instance_key() != self.instance_key {
             return Err(PlayerError::ForeignSkeleton);
         }
-        let base_delta = if self.base.status().playback().is_some() {
-            playback_delta(delta, self.base_paused, self.base_speed)?
-        } else {
-            Duration::ZERO
+        self.plan_sync(delta)?;
+        let base_delta = match self.base_sync.plan {
+            Some(plan) => {
+                AnimationPlayer::validate_clock(&self.asset, plan.start, plan.delta)?;
+                plan.delta
+            }
+            None if self.base.status().playback().is_some() => {
+                let base_delta = playback_delta(delta, self.base_paused, self.base_speed)?;
+                self.base.validate_update_with_time(skeleton, base_delta)?;
+                base_delta
+            }
+            None => Duration::ZERO,
         };
-        self.base.validate_update_with_time(skeleton, base_delta)?;
         for track in &self.tracks {
             track.validate_update(delta)?;
         }
 
+        let base_plan = self.base_sync.plan.take();
+        let unsynced_clock = base_plan.and_then(|plan| {
+            let clock = self.base.active_playback();
+            self.base.set_active_clock(plan.start);
+            clock
+        });
         let base_id = self.base_id;
         let report = {
             let mut base_events = TrackEventAdapter {
@@ -740,13 +1185,162 @@ impl AnimationMixer {
                 sink: events,
             };
             self.base
-                .update_pose_with_time(skeleton, base_delta, delta, &mut base_events)?
+                .update_pose_with_time(skeleton, base_delta, delta, &mut base_events)
+        };
+        let report = match report {
+            Ok(report) => report,
+            Err(error) => {
+                // Nothing has advanced yet, so the planned sync is discarded
+                // and the next update plans from the same clocks.
+                if let Some(clock) = unsynced_clock {
+                    self.base.set_active_clock(clock);
+                }
+                return Err(error);
+            }
         };
+        if base_plan.is_some() {
+            self.base_sync.aligned = true;
+        }
+        for group in &mut self.sync_groups {
+            if let Some(phase) = group.next_phase.take() {
+                group.phase = Some(phase);
+            }
+        }
         self.base_report = report;
         for track in &mut self.tracks {
             track.update(skeleton, delta, events);
         }
-        Ok(EditablePose::new(skeleton, report))
+        if let Some(root_motion) = self.root_motion {
+            let bone = root_motion
+                .bone_index(&self.asset)
+                .expect("root-motion bones are validated when configured");
+            self.root_motion_delta = self.blend_root_motion(bone);
+            if root_motion.zero_in_pose() {
+                skeleton.pin_root_motion_bone(bone);
+            }
+        }
+        Ok(EditablePose::new(skeleton, report, delta))
+    }
+
+    /// Plans every sync group member's clock for this update without
+    /// changing any playback.
+    fn plan_sync(&mut self, delta: Duration) -> Result<(), PlayerError> {
+        self.base_sync.plan = None;
+        for track in &mut self.tracks {
+            track.sync.plan = None;
+        }
+        for group in &mut self.sync_groups {
+            group.next_phase = None;
+        }
+        for index in 0..self.sync_groups.len() {
+            self.plan_sync_group(index, delta)?;
+        }
+        Ok(())
+    }
+
+    fn plan_sync_group(&mut self, index: usize, delta: Duration) -> Result<(), PlayerError> {
+        let group = self.sync_groups[index];
+        let leader = group.leader();
+        let mut rate = SyncRate::default();
+        if let Some(member) = self.base_sync_member(group.id, delta)? {
+            rate.add(member, leader == Some(self.base_id));
+        }
+        for track in &self.tracks {
+            if let Some(member) = track.sync_member(group.id, delta)? {
+                rate.add(member, leader == Some(track.id));
+            }
+        }
+        let (Some(cycles), Some(initial)) = (rate.cycles(), rate.initial_phase()) else {
+            return Ok(());
+        };
+        let phase = group.phase.unwrap_or(initial);
+        let leads = |track: TrackId| rate.has_leader() && leader == Some(track);
+
+        if let Some(member) = self.base_sync_member(group.id, delta)? {
+            let leads = leads(self.base_id);
+            if member.held && !leads {
+                self.base_sync.aligned = false;
+            } else {
+                self.base_sync.plan = Some(
+                    plan(member, self.base_sync.aligned, leads, phase, cycles)
+                        .ok_or(PlayerError::TimeOverflow)?,
+                );
+            }
+        }
+        for track in &mut self.tracks {
+            if let Some(member) = track.sync_member(group.id, delta)? {
+                let leads = leads(track.id);
+                if member.held && !leads {
+                    track.sync.aligned = false;
+                    continue;
+                }
+                track.sync.plan = Some(
+                    plan(member, track.sync.aligned, leads, phase, cycles)
+                        .ok_or(PlayerError::TimeOverflow)?,
+                );
+            }
+        }
+        self.sync_groups[index].next_phase = Some((phase + cycles).fract());
+        Ok(())
+    }
+
+    fn base_sync_member(
+        &self,
+        group: SyncGroupId,
+        delta: Duration,
+    ) -> Result<Option<SyncMember>, PlayerError> {
+        let Some(playback) = self
+            .base
+            .active_playback()
+            .filter(|playback| self.base_sync.group == Some(group) && playback.cycle_ticks() != 0)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(SyncMember {
+            playback,
+            delta: playback_delta(delta, self.base_paused, self.base_speed)?,
+            weight: 1.0,
+            held: self.base_paused || self.base_speed == 0.0,
+        }))
+    }
+
+    fn validate_sync_policy(&self, policy: SyncPolicy) -> Result<(), TrackError> {
+        match policy {
+            SyncPolicy::Leader(track) => self.validate_track_scope(track),
+            SyncPolicy::Weighted => Ok(()),
+        }
+    }
+
+    fn sync_group_index(&self, id: SyncGroupId) -> Result<usize, TrackError> {
+        if id.mixer != self.key {
+            return Err(TrackError::new(TrackErrorKind::ForeignMixer));
+        }
+        self.sync_groups
+            .iter()
+            .position(|group| group.id == id)
+            .ok_or_else(|| TrackError::new(TrackErrorKind::SyncGroupRemoved))
+    }
+
+    fn track_sync_mut(&mut self, track: TrackId) -> Result<&mut TrackSync, TrackError> {
+        self.validate_track_scope(track)?;
+        if track == self.base_id {
+            return Ok(&mut self.base_sync);
+        }
+        self.tracks
+            .iter_mut()
+            .find(|candidate| candidate.id == track)
+            .map(|candidate| &mut candidate.sync)
+            .ok_or_else(|| TrackError::new(TrackErrorKind::Removed))
+    }
+
+    fn blend_root_motion(&self, bone: usize) -> RootMotionDelta {
+        let (motion, mix) = self.base.layer_motion(bone);
+        let mut translation = motion.translation_or_zero() * mix;
+        let mut rotation = motion.rotation_or_zero() * f64::from(mix);
+        for track in &self.tracks {
+            track.blend_root_motion(bone, &mut translation, &mut rotation);
+        }
+        RootMotionDelta::new(translation, rotation)
     }
 
     fn validate_track_scope(&self, id: TrackId) -> Result<(), TrackError> {
@@ -757,7 +1351,16 @@ impl AnimationMixer {
     }
 }
 
-#[derive(Debug)]
+/// One track's sync group membership and the clock planned for it.
+#[derive(Clone, Copy, Debug, Default)]
+struct TrackSync {
+    group: Option<SyncGroupId>,
+    /// Whether the current playback already follows the group phase.
+    aligned: bool,
+    plan: Option<SyncPlan>,
+}
+
+#[derive(Clone, Debug)]
 struct OverrideTrack {
     id: TrackId,
     asset: Arc<SkeletonAsset>,
@@ -775,6 +1378,10 @@ struct OverrideTrack {
     apply_branches: AngleBranches,
     last_report: UpdateReport,
     last_weight_fade_completed: bool,
+    mix_table: Option<Arc<MixTable>>,
+    last_advance: Option<Advance>,
+    last_target_mix: f32,
+    sync: TrackSync,
 }
 
 impl OverrideTrack {
@@ -796,9 +1403,38 @@ impl OverrideTrack {
             apply_branches: AngleBranches::new(asset.bones().len()),
             last_report: UpdateReport::default(),
             last_weight_fade_completed: false,
+            mix_table: None,
+            last_advance: None,
+            last_target_mix: 1.0,
+            sync: TrackSync::default(),
         }
     }
 
+    /// Copies another track's state into this track's preallocated buffers.
+    ///
+    /// The sampled contribution is scratch rebuilt by every update.
+    fn copy_from(&mut self, source: &Self) {
+        self.id = source.id;
+        self.active = source.active;
+        self.transition = source.transition;
+        self.next_playback_id = source.next_playback_id;
+        self.weight = source.weight;
+        self.weight_fade = source.weight_fade;
+        self.paused = source.paused;
+        self.speed = source.speed;
+        self.presented.copy_from(&source.presented);
+        self.transition_source.copy_from(&source.transition_source);
+        self.transition_branches
+            .copy_from(&source.transition_branches);
+        self.apply_branches.copy_from(&source.apply_branches);
+        self.last_report = source.last_report;
+        self.last_weight_fade_completed = source.last_weight_fade_completed;
+        self.mix_table.clone_from(&source.mix_table);
+        self.last_advance = source.last_advance;
+        self.last_target_mix = source.last_target_mix;
+        self.sync = source.sync;
+    }
+
     fn play(
         &mut self,
         animation: AnimationId,
@@ -809,20 +1445,23 @@ impl OverrideTrack {
             .animation_index(animation)
             .map_err(PlayerError::InvalidAnimation)?;
         let duration_ticks = self.asset.animation_data(animation_index).duration.ticks;
+        let window = PlaybackWindow::new(options, duration_ticks)?;
         let playback = self.issue_playback_id();
         let interrupted = self.active.map(|active| active.id);
-        self.begin_transition(options.transition());
-        self.active = Some(Playback {
-            id: playback,
+        let transition = play_transition(
+            options,
+            self.mix_table.as_deref(),
+            self.active.map(|active| active.animation),
+            animation,
+        );
+        self.begin_transition(transition);
+        self.active = Some(Playback::new(
+            playback,
             animation,
             animation_index,
-            mode: options.mode(),
-            duration_ticks,
-            local_ticks: 0,
-            loop_index: 0,
-            pending_start: true,
-            complete: false,
-        });
+            options.mode(),
+            window,
+        ));
         Ok(PlayOutcome::new(playback, interrupted))
     }
 
@@ -830,14 +1469,14 @@ impl OverrideTrack {
         let Some(active) = self.active else {
             return Ok(None);
         };
-        self.play(
-            active.animation,
-            match active.mode {
-                crate::PlaybackMode::Once => PlayOptions::once(),
-                crate::PlaybackMode::Loop => PlayOptions::looping(),
-            },
-        )
-        .map(Some)
+        self.play(active.animation, active.options(Transition::Immediate))
+            .map(Some)
+    }
+
+    fn release_loop(&mut self) -> Option<PlaybackId> {
+        let active = self.active.as_mut()?;
+        active.loop_ticks = None;
+        Some(active.id)
     }
 
     fn stop(&mut self, transition: Transition) -> Option<PlaybackId> {
@@ -856,6 +1495,9 @@ impl OverrideTrack {
     }
 
     fn validate_update(&self, delta: Duration) -> Result<(), PlayerError> {
+        if let Some(plan) = self.sync.plan {
+            return AnimationPlayer::validate_clock(&self.asset, plan.start, plan.delta);
+        }
         let Some(active) = self.active else {
             return Ok(());
         };
@@ -871,13 +1513,23 @@ impl OverrideTrack {
         delta: Duration,
         events: &mut S,
     ) {
-        let advance = self.active.map(|active| {
-            let playback_delta = playback_delta(delta, self.paused, self.speed)
-                .expect("scaled track time was validated before mixer mutation");
-            active
-                .advance(playback_delta)
-                .expect("all track clocks were validated before mixer mutation")
-        });
+        let advance = match self.sync.plan.take() {
+            Some(plan) => {
+                self.sync.aligned = true;
+                Some(
+                    plan.start
+                        .advance(plan.delta)
+                        .expect("all track clocks were validated before mixer mutation"),
+                )
+            }
+            None => self.active.map(|active| {
+                let playback_delta = playback_delta(delta, self.paused, self.speed)
+                    .expect("scaled track time was validated before mixer mutation");
+                active
+                    .advance(playback_delta)
+                    .expect("all track clocks were validated before mixer mutation")
+            }),
+        };
         let next_weight_fade = self.weight_fade.map(|fade| fade.advance(delta));
         self.last_weight_fade_completed =
             next_weight_fade.is_some_and(ActiveWeightFade::is_complete);
@@ -887,11 +1539,13 @@ impl OverrideTrack {
         self.weight_fade = next_weight_fade.filter(|fade| !fade.is_complete());
         let next_transition = self.transition.map(|transition| transition.advance(delta));
         if let Some(advance) = advance {
+            // The clock already holds the exact local time; see the player's
+            // sampling for why clamped sampling is exact.
             skeleton
                 .sample_animation_contribution(
                     advance.next.animation,
                     Duration::from_nanos(advance.next.local_ticks),
-                    advance.next.mode,
+                    PlaybackMode::Once,
                     &mut self.sampled,
                 )
                 .expect("an active track retains an asset-local animation");
@@ -915,6 +1569,8 @@ impl OverrideTrack {
             self.emit_events(advance, events);
         }
         self.active = advance.map(|advance| advance.next);
+        self.last_advance = advance;
+        self.last_target_mix = next_transition.map_or(1.0, |transition| transition.amount().get());
         let transition_completed = next_transition.is_some_and(ContributionTransition::is_complete);
         self.transition = next_transition.filter(|transition| !transition.is_complete());
         self.last_report = UpdateReport::new(
@@ -925,6 +1581,45 @@ impl OverrideTrack {
         );
     }
 
+    fn sync_member(
+        &self,
+        group: SyncGroupId,
+        delta: Duration,
+    ) -> Result<Option<SyncMember>, PlayerError> {
+        let Some(playback) = self
+            .active
+            .filter(|playback| self.sync.group == Some(group) && playback.cycle_ticks() != 0)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(SyncMember {
+            playback,
+            delta: playback_delta(delta, self.paused, self.speed)?,
+            weight: self.weight.get(),
+            held: self.paused || self.speed == 0.0,
+        }))
+    }
+
+    /// Layers this track's latest clip motion over the motion below it, using
+    /// the weight and influence its contribution applied to the bone.
+    fn blend_root_motion(&self, bone: usize, translation: &mut Vec2, rotation: &mut f64) {
+        let weight = self.weight.get();
+        let live = weight * self.last_target_mix;
+        let motion = self
+            .last_advance
+            .map(|advance| ClipMotion::measure(&self.asset, bone, advance))
+            .unwrap_or_default();
+        let contribution = self.presented.bones[bone];
+        if let Some(applied) = contribution.translation {
+            *translation = *translation * (1.0 - weight * applied.influence)
+                + motion.translation_or_zero() * live;
+        }
+        if let Some(applied) = contribution.rotation {
+            *rotation = *rotation * f64::from(1.0 - weight * applied.influence)
+                + motion.rotation_or_zero() * f64::from(live);
+        }
+    }
+
     fn issue_playback_id(&mut self) -> PlaybackId {
         let value = NonZeroU64::new(self.next_playback_id)
             .expect("the track always skips the zero playback ID");
@@ -1003,7 +1698,8 @@ impl ActiveWeightFade {
             (self.elapsed.as_secs_f64() / self.fade.duration().as_secs_f64()) as f32
         };
         let amount = self.fade.curve().apply(linear);
-        Mix::clamped(f64::from(self.source.get()).mul_add(
+        Mix::clamped(float::mul_add(
+            f64::from(self.source.get()),
             1.0 - f64::from(amount),
             f64::from(self.target.get()) * f64::from(amount),
         ) as f32)
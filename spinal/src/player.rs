@@ -1,14 +1,18 @@
 use std::{num::NonZeroU64, sync::Arc, time::Duration};
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
-    AnimationId, Diagnostic, DiagnosticScope, EventDefinitionRef, EventId, IdError, Mix,
-    PlaybackMode, Skeleton, SkeletonAsset,
-    animation::{AnimationData, EventFrame, TimelineData},
+    AnimationId, Diagnostic, DiagnosticScope, EventDefinitionRef, EventId, IdError, Mix, MixTable,
+    PlaybackMode, RootMotion, RootMotionDelta, Skeleton, SkeletonAsset,
+    animation::EventFrame,
     frame::EditablePose,
+    mix_table::play_transition,
     pose::{AngleBranches, BlendSwitches, PoseBuffers},
+    root_motion::ClipMotion,
     skeleton::SkeletonInstanceKey,
+    snapshot::{PlayerSnapshot, SnapshotError},
 };
 
 /// Identifies one invocation of [`AnimationPlayer::play`].
@@ -35,7 +39,9 @@ impl PlaybackId {
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct PlayOptions {
     mode: PlaybackMode,
-    transition: Transition,
+    transition: Option<Transition>,
+    range: Option<(Duration, Duration)>,
+    loop_range: Option<(Duration, Duration)>,
 }
 
 impl PlayOptions {
@@ -44,7 +50,9 @@ impl PlayOptions {
     pub const fn once() -> Self {
         Self {
             mode: PlaybackMode::Once,
-            transition: Transition::Immediate,
+            transition: None,
+            range: None,
+            loop_range: None,
         }
     }
 
@@ -53,14 +61,46 @@ impl PlayOptions {
     pub const fn looping() -> Self {
         Self {
             mode: PlaybackMode::Loop,
-            transition: Transition::Immediate,
+            transition: None,
+            range: None,
+            loop_range: None,
         }
     }
 
     /// Replaces the transition used to enter this playback.
+    ///
+    /// An explicit transition, including [`Transition::Immediate`], bypasses
+    /// any attached [`crate::MixTable`].
     #[must_use]
     pub const fn with_transition(mut self, transition: Transition) -> Self {
-        self.transition = transition;
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Plays only the animation-local window from `start` to `end`.
+    ///
+    /// Playback starts at `start` and its end behavior applies at `end`: once
+    /// playback holds the pose at `end`, and looping playback wraps back to
+    /// `start`. Events keyed exactly at `start` fire when the window begins
+    /// and again on every wrap, just as time-zero events do for the whole
+    /// animation. The window must lie within the animation duration.
+    #[must_use]
+    pub const fn with_range(mut self, start: Duration, end: Duration) -> Self {
+        self.range = Some((start, end));
+        self
+    }
+
+    /// Repeats the animation-local window from `start` to `end` until the
+    /// loop is released.
+    ///
+    /// Playback begins at the start of the playback window, so an intro before
+    /// `start` plays once. Reaching `end` wraps back to `start` whatever the
+    /// mode; after [`AnimationPlayer::release_loop`] the clock continues past
+    /// `end` and the mode applies at the window end, which lets an outro
+    /// follow the loop. The loop must lie within the playback window.
+    #[must_use]
+    pub const fn with_loop_range(mut self, start: Duration, end: Duration) -> Self {
+        self.loop_range = Some((start, end));
         self
     }
 
@@ -70,9 +110,36 @@ impl PlayOptions {
         self.mode
     }
 
-    /// Returns the requested transition.
+    /// Returns the window set by [`Self::with_range`], if any.
+    #[must_use]
+    pub const fn range(self) -> Option<(Duration, Duration)> {
+        self.range
+    }
+
+    /// Returns the loop set by [`Self::with_loop_range`], if any.
+    #[must_use]
+    pub const fn loop_range(self) -> Option<(Duration, Duration)> {
+        self.loop_range
+    }
+
+    /// Returns the requested transition, or immediate when none was set.
+    ///
+    /// This is only what the options request. A play without an explicit
+    /// transition resolves it against the attached [`crate::MixTable`], which
+    /// may crossfade; [`PlayerStatus::transition_mix`] reports the result.
+    #[must_use]
+    pub const fn requested_transition(self) -> Transition {
+        match self.transition {
+            Some(transition) => transition,
+            None => Transition::Immediate,
+        }
+    }
+
+    /// Returns the transition set by [`Self::with_transition`], if any.
+    ///
+    /// `None` lets an attached [`crate::MixTable`] choose the transition.
     #[must_use]
-    pub const fn transition(self) -> Transition {
+    pub const fn explicit_transition(self) -> Option<Transition> {
         self.transition
     }
 }
@@ -162,7 +229,8 @@ impl Crossfade {
 }
 
 /// How angular values choose a path during a crossfade.
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum RotationPath {
     /// Re-evaluates the shortest angular path on every update.
@@ -179,7 +247,8 @@ pub enum RotationPath {
 }
 
 /// Interpolation applied to a crossfade's normalized elapsed time.
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum MixCurve {
     /// Uses normalized elapsed time directly.
@@ -601,6 +670,11 @@ pub struct AnimationPlayer {
     transition_source: PoseBuffers,
     angle_branches: AngleBranches,
     skin_revision: u64,
+    mix_table: Option<Arc<MixTable>>,
+    root_motion: Option<RootMotion>,
+    root_motion_delta: RootMotionDelta,
+    last_advance: Option<Advance>,
+    last_target_mix: f32,
 }
 
 impl AnimationPlayer {
@@ -622,6 +696,11 @@ impl AnimationPlayer {
             transition_source,
             angle_branches: AngleBranches::new(skeleton.asset().bones().len()),
             skin_revision: skeleton.skin_revision(),
+            mix_table: None,
+            root_motion: None,
+            root_motion_delta: RootMotionDelta::ZERO,
+            last_advance: None,
+            last_target_mix: 1.0,
         }
     }
 
@@ -631,12 +710,98 @@ impl AnimationPlayer {
         &self.asset
     }
 
-    /// Replaces the current animation and starts the new playback at time zero.
+    /// Attaches a shared table that chooses transitions for play requests
+    /// without an explicit transition, or detaches it with `None`.
+    ///
+    /// The table must belong to this player's asset. Active transitions are
+    /// unaffected.
+    pub fn set_mix_table(&mut self, table: Option<Arc<MixTable>>) -> Result<(), IdError> {
+        if let Some(table) = &table {
+            table.validate_asset(&self.asset)?;
+        }
+        self.mix_table = table;
+        Ok(())
+    }
+
+    /// Returns the attached mix table, if any.
+    #[must_use]
+    pub fn mix_table(&self) -> Option<&MixTable> {
+        self.mix_table.as_deref()
+    }
+
+    /// Extracts authored motion from one bone on every update, or stops
+    /// extracting with `None`.
+    ///
+    /// The bone must belong to this player's asset. While a crossfade is
+    /// active the delta is scaled by the incoming playback's mix, because the
+    /// outgoing pose is held rather than advanced.
+    pub fn set_root_motion(&mut self, root_motion: Option<RootMotion>) -> Result<(), IdError> {
+        if let Some(root_motion) = root_motion {
+            root_motion.bone_index(&self.asset)?;
+        }
+        self.root_motion = root_motion;
+        self.root_motion_delta = RootMotionDelta::ZERO;
+        Ok(())
+    }
+
+    /// Returns the root-motion configuration, if any.
+    #[must_use]
+    pub const fn root_motion(&self) -> Option<RootMotion> {
+        self.root_motion
+    }
+
+    /// Returns the root motion extracted by the latest update.
+    ///
+    /// Loop boundaries crossed during one update contribute whole cycles, so
+    /// a looping walk keeps moving forward instead of snapping back.
+    #[must_use]
+    pub const fn root_motion_delta(&self) -> RootMotionDelta {
+        self.root_motion_delta
+    }
+
+    /// Captures the complete player state for a later [`Self::restore`].
+    #[must_use]
+    pub fn snapshot(&self) -> PlayerSnapshot {
+        PlayerSnapshot {
+            player: self.duplicate(),
+        }
+    }
+
+    /// Captures the complete player state into an existing snapshot.
+    ///
+    /// A snapshot previously taken from a player of the same asset is
+    /// overwritten in place without allocating.
+    pub fn snapshot_into(&self, snapshot: &mut PlayerSnapshot) {
+        if Arc::ptr_eq(&snapshot.player.asset, &self.asset) {
+            snapshot.player.copy_state_from(self);
+        } else {
+            *snapshot = self.snapshot();
+        }
+    }
+
+    /// Returns this player to a snapshot taken from a player bound to the
+    /// same skeleton instance, without allocating.
+    ///
+    /// Restore the skeleton's own [`crate::SkeletonSnapshot`] from the same
+    /// frame so that re-simulated updates reproduce identical frames.
+    pub fn restore(&mut self, snapshot: &PlayerSnapshot) -> Result<(), SnapshotError> {
+        if snapshot.player.instance_key != self.instance_key {
+            return Err(SnapshotError::ForeignSkeleton);
+        }
+        self.copy_state_from(&snapshot.player);
+        Ok(())
+    }
+
+    /// Replaces the current animation and starts the new playback at time zero,
+    /// or at the start of its [`PlayOptions::with_range`] window.
     ///
     /// Outgoing authored events stop immediately. The next [`Self::update`]
     /// emits the new target's time-zero events and advances its clock by the
     /// supplied delta. Repeated calls before an update retain only the last
     /// requested target and do not emit events for skipped targets.
+    ///
+    /// Options without an explicit transition consult the attached
+    /// [`MixTable`] using the current animation as the source of the pair.
     pub fn play(
         &mut self,
         animation: AnimationId,
@@ -647,21 +812,24 @@ impl AnimationPlayer {
             .animation_index(animation)
             .map_err(PlayerError::InvalidAnimation)?;
         let duration_ticks = self.asset.animation_data(animation_index).duration.ticks;
+        let window = PlaybackWindow::new(options, duration_ticks)?;
         let playback = self.issue_playback_id();
         let interrupted = self.active.map(|active| active.id);
 
-        self.begin_transition(options.transition);
-        self.active = Some(Playback {
-            id: playback,
+        let transition = play_transition(
+            options,
+            self.mix_table.as_deref(),
+            self.active.map(|active| active.animation),
+            animation,
+        );
+        self.begin_transition(transition);
+        self.active = Some(Playback::new(
+            playback,
             animation,
             animation_index,
-            mode: options.mode,
-            duration_ticks,
-            local_ticks: 0,
-            loop_index: 0,
-            pending_start: true,
-            complete: false,
-        });
+            options.mode,
+            window,
+        ));
         self.reset_to_setup = false;
 
         Ok(PlayOutcome {
@@ -675,17 +843,22 @@ impl AnimationPlayer {
         let Some(active) = self.active else {
             return Ok(None);
         };
-        self.play(
-            active.animation,
-            PlayOptions {
-                mode: active.mode,
-                transition: Transition::Immediate,
-            },
-        )
-        .map(Some)
+        self.play(active.animation, active.options(Transition::Immediate))
+            .map(Some)
+    }
+
+    /// Releases the active playback's loop range, if any, so its clock runs
+    /// on past the loop end toward the window end.
+    ///
+    /// Returns the affected playback, or `None` when nothing is playing.
+    pub fn release_loop(&mut self) -> Option<PlaybackId> {
+        let active = self.active.as_mut()?;
+        active.loop_ticks = None;
+        Some(active.id)
     }
 
-    /// Moves the active playback clock to an absolute elapsed time.
+    /// Moves the active playback clock to an elapsed time since its window
+    /// start.
     ///
     /// Once playback clamps at its endpoint. Nonzero looping playback derives
     /// its loop index and local time from the elapsed duration; a zero-duration
@@ -756,7 +929,7 @@ impl AnimationPlayer {
         events: &mut S,
     ) -> Result<EditablePose<'s>, PlayerError> {
         let report = self.update_pose_with_time(skeleton, delta, delta, events)?;
-        Ok(EditablePose::new(skeleton, report))
+        Ok(EditablePose::new(skeleton, report, delta))
     }
 
     pub(crate) fn validate_update_with_time(
@@ -802,11 +975,14 @@ impl AnimationPlayer {
             .map(|transition| transition.advance(transition_delta));
 
         match next_active {
+            // The clock already holds the exact local time, and it only rests
+            // on a wrapping end during a zero-length cycle where the held end
+            // pose is wanted, so clamped sampling is always exact.
             Some(active) => skeleton
                 .sample_animation(
                     active.animation,
                     Duration::from_nanos(active.local_ticks),
-                    active.mode,
+                    PlaybackMode::Once,
                 )
                 .map_err(PlayerError::InvalidAnimation)?,
             None if self.reset_to_setup => skeleton.reset_to_setup_pose(),
@@ -825,6 +1001,22 @@ impl AnimationPlayer {
             transition_completed = transition.is_complete();
         }
 
+        self.last_advance = advance;
+        self.last_target_mix = next_transition.map_or(1.0, |transition| transition.amount().get());
+        if let Some(root_motion) = self.root_motion {
+            let bone = root_motion
+                .bone_index(&self.asset)
+                .expect("root-motion bones are validated when configured");
+            let (motion, mix) = self.layer_motion(bone);
+            self.root_motion_delta = RootMotionDelta::new(
+                motion.translation_or_zero() * mix,
+                motion.rotation_or_zero() * f64::from(mix),
+            );
+            if root_motion.zero_in_pose() {
+                skeleton.pin_root_motion_bone(bone);
+            }
+        }
+
         skeleton.copy_pose_into(&mut self.presented_pose);
 
         if let Some(advance) = advance {
@@ -850,6 +1042,69 @@ impl AnimationPlayer {
         Ok(report)
     }
 
+    pub(crate) const fn active_playback(&self) -> Option<Playback> {
+        self.active
+    }
+
+    pub(crate) fn active_playback_mut(&mut self) -> Option<&mut Playback> {
+        self.active.as_mut()
+    }
+
+    /// Replaces the active clock with a validated, realigned copy of itself.
+    pub(crate) fn set_active_clock(&mut self, playback: Playback) {
+        debug_assert!(self.active.is_some_and(|active| active.id == playback.id));
+        self.active = Some(playback);
+    }
+
+    /// Returns the latest clip motion of `bone` and the mix its playback had
+    /// over the held crossfade source.
+    pub(crate) fn layer_motion(&self, bone: usize) -> (ClipMotion, f32) {
+        let motion = self
+            .last_advance
+            .map(|advance| ClipMotion::measure(&self.asset, bone, advance))
+            .unwrap_or_default();
+        (motion, self.last_target_mix)
+    }
+
+    pub(crate) fn duplicate(&self) -> Self {
+        Self {
+            instance_key: self.instance_key,
+            asset: Arc::clone(&self.asset),
+            active: self.active,
+            transition: self.transition,
+            reset_to_setup: self.reset_to_setup,
+            next_playback_id: self.next_playback_id,
+            presented_pose: self.presented_pose.clone(),
+            transition_source: self.transition_source.clone(),
+            angle_branches: self.angle_branches.clone(),
+            skin_revision: self.skin_revision,
+            mix_table: self.mix_table.clone(),
+            root_motion: self.root_motion,
+            root_motion_delta: self.root_motion_delta,
+            last_advance: self.last_advance,
+            last_target_mix: self.last_target_mix,
+        }
+    }
+
+    /// Copies every field of a player of the same asset into preallocated
+    /// buffers.
+    pub(crate) fn copy_state_from(&mut self, source: &Self) {
+        self.instance_key = source.instance_key;
+        self.active = source.active;
+        self.transition = source.transition;
+        self.reset_to_setup = source.reset_to_setup;
+        self.next_playback_id = source.next_playback_id;
+        self.presented_pose.copy_from(&source.presented_pose);
+        self.transition_source.copy_from(&source.transition_source);
+        self.angle_branches.copy_from(&source.angle_branches);
+        self.skin_revision = source.skin_revision;
+        self.mix_table.clone_from(&source.mix_table);
+        self.root_motion = source.root_motion;
+        self.root_motion_delta = source.root_motion_delta;
+        self.last_advance = source.last_advance;
+        self.last_target_mix = source.last_target_mix;
+    }
+
     fn issue_playback_id(&mut self) -> PlaybackId {
         let value = NonZeroU64::new(self.next_playback_id)
             .expect("the player always skips the zero playback ID");
@@ -881,7 +1136,7 @@ impl AnimationPlayer {
         events: &mut S,
     ) {
         let animation = asset.animation_data(advance.next.animation_index);
-        let Some(frames) = event_frames(animation) else {
+        let Some(frames) = animation.event_frames() else {
             return;
         };
         if frames.is_empty() {
@@ -889,39 +1144,25 @@ impl AnimationPlayer {
         }
 
         if advance.previous.pending_start {
-            Self::emit_zero_events(asset, advance.next, frames, 0, events);
-        }
-
-        match advance.next.mode {
-            PlaybackMode::Once => Self::emit_exclusive_inclusive(
+            Self::emit_at(
                 asset,
                 advance.next,
                 frames,
-                advance.previous.local_ticks,
-                advance.next.local_ticks,
+                advance.next.start_ticks,
                 0,
                 events,
-            ),
-            PlaybackMode::Loop if advance.next.duration_ticks == 0 => {}
-            PlaybackMode::Loop if advance.loops_completed == 0 => {
-                Self::emit_exclusive_inclusive(
-                    asset,
-                    advance.next,
-                    frames,
-                    advance.previous.local_ticks,
-                    advance.next.local_ticks,
-                    advance.previous.loop_index,
-                    events,
-                );
-            }
-            PlaybackMode::Loop => {
+            );
+        }
+
+        match advance.next.wrap() {
+            Some((start, end)) if advance.loops_completed > 0 => {
                 let mut cycle = advance.previous.loop_index;
                 Self::emit_exclusive_inclusive(
                     asset,
                     advance.next,
                     frames,
                     advance.previous.local_ticks,
-                    advance.next.duration_ticks,
+                    end,
                     cycle,
                     events,
                 );
@@ -929,33 +1170,51 @@ impl AnimationPlayer {
                 let mut boundaries = advance.loops_completed;
                 while boundaries > 0 {
                     cycle += 1;
-                    Self::emit_zero_events(asset, advance.next, frames, cycle, events);
+                    Self::emit_at(asset, advance.next, frames, start, cycle, events);
                     boundaries -= 1;
                     let upper = if boundaries == 0 {
                         advance.next.local_ticks
                     } else {
-                        advance.next.duration_ticks
+                        end
                     };
                     Self::emit_exclusive_inclusive(
                         asset,
                         advance.next,
                         frames,
-                        0,
+                        start,
                         upper,
                         cycle,
                         events,
                     );
                 }
             }
+            _ => Self::emit_exclusive_inclusive(
+                asset,
+                advance.next,
+                frames,
+                advance.previous.local_ticks,
+                advance.next.local_ticks,
+                advance.previous.loop_index,
+                events,
+            ),
         }
     }
 
+    pub(crate) fn validate_clock(
+        asset: &SkeletonAsset,
+        playback: Playback,
+        delta: Duration,
+    ) -> Result<(), PlayerError> {
+        let advance = playback.advance(delta)?;
+        Self::validate_event_budget(asset, advance)
+    }
+
     pub(crate) fn validate_event_budget(
         asset: &SkeletonAsset,
         advance: Advance,
     ) -> Result<(), PlayerError> {
         let animation = asset.animation_data(advance.next.animation_index);
-        let Some(frames) = event_frames(animation) else {
+        let Some(frames) = animation.event_frames() else {
             return Ok(());
         };
         let count = Self::event_count(advance, frames);
@@ -971,42 +1230,44 @@ impl AnimationPlayer {
         if frames.is_empty() {
             return 0;
         }
-        let zero = frames.partition_point(|frame| frame.time.ticks == 0) as u128;
-        let mut count = if advance.previous.pending_start {
-            zero
+        let count = if advance.previous.pending_start {
+            Self::event_at_count(frames, advance.next.start_ticks)
         } else {
             0
         };
 
-        match advance.next.mode {
-            PlaybackMode::Once => count.saturating_add(Self::event_range_count(
-                frames,
-                advance.previous.local_ticks,
-                advance.next.local_ticks,
-            )),
-            PlaybackMode::Loop if advance.next.duration_ticks == 0 => count,
-            PlaybackMode::Loop if advance.loops_completed == 0 => {
-                count.saturating_add(Self::event_range_count(
+        match advance.next.wrap() {
+            Some((start, end)) if advance.loops_completed > 0 => {
+                let mut count = count.saturating_add(Self::event_range_count(
                     frames,
                     advance.previous.local_ticks,
+                    end,
+                ));
+                let at_start = Self::event_at_count(frames, start);
+                count = count.saturating_add(at_start.saturating_mul(advance.loops_completed));
+                let complete_cycles = advance.loops_completed.saturating_sub(1);
+                let full_cycle = Self::event_range_count(frames, start, end);
+                count = count.saturating_add(full_cycle.saturating_mul(complete_cycles));
+                count.saturating_add(Self::event_range_count(
+                    frames,
+                    start,
                     advance.next.local_ticks,
                 ))
             }
-            PlaybackMode::Loop => {
-                count = count.saturating_add(Self::event_range_count(
-                    frames,
-                    advance.previous.local_ticks,
-                    advance.next.duration_ticks,
-                ));
-                count = count.saturating_add(zero.saturating_mul(advance.loops_completed));
-                let complete_nonzero_cycles = advance.loops_completed.saturating_sub(1);
-                let full_nonzero = Self::event_range_count(frames, 0, advance.next.duration_ticks);
-                count = count.saturating_add(full_nonzero.saturating_mul(complete_nonzero_cycles));
-                count.saturating_add(Self::event_range_count(frames, 0, advance.next.local_ticks))
-            }
+            _ => count.saturating_add(Self::event_range_count(
+                frames,
+                advance.previous.local_ticks,
+                advance.next.local_ticks,
+            )),
         }
     }
 
+    fn event_at_count(frames: &[EventFrame], ticks: u64) -> u128 {
+        let start = frames.partition_point(|frame| frame.time.ticks < ticks);
+        let end = frames.partition_point(|frame| frame.time.ticks <= ticks);
+        (end - start) as u128
+    }
+
     fn event_range_count(frames: &[EventFrame], lower: u64, upper: u64) -> u128 {
         if upper <= lower {
             return 0;
@@ -1016,15 +1277,17 @@ impl AnimationPlayer {
         (end - start) as u128
     }
 
-    fn emit_zero_events<S: EventSink + ?Sized>(
+    fn emit_at<S: EventSink + ?Sized>(
         asset: &SkeletonAsset,
         playback: Playback,
         frames: &[EventFrame],
+        ticks: u64,
         loop_index: u128,
         events: &mut S,
     ) {
-        let end = frames.partition_point(|frame| frame.time.ticks == 0);
-        for frame in &frames[..end] {
+        let start = frames.partition_point(|frame| frame.time.ticks < ticks);
+        let end = frames.partition_point(|frame| frame.time.ticks <= ticks);
+        for frame in &frames[start..end] {
             Self::emit_event(asset, playback, frame, loop_index, events);
         }
     }
@@ -1080,7 +1343,11 @@ pub(crate) struct Playback {
     pub(crate) animation: AnimationId,
     pub(crate) animation_index: usize,
     pub(crate) mode: PlaybackMode,
-    pub(crate) duration_ticks: u64,
+    /// Animation-local window the clock starts at and ends at.
+    pub(crate) start_ticks: u64,
+    pub(crate) end_ticks: u64,
+    /// An unreleased loop inside the window, which overrides the mode.
+    pub(crate) loop_ticks: Option<(u64, u64)>,
     pub(crate) local_ticks: u64,
     pub(crate) loop_index: u128,
     pub(crate) pending_start: bool,
@@ -1088,51 +1355,116 @@ pub(crate) struct Playback {
 }
 
 impl Playback {
-    fn seek_to(&mut self, elapsed: Duration) {
+    /// Starts a fresh clock at the start of its window.
+    pub(crate) const fn new(
+        id: PlaybackId,
+        animation: AnimationId,
+        animation_index: usize,
+        mode: PlaybackMode,
+        window: PlaybackWindow,
+    ) -> Self {
+        Self {
+            id,
+            animation,
+            animation_index,
+            mode,
+            start_ticks: window.start_ticks,
+            end_ticks: window.end_ticks,
+            loop_ticks: window.loop_ticks,
+            local_ticks: window.start_ticks,
+            loop_index: 0,
+            pending_start: true,
+            complete: false,
+        }
+    }
+
+    /// Moves a fresh clock to a saved position inside its window.
+    pub(crate) fn resume_at(&mut self, local_ticks: u64, loop_index: u128) {
+        self.local_ticks = local_ticks;
+        self.loop_index = loop_index;
         self.pending_start = false;
-        self.loop_index = 0;
-        self.complete = false;
-
-        let elapsed_ticks = elapsed.as_nanos();
-        match self.mode {
-            PlaybackMode::Once => {
-                self.local_ticks =
-                    u64::try_from(elapsed_ticks.min(u128::from(self.duration_ticks)))
-                        .expect("a clamped animation tick fits in u64");
-                self.complete = self.local_ticks == self.duration_ticks;
-            }
-            PlaybackMode::Loop if self.duration_ticks == 0 => {
-                self.local_ticks = 0;
-            }
-            PlaybackMode::Loop => {
-                let duration = u128::from(self.duration_ticks);
-                self.local_ticks = u64::try_from(elapsed_ticks % duration)
-                    .expect("a wrapped animation tick fits in u64");
-                self.loop_index = elapsed_ticks / duration;
-            }
+        self.complete = self.wrap().is_none() && local_ticks == self.end_ticks;
+    }
+
+    /// Returns options that replay this clock's window and unreleased loop.
+    pub(crate) const fn options(self, transition: Transition) -> PlayOptions {
+        PlayOptions {
+            mode: self.mode,
+            transition: Some(transition),
+            range: Some((
+                Duration::from_nanos(self.start_ticks),
+                Duration::from_nanos(self.end_ticks),
+            )),
+            loop_range: match self.loop_ticks {
+                Some((start, end)) => {
+                    Some((Duration::from_nanos(start), Duration::from_nanos(end)))
+                }
+                None => None,
+            },
+        }
+    }
+
+    /// Returns the tick the clock wraps back to and the tick it wraps at, or
+    /// `None` while it clamps at its window end.
+    pub(crate) const fn wrap(self) -> Option<(u64, u64)> {
+        match (self.loop_ticks, self.mode) {
+            (Some(range), _) => Some(range),
+            (None, PlaybackMode::Loop) => Some((self.start_ticks, self.end_ticks)),
+            (None, PlaybackMode::Once) => None,
+        }
+    }
+
+    /// Returns the span one playback cycle covers: the active loop, or the
+    /// whole window when the clock clamps.
+    pub(crate) const fn cycle(self) -> (u64, u64) {
+        match self.wrap() {
+            Some(range) => range,
+            None => (self.start_ticks, self.end_ticks),
         }
     }
 
+    pub(crate) const fn cycle_ticks(self) -> u64 {
+        let (start, end) = self.cycle();
+        end - start
+    }
+
+    fn seek_to(&mut self, elapsed: Duration) {
+        let mut start = *self;
+        start.local_ticks = self.start_ticks;
+        start.loop_index = 0;
+        start.pending_start = false;
+        start.complete = false;
+        *self = start
+            .advance(elapsed)
+            .expect("a loop index counted from zero cannot overflow")
+            .next;
+    }
+
     pub(crate) fn advance(self, delta: Duration) -> Result<Advance, PlayerError> {
         let mut next = self;
         next.pending_start = false;
+        let total = u128::from(self.local_ticks) + delta.as_nanos();
+        let clamped = |end: u64| {
+            u64::try_from(total.min(u128::from(end))).expect("a clamped animation tick fits in u64")
+        };
 
-        let (loops_completed, completed) = match self.mode {
-            PlaybackMode::Once => {
-                let total = u128::from(self.local_ticks) + delta.as_nanos();
-                next.local_ticks = u64::try_from(total.min(u128::from(self.duration_ticks)))
-                    .expect("a clamped animation tick fits in u64");
-                let completed = !self.complete && next.local_ticks == self.duration_ticks;
+        let (loops_completed, completed) = match self.wrap() {
+            None => {
+                next.local_ticks = clamped(self.end_ticks);
+                let completed = !self.complete && next.local_ticks == self.end_ticks;
                 next.complete |= completed;
                 (0, completed)
             }
-            PlaybackMode::Loop if self.duration_ticks == 0 => (0, false),
-            PlaybackMode::Loop => {
-                let total = u128::from(self.local_ticks) + delta.as_nanos();
-                let duration = u128::from(self.duration_ticks);
-                let loops_completed = total / duration;
-                next.local_ticks =
-                    u64::try_from(total % duration).expect("a wrapped animation tick fits in u64");
+            Some((start, end)) if start == end || total < u128::from(end) => {
+                next.local_ticks = clamped(end);
+                (0, false)
+            }
+            Some((start, end)) => {
+                let past = total - u128::from(end);
+                let cycle = u128::from(end - start);
+                let loops_completed = past / cycle + 1;
+                next.local_ticks = start
+                    + u64::try_from(past % cycle).expect("a wrapped animation tick fits in u64");
                 next.loop_index = self
                     .loop_index
                     .checked_add(loops_completed)
@@ -1150,6 +1482,50 @@ impl Playback {
     }
 }
 
+/// The validated ranges of one play request, in animation-local ticks.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PlaybackWindow {
+    start_ticks: u64,
+    end_ticks: u64,
+    loop_ticks: Option<(u64, u64)>,
+}
+
+impl PlaybackWindow {
+    pub(crate) fn new(options: PlayOptions, duration_ticks: u64) -> Result<Self, PlayerError> {
+        let (start_ticks, end_ticks) = match options.range {
+            Some(range) => range_ticks(range, 0, duration_ticks)?,
+            None => (0, duration_ticks),
+        };
+        let loop_ticks = options
+            .loop_range
+            .map(|range| range_ticks(range, start_ticks, end_ticks))
+            .transpose()?;
+        Ok(Self {
+            start_ticks,
+            end_ticks,
+            loop_ticks,
+        })
+    }
+
+    pub(crate) const fn contains(self, ticks: u64) -> bool {
+        self.start_ticks <= ticks && ticks <= self.end_ticks
+    }
+}
+
+fn range_ticks(
+    (start, end): (Duration, Duration),
+    lower: u64,
+    upper: u64,
+) -> Result<(u64, u64), PlayerError> {
+    let start = u64::try_from(start.as_nanos()).map_err(|_| PlayerError::InvalidRange)?;
+    let end = u64::try_from(end.as_nanos()).map_err(|_| PlayerError::InvalidRange)?;
+    if lower <= start && start <= end && end <= upper {
+        Ok((start, end))
+    } else {
+        Err(PlayerError::InvalidRange)
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct Advance {
     pub(crate) previous: Playback,
@@ -1188,16 +1564,6 @@ impl ActiveTransition {
     }
 }
 
-fn event_frames(animation: &AnimationData) -> Option<&[EventFrame]> {
-    animation
-        .timelines
-        .iter()
-        .find_map(|timeline| match timeline {
-            TimelineData::Events { frames } => Some(frames.as_ref()),
-            _ => None,
-        })
-}
-
 /// A failure to apply a player command or update.
 #[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
 #[non_exhaustive]
@@ -1223,6 +1589,10 @@ pub enum PlayerError {
         /// Maximum authored event occurrences accepted by one update.
         limit: u64,
     },
+    /// A requested playback or loop range is reversed or lies outside the
+    /// animation or its playback window.
+    #[error("the requested playback range is reversed or outside the animation")]
+    InvalidRange,
 }
 
 impl PlayerError {
@@ -1231,7 +1601,10 @@ impl PlayerError {
     pub const fn id_error(self) -> Option<IdError> {
         match self {
             Self::InvalidAnimation(error) => Some(error),
-            Self::ForeignSkeleton | Self::TimeOverflow | Self::EventLimitExceeded { .. } => None,
+            Self::ForeignSkeleton
+            | Self::TimeOverflow
+            | Self::EventLimitExceeded { .. }
+            | Self::InvalidRange => None,
         }
     }
 }
@@ -26,7 +26,7 @@ struct BoneAngleBranches {
     shear_y: AngleBranch,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct AngleBranches {
     bones: Box<[BoneAngleBranches]>,
 }
@@ -41,6 +41,10 @@ impl AngleBranches {
     pub(crate) fn reset(&mut self) {
         self.bones.fill(BoneAngleBranches::default());
     }
+
+    pub(crate) fn copy_from(&mut self, source: &Self) {
+        self.bones.copy_from_slice(&source.bones);
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -104,7 +108,7 @@ pub(crate) struct TransformContribution {
     pub(crate) mix_shear_y: Option<WeightedContribution<TransformMix>>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct ContributionPose {
     pub(crate) bones: Box<[BoneContribution]>,
     pub(crate) slots: Box<[SlotContribution]>,
@@ -348,7 +352,7 @@ impl ContributionPose {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct PoseBuffers {
     pub(crate) bones: Box<[BonePose]>,
     pub(crate) slots: Box<[SlotPose]>,
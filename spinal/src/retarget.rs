@@ -0,0 +1,340 @@
+//! Retargeting animations between skeletons that share bone and slot names.
+
+use std::collections::HashMap;
+
+use crate::{
+    AnimationRef, DeriveError, DerivedAsset, DiagnosticCode, SkeletonAsset,
+    animation::{AnimationData, AttachmentFrame, DrawOrderFrame, EventFrame, TimelineData},
+    derived::{derive_asset, map_vec2_frames, remap_draw_order},
+    load::{PendingDiagnostic, PendingScope},
+};
+
+/// How retargeted bone translation keys adapt to the target proportions.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TranslationRetarget {
+    /// Copies translation keys unchanged.
+    Unscaled,
+    /// Scales by the target-to-source length ratio of the bone's parent,
+    /// whose space the translation is expressed in. Root bones use their own
+    /// length.
+    #[default]
+    BoneLength,
+    /// Scales by the target-to-source ratio of the bone's setup offset from
+    /// its parent.
+    SetupOffset,
+}
+
+/// Name mapping and scaling rules for [`retarget_animations`].
+///
+/// Bones and slots map to the target element with the same name unless an
+/// explicit pair overrides them. IK constraints, transform constraints and
+/// events always map by name.
+#[derive(Clone, Debug, Default)]
+pub struct RetargetOptions {
+    bones: HashMap<Box<str>, Box<str>>,
+    slots: HashMap<Box<str>, Box<str>>,
+    translation: TranslationRetarget,
+}
+
+impl RetargetOptions {
+    /// Maps every element by name and scales translation by bone length.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the source bone `source` onto the target bone `target`.
+    #[must_use]
+    pub fn with_bone(mut self, source: &str, target: &str) -> Self {
+        self.bones.insert(source.into(), target.into());
+        self
+    }
+
+    /// Maps the source slot `source` onto the target slot `target`.
+    #[must_use]
+    pub fn with_slot(mut self, source: &str, target: &str) -> Self {
+        self.slots.insert(source.into(), target.into());
+        self
+    }
+
+    /// Sets how translation keys adapt to the target proportions.
+    #[must_use]
+    pub const fn with_translation(mut self, translation: TranslationRetarget) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns how translation keys adapt to the target proportions.
+    #[must_use]
+    pub const fn translation(&self) -> TranslationRetarget {
+        self.translation
+    }
+
+    /// Returns the target bone name for a source bone name.
+    #[must_use]
+    pub fn target_bone<'a>(&'a self, source: &'a str) -> &'a str {
+        self.bones.get(source).map_or(source, AsRef::as_ref)
+    }
+
+    /// Returns the target slot name for a source slot name.
+    #[must_use]
+    pub fn target_slot<'a>(&'a self, source: &'a str) -> &'a str {
+        self.slots.get(source).map_or(source, AsRef::as_ref)
+    }
+}
+
+/// Maps `animations` from their source asset onto `target`.
+///
+/// The result is a new immutable asset with the target's rig and every
+/// target animation, plus one retargeted clip per input that replaces any
+/// target clip of the same name. Rotation, scale and shear keys are offsets
+/// from the setup pose and carry over unchanged; translation keys are scaled
+/// by [`RetargetOptions::translation`]. Timelines and event keys whose
+/// element has no target counterpart are dropped and reported as degraded
+/// diagnostics. Skeletons must be created from the derived asset to play the
+/// retargeted clips.
+pub fn retarget_animations<'a>(
+    target: &SkeletonAsset,
+    animations: impl IntoIterator<Item = AnimationRef<'a>>,
+    options: &RetargetOptions,
+) -> Result<DerivedAsset, DeriveError> {
+    let mut retargeted = Vec::new();
+    let mut pending = Vec::new();
+    for (position, animation) in animations.into_iter().enumerate() {
+        let position = u32::try_from(position)
+            .expect("retargeted animations fit the asset-scoped ID representation");
+        let mut retarget = Retarget {
+            source: animation.asset(),
+            target,
+            options,
+            animation: animation.name(),
+            scope: PendingScope::Animation(position),
+            pending: &mut pending,
+        };
+        retargeted.push(retarget.animation(animation.data()));
+    }
+    derive_asset(target, retargeted, pending)
+}
+
+struct Retarget<'a, 'p> {
+    source: &'a SkeletonAsset,
+    target: &'a SkeletonAsset,
+    options: &'a RetargetOptions,
+    animation: &'a str,
+    scope: PendingScope,
+    pending: &'p mut Vec<PendingDiagnostic>,
+}
+
+impl Retarget<'_, '_> {
+    fn animation(&mut self, source: &AnimationData) -> AnimationData {
+        let timelines = source
+            .timelines
+            .iter()
+            .filter_map(|timeline| self.timeline(timeline))
+            .collect();
+        AnimationData {
+            name: source.name.clone(),
+            duration: source.duration,
+            timelines,
+            properties: Box::default(),
+            deferred_override_properties: Box::default(),
+        }
+    }
+
+    fn timeline(&mut self, timeline: &TimelineData) -> Option<TimelineData> {
+        Some(match timeline {
+            TimelineData::BoneRotate { bone, frames } => TimelineData::BoneRotate {
+                bone: self.bone(*bone)?,
+                frames: frames.clone(),
+            },
+            TimelineData::BoneTranslate { bone, frames } => {
+                let target = self.bone(*bone)?;
+                let scale = self.translation_scale(*bone, target);
+                TimelineData::BoneTranslate {
+                    bone: target,
+                    frames: map_vec2_frames(frames, [scale; 2], [0.0; 2]),
+                }
+            }
+            TimelineData::BoneScale { bone, frames } => TimelineData::BoneScale {
+                bone: self.bone(*bone)?,
+                frames: frames.clone(),
+            },
+            TimelineData::BoneShear { bone, frames } => TimelineData::BoneShear {
+                bone: self.bone(*bone)?,
+                frames: frames.clone(),
+            },
+            TimelineData::SlotAttachment { slot, frames } => {
+                let slot = self.slot(*slot)?;
+                TimelineData::SlotAttachment {
+                    slot,
+                    frames: self.attachment_frames(slot, frames)?,
+                }
+            }
+            TimelineData::SlotColour { slot, frames } => TimelineData::SlotColour {
+                slot: self.slot(*slot)?,
+                frames: frames.clone(),
+            },
+            TimelineData::Ik { constraint, frames } => {
+                let name = &self.source.ik_constraint_data(*constraint as usize).name;
+                let Some(target) = self.target.ik_constraint_id(name) else {
+                    self.unmapped(DiagnosticCode::UnmappedConstraint, "IK constraint", name);
+                    return None;
+                };
+                TimelineData::Ik {
+                    constraint: target.index(),
+                    frames: frames.clone(),
+                }
+            }
+            TimelineData::Transform { constraint, frames } => {
+                let name = &self
+                    .source
+                    .transform_constraint_data(*constraint as usize)
+                    .name;
+                let Some(target) = self.target.transform_constraint_id(name) else {
+                    self.unmapped(
+                        DiagnosticCode::UnmappedConstraint,
+                        "transform constraint",
+                        name,
+                    );
+                    return None;
+                };
+                TimelineData::Transform {
+                    constraint: target.index(),
+                    frames: frames.clone(),
+                }
+            }
+            TimelineData::DrawOrder { frames } => TimelineData::DrawOrder {
+                frames: self.draw_order(frames)?,
+            },
+            TimelineData::Events { frames } => {
+                let frames = frames
+                    .iter()
+                    .filter_map(|frame| self.event(frame))
+                    .collect::<Box<[_]>>();
+                if frames.is_empty() {
+                    return None;
+                }
+                TimelineData::Events { frames }
+            }
+            TimelineData::Unsupported { .. } => timeline.clone(),
+        })
+    }
+
+    fn bone(&mut self, bone: u32) -> Option<u32> {
+        let name = &self.source.bone_data(bone as usize).name;
+        let target = self.options.target_bone(name);
+        let mapped = self.target.bone_id(target).map(|id| id.index());
+        if mapped.is_none() {
+            self.unmapped(DiagnosticCode::UnmappedBone, "bone", name);
+        }
+        mapped
+    }
+
+    fn slot(&mut self, slot: u32) -> Option<u32> {
+        let name = &self.source.slot_data(slot as usize).name;
+        let target = self.options.target_slot(name);
+        let mapped = self.target.slot_id(target).map(|id| id.index());
+        if mapped.is_none() {
+            self.unmapped(DiagnosticCode::UnmappedSlot, "slot", name);
+        }
+        mapped
+    }
+
+    /// Keeps the attachment keys whose placeholder exists on the target
+    /// slot; hiding keys always map.
+    fn attachment_frames(
+        &mut self,
+        target_slot: u32,
+        frames: &[AttachmentFrame],
+    ) -> Option<Box<[AttachmentFrame]>> {
+        let frames = frames
+            .iter()
+            .filter(|frame| match frame.placeholder_name.as_deref() {
+                None => true,
+                Some(name) => {
+                    let mapped = self
+                        .target
+                        .attachment_placeholder_index(target_slot, name)
+                        .is_some();
+                    if !mapped {
+                        self.unmapped(DiagnosticCode::UnmappedAttachment, "attachment", name);
+                    }
+                    mapped
+                }
+            })
+            .cloned()
+            .collect::<Box<[_]>>();
+        (!frames.is_empty()).then_some(frames)
+    }
+
+    fn event(&mut self, frame: &EventFrame) -> Option<EventFrame> {
+        let source = self
+            .source
+            .event_definitions()
+            .nth(frame.event as usize)
+            .expect("linked event keys reference their asset's definitions");
+        let Some(target) = self.target.event_id(source.name()) else {
+            self.unmapped(DiagnosticCode::UnmappedEvent, "event", source.name());
+            return None;
+        };
+        Some(EventFrame {
+            time: frame.time,
+            event: target.index(),
+            payload: frame.payload.clone(),
+        })
+    }
+
+    fn draw_order(&mut self, frames: &[DrawOrderFrame]) -> Option<Box<[DrawOrderFrame]>> {
+        let slot_count = self.target.slots().len();
+        let remapped = remap_draw_order(frames, slot_count, |slot| self.slot(slot));
+        if remapped.is_none() {
+            self.pending.push(PendingDiagnostic::degraded(
+                DiagnosticCode::UnmappedSlot,
+                self.scope,
+                format!(
+                    "the draw-order timeline of animation {:?} does not fit the target slot \
+                     order and was dropped",
+                    self.animation
+                ),
+            ));
+        }
+        remapped
+    }
+
+    fn translation_scale(&self, source: u32, target: u32) -> f32 {
+        let measure = |asset: &SkeletonAsset, bone: u32| {
+            let data = asset.bone_data(bone as usize);
+            match self.options.translation {
+                TranslationRetarget::Unscaled => 1.0,
+                TranslationRetarget::BoneLength => data.parent.map_or(data.length, |parent| {
+                    asset.bone_data(parent as usize).length
+                }),
+                TranslationRetarget::SetupOffset => data.setup_transform.translation().length(),
+            }
+        };
+        let from = measure(self.source, source);
+        let to = measure(self.target, target);
+        let scale = to / from;
+        if from > 0.0 && to > 0.0 && scale.is_finite() {
+            scale
+        } else {
+            1.0
+        }
+    }
+
+    /// Reports an unmapped element once per animation.
+    fn unmapped(&mut self, code: DiagnosticCode, kind: &str, name: &str) {
+        let message = format!(
+            "{kind} {name:?} of animation {:?} has no target counterpart; its keys were dropped",
+            self.animation
+        );
+        if self.pending.iter().any(|diagnostic| {
+            diagnostic.scope == self.scope && *diagnostic.message == *message.as_str()
+        }) {
+            return;
+        }
+        self.pending
+            .push(PendingDiagnostic::degraded(code, self.scope, message));
+    }
+}
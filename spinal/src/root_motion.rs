@@ -0,0 +1,180 @@
+//! Root-motion extraction from one authored bone.
+
+use glam::Vec2;
+
+use crate::{
+    Angle, BoneId, IdError, SkeletonAsset,
+    animation::{TimelineData, TimelineTime, sample_scalar, sample_vec2},
+    player::Advance,
+};
+
+/// Selects the bone whose authored motion a player or mixer extracts.
+///
+/// Every update measures how far the bone's translate and rotate timelines
+/// moved and reports it as a [`RootMotionDelta`]. With
+/// [`RootMotion::with_zero_in_pose`] the bone's translation and rotation are
+/// then pinned to setup pose, so the motion is carried only by the delta.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RootMotion {
+    bone: BoneId,
+    zero_in_pose: bool,
+}
+
+impl RootMotion {
+    /// Extracts motion from `bone` without changing the pose.
+    #[must_use]
+    pub const fn new(bone: BoneId) -> Self {
+        Self {
+            bone,
+            zero_in_pose: false,
+        }
+    }
+
+    /// Chooses whether the bone's translation and rotation are reset to setup
+    /// pose after extraction.
+    #[must_use]
+    pub const fn with_zero_in_pose(mut self, zero_in_pose: bool) -> Self {
+        self.zero_in_pose = zero_in_pose;
+        self
+    }
+
+    /// Returns the extracted bone.
+    #[must_use]
+    pub const fn bone(self) -> BoneId {
+        self.bone
+    }
+
+    /// Returns whether the bone is pinned to setup pose after extraction.
+    #[must_use]
+    pub const fn zero_in_pose(self) -> bool {
+        self.zero_in_pose
+    }
+
+    pub(crate) fn bone_index(self, asset: &SkeletonAsset) -> Result<usize, IdError> {
+        asset.bone_index(self.bone)
+    }
+}
+
+/// Authored motion of the root-motion bone during one update.
+///
+/// Translation is in the bone's parent space and skeleton units; rotation is
+/// the counterclockwise change of the bone's local rotation. Both are zero
+/// when nothing played or the current animations do not key the bone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RootMotionDelta {
+    translation: Vec2,
+    rotation: Angle,
+}
+
+impl RootMotionDelta {
+    /// No motion.
+    pub const ZERO: Self = Self {
+        translation: Vec2::ZERO,
+        rotation: Angle::ZERO,
+    };
+
+    pub(crate) fn new(translation: Vec2, rotation_radians: f64) -> Self {
+        Self {
+            translation: finite_vec2(translation),
+            rotation: Angle::from_radians(saturating_f32(rotation_radians))
+                .expect("saturating finite rotation conversion remains finite"),
+        }
+    }
+
+    /// Returns the translation delta.
+    #[must_use]
+    pub const fn translation(self) -> Vec2 {
+        self.translation
+    }
+
+    /// Returns the rotation delta.
+    #[must_use]
+    pub const fn rotation(self) -> Angle {
+        self.rotation
+    }
+}
+
+impl Default for RootMotionDelta {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+/// Motion of one playing clock, split by channel so layers can blend the
+/// channels the animation actually keys.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ClipMotion {
+    pub(crate) translation: Option<Vec2>,
+    pub(crate) rotation: Option<f64>,
+}
+
+impl ClipMotion {
+    /// Measures the bone's authored motion over one clock advance, including
+    /// every loop boundary the advance crossed.
+    pub(crate) fn measure(asset: &SkeletonAsset, bone: usize, advance: Advance) -> Self {
+        let animation = asset.animation_data(advance.next.animation_index);
+        let mut motion = Self::default();
+        for timeline in &animation.timelines {
+            match timeline {
+                TimelineData::BoneTranslate {
+                    bone: keyed,
+                    frames,
+                } if *keyed as usize == bone => {
+                    let sample = |ticks| {
+                        sample_vec2(frames, TimelineTime { ticks })
+                            .map_or([0.0; 2], |value| value.map(f64::from))
+                    };
+                    let x = channel_delta(advance, |ticks| sample(ticks)[0]);
+                    let y = channel_delta(advance, |ticks| sample(ticks)[1]);
+                    motion.translation = Some(Vec2::new(saturating_f32(x), saturating_f32(y)));
+                }
+                TimelineData::BoneRotate {
+                    bone: keyed,
+                    frames,
+                } if *keyed as usize == bone => {
+                    let degrees = channel_delta(advance, |ticks| {
+                        sample_scalar(frames, TimelineTime { ticks }).map_or(0.0, f64::from)
+                    });
+                    motion.rotation = Some(degrees.to_radians());
+                }
+                _ => {}
+            }
+        }
+        motion
+    }
+
+    pub(crate) fn translation_or_zero(self) -> Vec2 {
+        self.translation.unwrap_or(Vec2::ZERO)
+    }
+
+    pub(crate) fn rotation_or_zero(self) -> f64 {
+        self.rotation.unwrap_or(0.0)
+    }
+}
+
+fn channel_delta(advance: Advance, sample: impl Fn(u64) -> f64) -> f64 {
+    let previous = advance.previous.local_ticks;
+    let next = advance.next.local_ticks;
+    match advance.next.wrap() {
+        Some((start, end)) if advance.loops_completed > 0 => {
+            let cycle = sample(end) - sample(start);
+            let full_cycles = (advance.loops_completed - 1) as f64;
+            (sample(end) - sample(previous)) + cycle * full_cycles + (sample(next) - sample(start))
+        }
+        _ => sample(next) - sample(previous),
+    }
+}
+
+fn finite_vec2(value: Vec2) -> Vec2 {
+    Vec2::new(
+        saturating_f32(f64::from(value.x)),
+        saturating_f32(f64::from(value.y)),
+    )
+}
+
+fn saturating_f32(value: f64) -> f32 {
+    if value.is_nan() {
+        return 0.0;
+    }
+    value.clamp(-(f32::MAX as f64), f32::MAX as f64) as f32
+}
@@ -0,0 +1,464 @@
+//! Caller-defined constraints evaluated between authored constraints.
+//!
+//! Constraints are registered on the [`Skeleton`] rather than on an
+//! [`crate::AnimationMixer`] or [`crate::AnimationPlayer`]: the constraint
+//! order is solved on the skeleton instance, and every animator poses the
+//! skeleton it is bound to, so one registration serves a mixer, a player and
+//! direct pose edits alike. Registering on the skeleton also keeps the
+//! constraint in skeleton snapshots and with its instance across animators.
+
+use std::{any::Any, fmt, num::NonZeroU64, time::Duration};
+
+use glam::Vec2;
+use thiserror::Error;
+
+use crate::{
+    Angle, BoneId, BoneTransform, ConstraintId, ControlTargetError, IdError, Skeleton,
+    SkeletonAsset,
+    frame::{recompute_subtree_world_transforms, replace_rotation},
+    skeleton::SkeletonInstanceKey,
+    world::{WorldTransform, solve_world_rotation},
+};
+
+/// Procedural logic solved at a chosen point of a skeleton's constraint
+/// order, such as look-at, foot planting or aim clamping.
+///
+/// The constraint reads and adjusts the partially solved pose through
+/// [`ConstraintPose`]. Later authored and runtime constraints observe its
+/// result.
+///
+/// A constraint that carries state from one frame to the next, such as
+/// simulated motion, implements [`Self::snapshot_state`] and
+/// [`Self::restore_state`] so skeleton snapshots roll it back too.
+pub trait RuntimeConstraint: Any + fmt::Debug + Send + Sync {
+    /// Adjusts the pose once per solved frame and reports the outcome.
+    fn apply(&mut self, pose: &mut ConstraintPose<'_>) -> RuntimeConstraintStatus;
+
+    /// Returns a copy holding the state this constraint carries between
+    /// frames, for [`Skeleton::snapshot`].
+    ///
+    /// The default returns `None` for a constraint without such state.
+    fn snapshot_state(&self) -> Option<Box<dyn RuntimeConstraint>> {
+        None
+    }
+
+    /// Copies the state carried between frames from `snapshot`, a constraint
+    /// of the same type returned by [`Self::snapshot_state`].
+    ///
+    /// Snapshots are restored and refreshed through this method, so it
+    /// should not allocate. The default does nothing.
+    fn restore_state(&mut self, _snapshot: &dyn RuntimeConstraint) {}
+}
+
+/// Where a runtime constraint is solved relative to authored constraints.
+///
+/// Runtime constraints sharing a position are solved in insertion order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConstraintPosition {
+    /// Before every authored constraint.
+    First,
+    /// Immediately before one authored constraint.
+    Before(ConstraintId),
+    /// Immediately after one authored constraint.
+    After(ConstraintId),
+    /// After every authored constraint.
+    Last,
+}
+
+/// Identifies one runtime constraint on a skeleton instance.
+///
+/// IDs are scoped to the skeleton that created them and remain stale after
+/// removal.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RuntimeConstraintId {
+    skeleton: SkeletonInstanceKey,
+    serial: NonZeroU64,
+}
+
+impl RuntimeConstraintId {
+    /// Returns the nonzero skeleton-local serial number.
+    #[must_use]
+    pub const fn get(self) -> NonZeroU64 {
+        self.serial
+    }
+}
+
+/// A failure to register, find or downcast a runtime constraint.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum RuntimeConstraintError {
+    /// The authored constraint anchoring the position belongs to another
+    /// asset.
+    #[error("the runtime constraint position is invalid: {0}")]
+    InvalidPosition(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// The ID belongs to another skeleton or its constraint was removed.
+    #[error("the runtime constraint does not exist on this skeleton")]
+    UnknownConstraint,
+    /// The constraint is not of the requested type.
+    #[error("the runtime constraint has a different type")]
+    TypeMismatch,
+}
+
+/// The outcome a runtime constraint reports for one solved frame.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RuntimeConstraintStatus {
+    active: bool,
+    degraded: bool,
+}
+
+impl RuntimeConstraintStatus {
+    /// The constraint left the pose unchanged.
+    pub const INACTIVE: Self = Self {
+        active: false,
+        degraded: false,
+    };
+
+    /// The constraint adjusted the pose as intended.
+    pub const APPLIED: Self = Self {
+        active: true,
+        degraded: false,
+    };
+
+    /// The constraint was active but fell back to a safer pose, for example
+    /// because its target geometry was singular.
+    pub const DEGRADED: Self = Self {
+        active: true,
+        degraded: true,
+    };
+
+    /// Returns whether the constraint had influence this frame.
+    #[must_use]
+    pub const fn is_active(self) -> bool {
+        self.active
+    }
+
+    /// Returns whether a runtime fallback changed the intended result.
+    #[must_use]
+    pub const fn is_degraded(self) -> bool {
+        self.degraded
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RuntimeConstraintEntry {
+    pub(crate) id: RuntimeConstraintId,
+    /// The authored evaluation-order position this entry runs before.
+    before: usize,
+    constraint: Box<dyn RuntimeConstraint>,
+    pub(crate) status: RuntimeConstraintStatus,
+}
+
+/// One runtime constraint's latest status and state, captured in a skeleton
+/// snapshot.
+#[derive(Debug)]
+pub(crate) struct RuntimeConstraintSnapshot {
+    id: RuntimeConstraintId,
+    status: RuntimeConstraintStatus,
+    state: Option<Box<dyn RuntimeConstraint>>,
+}
+
+/// The partially solved pose handed to [`RuntimeConstraint::apply`].
+///
+/// Reads observe every earlier constraint. Each adjustment immediately
+/// updates the world transforms of the bone and its descendants.
+#[derive(Debug)]
+pub struct ConstraintPose<'a> {
+    skeleton: &'a mut Skeleton,
+    elapsed: Duration,
+}
+
+impl ConstraintPose<'_> {
+    /// Returns the immutable asset being solved.
+    #[must_use]
+    pub fn asset(&self) -> &SkeletonAsset {
+        self.skeleton.asset()
+    }
+
+    /// Returns the time since the previous solve, as reported by
+    /// [`crate::EditablePose::elapsed`].
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns one bone's current skeleton-space transform.
+    pub fn world_transform(&self, bone: BoneId) -> Result<WorldTransform, IdError> {
+        let index = self.skeleton.asset().bone_index(bone)?;
+        Ok(self.skeleton.world_transforms[index])
+    }
+
+    /// Returns one bone's current local transform, including earlier
+    /// constraints.
+    pub fn local_transform(&self, bone: BoneId) -> Result<BoneTransform, IdError> {
+        let index = self.skeleton.asset().bone_index(bone)?;
+        Ok(self.skeleton.applied_bones[index].local_transform)
+    }
+
+    /// Replaces one bone's local transform.
+    pub fn set_local_transform(
+        &mut self,
+        bone: BoneId,
+        transform: BoneTransform,
+    ) -> Result<(), IdError> {
+        let index = self.skeleton.asset().bone_index(bone)?;
+        self.skeleton.applied_bones[index].local_transform = transform;
+        recompute_subtree_world_transforms(self.skeleton, index);
+        Ok(())
+    }
+
+    /// Rotates one bone so its local X axis points along a skeleton-space
+    /// rotation.
+    ///
+    /// Translation, scale and shear remain unchanged.
+    pub fn set_world_rotation(
+        &mut self,
+        bone: BoneId,
+        rotation: Angle,
+    ) -> Result<(), ControlTargetError> {
+        let index = self
+            .skeleton
+            .asset()
+            .bone_index(bone)
+            .map_err(ControlTargetError::InvalidBone)?;
+        let local = self.skeleton.applied_bones[index].local_transform;
+        let local_rotation = solve_world_rotation(self.parent_world(index), local, rotation)
+            .ok_or(ControlTargetError::SingularParent { bone })?;
+        self.skeleton.applied_bones[index].local_transform =
+            replace_rotation(local, local_rotation);
+        recompute_subtree_world_transforms(self.skeleton, index);
+        Ok(())
+    }
+
+    /// Moves one bone origin to a finite skeleton-space position.
+    ///
+    /// Rotation, scale and shear remain unchanged.
+    pub fn set_world_position(
+        &mut self,
+        bone: BoneId,
+        position: Vec2,
+    ) -> Result<(), ControlTargetError> {
+        let index = self
+            .skeleton
+            .asset()
+            .bone_index(bone)
+            .map_err(ControlTargetError::InvalidBone)?;
+        if !position.is_finite() {
+            return Err(ControlTargetError::NonFinitePosition);
+        }
+        let local_position = match self.parent_world(index) {
+            None => position,
+            Some(parent) => parent
+                .try_inverse_point(position)
+                .ok_or(ControlTargetError::SingularParent { bone })?,
+        };
+        let current = self.skeleton.applied_bones[index].local_transform;
+        self.skeleton.applied_bones[index].local_transform = BoneTransform::new(
+            local_position,
+            current.rotation(),
+            current.scale(),
+            current.shear(),
+        )
+        .expect("finite positions and retained finite channels produce a finite transform");
+        recompute_subtree_world_transforms(self.skeleton, index);
+        Ok(())
+    }
+
+    pub(crate) fn skeleton_mut(&mut self) -> &mut Skeleton {
+        self.skeleton
+    }
+
+    /// Returns the world transform of a bone's parent, or `None` for a root.
+    pub(crate) fn parent_world_transform(
+        &self,
+        bone: BoneId,
+    ) -> Result<Option<WorldTransform>, IdError> {
+        let index = self.skeleton.asset().bone_index(bone)?;
+        Ok(self.parent_world(index))
+    }
+
+    fn parent_world(&self, index: usize) -> Option<WorldTransform> {
+        self.skeleton
+            .asset()
+            .bone_data(index)
+            .parent
+            .map(|parent| self.skeleton.world_transforms[parent as usize])
+    }
+}
+
+impl Skeleton {
+    /// Registers a runtime constraint at a position in the constraint order.
+    ///
+    /// It is solved by every later [`crate::EditablePose::solve`], whether the
+    /// pose came from [`Self::editable_pose`], an [`crate::AnimationPlayer`] or
+    /// an [`crate::AnimationMixer`]. The shared asset is not modified.
+    pub fn insert_runtime_constraint(
+        &mut self,
+        position: ConstraintPosition,
+        constraint: impl RuntimeConstraint,
+    ) -> Result<RuntimeConstraintId, RuntimeConstraintError> {
+        let order = self.asset().constraint_evaluation_order();
+        let authored = |id: ConstraintId| {
+            let index = self
+                .asset()
+                .constraint(id)
+                .map_err(RuntimeConstraintError::InvalidPosition)?
+                .ordinal() as u32;
+            Ok(order
+                .iter()
+                .position(|ordered| *ordered == index)
+                .expect("every authored constraint has an evaluation-order position"))
+        };
+        let before = match position {
+            ConstraintPosition::First => 0,
+            ConstraintPosition::Before(id) => authored(id)?,
+            ConstraintPosition::After(id) => authored(id)? + 1,
+            ConstraintPosition::Last => order.len(),
+        };
+        let id = RuntimeConstraintId {
+            skeleton: self.instance_key(),
+            serial: self.next_runtime_constraint,
+        };
+        self.next_runtime_constraint = self
+            .next_runtime_constraint
+            .checked_add(1)
+            .expect("runtime constraint serials do not overflow 64 bits");
+        let at = self
+            .runtime_constraints
+            .partition_point(|entry| entry.before <= before);
+        self.runtime_constraints.insert(
+            at,
+            RuntimeConstraintEntry {
+                id,
+                before,
+                constraint: Box::new(constraint),
+                status: RuntimeConstraintStatus::INACTIVE,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Unregisters a runtime constraint and returns it.
+    pub fn remove_runtime_constraint(
+        &mut self,
+        id: RuntimeConstraintId,
+    ) -> Result<Box<dyn RuntimeConstraint>, RuntimeConstraintError> {
+        let index = self.runtime_constraint_index(id)?;
+        Ok(self.runtime_constraints.remove(index).constraint)
+    }
+
+    /// Iterates registered runtime constraints in solve order.
+    pub fn runtime_constraints(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = RuntimeConstraintId> + ExactSizeIterator + '_ {
+        self.runtime_constraints.iter().map(|entry| entry.id)
+    }
+
+    /// Borrows a registered runtime constraint as its concrete type.
+    pub fn runtime_constraint<T: RuntimeConstraint>(
+        &self,
+        id: RuntimeConstraintId,
+    ) -> Result<&T, RuntimeConstraintError> {
+        let index = self.runtime_constraint_index(id)?;
+        let constraint: &dyn Any = &*self.runtime_constraints[index].constraint;
+        constraint
+            .downcast_ref()
+            .ok_or(RuntimeConstraintError::TypeMismatch)
+    }
+
+    /// Mutably borrows a registered runtime constraint as its concrete type,
+    /// for example to move its target between frames.
+    pub fn runtime_constraint_mut<T: RuntimeConstraint>(
+        &mut self,
+        id: RuntimeConstraintId,
+    ) -> Result<&mut T, RuntimeConstraintError> {
+        let index = self.runtime_constraint_index(id)?;
+        let constraint: &mut dyn Any = &mut *self.runtime_constraints[index].constraint;
+        constraint
+            .downcast_mut()
+            .ok_or(RuntimeConstraintError::TypeMismatch)
+    }
+
+    /// Captures every registered runtime constraint's status and state.
+    pub(crate) fn snapshot_runtime_constraints(&self) -> Vec<RuntimeConstraintSnapshot> {
+        self.runtime_constraints
+            .iter()
+            .map(|entry| RuntimeConstraintSnapshot {
+                id: entry.id,
+                status: entry.status,
+                state: entry.constraint.snapshot_state(),
+            })
+            .collect()
+    }
+
+    /// Refreshes captured runtime constraints in place while the same
+    /// constraints are registered, and captures them anew otherwise.
+    pub(crate) fn snapshot_runtime_constraints_into(
+        &self,
+        saved: &mut Vec<RuntimeConstraintSnapshot>,
+    ) {
+        let unchanged = saved.len() == self.runtime_constraints.len()
+            && saved
+                .iter()
+                .zip(&self.runtime_constraints)
+                .all(|(saved, entry)| saved.id == entry.id);
+        if !unchanged {
+            *saved = self.snapshot_runtime_constraints();
+            return;
+        }
+        for (saved, entry) in saved.iter_mut().zip(&self.runtime_constraints) {
+            saved.status = entry.status;
+            if let Some(state) = &mut saved.state {
+                state.restore_state(&*entry.constraint);
+            }
+        }
+    }
+
+    /// Restores the status and state of each registered runtime constraint
+    /// captured in `saved`; constraints registered since keep theirs.
+    pub(crate) fn restore_runtime_constraints(&mut self, saved: &[RuntimeConstraintSnapshot]) {
+        for entry in &mut self.runtime_constraints {
+            let Some(saved) = saved.iter().find(|saved| saved.id == entry.id) else {
+                continue;
+            };
+            entry.status = saved.status;
+            if let Some(state) = &saved.state {
+                entry.constraint.restore_state(&**state);
+            }
+        }
+    }
+
+    fn runtime_constraint_index(
+        &self,
+        id: RuntimeConstraintId,
+    ) -> Result<usize, RuntimeConstraintError> {
+        if id.skeleton != self.instance_key() {
+            return Err(RuntimeConstraintError::UnknownConstraint);
+        }
+        self.runtime_constraints
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or(RuntimeConstraintError::UnknownConstraint)
+    }
+}
+
+/// Solves the runtime constraints from `next` that run before authored
+/// evaluation-order position `before`, returning the first unsolved entry.
+pub(crate) fn solve_runtime_constraints(
+    skeleton: &mut Skeleton,
+    elapsed: Duration,
+    entries: &mut [RuntimeConstraintEntry],
+    mut next: usize,
+    before: usize,
+) -> usize {
+    while let Some(entry) = entries.get_mut(next).filter(|entry| entry.before <= before) {
+        entry.status = entry
+            .constraint
+            .apply(&mut ConstraintPose { skeleton, elapsed });
+        next += 1;
+    }
+    next
+}
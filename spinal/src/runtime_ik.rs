@@ -0,0 +1,204 @@
+//! Caller-created IK chains solved as runtime constraints.
+
+use glam::Vec2;
+use thiserror::Error;
+
+use crate::{
+    BendDirection, BoneId, ConstraintPose, IdError, IkSolveStatus, Mix, RuntimeConstraint,
+    RuntimeConstraintStatus, SkeletonAsset,
+    frame::{apply_one_bone_ik, apply_two_bone_ik, recompute_world_transforms},
+};
+
+/// What a [`RuntimeIk`] chain reaches for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IkTarget {
+    /// A fixed skeleton-space point, such as a toy.
+    Point(Vec2),
+    /// The solved origin of a bone when the chain runs.
+    Bone(BoneId),
+}
+
+/// A failure to build a [`RuntimeIk`] chain.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum RuntimeIkError {
+    /// A bone belongs to another asset.
+    #[error("the IK chain bone is invalid: {0}")]
+    InvalidBone(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// The child of a two-bone chain is not a direct child of its parent.
+    #[error("IK chain bone {child:?} is not a child of the chain parent")]
+    Disconnected {
+        /// The bone that breaks the chain.
+        child: BoneId,
+    },
+}
+
+/// A transient one- or two-bone IK chain on one skeleton instance, such as a
+/// head look-at or a paw reaching for a toy.
+///
+/// Register it with [`crate::Skeleton::insert_runtime_constraint`]. It is
+/// solved with the same math as authored IK constraints and leaves the shared
+/// asset untouched. [`Self::status`] reports the latest [`IkSolveStatus`].
+#[derive(Clone, Debug)]
+pub struct RuntimeIk {
+    parent: BoneId,
+    child: Option<BoneId>,
+    target: IkTarget,
+    mix: Mix,
+    bend_direction: BendDirection,
+    status: IkSolveStatus,
+}
+
+impl RuntimeIk {
+    /// Creates a chain that rotates one bone to point at `target`.
+    pub fn one_bone(
+        asset: &SkeletonAsset,
+        bone: BoneId,
+        target: IkTarget,
+    ) -> Result<Self, RuntimeIkError> {
+        asset.bone(bone).map_err(RuntimeIkError::InvalidBone)?;
+        Self::validate_target(asset, target)?;
+        Ok(Self::new(bone, None, target))
+    }
+
+    /// Creates a chain that bends `parent` and its direct `child` so the
+    /// child's tip reaches `target`.
+    pub fn two_bone(
+        asset: &SkeletonAsset,
+        parent: BoneId,
+        child: BoneId,
+        target: IkTarget,
+    ) -> Result<Self, RuntimeIkError> {
+        asset.bone(parent).map_err(RuntimeIkError::InvalidBone)?;
+        let child_ref = asset.bone(child).map_err(RuntimeIkError::InvalidBone)?;
+        if child_ref.parent() != Some(parent) {
+            return Err(RuntimeIkError::Disconnected { child });
+        }
+        Self::validate_target(asset, target)?;
+        Ok(Self::new(parent, Some(child), target))
+    }
+
+    const fn new(parent: BoneId, child: Option<BoneId>, target: IkTarget) -> Self {
+        Self {
+            parent,
+            child,
+            target,
+            mix: Mix::ONE,
+            bend_direction: BendDirection::Positive,
+            status: IkSolveStatus::INACTIVE,
+        }
+    }
+
+    fn validate_target(asset: &SkeletonAsset, target: IkTarget) -> Result<(), RuntimeIkError> {
+        if let IkTarget::Bone(bone) = target {
+            asset.bone(bone).map_err(RuntimeIkError::InvalidBone)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the influence over the animated pose.
+    #[must_use]
+    pub const fn with_mix(mut self, mix: Mix) -> Self {
+        self.mix = mix;
+        self
+    }
+
+    /// Sets the side a two-bone chain bends toward.
+    #[must_use]
+    pub const fn with_bend_direction(mut self, bend_direction: BendDirection) -> Self {
+        self.bend_direction = bend_direction;
+        self
+    }
+
+    /// Returns the chain's bones from parent to child.
+    pub fn bones(&self) -> impl DoubleEndedIterator<Item = BoneId> + '_ {
+        std::iter::once(self.parent).chain(self.child)
+    }
+
+    /// Returns what the chain reaches for.
+    #[must_use]
+    pub const fn target(&self) -> IkTarget {
+        self.target
+    }
+
+    /// Moves the target, for example every frame before updating.
+    ///
+    /// A bone target must belong to the chain's asset or the next solve
+    /// reports a degraded status.
+    pub const fn set_target(&mut self, target: IkTarget) {
+        self.target = target;
+    }
+
+    /// Returns the influence over the animated pose.
+    #[must_use]
+    pub const fn mix(&self) -> Mix {
+        self.mix
+    }
+
+    /// Replaces the influence over the animated pose.
+    pub const fn set_mix(&mut self, mix: Mix) {
+        self.mix = mix;
+    }
+
+    /// Returns the side a two-bone chain bends toward.
+    #[must_use]
+    pub const fn bend_direction(&self) -> BendDirection {
+        self.bend_direction
+    }
+
+    /// Replaces the side a two-bone chain bends toward.
+    pub const fn set_bend_direction(&mut self, bend_direction: BendDirection) {
+        self.bend_direction = bend_direction;
+    }
+
+    /// Returns the result of the latest solve.
+    #[must_use]
+    pub const fn status(&self) -> IkSolveStatus {
+        self.status
+    }
+
+    fn solve(&self, pose: &mut ConstraintPose<'_>) -> Option<IkSolveStatus> {
+        let target = match self.target {
+            IkTarget::Point(point) => point.is_finite().then_some(point)?,
+            IkTarget::Bone(bone) => pose.world_transform(bone).ok()?.translation(),
+        };
+        let skeleton = pose.skeleton_mut();
+        let parent = skeleton.asset().bone_index(self.parent).ok()?;
+        let status = match self.child {
+            None => apply_one_bone_ik(skeleton, parent, target, self.mix.get()),
+            Some(child) => apply_two_bone_ik(
+                skeleton,
+                parent,
+                skeleton.asset().bone_index(child).ok()?,
+                target,
+                self.bend_direction,
+                self.mix.get(),
+            ),
+        };
+        recompute_world_transforms(skeleton);
+        Some(status)
+    }
+}
+
+impl RuntimeConstraint for RuntimeIk {
+    fn apply(&mut self, pose: &mut ConstraintPose<'_>) -> RuntimeConstraintStatus {
+        if self.mix == Mix::ZERO {
+            self.status = IkSolveStatus::INACTIVE;
+            return RuntimeConstraintStatus::INACTIVE;
+        }
+        let Some(status) = self.solve(pose) else {
+            self.status = IkSolveStatus::INACTIVE;
+            return RuntimeConstraintStatus::DEGRADED;
+        };
+        self.status = status;
+        if status.is_degraded() {
+            RuntimeConstraintStatus::DEGRADED
+        } else {
+            RuntimeConstraintStatus::APPLIED
+        }
+    }
+}
@@ -0,0 +1,500 @@
+//! Instance-owned skins assembled from authored attachments.
+
+use std::{mem, num::NonZeroU64};
+
+use thiserror::Error;
+
+use crate::{
+    AttachmentId, IdError, Skeleton, SkeletonAsset, SkinId, SlotId, asset::AttachmentDataKind,
+    id::AssetKey, mesh::MeshVerticesData, skeleton::SkeletonInstanceKey,
+};
+
+/// One attachment picked into a [`RuntimeSkin`].
+///
+/// By default the attachment answers its authored slot and placeholder.
+/// [`Self::with_target`] moves it to another slot placeholder, for example to
+/// wear one skin's hat on another skin's head slot.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SkinAttachment<'a> {
+    attachment: AttachmentId,
+    target: Option<(SlotId, &'a str)>,
+}
+
+impl<'a> SkinAttachment<'a> {
+    /// Picks an attachment for its authored slot and placeholder.
+    #[must_use]
+    pub const fn new(attachment: AttachmentId) -> Self {
+        Self {
+            attachment,
+            target: None,
+        }
+    }
+
+    /// Answers `placeholder` on `slot` instead of the authored placeholder.
+    #[must_use]
+    pub const fn with_target(mut self, slot: SlotId, placeholder: &'a str) -> Self {
+        self.target = Some((slot, placeholder));
+        self
+    }
+
+    /// Returns the picked attachment.
+    #[must_use]
+    pub const fn attachment(self) -> AttachmentId {
+        self.attachment
+    }
+
+    /// Returns the slot placeholder the attachment is moved to, if any.
+    #[must_use]
+    pub const fn target(self) -> Option<(SlotId, &'a str)> {
+        self.target
+    }
+}
+
+/// A skin assembled at runtime from attachments of any authored skins.
+///
+/// Register it on one instance with [`Skeleton::insert_runtime_skin`] and
+/// layer it like an authored skin through [`Skeleton::set_skin_layer_stack`].
+/// Building the skin is the only allocating step; layering and resolving it
+/// reuse the instance's storage.
+#[derive(Clone, Debug)]
+pub struct RuntimeSkin {
+    asset: AssetKey,
+    name: Box<str>,
+    /// Sorted by slot and placeholder, one attachment per placeholder.
+    entries: Box<[RuntimeSkinEntry]>,
+}
+
+#[derive(Clone, Debug)]
+struct RuntimeSkinEntry {
+    slot: u32,
+    placeholder: Box<str>,
+    attachment: u32,
+}
+
+impl RuntimeSkin {
+    /// Validates the picked attachments against `asset` and its slots.
+    ///
+    /// A later pick for the same slot placeholder replaces an earlier one.
+    pub fn new<'a>(
+        asset: &SkeletonAsset,
+        name: impl Into<Box<str>>,
+        attachments: impl IntoIterator<Item = SkinAttachment<'a>>,
+    ) -> Result<Self, RuntimeSkinError> {
+        let mut entries = Vec::new();
+        for pick in attachments {
+            let attachment = asset
+                .attachment(pick.attachment)
+                .map_err(RuntimeSkinError::InvalidAttachment)?
+                .ordinal();
+            let data = asset.attachment_data(attachment);
+            let (slot, placeholder) = match pick.target {
+                None => (data.slot, &*data.placeholder_name),
+                Some((slot, placeholder)) => {
+                    let slot = asset
+                        .slot_index(slot)
+                        .map_err(RuntimeSkinError::InvalidSlot)?
+                        as u32;
+                    (slot, placeholder)
+                }
+            };
+            let slot_id = SlotId::new(asset.key(), slot);
+            if asset
+                .attachment_placeholder_index(slot, placeholder)
+                .is_none()
+            {
+                return Err(RuntimeSkinError::UnknownPlaceholder { slot: slot_id });
+            }
+            if !follows_any_slot(asset, attachment)
+                && asset.slot_data(slot as usize).bone != asset.slot_data(data.slot as usize).bone
+            {
+                return Err(RuntimeSkinError::IncompatibleSlot {
+                    attachment: pick.attachment,
+                    slot: slot_id,
+                });
+            }
+            entries.push(RuntimeSkinEntry {
+                slot,
+                placeholder: placeholder.into(),
+                attachment: attachment as u32,
+            });
+        }
+        // A stable sort keeps picks in order, so the last of equal keys wins.
+        entries.sort_by(|a, b| (a.slot, &a.placeholder).cmp(&(b.slot, &b.placeholder)));
+        let mut kept: Vec<RuntimeSkinEntry> = Vec::with_capacity(entries.len());
+        for entry in entries {
+            match kept.last_mut() {
+                Some(last) if last.slot == entry.slot && last.placeholder == entry.placeholder => {
+                    *last = entry;
+                }
+                _ => kept.push(entry),
+            }
+        }
+        Ok(Self {
+            asset: asset.key(),
+            name: name.into(),
+            entries: kept.into_boxed_slice(),
+        })
+    }
+
+    /// Returns the caller-chosen name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Iterates the skin's slot, placeholder and attachment entries in slot
+    /// order.
+    pub fn attachments(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (SlotId, &str, AttachmentId)> + ExactSizeIterator + '_
+    {
+        self.entries.iter().map(|entry| {
+            (
+                SlotId::new(self.asset, entry.slot),
+                &*entry.placeholder,
+                AttachmentId::new(self.asset, entry.attachment),
+            )
+        })
+    }
+
+    /// Returns the attachment this skin places at one slot placeholder.
+    pub fn attachment(
+        &self,
+        slot: SlotId,
+        placeholder: &str,
+    ) -> Result<Option<AttachmentId>, IdError> {
+        if slot.asset() != self.asset {
+            return Err(IdError::foreign_asset());
+        }
+        Ok(self
+            .attachment_index(slot.index(), placeholder)
+            .map(|index| AttachmentId::new(self.asset, index)))
+    }
+
+    fn attachment_index(&self, slot: u32, placeholder: &str) -> Option<u32> {
+        self.entries
+            .binary_search_by(|entry| (entry.slot, &*entry.placeholder).cmp(&(slot, placeholder)))
+            .ok()
+            .map(|index| self.entries[index].attachment)
+    }
+}
+
+/// Returns whether an attachment draws correctly on a slot of any bone.
+///
+/// Regions follow the drawing slot's bone and weighted meshes follow their
+/// own influences, but unweighted mesh vertices stay on the authored slot's
+/// bone.
+pub(crate) fn follows_any_slot(asset: &SkeletonAsset, attachment: usize) -> bool {
+    match &asset.attachment_data(attachment).kind {
+        AttachmentDataKind::Mesh(mesh) => !matches!(
+            asset.mesh_geometry_data(mesh.geometry as usize).vertices,
+            MeshVerticesData::Unweighted(_)
+        ),
+        _ => true,
+    }
+}
+
+/// Identifies one runtime skin registered on a skeleton instance.
+///
+/// IDs are scoped to the skeleton that created them and remain stale after
+/// removal.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RuntimeSkinId {
+    skeleton: SkeletonInstanceKey,
+    serial: NonZeroU64,
+}
+
+impl RuntimeSkinId {
+    /// Returns the nonzero skeleton-local serial number.
+    #[must_use]
+    pub const fn get(self) -> NonZeroU64 {
+        self.serial
+    }
+}
+
+/// One entry of a skeleton's skin layer stack.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SkinLayer {
+    /// A skin authored in the asset.
+    Authored(SkinId),
+    /// A runtime skin registered on the skeleton.
+    Runtime(RuntimeSkinId),
+}
+
+impl From<SkinId> for SkinLayer {
+    fn from(skin: SkinId) -> Self {
+        Self::Authored(skin)
+    }
+}
+
+impl From<RuntimeSkinId> for SkinLayer {
+    fn from(skin: RuntimeSkinId) -> Self {
+        Self::Runtime(skin)
+    }
+}
+
+/// A failure to build, register or layer a runtime skin.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum RuntimeSkinError {
+    /// A picked attachment belongs to another asset.
+    #[error("the runtime skin attachment is invalid: {0}")]
+    InvalidAttachment(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// A target slot belongs to another asset.
+    #[error("the runtime skin slot is invalid: {0}")]
+    InvalidSlot(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// No setup pose or animation of the slot refers to the placeholder, so
+    /// the entry could never be shown.
+    #[error("slot {slot:?} has no such attachment placeholder")]
+    UnknownPlaceholder {
+        /// The slot the attachment was placed on.
+        slot: SlotId,
+    },
+    /// An unweighted mesh was moved to a slot on a different bone, where its
+    /// vertices would still follow the authored bone.
+    #[error("attachment {attachment:?} cannot be drawn on slot {slot:?}")]
+    IncompatibleSlot {
+        /// The mesh attachment.
+        attachment: AttachmentId,
+        /// The rejected target slot.
+        slot: SlotId,
+    },
+    /// The runtime skin was built for another asset.
+    #[error("the runtime skin belongs to another asset")]
+    ForeignAsset,
+    /// An authored skin layer belongs to another asset.
+    #[error("the skin layer is invalid: {0}")]
+    InvalidSkin(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// The ID belongs to another skeleton or its skin was removed.
+    #[error("the runtime skin does not exist on this skeleton")]
+    UnknownSkin,
+}
+
+/// A validated skin layer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum LayerRef {
+    Authored(u32),
+    Runtime(RuntimeSkinId),
+}
+
+/// An instance's registered runtime skins and its ordered layer stack.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SkinStack {
+    /// From low to high priority, without repeats.
+    pub(crate) layers: Vec<LayerRef>,
+    runtime: Vec<(RuntimeSkinId, RuntimeSkin)>,
+}
+
+impl SkinStack {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            layers: Vec::with_capacity(capacity),
+            runtime: Vec::new(),
+        }
+    }
+
+    /// Resolves a placeholder through the layers, then the default skin.
+    pub(crate) fn resolve(
+        &self,
+        asset: &SkeletonAsset,
+        slot: u32,
+        placeholder_name: &str,
+    ) -> Option<u32> {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| match *layer {
+                LayerRef::Authored(skin) => {
+                    asset.skin_attachment_index(skin, slot, placeholder_name)
+                }
+                LayerRef::Runtime(id) => self
+                    .runtime_skin(id)
+                    .and_then(|skin| skin.attachment_index(slot, placeholder_name)),
+            })
+            .or_else(|| asset.default_skin_attachment_index(slot, placeholder_name))
+    }
+
+    /// Returns a copy of the layers without the registered skins, for a
+    /// snapshot.
+    pub(crate) fn layers_only(&self) -> Self {
+        Self {
+            layers: self.layers.clone(),
+            runtime: Vec::new(),
+        }
+    }
+
+    /// Drops layers naming runtime skins this stack does not have and
+    /// returns whether any were dropped.
+    pub(crate) fn retain_registered(&mut self) -> bool {
+        let runtime = &self.runtime;
+        let layered = self.layers.len();
+        self.layers.retain(|layer| match *layer {
+            LayerRef::Authored(_) => true,
+            LayerRef::Runtime(id) => runtime.iter().any(|(entry, _)| *entry == id),
+        });
+        self.layers.len() != layered
+    }
+
+    fn runtime_skin(&self, id: RuntimeSkinId) -> Option<&RuntimeSkin> {
+        self.runtime
+            .iter()
+            .find_map(|(entry, skin)| (*entry == id).then_some(skin))
+    }
+}
+
+impl Skeleton {
+    /// Registers a runtime skin on this instance without layering it.
+    pub fn insert_runtime_skin(
+        &mut self,
+        skin: RuntimeSkin,
+    ) -> Result<RuntimeSkinId, RuntimeSkinError> {
+        if skin.asset != self.asset().key() {
+            return Err(RuntimeSkinError::ForeignAsset);
+        }
+        let id = RuntimeSkinId {
+            skeleton: self.instance_key(),
+            serial: self.next_runtime_skin,
+        };
+        self.next_runtime_skin = self
+            .next_runtime_skin
+            .checked_add(1)
+            .expect("runtime skin serials do not overflow 64 bits");
+        self.skin_stack.runtime.push((id, skin));
+        // Keep room to layer every skin without allocating.
+        let capacity = self.asset().skin_count() + self.skin_stack.runtime.len();
+        self.skin_stack
+            .layers
+            .reserve(capacity - self.skin_stack.layers.len());
+        self.skin_layer_scratch
+            .reserve(capacity - self.skin_layer_scratch.len());
+        Ok(id)
+    }
+
+    /// Unregisters a runtime skin and returns it.
+    ///
+    /// If the skin was layered it leaves the stack, and every slot's setup
+    /// attachment is restored through the remaining layers.
+    pub fn remove_runtime_skin(
+        &mut self,
+        id: RuntimeSkinId,
+    ) -> Result<RuntimeSkin, RuntimeSkinError> {
+        let index = self
+            .skin_stack
+            .runtime
+            .iter()
+            .position(|(entry, _)| *entry == id)
+            .ok_or(RuntimeSkinError::UnknownSkin)?;
+        let (_, skin) = self.skin_stack.runtime.remove(index);
+        let layered = self.skin_stack.layers.len();
+        self.skin_stack
+            .layers
+            .retain(|layer| *layer != LayerRef::Runtime(id));
+        if self.skin_stack.layers.len() != layered {
+            self.skin_layers_changed();
+        }
+        Ok(skin)
+    }
+
+    /// Borrows a registered runtime skin.
+    pub fn runtime_skin(&self, id: RuntimeSkinId) -> Result<&RuntimeSkin, RuntimeSkinError> {
+        self.skin_stack
+            .runtime_skin(id)
+            .ok_or(RuntimeSkinError::UnknownSkin)
+    }
+
+    /// Iterates registered runtime skins in registration order.
+    pub fn runtime_skins(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = RuntimeSkinId> + ExactSizeIterator + '_ {
+        self.skin_stack.runtime.iter().map(|(id, _)| *id)
+    }
+
+    /// Iterates the authored and runtime skin layers from low to high
+    /// priority.
+    pub fn skin_layer_stack(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = SkinLayer> + ExactSizeIterator + '_ {
+        self.skin_stack.layers.iter().map(|layer| match *layer {
+            LayerRef::Authored(index) => {
+                SkinLayer::Authored(SkinId::new(self.asset().key(), index))
+            }
+            LayerRef::Runtime(id) => SkinLayer::Runtime(id),
+        })
+    }
+
+    /// Replaces the ordered skin layers with any mix of authored and
+    /// registered runtime skins, transactionally and without allocating.
+    ///
+    /// Layers compose exactly as in [`Self::set_skin_layers`].
+    pub fn set_skin_layer_stack(&mut self, layers: &[SkinLayer]) -> Result<(), RuntimeSkinError> {
+        self.replace_skin_layers(layers, |skeleton, layer| match layer {
+            SkinLayer::Authored(skin) => skeleton
+                .asset()
+                .skin_index(skin)
+                .map(|index| LayerRef::Authored(index as u32))
+                .map_err(RuntimeSkinError::InvalidSkin),
+            SkinLayer::Runtime(id) => skeleton.runtime_skin(id).map(|_| LayerRef::Runtime(id)),
+        })
+    }
+
+    /// Validates and installs layers from low to high priority, keeping only
+    /// the highest repeat of each.
+    pub(crate) fn replace_skin_layers<T: Copy, E>(
+        &mut self,
+        layers: &[T],
+        validate: impl Fn(&Self, T) -> Result<LayerRef, E>,
+    ) -> Result<(), E> {
+        let mut scratch = mem::take(&mut self.skin_layer_scratch);
+        scratch.clear();
+        for layer in layers.iter().rev() {
+            match validate(self, *layer) {
+                Ok(layer) if scratch.contains(&layer) => {}
+                Ok(layer) => scratch.push(layer),
+                Err(error) => {
+                    scratch.clear();
+                    self.skin_layer_scratch = scratch;
+                    return Err(error);
+                }
+            }
+        }
+        scratch.reverse();
+        mem::swap(&mut self.skin_stack.layers, &mut scratch);
+        scratch.clear();
+        self.skin_layer_scratch = scratch;
+        self.skin_layers_changed();
+        Ok(())
+    }
+
+    /// Mirrors the authored layers, restores every slot's setup attachment
+    /// through the new composition and notifies bound animators.
+    fn skin_layers_changed(&mut self) {
+        self.sync_authored_skin_layers();
+        self.reset_slot_attachments_to_setup_pose();
+        self.skin_revision = self.skin_revision.wrapping_add(1);
+    }
+
+    pub(crate) fn sync_authored_skin_layers(&mut self) {
+        self.skin_layers.clear();
+        self.skin_layers.extend(
+            self.skin_stack
+                .layers
+                .iter()
+                .filter_map(|layer| match *layer {
+                    LayerRef::Authored(index) => Some(index),
+                    LayerRef::Runtime(_) => None,
+                }),
+        );
+    }
+}
@@ -0,0 +1,429 @@
+//! Versioned, name-based animation state for save games.
+//!
+//! An [`AnimationSaveState`] describes a mixer and its skeleton by animation
+//! and skin names, so it survives asset reloads that renumber IDs.
+//! [`crate::AnimationMixer::restore_save_state`] resolves every name before
+//! changing anything and reports all mismatches at once.
+
+use std::{fmt, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    AnimationId, Mix, PlayOptions, PlaybackMode, SkeletonAsset, SkinId, TrackError, Transition,
+    player::{Playback, PlaybackWindow},
+};
+
+/// The save-state format version written by this crate.
+pub const ANIMATION_SAVE_STATE_VERSION: u32 = 1;
+
+/// A serde-serializable description of one mixer and its skeleton instance.
+///
+/// Created by [`crate::AnimationMixer::save_state`]. Animations and skins are
+/// stored by name; crossfades, weight fades and mixer configuration such as
+/// mix tables, sync groups and root motion are not part of the state. Bone
+/// bindings, such as the root-motion bone name, are not saved either; the
+/// restoring mixer keeps its own, as described on
+/// [`crate::AnimationMixer::restore_save_state`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationSaveState {
+    version: u32,
+    #[serde(default)]
+    skin_layers: Vec<Box<str>>,
+    base: TrackSaveState,
+    #[serde(default)]
+    tracks: Vec<TrackSaveState>,
+}
+
+impl AnimationSaveState {
+    pub(crate) const fn new(
+        skin_layers: Vec<Box<str>>,
+        base: TrackSaveState,
+        tracks: Vec<TrackSaveState>,
+    ) -> Self {
+        Self {
+            version: ANIMATION_SAVE_STATE_VERSION,
+            skin_layers,
+            base,
+            tracks,
+        }
+    }
+
+    /// Returns the format version this state was written with.
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the skeleton's skin layer names, lowest layer first.
+    pub fn skin_layers(&self) -> impl ExactSizeIterator<Item = &str> + '_ {
+        self.skin_layers.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns the base track.
+    #[must_use]
+    pub const fn base(&self) -> &TrackSaveState {
+        &self.base
+    }
+
+    /// Returns override tracks from low to high priority.
+    #[must_use]
+    pub fn tracks(&self) -> &[TrackSaveState] {
+        &self.tracks
+    }
+
+    /// Resolves every name against `asset`, collecting all mismatches.
+    pub(crate) fn resolve(
+        &self,
+        asset: &SkeletonAsset,
+    ) -> Result<ResolvedSaveState, Box<[SaveStateMismatch]>> {
+        let mut mismatches = Vec::new();
+        if self.version != ANIMATION_SAVE_STATE_VERSION {
+            mismatches.push(SaveStateMismatch::UnsupportedVersion {
+                found: self.version,
+            });
+        }
+        let skin_layers = self
+            .skin_layers
+            .iter()
+            .filter_map(|name| {
+                let skin = asset.skin_id(name);
+                if skin.is_none() {
+                    mismatches.push(SaveStateMismatch::UnknownSkin(name.clone()));
+                }
+                skin
+            })
+            .collect();
+        let base = self.base.resolve(asset, SavedTrack::Base, &mut mismatches);
+        let tracks = self
+            .tracks
+            .iter()
+            .enumerate()
+            .map(|(index, track)| {
+                track.resolve(asset, SavedTrack::Override(index), &mut mismatches)
+            })
+            .collect();
+        if mismatches.is_empty() {
+            Ok(ResolvedSaveState {
+                skin_layers,
+                base,
+                tracks,
+            })
+        } else {
+            Err(mismatches.into_boxed_slice())
+        }
+    }
+}
+
+/// The saved state of the base track or one override track.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TrackSaveState {
+    #[serde(default)]
+    playback: Option<PlaybackSaveState>,
+    #[serde(default = "unit_value")]
+    weight: f32,
+    #[serde(default)]
+    paused: bool,
+    #[serde(default = "unit_value")]
+    speed: f32,
+}
+
+impl TrackSaveState {
+    pub(crate) const fn new(
+        playback: Option<PlaybackSaveState>,
+        weight: f32,
+        paused: bool,
+        speed: f32,
+    ) -> Self {
+        Self {
+            playback,
+            weight,
+            paused,
+            speed,
+        }
+    }
+
+    /// Returns the playing animation, or `None` for an idle track.
+    #[must_use]
+    pub const fn playback(&self) -> Option<&PlaybackSaveState> {
+        self.playback.as_ref()
+    }
+
+    /// Returns the presented track weight; the base track always saves one.
+    #[must_use]
+    pub const fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Returns whether the track clock was paused.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the track clock speed.
+    #[must_use]
+    pub const fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    fn resolve(
+        &self,
+        asset: &SkeletonAsset,
+        track: SavedTrack,
+        mismatches: &mut Vec<SaveStateMismatch>,
+    ) -> ResolvedTrack {
+        let weight = Mix::new(self.weight).unwrap_or_else(|_| {
+            mismatches.push(SaveStateMismatch::InvalidWeight(track));
+            Mix::ONE
+        });
+        if !self.speed.is_finite() || self.speed < 0.0 {
+            mismatches.push(SaveStateMismatch::InvalidSpeed(track));
+        }
+        ResolvedTrack {
+            playback: self
+                .playback
+                .as_ref()
+                .and_then(|playback| playback.resolve(asset, track, mismatches)),
+            weight,
+            paused: self.paused,
+            speed: self.speed,
+        }
+    }
+}
+
+/// One saved playing animation and its exact clock.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlaybackSaveState {
+    animation: Box<str>,
+    #[serde(default)]
+    mode: PlaybackMode,
+    position: Duration,
+    #[serde(default)]
+    loop_index: u128,
+    #[serde(default)]
+    range: Option<(Duration, Duration)>,
+    #[serde(default)]
+    loop_range: Option<(Duration, Duration)>,
+}
+
+impl PlaybackSaveState {
+    pub(crate) fn new(asset: &SkeletonAsset, playback: Playback) -> Self {
+        let options = playback.options(Transition::Immediate);
+        let animation = asset
+            .animation(playback.animation)
+            .expect("a playing animation belongs to its animator's asset");
+        Self {
+            animation: animation.name().into(),
+            mode: playback.mode,
+            position: Duration::from_nanos(playback.local_ticks),
+            loop_index: playback.loop_index,
+            range: options
+                .range()
+                .filter(|range| *range != (Duration::ZERO, animation.duration())),
+            loop_range: options.loop_range(),
+        }
+    }
+
+    /// Returns the animation name.
+    #[must_use]
+    pub fn animation(&self) -> &str {
+        &self.animation
+    }
+
+    /// Returns the playback mode.
+    #[must_use]
+    pub const fn mode(&self) -> PlaybackMode {
+        self.mode
+    }
+
+    /// Returns the animation-local clock position.
+    #[must_use]
+    pub const fn position(&self) -> Duration {
+        self.position
+    }
+
+    /// Returns the zero-based loop index.
+    #[must_use]
+    pub const fn loop_index(&self) -> u128 {
+        self.loop_index
+    }
+
+    /// Returns the playback window, or `None` for the whole animation.
+    #[must_use]
+    pub const fn range(&self) -> Option<(Duration, Duration)> {
+        self.range
+    }
+
+    /// Returns the unreleased loop range, if any.
+    #[must_use]
+    pub const fn loop_range(&self) -> Option<(Duration, Duration)> {
+        self.loop_range
+    }
+
+    fn resolve(
+        &self,
+        asset: &SkeletonAsset,
+        track: SavedTrack,
+        mismatches: &mut Vec<SaveStateMismatch>,
+    ) -> Option<ResolvedPlayback> {
+        let Some(animation) = asset.animation_id(&self.animation) else {
+            mismatches.push(SaveStateMismatch::UnknownAnimation {
+                track,
+                name: self.animation.clone(),
+            });
+            return None;
+        };
+        let mut options = match self.mode {
+            PlaybackMode::Once => PlayOptions::once(),
+            PlaybackMode::Loop => PlayOptions::looping(),
+        };
+        if let Some((start, end)) = self.range {
+            options = options.with_range(start, end);
+        }
+        if let Some((start, end)) = self.loop_range {
+            options = options.with_loop_range(start, end);
+        }
+        let duration_ticks = asset
+            .animation_data(
+                asset
+                    .animation_index(animation)
+                    .expect("a resolved name belongs to the asset"),
+            )
+            .duration
+            .ticks;
+        let position_ticks = u64::try_from(self.position.as_nanos()).ok();
+        let in_range = PlaybackWindow::new(options, duration_ticks)
+            .ok()
+            .zip(position_ticks)
+            .is_some_and(|(window, position)| window.contains(position));
+        if !in_range {
+            mismatches.push(SaveStateMismatch::OutOfRange {
+                track,
+                name: self.animation.clone(),
+            });
+            return None;
+        }
+        Some(ResolvedPlayback {
+            animation,
+            options,
+            position_ticks: position_ticks.expect("an in-range position fits in u64 ticks"),
+            loop_index: self.loop_index,
+        })
+    }
+}
+
+const fn unit_value() -> f32 {
+    1.0
+}
+
+/// Identifies one track within an [`AnimationSaveState`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SavedTrack {
+    /// The permanent base track.
+    Base,
+    /// The override track at this low-to-high priority index.
+    Override(usize),
+}
+
+impl fmt::Display for SavedTrack {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base => formatter.write_str("the base track"),
+            Self::Override(index) => write!(formatter, "override track {index}"),
+        }
+    }
+}
+
+/// One way a save state disagrees with the asset it is restored against.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum SaveStateMismatch {
+    /// The state was written with an unsupported format version.
+    #[error("save state version {found} is not supported")]
+    UnsupportedVersion {
+        /// The version stored in the state.
+        found: u32,
+    },
+    /// A skin layer names a skin missing from the asset.
+    #[error("skin `{0}` does not exist in the skeleton asset")]
+    UnknownSkin(Box<str>),
+    /// A track plays an animation missing from the asset.
+    #[error("{track} plays animation `{name}`, which does not exist in the skeleton asset")]
+    UnknownAnimation {
+        /// The track that played the animation.
+        track: SavedTrack,
+        /// The saved animation name.
+        name: Box<str>,
+    },
+    /// A saved position or range lies outside the asset's animation, for
+    /// example because the animation was shortened.
+    #[error("{track} position or range lies outside animation `{name}`")]
+    OutOfRange {
+        /// The track that played the animation.
+        track: SavedTrack,
+        /// The saved animation name.
+        name: Box<str>,
+    },
+    /// A saved track weight is not a normalized mix.
+    #[error("{0} weight is not within zero and one")]
+    InvalidWeight(SavedTrack),
+    /// A saved track speed is negative or non-finite.
+    #[error("{0} speed must be finite and nonnegative")]
+    InvalidSpeed(SavedTrack),
+}
+
+/// A failure to save or restore animation state.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum SaveStateError {
+    /// The skeleton is not the instance the mixer is bound to.
+    #[error("the animation mixer is bound to a different skeleton instance")]
+    ForeignSkeleton,
+    /// The state does not match the asset; nothing was changed.
+    #[error("the save state does not match the skeleton asset ({} mismatches)", .0.len())]
+    Mismatch(Box<[SaveStateMismatch]>),
+    /// The mixer cannot create the saved override tracks; nothing was
+    /// changed.
+    #[error("the saved override tracks cannot be created: {0}")]
+    Track(
+        #[doc = "The underlying track error."]
+        #[source]
+        TrackError,
+    ),
+}
+
+impl SaveStateError {
+    /// Returns every mismatch found, or an empty slice for other failures.
+    #[must_use]
+    pub fn mismatches(&self) -> &[SaveStateMismatch] {
+        match self {
+            Self::Mismatch(mismatches) => mismatches,
+            Self::ForeignSkeleton | Self::Track(_) => &[],
+        }
+    }
+}
+
+pub(crate) struct ResolvedSaveState {
+    pub(crate) skin_layers: Vec<SkinId>,
+    pub(crate) base: ResolvedTrack,
+    pub(crate) tracks: Vec<ResolvedTrack>,
+}
+
+pub(crate) struct ResolvedTrack {
+    pub(crate) playback: Option<ResolvedPlayback>,
+    pub(crate) weight: Mix,
+    pub(crate) paused: bool,
+    pub(crate) speed: f32,
+}
+
+pub(crate) struct ResolvedPlayback {
+    pub(crate) animation: AnimationId,
+    pub(crate) options: PlayOptions,
+    pub(crate) position_ticks: u64,
+    pub(crate) loop_index: u128,
+}
@@ -1,5 +1,5 @@
 use std::{
-    mem,
+    num::NonZeroU64,
     ops::Range,
     sync::{
         Arc,
@@ -12,8 +12,8 @@ use glam::Vec2;
 
 use crate::{
     Angle, AnimationId, AttachmentId, BendDirection, BoneId, BoneTransform, IdError,
-    IkConstraintId, Mix, Rgba, Shear, SkeletonAsset, SkinId, SlotId, TransformConstraintId,
-    TransformMix,
+    IkConstraintId, Mix, Rgba, Shear, SkeletonAsset, SkinId, SlotId, TextureOverride,
+    TransformConstraintId, TransformMix,
     animation::{
         PlaybackMode, TimelineData, resolve_sample_time, sample_attachment, sample_colour,
         sample_draw_order, sample_ik, sample_scalar, sample_transform, sample_vec2,
@@ -25,11 +25,59 @@ use crate::{
         AngleBranches, BlendSwitches, BonePose, ContributionPose, IkConstraintPose, PoseBuffers,
         SlotPose, WeightedContribution,
     },
+    runtime_constraint::RuntimeConstraintEntry,
+    runtime_skin::{LayerRef, SkinStack},
+    snapshot::{SkeletonSnapshot, SnapshotError},
     world::WorldTransform,
 };
 
 static NEXT_INSTANCE_KEY: AtomicU64 = AtomicU64::new(1);
 
+/// Writes one mesh attachment's skeleton-space vertices for the given bone
+/// world transforms. Non-mesh attachments leave `output` untouched.
+pub(crate) fn skin_mesh(
+    asset: &SkeletonAsset,
+    attachment_index: usize,
+    world_transforms: &[WorldTransform],
+    output: &mut [Vec2],
+) {
+    let attachment = asset.attachment_data(attachment_index);
+    let AttachmentDataKind::Mesh(mesh) = &attachment.kind else {
+        return;
+    };
+    let geometry = asset.mesh_geometry_data(mesh.geometry as usize);
+    match &geometry.vertices {
+        MeshVerticesData::Unweighted(vertices) => {
+            let bone = asset.slot_data(attachment.slot as usize).bone as usize;
+            let world = world_transforms[bone];
+            for (position, local) in output.iter_mut().zip(vertices.iter().copied()) {
+                *position = world.transform_point(local);
+            }
+        }
+        MeshVerticesData::Weighted {
+            vertices,
+            influences,
+        } => {
+            for (position, influence_range) in output.iter_mut().zip(vertices) {
+                let mut blended_x = 0.0_f64;
+                let mut blended_y = 0.0_f64;
+                for influence in
+                    &influences[influence_range.start as usize..influence_range.end as usize]
+                {
+                    let transformed = world_transforms[influence.bone as usize]
+                        .transform_point(influence.bind_position);
+                    blended_x += f64::from(transformed.x) * f64::from(influence.weight);
+                    blended_y += f64::from(transformed.y) * f64::from(influence.weight);
+                }
+                *position = Vec2::new(
+                    saturating_mesh_component(blended_x),
+                    saturating_mesh_component(blended_y),
+                );
+            }
+        }
+    }
+}
+
 fn saturating_mesh_component(value: f64) -> f32 {
     if value.is_nan() {
         0.0
@@ -38,7 +86,7 @@ fn saturating_mesh_component(value: f64) -> f32 {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub(crate) struct SkeletonInstanceKey(u64);
 
 /// An owned mutable runtime instance of one immutable skeleton asset.
@@ -58,9 +106,15 @@ pub struct Skeleton {
     pub(crate) mesh_world_positions: Box<[Vec2]>,
     pub(crate) mesh_vertex_ranges: Box<[Range<usize>]>,
     draw_order_scratch: Box<[u32]>,
+    /// The authored entries of `skin_stack`, from low to high priority.
     pub(crate) skin_layers: Vec<u32>,
-    skin_layer_scratch: Vec<u32>,
-    skin_revision: u64,
+    pub(crate) skin_layer_scratch: Vec<LayerRef>,
+    pub(crate) skin_stack: SkinStack,
+    pub(crate) next_runtime_skin: NonZeroU64,
+    pub(crate) skin_revision: u64,
+    pub(crate) texture_overrides: Box<[Option<TextureOverride>]>,
+    pub(crate) runtime_constraints: Vec<RuntimeConstraintEntry>,
+    pub(crate) next_runtime_constraint: NonZeroU64,
 }
 
 impl Skeleton {
@@ -97,6 +151,8 @@ impl Skeleton {
         let mesh_world_positions = vec![Vec2::ZERO; mesh_vertex_count].into_boxed_slice();
         let skin_layers = Vec::with_capacity(asset.skin_count());
         let skin_layer_scratch = Vec::with_capacity(asset.skin_count());
+        let skin_stack = SkinStack::with_capacity(asset.skin_count());
+        let texture_overrides = vec![None; asset.attachments().len()].into_boxed_slice();
         let mut skeleton = Self {
             instance_key,
             asset,
@@ -110,7 +166,12 @@ impl Skeleton {
             draw_order_scratch,
             skin_layers,
             skin_layer_scratch,
+            skin_stack,
+            next_runtime_skin: NonZeroU64::MIN,
             skin_revision: 0,
+            texture_overrides,
+            runtime_constraints: Vec::new(),
+            next_runtime_constraint: NonZeroU64::MIN,
         };
         skeleton.reset_slot_attachments_to_setup_pose();
         skeleton
@@ -122,42 +183,12 @@ impl Skeleton {
             if range.is_empty() {
                 continue;
             }
-            let attachment = self.asset.attachment_data(attachment_index);
-            let AttachmentDataKind::Mesh(mesh) = &attachment.kind else {
-                continue;
-            };
-            let geometry = self.asset.mesh_geometry_data(mesh.geometry as usize);
-            let output = &mut self.mesh_world_positions[range];
-            match &geometry.vertices {
-                MeshVerticesData::Unweighted(vertices) => {
-                    let bone = self.asset.slot_data(attachment.slot as usize).bone as usize;
-                    let world = self.world_transforms[bone];
-                    for (position, local) in output.iter_mut().zip(vertices.iter().copied()) {
-                        *position = world.transform_point(local);
-                    }
-                }
-                MeshVerticesData::Weighted {
-                    vertices,
-                    influences,
-                } => {
-                    for (position, influence_range) in output.iter_mut().zip(vertices) {
-                        let mut blended_x = 0.0_f64;
-                        let mut blended_y = 0.0_f64;
-                        for influence in &influences
-                            [influence_range.start as usize..influence_range.end as usize]
-                        {
-                            let transformed = self.world_transforms[influence.bone as usize]
-                                .transform_point(influence.bind_position);
-                            blended_x += f64::from(transformed.x) * f64::from(influence.weight);
-                            blended_y += f64::from(transformed.y) * f64::from(influence.weight);
-                        }
-                        *position = Vec2::new(
-                            saturating_mesh_component(blended_x),
-                            saturating_mesh_component(blended_y),
-                        );
-                    }
-                }
-            }
+            skin_mesh(
+                &self.asset,
+                attachment_index,
+                &self.world_transforms,
+                &mut self.mesh_world_positions[range],
+            );
         }
     }
 
@@ -173,6 +204,98 @@ impl Skeleton {
         &self.asset
     }
 
+    /// Captures the local pose, skin layers, latest solved output and the
+    /// state of runtime constraints for a later [`Self::restore`].
+    #[must_use]
+    pub fn snapshot(&self) -> SkeletonSnapshot {
+        SkeletonSnapshot {
+            skeleton: Self {
+                instance_key: self.instance_key,
+                asset: Arc::clone(&self.asset),
+                pose: self.pose.clone(),
+                applied_bones: self.applied_bones.clone(),
+                world_transforms: self.world_transforms.clone(),
+                ik_solve_statuses: self.ik_solve_statuses.clone(),
+                transform_solve_statuses: self.transform_solve_statuses.clone(),
+                mesh_world_positions: self.mesh_world_positions.clone(),
+                mesh_vertex_ranges: self.mesh_vertex_ranges.clone(),
+                draw_order_scratch: self.draw_order_scratch.clone(),
+                skin_layers: self.skin_layers.clone(),
+                skin_layer_scratch: Vec::with_capacity(self.skin_layer_scratch.capacity()),
+                skin_stack: self.skin_stack.layers_only(),
+                next_runtime_skin: NonZeroU64::MIN,
+                skin_revision: self.skin_revision,
+                texture_overrides: vec![None; self.texture_overrides.len()].into_boxed_slice(),
+                runtime_constraints: Vec::new(),
+                next_runtime_constraint: NonZeroU64::MIN,
+            },
+            runtime_constraints: self.snapshot_runtime_constraints(),
+        }
+    }
+
+    /// Captures this instance into an existing snapshot.
+    ///
+    /// A snapshot previously taken from an instance of the same asset with
+    /// the same runtime constraints is overwritten in place without
+    /// allocating.
+    pub fn snapshot_into(&self, snapshot: &mut SkeletonSnapshot) {
+        if Arc::ptr_eq(&snapshot.skeleton.asset, &self.asset) {
+            snapshot.skeleton.copy_state_from(self);
+            snapshot.skeleton.instance_key = self.instance_key;
+            snapshot.skeleton.skin_revision = self.skin_revision;
+            self.snapshot_runtime_constraints_into(&mut snapshot.runtime_constraints);
+        } else {
+            *snapshot = self.snapshot();
+        }
+    }
+
+    /// Replaces this instance's pose, skin layers and solved output with a
+    /// snapshot of any instance of the same asset.
+    ///
+    /// Only restoring more skin layers than this instance has held before
+    /// allocates.
+    ///
+    /// Players and mixers bound to this instance observe a restored skin like
+    /// any other skin change unless they are restored from the same frame.
+    /// Runtime constraints stay registered with their instance; those
+    /// captured from this instance get their status and state back.
+    pub fn restore(&mut self, snapshot: &SkeletonSnapshot) -> Result<(), SnapshotError> {
+        let source = &snapshot.skeleton;
+        if !Arc::ptr_eq(&source.asset, &self.asset) {
+            return Err(SnapshotError::ForeignAsset);
+        }
+        self.copy_state_from(source);
+        self.restore_runtime_constraints(&snapshot.runtime_constraints);
+        // Runtime skins stay with their instance; layers naming skins this
+        // instance lacks are dropped.
+        let dropped = self.skin_stack.retain_registered();
+        self.sync_authored_skin_layers();
+        self.skin_revision = if source.instance_key == self.instance_key && !dropped {
+            source.skin_revision
+        } else {
+            // Another instance's revisions are unrelated to the ones this
+            // instance's animators have observed, and a stack that lost layers
+            // no longer matches the recorded revision.
+            self.skin_revision.wrapping_add(1)
+        };
+        Ok(())
+    }
+
+    fn copy_state_from(&mut self, source: &Self) {
+        self.pose.copy_from(&source.pose);
+        self.applied_bones.copy_from_slice(&source.applied_bones);
+        self.world_transforms
+            .copy_from_slice(&source.world_transforms);
+        self.ik_solve_statuses
+            .copy_from_slice(&source.ik_solve_statuses);
+        self.transform_solve_statuses
+            .copy_from_slice(&source.transform_solve_statuses);
+        self.mesh_world_positions
+            .copy_from_slice(&source.mesh_world_positions);
+        self.skin_layers.clone_from(&source.skin_layers);
+        self.skin_stack.layers.clone_from(&source.skin_stack.layers);
+    }
+
     /// Resets bones, slots, IK state, and draw order to setup pose.
     ///
     /// Active skin layers are preserved and are used to resolve setup
@@ -197,6 +320,17 @@ impl Skeleton {
         self.pose.active_animations.clear();
     }
 
+    pub(crate) fn pin_root_motion_bone(&mut self, index: usize) {
+        let setup = self.asset.bone_data(index).setup_transform;
+        let current = self.pose.bones[index].local_transform;
+        self.pose.bones[index].local_transform = runtime_transform(
+            setup.translation(),
+            setup.rotation(),
+            current.scale(),
+            current.shear(),
+        );
+    }
+
     /// Borrows one unconstrained local bone pose after validating its asset
     /// identity.
     ///
@@ -270,7 +404,10 @@ impl Skeleton {
         })
     }
 
-    /// Iterates selected attachment-only skin layers from low to high priority.
+    /// Iterates selected authored attachment-only skin layers from low to high
+    /// priority.
+    ///
+    /// Runtime skins are listed by [`Self::skin_layer_stack`].
     pub fn skin_layers(&self) -> impl DoubleEndedIterator<Item = SkinId> + ExactSizeIterator + '_ {
         self.skin_layers
             .iter()
@@ -284,30 +421,17 @@ impl Skeleton {
     /// layer ID is repeated. Missing entries continue through lower layers and
     /// then the default skin. The change immediately restores every slot's
     /// setup attachment through the new composition; colours and draw order
-    /// are unchanged.
+    /// are unchanged. Runtime skin layers are removed; use
+    /// [`Self::set_skin_layer_stack`] to keep them.
     pub fn set_skin_layers(&mut self, layers: &[SkinId]) -> Result<(), IdError> {
-        self.skin_layer_scratch.clear();
-        for id in layers {
-            let _index = self.asset.skin_index(*id)?;
-        }
-        for id in layers.iter().rev() {
-            let index = self
-                .asset
-                .skin_index(*id)
-                .expect("all layer IDs were validated above") as u32;
-            if !self.skin_layer_scratch.contains(&index) {
-                self.skin_layer_scratch.push(index);
-            }
-        }
-        self.skin_layer_scratch.reverse();
-        mem::swap(&mut self.skin_layers, &mut self.skin_layer_scratch);
-        self.skin_layer_scratch.clear();
-        self.reset_slot_attachments_to_setup_pose();
-        self.skin_revision = self.skin_revision.wrapping_add(1);
-        Ok(())
+        self.replace_skin_layers(layers, |skeleton, skin| {
+            let index = skeleton.asset.skin_index(skin)?;
+            Ok(LayerRef::Authored(index as u32))
+        })
     }
 
-    /// Resolves a placeholder through active layers and the default skin.
+    /// Resolves a placeholder through active authored and runtime layers and
+    /// the default skin.
     pub fn resolve_attachment(
         &self,
         slot: SlotId,
@@ -315,8 +439,8 @@ impl Skeleton {
     ) -> Result<Option<AttachmentId>, IdError> {
         let slot = self.asset.slot_index(slot)? as u32;
         Ok(self
-            .asset
-            .resolve_attachment_index(&self.skin_layers, slot, placeholder_name)
+            .skin_stack
+            .resolve(&self.asset, slot, placeholder_name)
             .map(|index| AttachmentId::new(self.asset.key(), index)))
     }
 
@@ -331,8 +455,8 @@ impl Skeleton {
                     .attachment_placeholder_index(index as u32, placeholder)
             });
             pose.attachment = placeholder.and_then(|placeholder| {
-                self.asset
-                    .resolve_attachment_index(&self.skin_layers, index as u32, placeholder)
+                self.skin_stack
+                    .resolve(&self.asset, index as u32, placeholder)
             });
         }
     }
@@ -435,11 +559,7 @@ impl Skeleton {
                             self.asset.attachment_placeholder_index(*slot, placeholder)
                         });
                         pose.attachment = placeholder.and_then(|placeholder| {
-                            self.asset.resolve_attachment_index(
-                                &self.skin_layers,
-                                *slot,
-                                placeholder,
-                            )
+                            self.skin_stack.resolve(&self.asset, *slot, placeholder)
                         });
                     }
                 }
@@ -617,11 +737,9 @@ impl Skeleton {
                 .asset
                 .attachment_data(placeholder_index as usize)
                 .placeholder_name;
-            slot.attachment = self.asset.resolve_attachment_index(
-                &self.skin_layers,
-                slot_index as u32,
-                placeholder,
-            );
+            slot.attachment = self
+                .skin_stack
+                .resolve(&self.asset, slot_index as u32, placeholder);
         }
     }
 }
@@ -804,7 +922,35 @@ mod tests {
     use glam::Vec2;
 
     use super::*;
-    use crate::{Angle, IdErrorKind, Shear};
+    use crate::{Angle, IdErrorKind, RuntimeSkin, Shear, SkinAttachment, SkinLayer};
+
+    #[test]
+    fn restoring_over_a_removed_runtime_skin_changes_the_skin_revision() {
+        let asset = Arc::new(SkeletonAsset::test_fixture("cat"));
+        let mut skeleton = Skeleton::new(Arc::clone(&asset));
+        let outfit = RuntimeSkin::new(&asset, "outfit", Vec::<SkinAttachment>::new())
+            .expect("an empty pick is valid");
+        let outfit = skeleton
+            .insert_runtime_skin(outfit)
+            .expect("the skin belongs to the asset");
+        skeleton
+            .set_skin_layer_stack(&[SkinLayer::Runtime(outfit)])
+            .expect("the layer is registered");
+        let snapshot = skeleton.snapshot();
+        let captured = skeleton.skin_revision;
+
+        skeleton.restore(&snapshot).expect("same asset");
+        assert_eq!(
+            skeleton.skin_revision, captured,
+            "an unchanged stack keeps its revision"
+        );
+        skeleton
+            .remove_runtime_skin(outfit)
+            .expect("the skin is registered");
+        skeleton.restore(&snapshot).expect("same asset");
+        assert!(skeleton.skin_stack.layers.is_empty());
+        assert_ne!(skeleton.skin_revision, captured);
+    }
 
     #[test]
     fn instances_start_in_setup_pose_and_reuse_their_buffers() {
@@ -0,0 +1,919 @@
+//! Procedural skeletons assembled from typed definitions instead of JSON.
+
+use std::sync::Arc;
+
+use glam::Vec2;
+
+use crate::{
+    AlphaEncoding, BendDirection, LoadError, LoadErrorKind, LoadReport, Mix, PixelRect, PixelSize,
+    Rgba8, SlotBlendMode, TARGET_SPINE_VERSION, TextAtlas, TextureFilter, TextureFormat,
+    TransformMix, Trim, WrapMode,
+    atlas::{ParsedAtlasPage, ParsedAtlasRegion, assemble_atlas, texture_filter_token},
+    geometry::AtlasRotation,
+    json::{JsonMember, JsonValue},
+    load::{
+        link,
+        schema::{error, index_pointer, pointer},
+    },
+};
+
+/// A skeleton assembled from typed bones, slots, skins, constraints, and an
+/// in-memory atlas.
+///
+/// Definitions are recorded without validation and refer to each other by
+/// name, as Spine JSON does. [`Self::build`] links them with the loader's
+/// rules, so the resulting asset and its diagnostics are those of the
+/// equivalent [`load_json`](crate::load_json) call, and a failure is reported
+/// with the same [`LoadErrorKind`] and JSON pointer. Animations are added
+/// afterwards with [`build_animations`](crate::build_animations).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkeletonBuilder {
+    bones: Vec<BoneDefinition>,
+    slots: Vec<SlotDefinition>,
+    skins: Vec<SkinDefinition>,
+    constraints: Vec<ConstraintDefinition>,
+    atlas_pages: Vec<AtlasPageDefinition>,
+}
+
+impl SkeletonBuilder {
+    /// Creates a builder with no bones and no atlas pages.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends one bone. A parent must be appended before its children.
+    #[must_use]
+    pub fn with_bone(mut self, bone: BoneDefinition) -> Self {
+        self.bones.push(bone);
+        self
+    }
+
+    /// Appends one slot. Slots draw back to front in append order.
+    #[must_use]
+    pub fn with_slot(mut self, slot: SlotDefinition) -> Self {
+        self.slots.push(slot);
+        self
+    }
+
+    /// Appends one skin. A skin named `default` is the default skin.
+    #[must_use]
+    pub fn with_skin(mut self, skin: SkinDefinition) -> Self {
+        self.skins.push(skin);
+        self
+    }
+
+    /// Appends one IK constraint after the constraints already added.
+    #[must_use]
+    pub fn with_ik_constraint(mut self, constraint: IkConstraintDefinition) -> Self {
+        self.constraints.push(ConstraintDefinition::Ik(constraint));
+        self
+    }
+
+    /// Appends one transform constraint after the constraints already added.
+    #[must_use]
+    pub fn with_transform_constraint(mut self, constraint: TransformConstraintDefinition) -> Self {
+        self.constraints
+            .push(ConstraintDefinition::Transform(constraint));
+        self
+    }
+
+    /// Appends one atlas page and its regions.
+    #[must_use]
+    pub fn with_atlas_page(mut self, page: AtlasPageDefinition) -> Self {
+        self.atlas_pages.push(page);
+        self
+    }
+
+    /// Validates and links the definitions into a fresh asset.
+    ///
+    /// Skeleton errors are located in the [`SkeletonJson`] document and
+    /// atlas errors in the [`Atlas`] document, at the pointers the equivalent
+    /// texts would use.
+    ///
+    /// [`SkeletonJson`]: crate::LoadDocument::SkeletonJson
+    /// [`Atlas`]: crate::LoadDocument::Atlas
+    pub fn build(&self) -> Result<LoadReport, LoadError> {
+        let atlas = TextAtlas::from_parsed(self.atlas()?)?;
+        let root = self.document()?;
+        link(&root, &[Arc::new(atlas)])
+    }
+
+    fn atlas(&self) -> Result<crate::atlas::ParsedAtlas, LoadError> {
+        let mut pages = Vec::with_capacity(self.atlas_pages.len());
+        let mut regions = Vec::new();
+        for (page_index, page) in self.atlas_pages.iter().enumerate() {
+            let first_region = regions.len();
+            regions.extend(page.regions.iter().map(|region| ParsedAtlasRegion {
+                page: page_index,
+                name: region.name.clone(),
+                index: region.index,
+                bounds: region.bounds,
+                offsets: region.offsets.unwrap_or_else(|| {
+                    Trim::new(0, 0, region.bounds.width(), region.bounds.height())
+                }),
+                rotation: if region.rotated {
+                    AtlasRotation::QUARTER
+                } else {
+                    AtlasRotation::ZERO
+                },
+                split: None,
+                pad: None,
+                extensions: Vec::new(),
+            }));
+            pages.push(ParsedAtlasPage {
+                name: page.name.clone(),
+                size: page.size,
+                format: TextureFormat::Rgba8888,
+                format_token: "RGBA8888".into(),
+                min_filter: page.min_filter,
+                min_filter_token: texture_filter_token(page.min_filter).into(),
+                mag_filter: page.mag_filter,
+                mag_filter_token: texture_filter_token(page.mag_filter).into(),
+                wrap: page.wrap,
+                alpha_encoding: page.alpha_encoding,
+                scale: 1.0,
+                region_range: first_region..regions.len(),
+                extensions: Vec::new(),
+            });
+        }
+        assemble_atlas(pages, regions)
+    }
+
+    fn document(&self) -> Result<JsonValue, LoadError> {
+        let mut skins = Vec::with_capacity(self.skins.len());
+        for (index, skin) in self.skins.iter().enumerate() {
+            skins.push(self.skin(skin, &index_pointer("/skins", index))?);
+        }
+        Ok(object([
+            (
+                "skeleton",
+                object([("spine", string(TARGET_SPINE_VERSION))]),
+            ),
+            (
+                "bones",
+                array(self.bones.iter().map(BoneDefinition::document)),
+            ),
+            (
+                "slots",
+                array(self.slots.iter().map(SlotDefinition::document)),
+            ),
+            ("skins", array(skins)),
+            (
+                "constraints",
+                array(self.constraints.iter().map(ConstraintDefinition::document)),
+            ),
+        ]))
+    }
+
+    fn skin(&self, skin: &SkinDefinition, path: &str) -> Result<JsonValue, LoadError> {
+        let attachments_path = pointer(path, "attachments");
+        let mut slots: Vec<(&str, Vec<JsonMember>)> = Vec::new();
+        for (slot, name, attachment) in &skin.attachments {
+            let attachment_path = pointer(&pointer(&attachments_path, slot), name);
+            let value = match attachment {
+                AttachmentDefinition::Region(region) => region.document(),
+                AttachmentDefinition::Mesh(mesh) => mesh.document(&self.bones, &attachment_path)?,
+            };
+            let member = JsonMember::new(name.as_ref(), value);
+            match slots.iter_mut().find(|(name, _)| *name == slot.as_ref()) {
+                Some((_, members)) => members.push(member),
+                None => slots.push((slot, vec![member])),
+            }
+        }
+        let attachments = slots
+            .into_iter()
+            .map(|(slot, members)| JsonMember::new(slot, JsonValue::Object(members.into())))
+            .collect::<Box<_>>();
+        Ok(object([
+            ("name", string(&skin.name)),
+            ("attachments", JsonValue::Object(attachments)),
+        ]))
+    }
+}
+
+/// One bone's name, parent, and setup pose.
+///
+/// Angles are in degrees, as in Spine JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoneDefinition {
+    name: Box<str>,
+    parent: Option<Box<str>>,
+    length: f32,
+    translation: Vec2,
+    rotation: f32,
+    scale: Vec2,
+    shear: Vec2,
+}
+
+impl BoneDefinition {
+    /// Creates a root bone with the identity setup pose.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            parent: None,
+            length: 0.0,
+            translation: Vec2::ZERO,
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            shear: Vec2::ZERO,
+        }
+    }
+
+    /// Attaches the bone to a previously defined parent.
+    #[must_use]
+    pub fn with_parent(mut self, parent: &str) -> Self {
+        self.parent = Some(parent.into());
+        self
+    }
+
+    /// Replaces the bone length.
+    #[must_use]
+    pub const fn with_length(mut self, length: f32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Replaces the setup translation in the parent's space.
+    #[must_use]
+    pub const fn with_translation(mut self, translation: Vec2) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Replaces the setup rotation in degrees.
+    #[must_use]
+    pub const fn with_rotation(mut self, degrees: f32) -> Self {
+        self.rotation = degrees;
+        self
+    }
+
+    /// Replaces the setup scale.
+    #[must_use]
+    pub const fn with_scale(mut self, scale: Vec2) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Replaces the setup shear in degrees on each axis.
+    #[must_use]
+    pub const fn with_shear(mut self, degrees: Vec2) -> Self {
+        self.shear = degrees;
+        self
+    }
+
+    fn document(&self) -> JsonValue {
+        let mut members = vec![JsonMember::new("name", string(&self.name))];
+        if let Some(parent) = &self.parent {
+            members.push(JsonMember::new("parent", string(parent)));
+        }
+        members.extend([
+            JsonMember::new("length", number(self.length)),
+            JsonMember::new("x", number(self.translation.x)),
+            JsonMember::new("y", number(self.translation.y)),
+            JsonMember::new("rotation", number(self.rotation)),
+            JsonMember::new("scaleX", number(self.scale.x)),
+            JsonMember::new("scaleY", number(self.scale.y)),
+            JsonMember::new("shearX", number(self.shear.x)),
+            JsonMember::new("shearY", number(self.shear.y)),
+        ]);
+        JsonValue::Object(members.into())
+    }
+}
+
+/// One slot's bone, setup colour, setup attachment, and blend mode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlotDefinition {
+    name: Box<str>,
+    bone: Box<str>,
+    attachment: Option<Box<str>>,
+    color: Rgba8,
+    blend_mode: SlotBlendMode,
+}
+
+impl SlotDefinition {
+    /// Creates a white, normally blended slot on `bone` with nothing shown.
+    #[must_use]
+    pub fn new(name: &str, bone: &str) -> Self {
+        Self {
+            name: name.into(),
+            bone: bone.into(),
+            attachment: None,
+            color: Rgba8::WHITE,
+            blend_mode: SlotBlendMode::Normal,
+        }
+    }
+
+    /// Shows the named attachment placeholder in the setup pose.
+    #[must_use]
+    pub fn with_attachment(mut self, placeholder: &str) -> Self {
+        self.attachment = Some(placeholder.into());
+        self
+    }
+
+    /// Replaces the setup colour.
+    #[must_use]
+    pub const fn with_color(mut self, color: Rgba8) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Replaces the blend mode.
+    #[must_use]
+    pub const fn with_blend_mode(mut self, blend_mode: SlotBlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    fn document(&self) -> JsonValue {
+        let mut members = vec![
+            JsonMember::new("name", string(&self.name)),
+            JsonMember::new("bone", string(&self.bone)),
+        ];
+        if let Some(attachment) = &self.attachment {
+            members.push(JsonMember::new("attachment", string(attachment)));
+        }
+        let blend = match self.blend_mode {
+            SlotBlendMode::Normal => "normal",
+            SlotBlendMode::Additive => "additive",
+            SlotBlendMode::Multiply => "multiply",
+            SlotBlendMode::Screen => "screen",
+            SlotBlendMode::Unknown => "unknown",
+        };
+        members.extend([
+            JsonMember::new("color", color(self.color)),
+            JsonMember::new("blend", string(blend)),
+        ]);
+        JsonValue::Object(members.into())
+    }
+}
+
+/// One named skin's attachments, keyed by slot and placeholder name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkinDefinition {
+    name: Box<str>,
+    attachments: Vec<(Box<str>, Box<str>, AttachmentDefinition)>,
+}
+
+impl SkinDefinition {
+    /// Creates an empty skin.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            attachments: Vec::new(),
+        }
+    }
+
+    /// Adds an attachment answering `placeholder` on `slot`.
+    ///
+    /// Attachments are grouped by slot in the order each slot is first used.
+    #[must_use]
+    pub fn with_attachment(
+        mut self,
+        slot: &str,
+        placeholder: &str,
+        attachment: impl Into<AttachmentDefinition>,
+    ) -> Self {
+        self.attachments
+            .push((slot.into(), placeholder.into(), attachment.into()));
+        self
+    }
+}
+
+/// A drawable attachment placed in a [`SkinDefinition`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum AttachmentDefinition {
+    /// A textured quad.
+    Region(RegionDefinition),
+    /// A textured triangle mesh.
+    Mesh(MeshDefinition),
+}
+
+impl From<RegionDefinition> for AttachmentDefinition {
+    fn from(region: RegionDefinition) -> Self {
+        Self::Region(region)
+    }
+}
+
+impl From<MeshDefinition> for AttachmentDefinition {
+    fn from(mesh: MeshDefinition) -> Self {
+        Self::Mesh(mesh)
+    }
+}
+
+/// A region attachment's size, placement on its bone, and atlas region.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegionDefinition {
+    path: Option<Box<str>>,
+    size: Vec2,
+    translation: Vec2,
+    rotation: f32,
+    scale: Vec2,
+    color: Rgba8,
+}
+
+impl RegionDefinition {
+    /// Creates a white region of `size` centred on its bone.
+    ///
+    /// The atlas region defaults to the attachment's placeholder name.
+    #[must_use]
+    pub const fn new(size: Vec2) -> Self {
+        Self {
+            path: None,
+            size,
+            translation: Vec2::ZERO,
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            color: Rgba8::WHITE,
+        }
+    }
+
+    /// Draws the named atlas region instead of the placeholder name.
+    #[must_use]
+    pub fn with_path(mut self, path: &str) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Replaces the offset from the bone origin.
+    #[must_use]
+    pub const fn with_translation(mut self, translation: Vec2) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// Replaces the rotation on the bone in degrees.
+    #[must_use]
+    pub const fn with_rotation(mut self, degrees: f32) -> Self {
+        self.rotation = degrees;
+        self
+    }
+
+    /// Replaces the scale on the bone.
+    #[must_use]
+    pub const fn with_scale(mut self, scale: Vec2) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Replaces the attachment colour.
+    #[must_use]
+    pub const fn with_color(mut self, color: Rgba8) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn document(&self) -> JsonValue {
+        let mut members = vec![JsonMember::new("type", string("region"))];
+        if let Some(path) = &self.path {
+            members.push(JsonMember::new("path", string(path)));
+        }
+        members.extend([
+            JsonMember::new("x", number(self.translation.x)),
+            JsonMember::new("y", number(self.translation.y)),
+            JsonMember::new("rotation", number(self.rotation)),
+            JsonMember::new("scaleX", number(self.scale.x)),
+            JsonMember::new("scaleY", number(self.scale.y)),
+            JsonMember::new("width", number(self.size.x)),
+            JsonMember::new("height", number(self.size.y)),
+            JsonMember::new("color", color(self.color)),
+        ]);
+        JsonValue::Object(members.into())
+    }
+}
+
+/// A mesh attachment's triangles, UVs, and rigid or weighted vertices.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshDefinition {
+    path: Option<Box<str>>,
+    uvs: Box<[Vec2]>,
+    triangles: Box<[u32]>,
+    vertices: MeshVertices,
+    hull: u32,
+    color: Rgba8,
+}
+
+/// One weighted vertex's bone name, bind position, and weight.
+type MeshInfluence = (Box<str>, Vec2, f32);
+
+#[derive(Clone, Debug, PartialEq)]
+enum MeshVertices {
+    Rigid(Box<[Vec2]>),
+    Weighted(Box<[Box<[MeshInfluence]>]>),
+}
+
+impl MeshDefinition {
+    /// Creates a mesh whose vertices move rigidly with the slot's bone.
+    ///
+    /// `uvs` are normalized within the atlas region with a top-left origin,
+    /// one per vertex in `positions`, and the first `hull` vertices outline
+    /// the mesh.
+    #[must_use]
+    pub fn new(uvs: &[Vec2], triangles: &[u32], positions: &[Vec2], hull: u32) -> Self {
+        Self::with_vertices(uvs, triangles, MeshVertices::Rigid(positions.into()), hull)
+    }
+
+    /// Creates a mesh whose vertices are each bound to weighted bones.
+    ///
+    /// Every vertex lists `(bone, position in that bone's setup space,
+    /// weight)` influences whose weights sum to one.
+    #[must_use]
+    pub fn weighted(
+        uvs: &[Vec2],
+        triangles: &[u32],
+        vertices: &[&[(&str, Vec2, f32)]],
+        hull: u32,
+    ) -> Self {
+        let vertices = vertices
+            .iter()
+            .map(|influences| {
+                influences
+                    .iter()
+                    .map(|&(bone, position, weight)| (bone.into(), position, weight))
+                    .collect()
+            })
+            .collect();
+        Self::with_vertices(uvs, triangles, MeshVertices::Weighted(vertices), hull)
+    }
+
+    fn with_vertices(uvs: &[Vec2], triangles: &[u32], vertices: MeshVertices, hull: u32) -> Self {
+        Self {
+            path: None,
+            uvs: uvs.into(),
+            triangles: triangles.into(),
+            vertices,
+            hull,
+            color: Rgba8::WHITE,
+        }
+    }
+
+    /// Draws the named atlas region instead of the placeholder name.
+    #[must_use]
+    pub fn with_path(mut self, path: &str) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Replaces the attachment colour.
+    #[must_use]
+    pub const fn with_color(mut self, color: Rgba8) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn document(&self, bones: &[BoneDefinition], path: &str) -> Result<JsonValue, LoadError> {
+        let vertices = match &self.vertices {
+            MeshVertices::Rigid(positions) => positions
+                .iter()
+                .flat_map(|position| [number(position.x), number(position.y)])
+                .collect::<Vec<_>>(),
+            MeshVertices::Weighted(vertices) => {
+                let vertices_path = pointer(path, "vertices");
+                let mut stream = Vec::new();
+                for influences in vertices {
+                    stream.push(JsonValue::U64(influences.len() as u64));
+                    for (bone, position, weight) in influences {
+                        let index = bones
+                            .iter()
+                            .position(|definition| definition.name == *bone)
+                            .ok_or_else(|| {
+                                error(
+                                    LoadErrorKind::UnresolvedReference,
+                                    &index_pointer(&vertices_path, stream.len()),
+                                    format!("weighted mesh bone {bone:?} does not exist"),
+                                )
+                            })?;
+                        stream.extend([
+                            JsonValue::U64(index as u64),
+                            number(position.x),
+                            number(position.y),
+                            number(*weight),
+                        ]);
+                    }
+                }
+                stream
+            }
+        };
+        let mut members = vec![JsonMember::new("type", string("mesh"))];
+        if let Some(path) = &self.path {
+            members.push(JsonMember::new("path", string(path)));
+        }
+        members.extend([
+            JsonMember::new(
+                "uvs",
+                array(self.uvs.iter().flat_map(|uv| [number(uv.x), number(uv.y)])),
+            ),
+            JsonMember::new(
+                "triangles",
+                array(
+                    self.triangles
+                        .iter()
+                        .map(|&index| JsonValue::U64(u64::from(index))),
+                ),
+            ),
+            JsonMember::new("vertices", array(vertices)),
+            JsonMember::new("hull", JsonValue::U64(u64::from(self.hull))),
+            JsonMember::new("color", color(self.color)),
+        ]);
+        Ok(JsonValue::Object(members.into()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ConstraintDefinition {
+    Ik(IkConstraintDefinition),
+    Transform(TransformConstraintDefinition),
+}
+
+impl ConstraintDefinition {
+    fn document(&self) -> JsonValue {
+        match self {
+            Self::Ik(constraint) => constraint.document(),
+            Self::Transform(constraint) => constraint.document(),
+        }
+    }
+}
+
+/// A one- or two-bone IK constraint reaching for a target bone.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IkConstraintDefinition {
+    name: Box<str>,
+    bones: Box<[Box<str>]>,
+    target: Box<str>,
+    mix: Mix,
+    softness: f32,
+    bend_direction: BendDirection,
+    compress: bool,
+    stretch: bool,
+    uniform: bool,
+}
+
+impl IkConstraintDefinition {
+    /// Creates a fully mixed, positively bending constraint on `bones`,
+    /// parent first.
+    #[must_use]
+    pub fn new(name: &str, bones: &[&str], target: &str) -> Self {
+        Self {
+            name: name.into(),
+            bones: bones.iter().map(|&bone| bone.into()).collect(),
+            target: target.into(),
+            mix: Mix::ONE,
+            softness: 0.0,
+            bend_direction: BendDirection::Positive,
+            compress: false,
+            stretch: false,
+            uniform: false,
+        }
+    }
+
+    /// Replaces the setup mix.
+    #[must_use]
+    pub const fn with_mix(mut self, mix: Mix) -> Self {
+        self.mix = mix;
+        self
+    }
+
+    /// Replaces the two-bone softness distance.
+    #[must_use]
+    pub const fn with_softness(mut self, softness: f32) -> Self {
+        self.softness = softness;
+        self
+    }
+
+    /// Replaces the setup bend direction.
+    #[must_use]
+    pub const fn with_bend_direction(mut self, bend_direction: BendDirection) -> Self {
+        self.bend_direction = bend_direction;
+        self
+    }
+
+    /// Requests that a one-bone chain scales down to reach a near target.
+    #[must_use]
+    pub const fn with_compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Requests that the chain scales up to reach a far target.
+    #[must_use]
+    pub const fn with_stretch(mut self, stretch: bool) -> Self {
+        self.stretch = stretch;
+        self
+    }
+
+    /// Requests that compression and stretch scale both axes.
+    #[must_use]
+    pub const fn with_uniform(mut self, uniform: bool) -> Self {
+        self.uniform = uniform;
+        self
+    }
+
+    fn document(&self) -> JsonValue {
+        object([
+            ("type", string("ik")),
+            ("name", string(&self.name)),
+            ("bones", array(self.bones.iter().map(|bone| string(bone)))),
+            ("target", string(&self.target)),
+            ("mix", number(self.mix.get())),
+            ("softness", number(self.softness)),
+            (
+                "bendPositive",
+                JsonValue::Bool(self.bend_direction == BendDirection::Positive),
+            ),
+            ("compress", JsonValue::Bool(self.compress)),
+            ("stretch", JsonValue::Bool(self.stretch)),
+            ("uniform", JsonValue::Bool(self.uniform)),
+        ])
+    }
+}
+
+/// A transform constraint copying a source bone's world rotation.
+///
+/// This is the supported subset of Spine's transform constraints; the other
+/// channels keep a zero mix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransformConstraintDefinition {
+    name: Box<str>,
+    bones: Box<[Box<str>]>,
+    source: Box<str>,
+    mix_rotate: TransformMix,
+    rotation_offset: f32,
+}
+
+impl TransformConstraintDefinition {
+    /// Creates a fully mixed rotation copy from `source` onto `bones`.
+    #[must_use]
+    pub fn new(name: &str, bones: &[&str], source: &str) -> Self {
+        Self {
+            name: name.into(),
+            bones: bones.iter().map(|&bone| bone.into()).collect(),
+            source: source.into(),
+            mix_rotate: TransformMix::ONE,
+            rotation_offset: 0.0,
+        }
+    }
+
+    /// Replaces the setup rotation mix.
+    #[must_use]
+    pub const fn with_rotate_mix(mut self, mix: TransformMix) -> Self {
+        self.mix_rotate = mix;
+        self
+    }
+
+    /// Replaces the rotation offset in degrees.
+    #[must_use]
+    pub const fn with_rotation_offset(mut self, degrees: f32) -> Self {
+        self.rotation_offset = degrees;
+        self
+    }
+
+    fn document(&self) -> JsonValue {
+        let rotate = object([("to", object([("rotate", object([]))]))]);
+        object([
+            ("type", string("transform")),
+            ("name", string(&self.name)),
+            ("bones", array(self.bones.iter().map(|bone| string(bone)))),
+            ("source", string(&self.source)),
+            ("properties", object([("rotate", rotate)])),
+            ("rotation", number(self.rotation_offset)),
+            ("mixRotate", number(self.mix_rotate.get())),
+        ])
+    }
+}
+
+/// One in-memory atlas page and the regions packed on it.
+///
+/// Pages use the RGBA8888 format and unit scale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AtlasPageDefinition {
+    name: Box<str>,
+    size: PixelSize,
+    alpha_encoding: AlphaEncoding,
+    min_filter: TextureFilter,
+    mag_filter: TextureFilter,
+    wrap: WrapMode,
+    regions: Vec<AtlasRegionDefinition>,
+}
+
+impl AtlasPageDefinition {
+    /// Creates an empty straight-alpha, nearest-filtered, clamped page.
+    ///
+    /// `name` identifies the page image, as the first line of an atlas page
+    /// does.
+    #[must_use]
+    pub fn new(name: &str, size: PixelSize) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            alpha_encoding: AlphaEncoding::Straight,
+            min_filter: TextureFilter::Nearest,
+            mag_filter: TextureFilter::Nearest,
+            wrap: WrapMode::CLAMP,
+            regions: Vec::new(),
+        }
+    }
+
+    /// Appends one region packed on this page.
+    #[must_use]
+    pub fn with_region(mut self, region: AtlasRegionDefinition) -> Self {
+        self.regions.push(region);
+        self
+    }
+
+    /// Replaces the page's alpha encoding.
+    #[must_use]
+    pub const fn with_alpha_encoding(mut self, alpha_encoding: AlphaEncoding) -> Self {
+        self.alpha_encoding = alpha_encoding;
+        self
+    }
+
+    /// Replaces the minification and magnification filters.
+    #[must_use]
+    pub const fn with_filter(mut self, min: TextureFilter, mag: TextureFilter) -> Self {
+        self.min_filter = min;
+        self.mag_filter = mag;
+        self
+    }
+
+    /// Replaces the texture wrapping.
+    #[must_use]
+    pub const fn with_wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap = wrap;
+        self
+    }
+}
+
+/// One packed atlas region.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AtlasRegionDefinition {
+    name: Box<str>,
+    index: Option<u32>,
+    bounds: PixelRect,
+    offsets: Option<Trim>,
+    rotated: bool,
+}
+
+impl AtlasRegionDefinition {
+    /// Creates an untrimmed, unrotated region packed at `bounds`.
+    #[must_use]
+    pub fn new(name: &str, bounds: PixelRect) -> Self {
+        Self {
+            name: name.into(),
+            index: None,
+            bounds,
+            offsets: None,
+            rotated: false,
+        }
+    }
+
+    /// Replaces the sequence frame index.
+    #[must_use]
+    pub const fn with_index(mut self, index: u32) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Records whitespace trimmed from the original image.
+    #[must_use]
+    pub const fn with_offsets(mut self, offsets: Trim) -> Self {
+        self.offsets = Some(offsets);
+        self
+    }
+
+    /// Marks the region as packed a quarter turn counter-clockwise, as
+    /// `rotate: true` does in atlas text.
+    #[must_use]
+    pub const fn rotated(mut self) -> Self {
+        self.rotated = true;
+        self
+    }
+}
+
+fn object<const N: usize>(members: [(&str, JsonValue); N]) -> JsonValue {
+    JsonValue::Object(
+        members
+            .into_iter()
+            .map(|(name, value)| JsonMember::new(name, value))
+            .collect(),
+    )
+}
+
+fn array(values: impl IntoIterator<Item = JsonValue>) -> JsonValue {
+    JsonValue::Array(values.into_iter().collect())
+}
+
+fn string(value: &str) -> JsonValue {
+    JsonValue::String(value.into())
+}
+
+fn number(value: f32) -> JsonValue {
+    JsonValue::F64(f64::from(value))
+}
+
+fn color(value: Rgba8) -> JsonValue {
+    let [red, green, blue, alpha] = value.to_array();
+    JsonValue::String(format!("{red:02X}{green:02X}{blue:02X}{alpha:02X}").into())
+}
@@ -0,0 +1,51 @@
+//! Rollback snapshots of players, mixers and skeleton instances.
+
+use thiserror::Error;
+
+use crate::{
+    AnimationMixer, AnimationPlayer, Skeleton, runtime_constraint::RuntimeConstraintSnapshot,
+};
+
+/// The complete state of one [`AnimationPlayer`], captured for rollback.
+///
+/// Restoring a snapshot returns clocks, playback identities, crossfades, the
+/// presented pose and the player configuration to the captured values, so the
+/// same updates afterwards produce identical poses and events.
+#[derive(Debug)]
+pub struct PlayerSnapshot {
+    pub(crate) player: AnimationPlayer,
+}
+
+/// The complete state of one [`AnimationMixer`], captured for rollback.
+///
+/// This covers the base player, every override track with its weight fade,
+/// paused and speed settings, the track order, and sync groups.
+#[derive(Debug)]
+pub struct MixerSnapshot {
+    pub(crate) mixer: AnimationMixer,
+}
+
+/// The local pose, skin layers, latest solved output and runtime-constraint
+/// state of one [`Skeleton`], captured for rollback.
+#[derive(Debug)]
+pub struct SkeletonSnapshot {
+    pub(crate) skeleton: Skeleton,
+    pub(crate) runtime_constraints: Vec<RuntimeConstraintSnapshot>,
+}
+
+/// A failure to restore a snapshot into an incompatible owner.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// A skeleton snapshot was taken from an instance of another asset.
+    #[error("the snapshot was taken from a skeleton of another asset")]
+    ForeignAsset,
+    /// A player or mixer snapshot was taken from one bound to another
+    /// skeleton instance.
+    #[error("the snapshot was taken from an animator bound to another skeleton instance")]
+    ForeignSkeleton,
+    /// A mixer snapshot was taken from another mixer, whose track IDs do not
+    /// apply to this one.
+    #[error("the snapshot was taken from another mixer")]
+    ForeignMixer,
+}
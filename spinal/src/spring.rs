@@ -0,0 +1,409 @@
+//! Procedural spring-bone secondary motion.
+
+use std::{any::Any, num::NonZeroU32, time::Duration};
+
+use glam::Vec2;
+use thiserror::Error;
+
+use crate::{
+    Angle, BoneId, BoneTransform, ConstraintPose, IdError, RuntimeConstraint,
+    RuntimeConstraintStatus, SkeletonAsset, float,
+    frame::replace_rotation,
+    world::{normal_local_to_world, solve_world_rotation},
+};
+
+const DEFAULT_FREQUENCY: NonZeroU32 = NonZeroU32::new(120).expect("120 is nonzero");
+const DEFAULT_MAX_SUBSTEPS: NonZeroU32 = NonZeroU32::new(8).expect("eight is nonzero");
+
+/// Physical settings for a [`SpringChain`].
+///
+/// Stiffness pulls each bone tip toward its animated position in units of
+/// `1/s²`, damping resists tip velocity in `1/s`, and gravity accelerates tips
+/// in skeleton-space units per second squared.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpringOptions {
+    stiffness: f32,
+    damping: f32,
+    gravity: Vec2,
+    angle_limit: Option<Angle>,
+    frequency: NonZeroU32,
+    max_substeps: NonZeroU32,
+}
+
+impl SpringOptions {
+    /// A moderately stiff, damped spring without gravity or angular limit,
+    /// integrated at 120 Hz with at most eight substeps per solve.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stiffness: 200.0,
+            damping: 12.0,
+            gravity: Vec2::ZERO,
+            angle_limit: None,
+            frequency: DEFAULT_FREQUENCY,
+            max_substeps: DEFAULT_MAX_SUBSTEPS,
+        }
+    }
+
+    /// Sets the pull toward the animated pose.
+    #[must_use]
+    pub const fn with_stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Sets the velocity damping.
+    #[must_use]
+    pub const fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Sets the skeleton-space gravity acceleration.
+    #[must_use]
+    pub const fn with_gravity(mut self, gravity: Vec2) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Limits how far each bone may swing from its animated rotation.
+    #[must_use]
+    pub const fn with_angle_limit(mut self, limit: Option<Angle>) -> Self {
+        self.angle_limit = limit;
+        self
+    }
+
+    /// Sets the fixed integration rate in substeps per second.
+    #[must_use]
+    pub const fn with_frequency(mut self, frequency: NonZeroU32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Caps the substeps of one solve; time beyond the cap is dropped so a
+    /// long stall cannot cascade.
+    #[must_use]
+    pub const fn with_max_substeps(mut self, max_substeps: NonZeroU32) -> Self {
+        self.max_substeps = max_substeps;
+        self
+    }
+
+    /// Returns the pull toward the animated pose.
+    #[must_use]
+    pub const fn stiffness(self) -> f32 {
+        self.stiffness
+    }
+
+    /// Returns the velocity damping.
+    #[must_use]
+    pub const fn damping(self) -> f32 {
+        self.damping
+    }
+
+    /// Returns the skeleton-space gravity acceleration.
+    #[must_use]
+    pub const fn gravity(self) -> Vec2 {
+        self.gravity
+    }
+
+    /// Returns the largest swing from the animated rotation, if limited.
+    #[must_use]
+    pub const fn angle_limit(self) -> Option<Angle> {
+        self.angle_limit
+    }
+
+    /// Returns the fixed integration rate in substeps per second.
+    #[must_use]
+    pub const fn frequency(self) -> NonZeroU32 {
+        self.frequency
+    }
+
+    /// Returns the substep cap of one solve.
+    #[must_use]
+    pub const fn max_substeps(self) -> NonZeroU32 {
+        self.max_substeps
+    }
+}
+
+impl Default for SpringOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A failure to build a [`SpringChain`].
+#[derive(Clone, Copy, Debug, Error, PartialEq)]
+#[non_exhaustive]
+pub enum SpringChainError {
+    /// The chain has no bones.
+    #[error("a spring chain needs at least one bone")]
+    EmptyChain,
+    /// A bone belongs to another asset.
+    #[error("the spring chain bone is invalid: {0}")]
+    InvalidBone(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// A bone is not a direct child of the bone before it.
+    #[error("spring chain bone {bone:?} is not a child of the previous bone")]
+    Disconnected {
+        /// The first bone that breaks the parent chain.
+        bone: BoneId,
+    },
+    /// A stiffness, damping or gravity setting is negative or not finite.
+    #[error("the spring {0} must be finite and non-negative")]
+    InvalidSetting(&'static str),
+}
+
+/// Secondary motion for a parent-to-child bone chain such as a tail or an
+/// ear tip.
+///
+/// Each bone tip is a damped particle pulled toward its animated position and
+/// integrated at a fixed rate, so the same inputs produce the same motion on
+/// every frame rate. Register the chain with
+/// [`crate::Skeleton::insert_runtime_constraint`] at
+/// [`crate::ConstraintPosition::First`] to run it after animation and before
+/// authored constraints. It integrates over [`ConstraintPose::elapsed`] and
+/// reacts to skeleton-space motion between solves; report engine-side motion
+/// of the whole skeleton with [`Self::move_skeleton`].
+#[derive(Clone, Debug)]
+pub struct SpringChain {
+    bones: Box<[SpringBone]>,
+    options: SpringOptions,
+    accumulated: Duration,
+    state: ChainState,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SpringBone {
+    id: BoneId,
+    length: f32,
+    animated: BoneTransform,
+    tip: Vec2,
+    velocity: Vec2,
+    target: Vec2,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ChainState {
+    /// Tips snap to the next animated pose.
+    Reset,
+    /// Tips keep their offsets from the next animated pose.
+    Teleported,
+    Running,
+}
+
+impl SpringChain {
+    /// Validates a root-to-tip chain of `bones` against `asset`.
+    pub fn new(
+        asset: &SkeletonAsset,
+        bones: impl IntoIterator<Item = BoneId>,
+        options: SpringOptions,
+    ) -> Result<Self, SpringChainError> {
+        let valid = |value: f32| value.is_finite() && value >= 0.0;
+        if !valid(options.stiffness) {
+            return Err(SpringChainError::InvalidSetting("stiffness"));
+        }
+        if !valid(options.damping) {
+            return Err(SpringChainError::InvalidSetting("damping"));
+        }
+        if !options.gravity.is_finite() {
+            return Err(SpringChainError::InvalidSetting("gravity"));
+        }
+
+        let mut chain = Vec::new();
+        let mut previous: Option<BoneId> = None;
+        for id in bones {
+            let bone = asset.bone(id).map_err(SpringChainError::InvalidBone)?;
+            if previous.is_some_and(|previous| bone.parent() != Some(previous)) {
+                return Err(SpringChainError::Disconnected { bone: id });
+            }
+            previous = Some(id);
+            chain.push(SpringBone {
+                id,
+                length: bone.length(),
+                animated: bone.setup_transform(),
+                tip: Vec2::ZERO,
+                velocity: Vec2::ZERO,
+                target: Vec2::ZERO,
+            });
+        }
+        if chain.is_empty() {
+            return Err(SpringChainError::EmptyChain);
+        }
+        Ok(Self {
+            bones: chain.into_boxed_slice(),
+            options,
+            accumulated: Duration::ZERO,
+            state: ChainState::Reset,
+        })
+    }
+
+    /// Returns the chain's bones from root to tip.
+    pub fn bones(&self) -> impl DoubleEndedIterator<Item = BoneId> + ExactSizeIterator + '_ {
+        self.bones.iter().map(|bone| bone.id)
+    }
+
+    /// Returns the physical settings.
+    #[must_use]
+    pub const fn options(&self) -> SpringOptions {
+        self.options
+    }
+
+    /// Discards all motion so the next solve starts at rest in the animated
+    /// pose.
+    pub fn reset(&mut self) {
+        self.state = ChainState::Reset;
+        self.accumulated = Duration::ZERO;
+    }
+
+    /// Keeps the current swing and velocity but carries the tips along with
+    /// the next animated pose, so a discontinuous pose change such as a seek
+    /// or respawn does not fling the chain.
+    pub fn teleport(&mut self) {
+        if self.state == ChainState::Running {
+            self.state = ChainState::Teleported;
+        }
+    }
+
+    /// Reports that the whole skeleton moved by `offset` skeleton-space units
+    /// since the previous solve, for example through its engine transform, so
+    /// the tips trail behind.
+    pub fn move_skeleton(&mut self, offset: Vec2) {
+        if self.state == ChainState::Running && offset.is_finite() {
+            for bone in &mut self.bones {
+                bone.tip -= offset;
+                bone.target -= offset;
+            }
+        }
+    }
+
+    fn substep_count(&mut self, elapsed: Duration) -> u32 {
+        let step = Duration::from_secs(1) / self.options.frequency.get();
+        self.accumulated = self.accumulated.saturating_add(elapsed);
+        let mut steps = 0;
+        while self.accumulated >= step && steps < self.options.max_substeps.get() {
+            self.accumulated -= step;
+            steps += 1;
+        }
+        // Time still owed after the cap would only grow; drop it.
+        if self.accumulated >= step {
+            self.accumulated = Duration::ZERO;
+        }
+        steps
+    }
+
+    /// Poses one bone from its tip particle, optionally integrating it first.
+    fn pose_bone(
+        &mut self,
+        pose: &mut ConstraintPose<'_>,
+        position: usize,
+        step: Option<f32>,
+    ) -> Option<()> {
+        let options = self.options;
+        let state = self.state;
+        let bone = &mut self.bones[position];
+        // The bone is posed from its animated transform in one edit, so only
+        // its subtree is recomputed once per substep.
+        let parent = pose.parent_world_transform(bone.id).ok()?;
+        let world = normal_local_to_world(parent, bone.animated);
+        let axis = world.x_axis();
+        let scale = axis.length();
+        if !(scale > f32::EPSILON && scale.is_finite()) {
+            return None;
+        }
+        let direction = axis / scale;
+        let reach = bone.length * scale;
+        let origin = world.translation();
+        let target = origin + direction * reach;
+
+        match state {
+            ChainState::Reset => {
+                bone.tip = target;
+                bone.velocity = Vec2::ZERO;
+            }
+            ChainState::Teleported => bone.tip += target - bone.target,
+            ChainState::Running => {}
+        }
+        bone.target = target;
+        if let Some(dt) = step {
+            let acceleration = (target - bone.tip) * options.stiffness
+                - bone.velocity * options.damping
+                + options.gravity;
+            bone.velocity += acceleration * dt;
+            bone.tip += bone.velocity * dt;
+        }
+
+        let animated_angle = float::atan2_f32(direction.y, direction.x);
+        let swing = bone.tip - origin;
+        let mut delta = if swing.length_squared() > f32::EPSILON {
+            float::atan2_f32(direction.perp_dot(swing), direction.dot(swing))
+        } else {
+            0.0
+        };
+        if let Some(limit) = options.angle_limit {
+            let limit = limit.as_radians().abs();
+            delta = delta.clamp(-limit, limit);
+        }
+        let angle = animated_angle + delta;
+        let (sine, cosine) = float::sin_cos(f64::from(angle));
+        let pointing = Vec2::new(cosine as f32, sine as f32);
+        bone.tip = origin + pointing * reach;
+        // The length constraint removes motion along the bone.
+        bone.velocity -= pointing * bone.velocity.dot(pointing);
+        let angle = Angle::from_radians(angle).ok()?;
+        let rotation = solve_world_rotation(parent, bone.animated, angle)?;
+        pose.set_local_transform(bone.id, replace_rotation(bone.animated, rotation))
+            .ok()
+    }
+}
+
+impl RuntimeConstraint for SpringChain {
+    fn apply(&mut self, pose: &mut ConstraintPose<'_>) -> RuntimeConstraintStatus {
+        for bone in &mut self.bones {
+            match pose.local_transform(bone.id) {
+                Ok(local) => bone.animated = local,
+                Err(_) => return RuntimeConstraintStatus::DEGRADED,
+            }
+        }
+        let steps = if self.state == ChainState::Reset {
+            self.accumulated = Duration::ZERO;
+            0
+        } else {
+            self.substep_count(pose.elapsed())
+        };
+        let dt = 1.0 / self.options.frequency.get() as f32;
+
+        let mut degraded = false;
+        for step in 0..steps.max(1) {
+            for position in 0..self.bones.len() {
+                let integrate = (step < steps).then_some(dt);
+                if self.pose_bone(pose, position, integrate).is_none() {
+                    degraded = true;
+                }
+            }
+            self.state = ChainState::Running;
+        }
+        if degraded {
+            RuntimeConstraintStatus::DEGRADED
+        } else {
+            RuntimeConstraintStatus::APPLIED
+        }
+    }
+
+    fn snapshot_state(&self) -> Option<Box<dyn RuntimeConstraint>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn restore_state(&mut self, snapshot: &dyn RuntimeConstraint) {
+        let snapshot: &dyn Any = snapshot;
+        if let Some(snapshot) = snapshot.downcast_ref::<Self>()
+            && snapshot.bones.len() == self.bones.len()
+        {
+            self.bones.copy_from_slice(&snapshot.bones);
+            self.accumulated = snapshot.accumulated;
+            self.state = snapshot.state;
+        }
+    }
+}
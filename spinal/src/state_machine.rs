@@ -0,0 +1,1318 @@
+//! Declarative animation state machines that drive one mixer track.
+//!
+//! A [`StateMachineDefinition`] is plain data and can be authored in any
+//! serde format. [`StateMachine::new`] resolves it against one loaded
+//! [`SkeletonAsset`] so that later updates only compare preallocated values.
+
+use std::{
+    mem,
+    num::NonZeroU64,
+    ops::Range,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    AnimationId, AnimationMixer, Crossfade, DiscreteSwitches, Mix, MixCurve, PlayOptions,
+    PlaybackId, PlaybackMode, PlayerError, PlayerStatus, RotationPath, Skeleton, SkeletonAsset,
+    TrackAnimationEvent, TrackError, TrackEventSink, TrackId, Transition, frame::EditablePose,
+};
+
+static NEXT_STATE_MACHINE_KEY: AtomicU64 = AtomicU64::new(1);
+
+/// A serde-loadable description of parameters, states, and transitions.
+///
+/// Names are resolved by [`StateMachine::new`]; the definition itself does
+/// not reference a particular asset and may be shared between skeletons.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StateMachineDefinition {
+    initial: Box<str>,
+    #[serde(default)]
+    parameters: Vec<ParameterDefinition>,
+    #[serde(default)]
+    states: Vec<StateDefinition>,
+    #[serde(default)]
+    transitions: Vec<TransitionDefinition>,
+}
+
+impl StateMachineDefinition {
+    /// Creates an empty definition that enters `initial` on its first update.
+    #[must_use]
+    pub fn new(initial: impl Into<Box<str>>) -> Self {
+        Self {
+            initial: initial.into(),
+            parameters: Vec::new(),
+            states: Vec::new(),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Appends one typed parameter.
+    #[must_use]
+    pub fn with_parameter(mut self, parameter: ParameterDefinition) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+
+    /// Appends one animation-bound state.
+    #[must_use]
+    pub fn with_state(mut self, state: StateDefinition) -> Self {
+        self.states.push(state);
+        self
+    }
+
+    /// Appends one transition at the lowest remaining priority.
+    #[must_use]
+    pub fn with_transition(mut self, transition: TransitionDefinition) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    /// Returns the name of the state entered by the first update.
+    #[must_use]
+    pub fn initial(&self) -> &str {
+        &self.initial
+    }
+
+    /// Returns parameters in definition order.
+    #[must_use]
+    pub fn parameters(&self) -> &[ParameterDefinition] {
+        &self.parameters
+    }
+
+    /// Returns states in definition order.
+    #[must_use]
+    pub fn states(&self) -> &[StateDefinition] {
+        &self.states
+    }
+
+    /// Returns transitions from highest to lowest priority.
+    #[must_use]
+    pub fn transitions(&self) -> &[TransitionDefinition] {
+        &self.transitions
+    }
+}
+
+/// One typed parameter and its initial value.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+#[non_exhaustive]
+pub enum ParameterDefinition {
+    /// A boolean flag.
+    Bool {
+        /// The unique parameter name.
+        name: Box<str>,
+        /// The value before the first caller assignment.
+        #[serde(default)]
+        default: bool,
+    },
+    /// A finite floating-point value.
+    Float {
+        /// The unique parameter name.
+        name: Box<str>,
+        /// The value before the first caller assignment.
+        #[serde(default)]
+        default: f32,
+    },
+    /// A signed integer value.
+    Int {
+        /// The unique parameter name.
+        name: Box<str>,
+        /// The value before the first caller assignment.
+        #[serde(default)]
+        default: i32,
+    },
+    /// A one-update pulse set by [`StateMachine::set_trigger`].
+    Trigger {
+        /// The unique parameter name.
+        name: Box<str>,
+    },
+}
+
+impl ParameterDefinition {
+    /// Returns the unique parameter name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Bool { name, .. }
+            | Self::Float { name, .. }
+            | Self::Int { name, .. }
+            | Self::Trigger { name } => name,
+        }
+    }
+
+    const fn initial_value(&self) -> ParameterValue {
+        match self {
+            Self::Bool { default, .. } => ParameterValue::Bool(*default),
+            Self::Float { default, .. } => ParameterValue::Float(*default),
+            Self::Int { default, .. } => ParameterValue::Int(*default),
+            Self::Trigger { .. } => ParameterValue::Trigger(false),
+        }
+    }
+}
+
+/// One state that plays a named animation while it is current.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StateDefinition {
+    name: Box<str>,
+    animation: Box<str>,
+    #[serde(default)]
+    mode: PlaybackMode,
+    #[serde(default = "unit_speed")]
+    speed: f32,
+}
+
+impl StateDefinition {
+    /// Creates a state that plays `animation` once at unit speed.
+    #[must_use]
+    pub fn new(name: impl Into<Box<str>>, animation: impl Into<Box<str>>) -> Self {
+        Self {
+            name: name.into(),
+            animation: animation.into(),
+            mode: PlaybackMode::Once,
+            speed: 1.0,
+        }
+    }
+
+    /// Replaces the playback mode used when the state is entered.
+    #[must_use]
+    pub const fn with_mode(mut self, mode: PlaybackMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Replaces the track speed applied when the state is entered.
+    #[must_use]
+    pub const fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Returns the unique state name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the stable animation name.
+    #[must_use]
+    pub fn animation(&self) -> &str {
+        &self.animation
+    }
+
+    /// Returns the playback mode.
+    #[must_use]
+    pub const fn mode(&self) -> PlaybackMode {
+        self.mode
+    }
+
+    /// Returns the animation-clock speed.
+    #[must_use]
+    pub const fn speed(&self) -> f32 {
+        self.speed
+    }
+}
+
+const fn unit_speed() -> f32 {
+    1.0
+}
+
+/// A guarded edge from one state, or from any state, to a target state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionDefinition {
+    #[serde(default)]
+    from: Option<Box<str>>,
+    to: Box<str>,
+    #[serde(default)]
+    conditions: Vec<ConditionDefinition>,
+    #[serde(default)]
+    crossfade: Option<CrossfadeDefinition>,
+}
+
+impl TransitionDefinition {
+    /// Creates an unconditional immediate transition between two states.
+    #[must_use]
+    pub fn new(from: impl Into<Box<str>>, to: impl Into<Box<str>>) -> Self {
+        Self {
+            from: Some(from.into()),
+            to: to.into(),
+            conditions: Vec::new(),
+            crossfade: None,
+        }
+    }
+
+    /// Creates a transition that may leave any state other than `to`.
+    #[must_use]
+    pub fn from_any(to: impl Into<Box<str>>) -> Self {
+        Self {
+            from: None,
+            to: to.into(),
+            conditions: Vec::new(),
+            crossfade: None,
+        }
+    }
+
+    /// Appends a condition. All conditions must hold for the edge to fire.
+    #[must_use]
+    pub fn with_condition(mut self, condition: ConditionDefinition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Replaces the crossfade used to enter the target state.
+    #[must_use]
+    pub const fn with_crossfade(mut self, crossfade: CrossfadeDefinition) -> Self {
+        self.crossfade = Some(crossfade);
+        self
+    }
+
+    /// Returns the source state name, or `None` for an any-state edge.
+    #[must_use]
+    pub fn from(&self) -> Option<&str> {
+        self.from.as_deref()
+    }
+
+    /// Returns the target state name.
+    #[must_use]
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
+    /// Returns conditions in evaluation order.
+    #[must_use]
+    pub fn conditions(&self) -> &[ConditionDefinition] {
+        &self.conditions
+    }
+
+    /// Returns the entry crossfade.
+    ///
+    /// `None` defers to the driven track's [`crate::MixTable`], or switches
+    /// immediately when the track has none.
+    #[must_use]
+    pub const fn crossfade(&self) -> Option<CrossfadeDefinition> {
+        self.crossfade
+    }
+}
+
+/// A serializable description of a [`Crossfade`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CrossfadeDefinition {
+    seconds: f64,
+    #[serde(default)]
+    curve: MixCurve,
+    #[serde(default)]
+    rotation_path: RotationPath,
+    #[serde(default)]
+    discrete_switch: f32,
+}
+
+impl CrossfadeDefinition {
+    /// Creates a linear crossfade whose discrete properties switch at start.
+    #[must_use]
+    pub const fn new(seconds: f64) -> Self {
+        Self {
+            seconds,
+            curve: MixCurve::Linear,
+            rotation_path: RotationPath::Shortest,
+            discrete_switch: 0.0,
+        }
+    }
+
+    /// Replaces the interpolation applied to normalized transition time.
+    #[must_use]
+    pub const fn with_curve(mut self, curve: MixCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Replaces how angular values choose their path.
+    #[must_use]
+    pub const fn with_rotation_path(mut self, rotation_path: RotationPath) -> Self {
+        self.rotation_path = rotation_path;
+        self
+    }
+
+    /// Replaces the uniform switch point for non-interpolated properties.
+    #[must_use]
+    pub const fn with_discrete_switch(mut self, at: f32) -> Self {
+        self.discrete_switch = at;
+        self
+    }
+
+    /// Returns the wall-clock duration in seconds.
+    #[must_use]
+    pub const fn seconds(self) -> f64 {
+        self.seconds
+    }
+
+    /// Returns the transition-time interpolation.
+    #[must_use]
+    pub const fn curve(self) -> MixCurve {
+        self.curve
+    }
+
+    /// Returns how angular values choose their path.
+    #[must_use]
+    pub const fn rotation_path(self) -> RotationPath {
+        self.rotation_path
+    }
+
+    /// Returns the uniform switch point for non-interpolated properties.
+    #[must_use]
+    pub const fn discrete_switch(self) -> f32 {
+        self.discrete_switch
+    }
+
+    fn to_crossfade(self) -> Option<Crossfade> {
+        let duration = Duration::try_from_secs_f64(self.seconds).ok()?;
+        let discrete = Mix::new(self.discrete_switch).ok()?;
+        Some(
+            Crossfade::new(duration)
+                .with_curve(self.curve)
+                .with_rotation_path(self.rotation_path)
+                .with_discrete(DiscreteSwitches::uniform(discrete)),
+        )
+    }
+}
+
+/// One guard evaluated before the mixer advances.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+#[non_exhaustive]
+pub enum ConditionDefinition {
+    /// A boolean parameter equals `value`.
+    Bool {
+        /// The boolean parameter name.
+        parameter: Box<str>,
+        /// The required value.
+        value: bool,
+    },
+    /// A float parameter compares against `value`.
+    Float {
+        /// The float parameter name.
+        parameter: Box<str>,
+        /// The comparison applied as `parameter <op> value`.
+        comparison: Comparison,
+        /// The finite right-hand operand.
+        value: f32,
+    },
+    /// An integer parameter compares against `value`.
+    Int {
+        /// The integer parameter name.
+        parameter: Box<str>,
+        /// The comparison applied as `parameter <op> value`.
+        comparison: Comparison,
+        /// The right-hand operand.
+        value: i32,
+    },
+    /// A trigger parameter was set since the previous update.
+    Trigger {
+        /// The trigger parameter name.
+        parameter: Box<str>,
+    },
+    /// The current state's animation completed.
+    ///
+    /// A once state satisfies this while it holds its final pose. A looping
+    /// state satisfies it on the update after its clock crossed a loop
+    /// boundary.
+    Complete,
+    /// The current state's playback emitted an authored event during the
+    /// previous update.
+    Event {
+        /// The authored event-definition name.
+        event: Box<str>,
+    },
+}
+
+/// A numeric comparison applied as `parameter <op> value`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Comparison {
+    /// `parameter < value`.
+    Less,
+    /// `parameter <= value`.
+    LessOrEqual,
+    /// `parameter == value`.
+    Equal,
+    /// `parameter != value`.
+    NotEqual,
+    /// `parameter >= value`.
+    GreaterOrEqual,
+    /// `parameter > value`.
+    Greater,
+}
+
+impl Comparison {
+    fn holds<T: PartialOrd>(self, parameter: T, value: T) -> bool {
+        match self {
+            Self::Less => parameter < value,
+            Self::LessOrEqual => parameter <= value,
+            Self::Equal => parameter == value,
+            Self::NotEqual => parameter != value,
+            Self::GreaterOrEqual => parameter >= value,
+            Self::Greater => parameter > value,
+        }
+    }
+}
+
+/// The value type of a state-machine parameter.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ParameterKind {
+    /// A boolean flag.
+    Bool,
+    /// A finite floating-point value.
+    Float,
+    /// A signed integer value.
+    Int,
+    /// A one-update pulse.
+    Trigger,
+}
+
+/// The current value of one state-machine parameter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParameterValue {
+    /// A boolean flag.
+    Bool(bool),
+    /// A finite floating-point value.
+    Float(f32),
+    /// A signed integer value.
+    Int(i32),
+    /// Whether a trigger is pending for the next update.
+    Trigger(bool),
+}
+
+impl ParameterValue {
+    /// Returns the value type.
+    #[must_use]
+    pub const fn kind(self) -> ParameterKind {
+        match self {
+            Self::Bool(_) => ParameterKind::Bool,
+            Self::Float(_) => ParameterKind::Float,
+            Self::Int(_) => ParameterKind::Int,
+            Self::Trigger(_) => ParameterKind::Trigger,
+        }
+    }
+}
+
+/// Identifies one state of a compiled [`StateMachine`].
+///
+/// IDs are scoped to the machine that created them.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StateId {
+    machine: NonZeroU64,
+    index: u32,
+}
+
+impl StateId {
+    /// Returns the zero-based definition-order index.
+    #[must_use]
+    pub const fn ordinal(self) -> usize {
+        self.index as usize
+    }
+}
+
+/// Identifies one parameter of a compiled [`StateMachine`].
+///
+/// IDs are scoped to the machine that created them.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParameterId {
+    machine: NonZeroU64,
+    index: u32,
+}
+
+impl ParameterId {
+    /// Returns the zero-based definition-order index.
+    #[must_use]
+    pub const fn ordinal(self) -> usize {
+        self.index as usize
+    }
+}
+
+/// One state change taken by a [`StateMachine`] update.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StateTransition {
+    from: Option<StateId>,
+    to: StateId,
+    transition: Option<usize>,
+    playback: PlaybackId,
+}
+
+impl StateTransition {
+    /// Returns the state that was left, or `None` for the initial entry.
+    #[must_use]
+    pub const fn from(self) -> Option<StateId> {
+        self.from
+    }
+
+    /// Returns the state that was entered.
+    #[must_use]
+    pub const fn to(self) -> StateId {
+        self.to
+    }
+
+    /// Returns the definition-order index of the edge that fired.
+    ///
+    /// `None` identifies the initial entry or an explicit
+    /// [`StateMachine::set_state`] request.
+    #[must_use]
+    pub const fn transition(self) -> Option<usize> {
+        self.transition
+    }
+
+    /// Returns the playback started on the driven track.
+    #[must_use]
+    pub const fn playback(self) -> PlaybackId {
+        self.playback
+    }
+}
+
+/// A definition that could not be resolved against an asset.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum StateMachineDefinitionError {
+    /// Two states share one name.
+    #[error("state `{0}` is defined more than once")]
+    DuplicateState(Box<str>),
+    /// Two parameters share one name.
+    #[error("parameter `{0}` is defined more than once")]
+    DuplicateParameter(Box<str>),
+    /// A transition or the initial state names an undefined state.
+    #[error("state `{0}` is not defined")]
+    UnknownState(Box<str>),
+    /// A condition names an undefined parameter.
+    #[error("parameter `{0}` is not defined")]
+    UnknownParameter(Box<str>),
+    /// A condition uses a parameter as a different type.
+    #[error("parameter `{name}` is a {actual:?} parameter, not {expected:?}")]
+    ParameterTypeMismatch {
+        /// The parameter name.
+        name: Box<str>,
+        /// The type required by the condition.
+        expected: ParameterKind,
+        /// The type declared by the parameter.
+        actual: ParameterKind,
+    },
+    /// A state names an animation missing from the asset.
+    #[error("animation `{0}` does not exist in the skeleton asset")]
+    UnknownAnimation(Box<str>),
+    /// An event condition names an event missing from the asset.
+    #[error("event `{0}` does not exist in the skeleton asset")]
+    UnknownEvent(Box<str>),
+    /// A float default or operand is NaN or infinite.
+    #[error("float parameter `{0}` uses a non-finite value")]
+    NonFiniteValue(Box<str>),
+    /// A state speed is negative or non-finite.
+    #[error("state `{0}` speed must be finite and nonnegative")]
+    InvalidSpeed(Box<str>),
+    /// A crossfade duration or switch point is invalid.
+    #[error("transition {0} has a crossfade with an invalid duration or switch point")]
+    InvalidCrossfade(usize),
+    /// A definition table exceeded an indexable runtime size.
+    #[error("the state machine definition exceeds its indexable capacity")]
+    CapacityExceeded,
+}
+
+/// A failure to address or assign a machine-scoped parameter or state.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum StateMachineError {
+    /// The identifier belongs to another state machine.
+    #[error("the identifier belongs to a different state machine")]
+    ForeignMachine,
+    /// The parameter has a different value type.
+    #[error("the parameter is a {actual:?} parameter, not {expected:?}")]
+    ParameterTypeMismatch {
+        /// The type used by the assignment.
+        expected: ParameterKind,
+        /// The type declared by the parameter.
+        actual: ParameterKind,
+    },
+    /// A float assignment was NaN or infinite.
+    #[error("float parameters must be finite")]
+    NonFiniteValue,
+}
+
+/// A failure to drive the mixer during a state-machine update.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum StateMachineUpdateError {
+    /// The driven override track was removed or belongs to another mixer.
+    #[error("the state machine track is unavailable: {0}")]
+    Track(
+        #[doc = "The underlying track error."]
+        #[source]
+        TrackError,
+    ),
+    /// The mixer rejected a state entry or update.
+    #[error("the mixer rejected the state machine update: {0}")]
+    Player(
+        #[doc = "The underlying player error."]
+        #[source]
+        PlayerError,
+    ),
+}
+
+/// A compiled state machine bound to one asset's animation identities.
+///
+/// The machine owns no pose storage. Each [`Self::update`] evaluates at most
+/// one transition in definition order, enters the chosen state on the driven
+/// mixer track, and then advances the whole mixer. Completion and event
+/// conditions observe the previous update, so a chain of edges advances one
+/// edge per update in a reproducible order. Updates allocate nothing.
+#[derive(Debug)]
+pub struct StateMachine {
+    key: NonZeroU64,
+    track: Option<TrackId>,
+    parameters: Box<[CompiledParameter]>,
+    states: Box<[CompiledState]>,
+    transitions: Box<[CompiledTransition]>,
+    conditions: Box<[CompiledCondition]>,
+    initial: u32,
+    current: Option<CurrentState>,
+    requested: Option<RequestedState>,
+    observed_events: Box<[bool]>,
+    recorded_events: Box<[bool]>,
+    observed_complete: bool,
+    last_transition: Option<StateTransition>,
+}
+
+#[derive(Debug)]
+struct CompiledParameter {
+    name: Box<str>,
+    value: ParameterValue,
+}
+
+#[derive(Debug)]
+struct CompiledState {
+    name: Box<str>,
+    animation: AnimationId,
+    mode: PlaybackMode,
+    speed: f32,
+}
+
+#[derive(Debug)]
+struct CompiledTransition {
+    from: Option<u32>,
+    to: u32,
+    conditions: Range<usize>,
+    transition: Option<Transition>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum CompiledCondition {
+    Bool {
+        parameter: usize,
+        value: bool,
+    },
+    Float {
+        parameter: usize,
+        comparison: Comparison,
+        value: f32,
+    },
+    Int {
+        parameter: usize,
+        comparison: Comparison,
+        value: i32,
+    },
+    Trigger {
+        parameter: usize,
+    },
+    Complete,
+    Event {
+        event: usize,
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CurrentState {
+    state: u32,
+    playback: PlaybackId,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RequestedState {
+    state: u32,
+    transition: Option<Transition>,
+}
+
+impl StateMachine {
+    /// Resolves a definition against one asset and drives the base track.
+    pub fn new(
+        asset: &SkeletonAsset,
+        definition: &StateMachineDefinition,
+    ) -> Result<Self, StateMachineDefinitionError> {
+        let key = next_state_machine_key();
+        let parameters = compile_parameters(definition)?;
+        let states = compile_states(asset, definition)?;
+        let initial = state_index(&states, &definition.initial)?;
+        let mut conditions = Vec::new();
+        let mut transitions = Vec::with_capacity(definition.transitions.len());
+        for (index, transition) in definition.transitions.iter().enumerate() {
+            let from = transition
+                .from
+                .as_deref()
+                .map(|name| state_index(&states, name))
+                .transpose()?;
+            let to = state_index(&states, &transition.to)?;
+            let start = conditions.len();
+            for condition in &transition.conditions {
+                conditions.push(compile_condition(asset, definition, condition)?);
+            }
+            let transition = transition
+                .crossfade
+                .map(|crossfade| {
+                    crossfade
+                        .to_crossfade()
+                        .map(Transition::Crossfade)
+                        .ok_or(StateMachineDefinitionError::InvalidCrossfade(index))
+                })
+                .transpose()?;
+            transitions.push(CompiledTransition {
+                from,
+                to,
+                conditions: start..conditions.len(),
+                transition,
+            });
+        }
+
+        Ok(Self {
+            key,
+            track: None,
+            parameters,
+            states,
+            transitions: transitions.into_boxed_slice(),
+            conditions: conditions.into_boxed_slice(),
+            initial,
+            current: None,
+            requested: None,
+            observed_events: vec![false; asset.event_definitions().len()].into_boxed_slice(),
+            recorded_events: vec![false; asset.event_definitions().len()].into_boxed_slice(),
+            observed_complete: false,
+            last_transition: None,
+        })
+    }
+
+    /// Drives an inserted override track instead of the base track.
+    ///
+    /// The track is resolved on every update, so removing it makes updates
+    /// fail instead of silently driving another track.
+    #[must_use]
+    pub const fn with_track(mut self, track: TrackId) -> Self {
+        self.track = Some(track);
+        self
+    }
+
+    /// Returns the driven override track, or `None` for the base track.
+    #[must_use]
+    pub const fn track(&self) -> Option<TrackId> {
+        self.track
+    }
+
+    /// Looks up a state by its unique name.
+    #[must_use]
+    pub fn state_id(&self, name: &str) -> Option<StateId> {
+        self.states
+            .iter()
+            .position(|state| state.name.as_ref() == name)
+            .map(|index| self.state(index as u32))
+    }
+
+    /// Returns a state's unique name.
+    pub fn state_name(&self, id: StateId) -> Result<&str, StateMachineError> {
+        self.validate_machine(id.machine)?;
+        Ok(&self.states[id.ordinal()].name)
+    }
+
+    /// Returns the animation played by a state.
+    pub fn state_animation(&self, id: StateId) -> Result<AnimationId, StateMachineError> {
+        self.validate_machine(id.machine)?;
+        Ok(self.states[id.ordinal()].animation)
+    }
+
+    /// Returns the number of defined states.
+    #[must_use]
+    pub const fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns the current state, or `None` before the first update.
+    #[must_use]
+    pub fn current_state(&self) -> Option<StateId> {
+        self.current.map(|current| self.state(current.state))
+    }
+
+    /// Returns the transition taken by the latest update, if any.
+    #[must_use]
+    pub const fn last_transition(&self) -> Option<StateTransition> {
+        self.last_transition
+    }
+
+    /// Requests an unconditional switch on the next update.
+    ///
+    /// An explicit request takes precedence over every authored edge and
+    /// enters the state even when it is already current.
+    pub fn set_state(
+        &mut self,
+        id: StateId,
+        transition: Transition,
+    ) -> Result<(), StateMachineError> {
+        self.validate_machine(id.machine)?;
+        self.requested = Some(RequestedState {
+            state: id.index,
+            transition: Some(transition),
+        });
+        Ok(())
+    }
+
+    /// Forgets the current state so the next update re-enters the initial one.
+    ///
+    /// Parameter values are preserved; pending triggers and observed
+    /// completion or event signals are cleared.
+    pub fn reset(&mut self) {
+        self.current = None;
+        self.requested = None;
+        self.observed_complete = false;
+        self.observed_events.fill(false);
+        self.last_transition = None;
+        self.clear_triggers();
+    }
+
+    fn clear_triggers(&mut self) {
+        for parameter in &mut self.parameters {
+            if let ParameterValue::Trigger(pending) = &mut parameter.value {
+                *pending = false;
+            }
+        }
+    }
+
+    /// Looks up a parameter by its unique name.
+    #[must_use]
+    pub fn parameter_id(&self, name: &str) -> Option<ParameterId> {
+        self.parameters
+            .iter()
+            .position(|parameter| parameter.name.as_ref() == name)
+            .map(|index| ParameterId {
+                machine: self.key,
+                index: index as u32,
+            })
+    }
+
+    /// Returns a parameter's current value.
+    pub fn parameter(&self, id: ParameterId) -> Result<ParameterValue, StateMachineError> {
+        self.validate_machine(id.machine)?;
+        Ok(self.parameters[id.ordinal()].value)
+    }
+
+    /// Assigns a boolean parameter.
+    pub fn set_bool(&mut self, id: ParameterId, value: bool) -> Result<(), StateMachineError> {
+        *self.parameter_slot(id, ParameterKind::Bool)? = ParameterValue::Bool(value);
+        Ok(())
+    }
+
+    /// Assigns a finite float parameter.
+    pub fn set_float(&mut self, id: ParameterId, value: f32) -> Result<(), StateMachineError> {
+        let slot = self.parameter_slot(id, ParameterKind::Float)?;
+        if !value.is_finite() {
+            return Err(StateMachineError::NonFiniteValue);
+        }
+        *slot = ParameterValue::Float(value);
+        Ok(())
+    }
+
+    /// Assigns an integer parameter.
+    pub fn set_int(&mut self, id: ParameterId, value: i32) -> Result<(), StateMachineError> {
+        *self.parameter_slot(id, ParameterKind::Int)? = ParameterValue::Int(value);
+        Ok(())
+    }
+
+    /// Sets a trigger for the next update.
+    ///
+    /// Triggers are cleared once that update succeeds, whether or not an
+    /// edge consumed them.
+    pub fn set_trigger(&mut self, id: ParameterId) -> Result<(), StateMachineError> {
+        *self.parameter_slot(id, ParameterKind::Trigger)? = ParameterValue::Trigger(true);
+        Ok(())
+    }
+
+    /// Evaluates transitions, enters at most one state, and advances the mixer.
+    ///
+    /// The first update enters the initial state immediately. Later updates
+    /// take the first authored edge, in definition order, whose source matches
+    /// and whose conditions all hold. Any-state edges never re-enter the
+    /// current state. The entered state's speed is applied to the driven
+    /// track before the mixer advances by `delta`, so the new playback and its
+    /// crossfade both observe this update's time.
+    ///
+    /// Events are forwarded to `events` unchanged. Pending triggers and
+    /// observed completion or event signals are cleared only once the mixer
+    /// update succeeds, so a failed update keeps them for the next one. If
+    /// the mixer update fails, an entered state remains current and its
+    /// playback stays requested.
+    pub fn update<'s, S: TrackEventSink + ?Sized>(
+        &mut self,
+        mixer: &mut AnimationMixer,
+        skeleton: &'s mut Skeleton,
+        delta: Duration,
+        events: &mut S,
+    ) -> Result<EditablePose<'s>, StateMachineUpdateError> {
+        let status = self.track_status(mixer)?;
+        let chosen = self.choose_transition(status);
+        self.last_transition = None;
+        if let Some((transition_index, state, transition)) = chosen {
+            self.enter(mixer, transition_index, state, transition)?;
+        }
+
+        self.recorded_events.fill(false);
+        let track = self.track.unwrap_or_else(|| mixer.base_track_id());
+        let playback = self.current.map(|current| current.playback);
+        let editable = {
+            let mut recorder = EventRecorder {
+                track,
+                playback,
+                observed: &mut self.recorded_events,
+                sink: events,
+            };
+            mixer
+                .update(skeleton, delta, &mut recorder)
+                .map_err(StateMachineUpdateError::Player)?
+        };
+        self.clear_triggers();
+        mem::swap(&mut self.observed_events, &mut self.recorded_events);
+        self.observed_complete = false;
+        if let Some(report) = mixer.reports().find(|report| report.track() == track) {
+            let report = report.playback();
+            self.observed_complete = playback.is_some()
+                && (report.completed() == playback
+                    || (report.current() == playback && report.loops_completed() > 0));
+        }
+        Ok(editable)
+    }
+
+    fn choose_transition(
+        &mut self,
+        status: PlayerStatus,
+    ) -> Option<(Option<usize>, u32, Option<Transition>)> {
+        if let Some(requested) = self.requested.take() {
+            return Some((None, requested.state, requested.transition));
+        }
+        let Some(current) = self.current else {
+            return Some((None, self.initial, Some(Transition::Immediate)));
+        };
+        let holding_end = self.states[current.state as usize].mode == PlaybackMode::Once
+            && status.playback() == Some(current.playback)
+            && status.is_complete();
+        let complete = self.observed_complete || holding_end;
+        self.transitions
+            .iter()
+            .enumerate()
+            .find(|(_index, transition)| {
+                let source_matches = match transition.from {
+                    Some(from) => from == current.state,
+                    None => transition.to != current.state,
+                };
+                source_matches
+                    && self.conditions[transition.conditions.clone()]
+                        .iter()
+                        .all(|condition| self.condition_holds(*condition, complete))
+            })
+            .map(|(index, transition)| (Some(index), transition.to, transition.transition))
+    }
+
+    fn condition_holds(&self, condition: CompiledCondition, complete: bool) -> bool {
+        match condition {
+            CompiledCondition::Bool { parameter, value } => {
+                matches!(
+                    self.parameters[parameter].value,
+                    ParameterValue::Bool(current) if current == value
+                )
+            }
+            CompiledCondition::Float {
+                parameter,
+                comparison,
+                value,
+            } => matches!(
+                self.parameters[parameter].value,
+                ParameterValue::Float(current) if comparison.holds(current, value)
+            ),
+            CompiledCondition::Int {
+                parameter,
+                comparison,
+                value,
+            } => matches!(
+                self.parameters[parameter].value,
+                ParameterValue::Int(current) if comparison.holds(current, value)
+            ),
+            CompiledCondition::Trigger { parameter } => {
+                matches!(
+                    self.parameters[parameter].value,
+                    ParameterValue::Trigger(true)
+                )
+            }
+            CompiledCondition::Complete => complete,
+            CompiledCondition::Event { event } => self.observed_events[event],
+        }
+    }
+
+    fn enter(
+        &mut self,
+        mixer: &mut AnimationMixer,
+        transition_index: Option<usize>,
+        state: u32,
+        transition: Option<Transition>,
+    ) -> Result<(), StateMachineUpdateError> {
+        let definition = &self.states[state as usize];
+        let mut options = match definition.mode {
+            PlaybackMode::Loop => PlayOptions::looping(),
+            PlaybackMode::Once => PlayOptions::once(),
+        };
+        if let Some(transition) = transition {
+            options = options.with_transition(transition);
+        }
+        let outcome = match self.track {
+            Some(track) => {
+                let mut track = mixer
+                    .track_mut(track)
+                    .map_err(StateMachineUpdateError::Track)?;
+                let outcome = track
+                    .play(definition.animation, options)
+                    .map_err(StateMachineUpdateError::Player)?;
+                track
+                    .set_speed(definition.speed)
+                    .expect("state speeds are validated when the machine is compiled");
+                outcome
+            }
+            None => {
+                let mut base = mixer.base_track_mut();
+                let outcome = base
+                    .play(definition.animation, options)
+                    .map_err(StateMachineUpdateError::Player)?;
+                base.set_speed(definition.speed)
+                    .expect("state speeds are validated when the machine is compiled");
+                outcome
+            }
+        };
+        let from = self.current.map(|current| self.state(current.state));
+        self.current = Some(CurrentState {
+            state,
+            playback: outcome.playback(),
+        });
+        self.last_transition = Some(StateTransition {
+            from,
+            to: self.state(state),
+            transition: transition_index,
+            playback: outcome.playback(),
+        });
+        Ok(())
+    }
+
+    fn track_status(
+        &self,
+        mixer: &AnimationMixer,
+    ) -> Result<PlayerStatus, StateMachineUpdateError> {
+        match self.track {
+            Some(track) => mixer
+                .track(track)
+                .map(|track| track.status())
+                .map_err(StateMachineUpdateError::Track),
+            None => Ok(mixer.base_track().status()),
+        }
+    }
+
+    fn parameter_slot(
+        &mut self,
+        id: ParameterId,
+        expected: ParameterKind,
+    ) -> Result<&mut ParameterValue, StateMachineError> {
+        self.validate_machine(id.machine)?;
+        let value = &mut self.parameters[id.ordinal()].value;
+        let actual = value.kind();
+        if actual != expected {
+            return Err(StateMachineError::ParameterTypeMismatch { expected, actual });
+        }
+        Ok(value)
+    }
+
+    const fn state(&self, index: u32) -> StateId {
+        StateId {
+            machine: self.key,
+            index,
+        }
+    }
+
+    fn validate_machine(&self, machine: NonZeroU64) -> Result<(), StateMachineError> {
+        if machine != self.key {
+            return Err(StateMachineError::ForeignMachine);
+        }
+        Ok(())
+    }
+}
+
+struct EventRecorder<'a, S: ?Sized> {
+    track: TrackId,
+    playback: Option<PlaybackId>,
+    observed: &'a mut [bool],
+    sink: &'a mut S,
+}
+
+impl<S: TrackEventSink + ?Sized> TrackEventSink for EventRecorder<'_, S> {
+    fn event(&mut self, event: TrackAnimationEvent<'_>) {
+        if event.track() == self.track && Some(event.event().playback()) == self.playback {
+            self.observed[event.event().definition().id().index() as usize] = true;
+        }
+        self.sink.event(event);
+    }
+}
+
+fn compile_parameters(
+    definition: &StateMachineDefinition,
+) -> Result<Box<[CompiledParameter]>, StateMachineDefinitionError> {
+    u32::try_from(definition.parameters.len())
+        .map_err(|_| StateMachineDefinitionError::CapacityExceeded)?;
+    let mut parameters = Vec::with_capacity(definition.parameters.len());
+    for (index, parameter) in definition.parameters.iter().enumerate() {
+        let name = parameter.name();
+        if definition.parameters[..index]
+            .iter()
+            .any(|earlier| earlier.name() == name)
+        {
+            return Err(StateMachineDefinitionError::DuplicateParameter(name.into()));
+        }
+        let value = parameter.initial_value();
+        if matches!(value, ParameterValue::Float(value) if !value.is_finite()) {
+            return Err(StateMachineDefinitionError::NonFiniteValue(name.into()));
+        }
+        parameters.push(CompiledParameter {
+            name: name.into(),
+            value,
+        });
+    }
+    Ok(parameters.into_boxed_slice())
+}
+
+fn compile_states(
+    asset: &SkeletonAsset,
+    definition: &StateMachineDefinition,
+) -> Result<Box<[CompiledState]>, StateMachineDefinitionError> {
+    u32::try_from(definition.states.len())
+        .map_err(|_| StateMachineDefinitionError::CapacityExceeded)?;
+    let mut states = Vec::with_capacity(definition.states.len());
+    for (index, state) in definition.states.iter().enumerate() {
+        if definition.states[..index]
+            .iter()
+            .any(|earlier| earlier.name == state.name)
+        {
+            return Err(StateMachineDefinitionError::DuplicateState(
+                state.name.clone(),
+            ));
+        }
+        let animation = asset.animation_id(&state.animation).ok_or_else(|| {
+            StateMachineDefinitionError::UnknownAnimation(state.animation.clone())
+        })?;
+        if !state.speed.is_finite() || state.speed < 0.0 {
+            return Err(StateMachineDefinitionError::InvalidSpeed(
+                state.name.clone(),
+            ));
+        }
+        states.push(CompiledState {
+            name: state.name.clone(),
+            animation,
+            mode: state.mode,
+            speed: state.speed,
+        });
+    }
+    Ok(states.into_boxed_slice())
+}
+
+fn state_index(states: &[CompiledState], name: &str) -> Result<u32, StateMachineDefinitionError> {
+    states
+        .iter()
+        .position(|state| state.name.as_ref() == name)
+        .map(|index| index as u32)
+        .ok_or_else(|| StateMachineDefinitionError::UnknownState(name.into()))
+}
+
+fn compile_condition(
+    asset: &SkeletonAsset,
+    definition: &StateMachineDefinition,
+    condition: &ConditionDefinition,
+) -> Result<CompiledCondition, StateMachineDefinitionError> {
+    let parameter = |name: &str, expected: ParameterKind| {
+        let index = definition
+            .parameters
+            .iter()
+            .position(|parameter| parameter.name() == name)
+            .ok_or_else(|| StateMachineDefinitionError::UnknownParameter(name.into()))?;
+        let actual = definition.parameters[index].initial_value().kind();
+        if actual != expected {
+            return Err(StateMachineDefinitionError::ParameterTypeMismatch {
+                name: name.into(),
+                expected,
+                actual,
+            });
+        }
+        Ok(index)
+    };
+    Ok(match condition {
+        ConditionDefinition::Bool {
+            parameter: name,
+            value,
+        } => CompiledCondition::Bool {
+            parameter: parameter(name, ParameterKind::Bool)?,
+            value: *value,
+        },
+        ConditionDefinition::Float {
+            parameter: name,
+            comparison,
+            value,
+        } => {
+            if !value.is_finite() {
+                return Err(StateMachineDefinitionError::NonFiniteValue(name.clone()));
+            }
+            CompiledCondition::Float {
+                parameter: parameter(name, ParameterKind::Float)?,
+                comparison: *comparison,
+                value: *value,
+            }
+        }
+        ConditionDefinition::Int {
+            parameter: name,
+            comparison,
+            value,
+        } => CompiledCondition::Int {
+            parameter: parameter(name, ParameterKind::Int)?,
+            comparison: *comparison,
+            value: *value,
+        },
+        ConditionDefinition::Trigger { parameter: name } => CompiledCondition::Trigger {
+            parameter: parameter(name, ParameterKind::Trigger)?,
+        },
+        ConditionDefinition::Complete => CompiledCondition::Complete,
+        ConditionDefinition::Event { event } => {
+            let id = asset
+                .event_id(event)
+                .ok_or_else(|| StateMachineDefinitionError::UnknownEvent(event.clone()))?;
+            CompiledCondition::Event {
+                event: id.index() as usize,
+            }
+        }
+    })
+}
+
+fn next_state_machine_key() -> NonZeroU64 {
+    let value = NEXT_STATE_MACHINE_KEY
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |value| {
+            value.checked_add(1)
+        })
+        .expect("the process exhausted its state machine identity space");
+    NonZeroU64::new(value).expect("the state machine identity counter starts at one")
+}
@@ -0,0 +1,211 @@
+//! Phase-synchronized groups of mixer tracks.
+
+use std::{num::NonZeroU64, time::Duration};
+
+use crate::{TrackId, player::Playback};
+
+/// Identifies one phase-synchronized group of tracks within a mixer.
+///
+/// Group IDs are scoped to the mixer that created them and remain stale after
+/// removal.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SyncGroupId {
+    pub(crate) mixer: NonZeroU64,
+    pub(crate) serial: NonZeroU64,
+}
+
+impl SyncGroupId {
+    /// Returns the nonzero mixer-local serial number.
+    #[must_use]
+    pub const fn get(self) -> NonZeroU64 {
+        self.serial
+    }
+}
+
+/// How a sync group chooses the rate at which its shared phase advances.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum SyncPolicy {
+    /// The member track's own clock drives the phase; every other member
+    /// follows. A paused or zero-speed leader holds the phase. Falls back to
+    /// [`SyncPolicy::Weighted`] while that track is not an active member.
+    Leader(TrackId),
+    /// Each active, moving member contributes its own phase rate in
+    /// proportion to its current track weight; the base track weighs one.
+    Weighted,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SyncGroup {
+    pub(crate) id: SyncGroupId,
+    pub(crate) policy: SyncPolicy,
+    /// Normalized phase in `[0, 1)` reached by the latest update, or `None`
+    /// until a member has played.
+    pub(crate) phase: Option<f64>,
+    /// Phase planned by the update in progress, committed once it succeeds.
+    pub(crate) next_phase: Option<f64>,
+}
+
+impl SyncGroup {
+    pub(crate) const fn new(id: SyncGroupId, policy: SyncPolicy) -> Self {
+        Self {
+            id,
+            policy,
+            phase: None,
+            next_phase: None,
+        }
+    }
+
+    pub(crate) const fn leader(self) -> Option<TrackId> {
+        match self.policy {
+            SyncPolicy::Leader(track) => Some(track),
+            SyncPolicy::Weighted => None,
+        }
+    }
+}
+
+/// The clock a member starts its update from and how far it moves.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SyncPlan {
+    pub(crate) start: Playback,
+    pub(crate) delta: Duration,
+}
+
+/// One member's clock as seen by its group during an update.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SyncMember {
+    pub(crate) playback: Playback,
+    /// The member's own scaled playback delta.
+    pub(crate) delta: Duration,
+    pub(crate) weight: f32,
+    /// Whether the member is paused or at zero speed, so it neither follows
+    /// nor drives the group phase unless it leads.
+    pub(crate) held: bool,
+}
+
+impl SyncMember {
+    /// Returns the position within the member's cycle; an intro before a
+    /// loop range counts as phase zero.
+    pub(crate) fn phase(self) -> f64 {
+        let (start, end) = self.playback.cycle();
+        let offset = self.playback.local_ticks.clamp(start, end) - start;
+        offset as f64 / self.playback.cycle_ticks() as f64
+    }
+
+    pub(crate) fn rate(self) -> f64 {
+        self.delta.as_nanos() as f64 / self.playback.cycle_ticks() as f64
+    }
+}
+
+/// Gathers the phase rate of one group from its active members.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct SyncRate {
+    weighted: f64,
+    total_weight: f64,
+    sum: f64,
+    count: u32,
+    leader: Option<SyncMember>,
+    first: Option<SyncMember>,
+}
+
+impl SyncRate {
+    pub(crate) fn add(&mut self, member: SyncMember, leads: bool) {
+        if member.held && !leads {
+            return;
+        }
+        let rate = member.rate();
+        let weight = f64::from(member.weight);
+        self.weighted += weight * rate;
+        self.total_weight += weight;
+        self.sum += rate;
+        self.count += 1;
+        if leads {
+            self.leader = Some(member);
+        }
+        self.first.get_or_insert(member);
+    }
+
+    pub(crate) const fn has_leader(self) -> bool {
+        self.leader.is_some()
+    }
+
+    /// Returns the phase advance in cycles, or `None` without members.
+    pub(crate) fn cycles(self) -> Option<f64> {
+        if let Some(leader) = self.leader {
+            return Some(leader.rate());
+        }
+        self.first?;
+        Some(if self.total_weight > 0.0 {
+            self.weighted / self.total_weight
+        } else {
+            self.sum / f64::from(self.count)
+        })
+    }
+
+    /// Returns the phase a group without history adopts: its leader's, or
+    /// else its first member's.
+    pub(crate) fn initial_phase(self) -> Option<f64> {
+        self.leader.or(self.first).map(SyncMember::phase)
+    }
+}
+
+/// Plans one member's update: realigns a fresh or newly joined clock to the
+/// group phase, then advances it by the group's cycles.
+///
+/// Held followers are not planned; they keep their own stopped clock and
+/// realign once they move again.
+pub(crate) fn plan(
+    member: SyncMember,
+    aligned: bool,
+    leads: bool,
+    phase: f64,
+    cycles: f64,
+) -> Option<SyncPlan> {
+    let start = if aligned && !member.playback.pending_start {
+        member.playback
+    } else {
+        align(member.playback, phase)
+    };
+    let delta = if leads {
+        member.delta
+    } else {
+        member_delta(start, cycles)?
+    };
+    Some(SyncPlan { start, delta })
+}
+
+/// Moves a member's clock onto `phase` without emitting what it skipped.
+///
+/// A member landing exactly on its window start keeps a pending start so its
+/// start events still fire once.
+fn align(mut playback: Playback, phase: f64) -> Playback {
+    let (start, end) = playback.cycle();
+    let mut local_ticks = start + phase_ticks(phase, end - start);
+    if playback.wrap().is_some() && local_ticks == end {
+        local_ticks = start;
+    }
+    if local_ticks != playback.start_ticks {
+        playback.pending_start = false;
+    }
+    playback.local_ticks = local_ticks;
+    playback.complete = false;
+    playback
+}
+
+/// Returns the clock delta that moves a member forward by `cycles` of its
+/// own duration, or `None` when it cannot be represented.
+fn member_delta(playback: Playback, cycles: f64) -> Option<Duration> {
+    let ticks = (cycles * playback.cycle_ticks() as f64).round();
+    if !ticks.is_finite() || ticks >= u128::MAX as f64 {
+        return None;
+    }
+    let ticks = ticks.max(0.0) as u128;
+    const NANOS_PER_SECOND: u128 = 1_000_000_000;
+    let seconds = u64::try_from(ticks / NANOS_PER_SECOND).ok()?;
+    Some(Duration::new(seconds, (ticks % NANOS_PER_SECOND) as u32))
+}
+
+fn phase_ticks(phase: f64, duration_ticks: u64) -> u64 {
+    let ticks = (phase.clamp(0.0, 1.0) * duration_ticks as f64).round();
+    (ticks as u64).min(duration_ticks)
+}
@@ -0,0 +1,168 @@
+//! Caller-supplied textures drawn in place of an attachment's atlas region.
+
+use glam::Vec2;
+use thiserror::Error;
+
+use crate::{AttachmentId, IdError, Skeleton};
+
+/// A renderer-defined texture page outside the asset's packed atlas.
+///
+/// The core never interprets the key. Renderers choose it, for example from
+/// an image handle, and resolve it again when drawing.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CustomPageKey(u64);
+
+impl CustomPageKey {
+    /// Wraps a renderer-defined page key.
+    #[must_use]
+    pub const fn new(key: u64) -> Self {
+        Self(key)
+    }
+
+    /// Returns the renderer-defined page key.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
+
+/// A custom page and UV rectangle that replace an attachment's atlas region.
+///
+/// UVs use the same top-left origin as atlas-page UVs. The rectangle maps the
+/// attachment's whole unpacked image, so atlas trimming and packed rotation
+/// no longer apply.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextureOverride {
+    page: CustomPageKey,
+    uv_min: Vec2,
+    uv_max: Vec2,
+}
+
+impl TextureOverride {
+    /// Maps the whole custom page onto the attachment.
+    #[must_use]
+    pub const fn full_page(page: CustomPageKey) -> Self {
+        Self {
+            page,
+            uv_min: Vec2::ZERO,
+            uv_max: Vec2::ONE,
+        }
+    }
+
+    /// Maps the rectangle from `uv_min` (top-left) to `uv_max` (bottom-right)
+    /// onto the attachment.
+    ///
+    /// Swapped corners are kept and mirror the image.
+    pub fn new(
+        page: CustomPageKey,
+        uv_min: Vec2,
+        uv_max: Vec2,
+    ) -> Result<Self, TextureOverrideError> {
+        if !uv_min.is_finite() || !uv_max.is_finite() {
+            return Err(TextureOverrideError::NonFiniteUv);
+        }
+        Ok(Self {
+            page,
+            uv_min,
+            uv_max,
+        })
+    }
+
+    /// Returns the custom page.
+    #[must_use]
+    pub const fn page(self) -> CustomPageKey {
+        self.page
+    }
+
+    /// Returns the UV of the image's top-left corner.
+    #[must_use]
+    pub const fn uv_min(self) -> Vec2 {
+        self.uv_min
+    }
+
+    /// Returns the UV of the image's bottom-right corner.
+    #[must_use]
+    pub const fn uv_max(self) -> Vec2 {
+        self.uv_max
+    }
+
+    /// Returns page UVs for the bottom-left, top-left, top-right and
+    /// bottom-right image corners.
+    pub(crate) fn corners(self) -> [Vec2; 4] {
+        [
+            Vec2::new(self.uv_min.x, self.uv_max.y),
+            self.uv_min,
+            Vec2::new(self.uv_max.x, self.uv_min.y),
+            self.uv_max,
+        ]
+    }
+}
+
+/// A failure to build or apply a [`TextureOverride`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum TextureOverrideError {
+    /// The attachment belongs to another asset.
+    #[error("the overridden attachment is invalid: {0}")]
+    InvalidAttachment(
+        #[doc = "The underlying asset-scoped identifier error."]
+        #[source]
+        IdError,
+    ),
+    /// The attachment is not a region or mesh, so it draws no texture.
+    #[error("the attachment draws no texture")]
+    Untextured,
+    /// A UV coordinate is NaN or infinite.
+    #[error("texture override UVs must be finite")]
+    NonFiniteUv,
+}
+
+impl Skeleton {
+    /// Draws `attachment` from a custom texture page on this instance,
+    /// replacing any earlier override.
+    ///
+    /// The override is instance-owned: it applies in every slot and skin that
+    /// shows the attachment, and snapshots neither capture nor restore it.
+    pub fn set_texture_override(
+        &mut self,
+        attachment: AttachmentId,
+        texture: TextureOverride,
+    ) -> Result<(), TextureOverrideError> {
+        let index = self.textured_attachment_index(attachment)?;
+        self.texture_overrides[index] = Some(texture);
+        Ok(())
+    }
+
+    /// Draws `attachment` from its atlas region again, returning the removed
+    /// override.
+    pub fn clear_texture_override(
+        &mut self,
+        attachment: AttachmentId,
+    ) -> Result<Option<TextureOverride>, TextureOverrideError> {
+        let index = self.textured_attachment_index(attachment)?;
+        Ok(self.texture_overrides[index].take())
+    }
+
+    /// Returns the custom texture `attachment` is drawn from, if any.
+    pub fn texture_override(
+        &self,
+        attachment: AttachmentId,
+    ) -> Result<Option<TextureOverride>, TextureOverrideError> {
+        let index = self.textured_attachment_index(attachment)?;
+        Ok(self.texture_overrides[index])
+    }
+
+    fn textured_attachment_index(
+        &self,
+        attachment: AttachmentId,
+    ) -> Result<usize, TextureOverrideError> {
+        let attachment = self
+            .asset()
+            .attachment(attachment)
+            .map_err(TextureOverrideError::InvalidAttachment)?;
+        if attachment.as_region().is_none() && attachment.as_mesh().is_none() {
+            return Err(TextureOverrideError::Untextured);
+        }
+        Ok(attachment.ordinal())
+    }
+}
@@ -0,0 +1,650 @@
+//! Borrowed, read-only views of authored animation timelines and their keys.
+
+use std::time::Duration;
+
+use glam::Vec2;
+
+use crate::{
+    AnimationRef, BendDirection, BoneId, EventDefinitionRef, EventId, IkConstraintId, Mix,
+    PropertyKey, Rgba, Rgba8, SkeletonAsset, SlotId, TransformConstraintId, TransformMix,
+    TransformMixChannel,
+    animation::{
+        AttachmentFrame, ColourFrame, DrawOrderFrame, EventFrame, FrameCurve, IkFrame, ScalarFrame,
+        TimelineData, TimelineTime, TransformFrame, Vec2Frame, sample_colour, sample_scalar,
+        sample_vec2, transform_pose_values,
+    },
+};
+
+impl<'a> AnimationRef<'a> {
+    /// Iterates the authored timelines in source order.
+    ///
+    /// Every view borrows the loaded key data; nothing is copied or
+    /// resampled.
+    pub fn timelines(
+        self,
+    ) -> impl DoubleEndedIterator<Item = TimelineRef<'a>> + ExactSizeIterator + 'a {
+        let asset = self.asset();
+        self.data()
+            .timelines
+            .iter()
+            .map(move |timeline| TimelineRef::new(asset, timeline))
+    }
+
+    /// Returns the first timeline that keys `property`.
+    ///
+    /// Compound timelines key several properties, so bone scale magnitude and
+    /// sign share one scale timeline, for example.
+    #[must_use]
+    pub fn timeline(self, property: PropertyKey) -> Option<TimelineRef<'a>> {
+        self.timelines().find(|timeline| timeline.affects(property))
+    }
+}
+
+/// A borrowed view of one authored timeline and its keys.
+///
+/// Bone values are relative to the setup pose: rotation and shear add
+/// degrees, translation adds skeleton units, and scale multiplies.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum TimelineRef<'a> {
+    /// Rotation keys for one bone.
+    BoneRotation(BoneId, ScalarTimelineRef<'a>),
+    /// Translation keys for one bone.
+    BoneTranslation(BoneId, Vec2TimelineRef<'a>),
+    /// Scale keys for one bone, including their axis signs.
+    BoneScale(BoneId, Vec2TimelineRef<'a>),
+    /// Shear keys for one bone.
+    BoneShear(BoneId, Vec2TimelineRef<'a>),
+    /// Light-colour keys for one slot.
+    SlotColor(SlotId, ColorTimelineRef<'a>),
+    /// Attachment-placeholder keys for one slot.
+    SlotAttachment(SlotId, AttachmentTimelineRef<'a>),
+    /// Mix and bend-direction keys for one IK constraint.
+    IkConstraint(IkConstraintId, IkTimelineRef<'a>),
+    /// Mix-channel keys for one transform constraint.
+    TransformConstraint(TransformConstraintId, TransformTimelineRef<'a>),
+    /// Skeleton slot draw-order keys.
+    DrawOrder(DrawOrderTimelineRef<'a>),
+    /// Authored event keys.
+    Events(EventTimelineRef<'a>),
+    /// A timeline record outside the supported profile, by its source name.
+    Unsupported(&'a str),
+}
+
+impl<'a> TimelineRef<'a> {
+    fn new(asset: &'a SkeletonAsset, timeline: &'a TimelineData) -> Self {
+        let key = asset.key();
+        match timeline {
+            TimelineData::BoneRotate { bone, frames } => {
+                Self::BoneRotation(BoneId::new(key, *bone), ScalarTimelineRef { frames })
+            }
+            TimelineData::BoneTranslate { bone, frames } => {
+                Self::BoneTranslation(BoneId::new(key, *bone), Vec2TimelineRef { frames })
+            }
+            TimelineData::BoneScale { bone, frames } => {
+                Self::BoneScale(BoneId::new(key, *bone), Vec2TimelineRef { frames })
+            }
+            TimelineData::BoneShear { bone, frames } => {
+                Self::BoneShear(BoneId::new(key, *bone), Vec2TimelineRef { frames })
+            }
+            TimelineData::SlotColour { slot, frames } => {
+                Self::SlotColor(SlotId::new(key, *slot), ColorTimelineRef { frames })
+            }
+            TimelineData::SlotAttachment { slot, frames } => {
+                Self::SlotAttachment(SlotId::new(key, *slot), AttachmentTimelineRef { frames })
+            }
+            TimelineData::Ik { constraint, frames } => Self::IkConstraint(
+                IkConstraintId::new(key, *constraint),
+                IkTimelineRef { frames },
+            ),
+            TimelineData::Transform { constraint, frames } => Self::TransformConstraint(
+                TransformConstraintId::new(key, *constraint),
+                TransformTimelineRef { frames },
+            ),
+            TimelineData::DrawOrder { frames } => {
+                Self::DrawOrder(DrawOrderTimelineRef { asset, frames })
+            }
+            TimelineData::Events { frames } => Self::Events(EventTimelineRef { asset, frames }),
+            TimelineData::Unsupported { name, .. } => Self::Unsupported(name),
+        }
+    }
+
+    /// Returns whether this timeline keys `property`.
+    #[must_use]
+    pub fn affects(self, property: PropertyKey) -> bool {
+        match (self, property) {
+            (Self::BoneRotation(bone, _), PropertyKey::BoneRotation(other))
+            | (Self::BoneTranslation(bone, _), PropertyKey::BoneTranslation(other))
+            | (
+                Self::BoneScale(bone, _),
+                PropertyKey::BoneScaleMagnitude(other) | PropertyKey::BoneScaleSign(other),
+            )
+            | (Self::BoneShear(bone, _), PropertyKey::BoneShear(other)) => bone == other,
+            (Self::SlotColor(slot, _), PropertyKey::SlotColor(other))
+            | (Self::SlotAttachment(slot, _), PropertyKey::SlotAttachment(other)) => slot == other,
+            (
+                Self::IkConstraint(constraint, _),
+                PropertyKey::IkMix(other) | PropertyKey::IkBendDirection(other),
+            ) => constraint == other,
+            (Self::TransformConstraint(constraint, _), PropertyKey::TransformMix(other, _)) => {
+                constraint == other
+            }
+            (Self::DrawOrder(_), PropertyKey::DrawOrder) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the number of keys, or zero for an unsupported record.
+    #[must_use]
+    pub fn key_count(self) -> usize {
+        match self {
+            Self::BoneRotation(_, timeline) => timeline.frames.len(),
+            Self::BoneTranslation(_, timeline)
+            | Self::BoneScale(_, timeline)
+            | Self::BoneShear(_, timeline) => timeline.frames.len(),
+            Self::SlotColor(_, timeline) => timeline.frames.len(),
+            Self::SlotAttachment(_, timeline) => timeline.frames.len(),
+            Self::IkConstraint(_, timeline) => timeline.frames.len(),
+            Self::TransformConstraint(_, timeline) => timeline.frames.len(),
+            Self::DrawOrder(timeline) => timeline.frames.len(),
+            Self::Events(timeline) => timeline.frames.len(),
+            Self::Unsupported(_) => 0,
+        }
+    }
+}
+
+/// The interpolation from one key's channel to the next key.
+///
+/// The final key's curve is retained from the source but never sampled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum KeyCurve {
+    /// Straight-line interpolation.
+    Linear,
+    /// Holds this key's value until the next key.
+    Stepped,
+    /// A cubic Bezier curve, sampled as a ten-segment polyline.
+    Bezier(BezierCurve),
+}
+
+impl KeyCurve {
+    fn channel<const CHANNELS: usize>(curve: &FrameCurve<CHANNELS>, channel: usize) -> Self {
+        match curve {
+            FrameCurve::Linear => Self::Linear,
+            FrameCurve::Stepped => Self::Stepped,
+            FrameCurve::Bezier(channels) => Self::Bezier(BezierCurve(channels[channel])),
+        }
+    }
+}
+
+/// The two inner control points of a Bezier key curve.
+///
+/// X is the fraction of the interval to the next key, normally from 0
+/// through 1. Y is an absolute channel value in the key's own units, so the
+/// curve runs from this key's value to the next key's value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BezierCurve([f32; 4]);
+
+impl BezierCurve {
+    /// Creates a curve from its leaving and entering control points.
+    #[must_use]
+    pub const fn new(control1: Vec2, control2: Vec2) -> Self {
+        Self([control1.x, control1.y, control2.x, control2.y])
+    }
+
+    /// Returns the control point leaving this key.
+    #[must_use]
+    pub const fn control1(self) -> Vec2 {
+        Vec2::new(self.0[0], self.0[1])
+    }
+
+    /// Returns the control point entering the next key.
+    #[must_use]
+    pub const fn control2(self) -> Vec2 {
+        Vec2::new(self.0[2], self.0[3])
+    }
+
+    pub(crate) const fn points(self) -> [f32; 4] {
+        self.0
+    }
+}
+
+fn timeline_time(time: Duration) -> TimelineTime {
+    TimelineTime {
+        ticks: u64::try_from(time.as_nanos()).unwrap_or(u64::MAX),
+    }
+}
+
+/// Borrowed keys carrying one scalar channel.
+#[derive(Clone, Copy, Debug)]
+pub struct ScalarTimelineRef<'a> {
+    frames: &'a [ScalarFrame],
+}
+
+impl<'a> ScalarTimelineRef<'a> {
+    /// Iterates the keys in strictly increasing time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = ScalarKeyRef<'a>> + ExactSizeIterator {
+        self.frames.iter().map(|frame| ScalarKeyRef { frame })
+    }
+
+    /// Samples the timeline exactly as playback does, or returns `None`
+    /// before the first key.
+    #[must_use]
+    pub fn sample(self, time: Duration) -> Option<f32> {
+        sample_scalar(self.frames, timeline_time(time))
+    }
+}
+
+/// One borrowed scalar key.
+#[derive(Clone, Copy, Debug)]
+pub struct ScalarKeyRef<'a> {
+    frame: &'a ScalarFrame,
+}
+
+impl ScalarKeyRef<'_> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Returns the keyed value.
+    #[must_use]
+    pub const fn value(self) -> f32 {
+        self.frame.value
+    }
+
+    /// Returns the curve toward the next key.
+    #[must_use]
+    pub fn curve(self) -> KeyCurve {
+        KeyCurve::channel(&self.frame.curve, 0)
+    }
+}
+
+/// Borrowed keys carrying independent X and Y channels.
+#[derive(Clone, Copy, Debug)]
+pub struct Vec2TimelineRef<'a> {
+    frames: &'a [Vec2Frame],
+}
+
+impl<'a> Vec2TimelineRef<'a> {
+    /// Iterates the keys in strictly increasing time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = Vec2KeyRef<'a>> + ExactSizeIterator {
+        self.frames.iter().map(|frame| Vec2KeyRef { frame })
+    }
+
+    /// Samples the timeline exactly as playback does, or returns `None`
+    /// before the first key.
+    #[must_use]
+    pub fn sample(self, time: Duration) -> Option<Vec2> {
+        sample_vec2(self.frames, timeline_time(time)).map(Vec2::from_array)
+    }
+}
+
+/// One borrowed two-channel key.
+#[derive(Clone, Copy, Debug)]
+pub struct Vec2KeyRef<'a> {
+    frame: &'a Vec2Frame,
+}
+
+impl Vec2KeyRef<'_> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Returns the keyed X and Y values.
+    #[must_use]
+    pub const fn value(self) -> Vec2 {
+        Vec2::new(self.frame.x, self.frame.y)
+    }
+
+    /// Returns the X and Y curves toward the next key.
+    #[must_use]
+    pub fn curves(self) -> [KeyCurve; 2] {
+        core::array::from_fn(|channel| KeyCurve::channel(&self.frame.curve, channel))
+    }
+}
+
+/// Borrowed slot light-colour keys.
+#[derive(Clone, Copy, Debug)]
+pub struct ColorTimelineRef<'a> {
+    frames: &'a [ColourFrame],
+}
+
+impl<'a> ColorTimelineRef<'a> {
+    /// Iterates the keys in strictly increasing time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = ColorKeyRef<'a>> + ExactSizeIterator {
+        self.frames.iter().map(|frame| ColorKeyRef { frame })
+    }
+
+    /// Samples the timeline exactly as playback does, or returns `None`
+    /// before the first key.
+    #[must_use]
+    pub fn sample(self, time: Duration) -> Option<Rgba> {
+        sample_colour(self.frames, timeline_time(time))
+    }
+}
+
+/// One borrowed slot-colour key.
+#[derive(Clone, Copy, Debug)]
+pub struct ColorKeyRef<'a> {
+    frame: &'a ColourFrame,
+}
+
+impl ColorKeyRef<'_> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Returns the keyed colour as authored.
+    #[must_use]
+    pub const fn color8(self) -> Rgba8 {
+        self.frame.colour
+    }
+
+    /// Returns the keyed colour as normalized channels.
+    #[must_use]
+    pub fn color(self) -> Rgba {
+        Rgba::from_rgba8(self.frame.colour)
+    }
+
+    /// Returns the red, green, blue and alpha curves toward the next key.
+    ///
+    /// Bezier control values are normalized channels from 0 through 1.
+    #[must_use]
+    pub fn curves(self) -> [KeyCurve; 4] {
+        core::array::from_fn(|channel| KeyCurve::channel(&self.frame.curve, channel))
+    }
+}
+
+/// Borrowed slot attachment-placeholder keys.
+///
+/// Attachment keys always hold until the next key.
+#[derive(Clone, Copy, Debug)]
+pub struct AttachmentTimelineRef<'a> {
+    frames: &'a [AttachmentFrame],
+}
+
+impl<'a> AttachmentTimelineRef<'a> {
+    /// Iterates the keys in strictly increasing time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = AttachmentKeyRef<'a>> + ExactSizeIterator {
+        self.frames.iter().map(|frame| AttachmentKeyRef { frame })
+    }
+}
+
+/// One borrowed attachment-placeholder key.
+#[derive(Clone, Copy, Debug)]
+pub struct AttachmentKeyRef<'a> {
+    frame: &'a AttachmentFrame,
+}
+
+impl<'a> AttachmentKeyRef<'a> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Returns the keyed placeholder name, or `None` when the key hides the
+    /// slot.
+    #[must_use]
+    pub fn placeholder(self) -> Option<&'a str> {
+        self.frame.placeholder_name.as_deref()
+    }
+}
+
+/// Borrowed IK constraint keys.
+#[derive(Clone, Copy, Debug)]
+pub struct IkTimelineRef<'a> {
+    frames: &'a [IkFrame],
+}
+
+impl<'a> IkTimelineRef<'a> {
+    /// Iterates the keys in strictly increasing time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = IkKeyRef<'a>> + ExactSizeIterator {
+        self.frames.iter().map(|frame| IkKeyRef { frame })
+    }
+}
+
+/// One borrowed IK constraint key.
+#[derive(Clone, Copy, Debug)]
+pub struct IkKeyRef<'a> {
+    frame: &'a IkFrame,
+}
+
+impl IkKeyRef<'_> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Returns the keyed mix.
+    #[must_use]
+    pub const fn mix(self) -> Mix {
+        self.frame.mix
+    }
+
+    /// Returns the keyed bend direction, which holds until the next key.
+    #[must_use]
+    pub const fn bend_direction(self) -> BendDirection {
+        self.frame.bend_direction
+    }
+
+    /// Returns the mix curve toward the next key.
+    #[must_use]
+    pub fn mix_curve(self) -> KeyCurve {
+        KeyCurve::channel(&self.frame.curve, 0)
+    }
+}
+
+/// Borrowed transform-constraint keys.
+#[derive(Clone, Copy, Debug)]
+pub struct TransformTimelineRef<'a> {
+    frames: &'a [TransformFrame],
+}
+
+impl<'a> TransformTimelineRef<'a> {
+    /// Iterates the keys in strictly increasing time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = TransformKeyRef<'a>> + ExactSizeIterator {
+        self.frames.iter().map(|frame| TransformKeyRef { frame })
+    }
+}
+
+/// One borrowed transform-constraint key.
+#[derive(Clone, Copy, Debug)]
+pub struct TransformKeyRef<'a> {
+    frame: &'a TransformFrame,
+}
+
+impl TransformKeyRef<'_> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Returns the keyed mix for one channel.
+    #[must_use]
+    pub fn mix(self, channel: TransformMixChannel) -> TransformMix {
+        let values = transform_pose_values(self.frame.pose);
+        TransformMix::new(values[channel.index()])
+            .expect("loaded transform constraint mixes are finite")
+    }
+
+    /// Returns one channel's curve toward the next key.
+    #[must_use]
+    pub fn curve(self, channel: TransformMixChannel) -> KeyCurve {
+        KeyCurve::channel(&self.frame.curve, channel.index())
+    }
+}
+
+/// Borrowed draw-order keys.
+///
+/// Draw-order keys always hold until the next key.
+#[derive(Clone, Copy, Debug)]
+pub struct DrawOrderTimelineRef<'a> {
+    asset: &'a SkeletonAsset,
+    frames: &'a [DrawOrderFrame],
+}
+
+impl<'a> DrawOrderTimelineRef<'a> {
+    /// Iterates the keys in strictly increasing time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = DrawOrderKeyRef<'a>> + ExactSizeIterator {
+        let asset = self.asset;
+        self.frames
+            .iter()
+            .map(move |frame| DrawOrderKeyRef { asset, frame })
+    }
+}
+
+/// One borrowed draw-order key.
+#[derive(Clone, Copy, Debug)]
+pub struct DrawOrderKeyRef<'a> {
+    asset: &'a SkeletonAsset,
+    frame: &'a DrawOrderFrame,
+}
+
+impl<'a> DrawOrderKeyRef<'a> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Iterates the moved slots and their offsets from setup draw order, in
+    /// setup order.
+    ///
+    /// A key without offsets restores the setup draw order.
+    pub fn offsets(
+        self,
+    ) -> impl DoubleEndedIterator<Item = (SlotId, i32)> + ExactSizeIterator + 'a {
+        let key = self.asset.key();
+        self.frame
+            .offsets
+            .iter()
+            .map(move |offset| (SlotId::new(key, offset.slot), offset.offset))
+    }
+}
+
+/// Borrowed event keys.
+#[derive(Clone, Copy, Debug)]
+pub struct EventTimelineRef<'a> {
+    asset: &'a SkeletonAsset,
+    frames: &'a [EventFrame],
+}
+
+impl<'a> EventTimelineRef<'a> {
+    /// Iterates the keys in time order.
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = EventKeyRef<'a>> + ExactSizeIterator {
+        let asset = self.asset;
+        self.frames
+            .iter()
+            .map(move |frame| EventKeyRef { asset, frame })
+    }
+}
+
+/// One borrowed event key with its resolved payload.
+#[derive(Clone, Copy, Debug)]
+pub struct EventKeyRef<'a> {
+    asset: &'a SkeletonAsset,
+    frame: &'a EventFrame,
+}
+
+impl<'a> EventKeyRef<'a> {
+    /// Returns the exact key time in nanosecond ticks.
+    #[must_use]
+    pub const fn ticks(self) -> u64 {
+        self.frame.time.ticks
+    }
+
+    /// Returns the exact key time.
+    #[must_use]
+    pub const fn time(self) -> Duration {
+        self.frame.time.as_duration()
+    }
+
+    /// Returns the keyed event definition.
+    #[must_use]
+    pub fn definition(self) -> EventDefinitionRef<'a> {
+        self.asset
+            .event_definition(EventId::new(self.asset.key(), self.frame.event))
+            .expect("loaded animation events reference their own asset")
+    }
+
+    /// Returns the resolved integer payload for this key.
+    #[must_use]
+    pub const fn integer(self) -> i32 {
+        self.frame.payload.integer
+    }
+
+    /// Returns the resolved floating-point payload for this key.
+    #[must_use]
+    pub const fn float(self) -> f32 {
+        self.frame.payload.float
+    }
+
+    /// Returns the resolved string payload for this key.
+    #[must_use]
+    pub fn string(self) -> Option<&'a str> {
+        self.frame.payload.string.as_deref()
+    }
+
+    /// Returns the resolved audio volume for this key.
+    #[must_use]
+    pub const fn volume(self) -> f32 {
+        self.frame.payload.volume
+    }
+
+    /// Returns the resolved audio balance for this key.
+    #[must_use]
+    pub const fn balance(self) -> f32 {
+        self.frame.payload.balance
+    }
+}
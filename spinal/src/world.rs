@@ -1,7 +1,7 @@
 use glam::Vec2;
 use thiserror::Error;
 
-use crate::{Angle, BendDirection, BoneTransform};
+use crate::{Angle, BendDirection, BoneTransform, float};
 
 const SAFE_INVERSE_EPSILON: f64 = 32.0 * f32::EPSILON as f64;
 const GEOMETRY_EPSILON: f64 = 32.0 * f32::EPSILON as f64;
@@ -115,6 +115,19 @@ impl WorldTransform {
         checked_vec2(x, y)
     }
 
+    /// Transforms a skeleton-space vector back to local coordinates, ignoring
+    /// translation.
+    ///
+    /// Returns `None` under the same conditions as [`Self::try_inverse_point`].
+    #[must_use]
+    pub fn try_inverse_vector(self, vector: Vec2) -> Option<Vec2> {
+        if !vector.is_finite() {
+            return None;
+        }
+        let (x, y) = self.try_inverse_vector_f64(f64::from(vector.x), f64::from(vector.y))?;
+        checked_vec2(x, y)
+    }
+
     fn transform_point_f64(self, x: f64, y: f64) -> (f64, f64) {
         let (x, y) = self.transform_vector_f64(x, y);
         (
@@ -130,6 +143,15 @@ impl WorldTransform {
         )
     }
 
+    /// Interpolates every component linearly toward `other`.
+    pub(crate) fn lerp(self, other: Self, alpha: f32) -> Self {
+        Self {
+            translation: self.translation.lerp(other.translation, alpha),
+            x_axis: self.x_axis.lerp(other.x_axis, alpha),
+            y_axis: self.y_axis.lerp(other.y_axis, alpha),
+        }
+    }
+
     fn try_inverse_point_f64(self, x: f64, y: f64) -> Option<(f64, f64)> {
         self.try_inverse_vector_f64(
             x - f64::from(self.translation.x),
@@ -146,8 +168,8 @@ impl WorldTransform {
         let c = f64::from(self.x_axis.y);
         let d = f64::from(self.y_axis.y);
         let determinant = a * d - b * c;
-        let x_length = a.hypot(c);
-        let y_length = b.hypot(d);
+        let x_length = float::hypot(a, c);
+        let y_length = float::hypot(b, d);
         let area_scale = x_length * y_length;
         if area_scale == 0.0
             || !area_scale.is_finite()
@@ -215,8 +237,8 @@ pub(crate) fn normal_local_to_world(
     let shear = local.shear();
     let x_angle = rotation + f64::from(shear.x().as_radians());
     let y_angle = rotation + std::f64::consts::FRAC_PI_2 + f64::from(shear.y().as_radians());
-    let (x_sine, x_cosine) = x_angle.sin_cos();
-    let (y_sine, y_cosine) = y_angle.sin_cos();
+    let (x_sine, x_cosine) = float::sin_cos(x_angle);
+    let (y_sine, y_cosine) = float::sin_cos(y_angle);
     let scale = local.scale();
     let local_x = (f64::from(scale.x) * x_cosine, f64::from(scale.x) * x_sine);
     let local_y = (f64::from(scale.y) * y_cosine, f64::from(scale.y) * y_sine);
@@ -267,7 +289,7 @@ pub(crate) fn solve_one_bone_ik(
     let translation = bone_local.translation();
     let target_x = target_x - f64::from(translation.x);
     let target_y = target_y - f64::from(translation.y);
-    let target_distance = target_x.hypot(target_y);
+    let target_distance = float::hypot(target_x, target_y);
     if target_distance <= GEOMETRY_EPSILON * target_distance.max(1.0) {
         return Some(OneBoneIkSolution::PreservedCoincident);
     }
@@ -277,7 +299,7 @@ pub(crate) fn solve_one_bone_ik(
     } else {
         0.0
     };
-    let target_rotation = target_y.atan2(target_x)
+    let target_rotation = float::atan2(target_y, target_x)
         - f64::from(bone_local.shear().x().as_radians())
         - signed_scale_correction;
     nearest_angle(bone_local.rotation(), target_rotation).map(OneBoneIkSolution::Rotation)
@@ -292,13 +314,13 @@ pub(crate) fn solve_world_rotation(
         return None;
     }
     let desired = f64::from(desired_world_rotation.as_radians());
-    let (world_y, world_x) = desired.sin_cos();
+    let (world_y, world_x) = float::sin_cos(desired);
     let (local_x, local_y) = if let Some(parent) = parent {
         parent.try_inverse_vector_f64(world_x, world_y)?
     } else {
         (world_x, world_y)
     };
-    let length = local_x.hypot(local_y);
+    let length = float::hypot(local_x, local_y);
     if length <= GEOMETRY_EPSILON {
         return None;
     }
@@ -307,7 +329,7 @@ pub(crate) fn solve_world_rotation(
     } else {
         0.0
     };
-    let desired_local = local_y.atan2(local_x)
+    let desired_local = float::atan2(local_y, local_x)
         - f64::from(bone_local.shear().x().as_radians())
         - signed_scale_correction;
     nearest_angle(bone_local.rotation(), desired_local)
@@ -333,7 +355,7 @@ pub(crate) fn solve_two_bone_ik(
     let parent_translation = parent_local.translation();
     let target_x = target_x - f64::from(parent_translation.x);
     let target_y = target_y - f64::from(parent_translation.y);
-    let target_distance = target_x.hypot(target_y);
+    let target_distance = float::hypot(target_x, target_y);
 
     let parent_scale = parent_local.scale();
     let scale_x = f64::from(parent_scale.x);
@@ -354,14 +376,14 @@ pub(crate) fn solve_two_bone_ik(
     // Keeping an authored offset here changes the first-link angle and can
     // select the opposite bend branch for nearly straight limbs.
     let child_y = 0.0;
-    if child_x.hypot(child_y) <= GEOMETRY_EPSILON {
+    if float::hypot(child_x, child_y) <= GEOMETRY_EPSILON {
         return None;
     }
 
     let mut theta_values = [None; 8];
     let mut theta_count = 0;
     if uniform_scale {
-        let first_length = child_x.hypot(child_y);
+        let first_length = float::hypot(child_x, child_y);
         let scaled_target_distance = target_distance / scale_x.abs();
         let denominator = 2.0 * child_reach * first_length;
         if denominator == 0.0 || !scaled_target_distance.is_finite() {
@@ -371,8 +393,8 @@ pub(crate) fn solve_two_bone_ik(
             - first_length * first_length
             - child_reach * child_reach)
             / denominator;
-        let joint_angle = cosine.clamp(-1.0, 1.0).acos();
-        let first_angle = child_y.atan2(child_x);
+        let joint_angle = float::acos(cosine.clamp(-1.0, 1.0));
+        let first_angle = float::atan2(child_y, child_x);
         push_unique_angle(
             &mut theta_values,
             &mut theta_count,
@@ -454,8 +476,16 @@ pub(crate) fn solve_two_bone_ik(
 
         for cosine in cosine_values.into_iter().take(cosine_count) {
             let sine = (1.0 - cosine * cosine).max(0.0).sqrt();
-            push_unique_angle(&mut theta_values, &mut theta_count, sine.atan2(cosine));
-            push_unique_angle(&mut theta_values, &mut theta_count, (-sine).atan2(cosine));
+            push_unique_angle(
+                &mut theta_values,
+                &mut theta_count,
+                float::atan2(sine, cosine),
+            );
+            push_unique_angle(
+                &mut theta_values,
+                &mut theta_count,
+                float::atan2(-sine, cosine),
+            );
         }
     }
 
@@ -466,11 +496,10 @@ pub(crate) fn solve_two_bone_ik(
     let mut best = None;
 
     for theta in theta_values.into_iter().take(theta_count).flatten() {
-        let cosine = theta.cos();
-        let sine = theta.sin();
+        let (sine, cosine) = float::sin_cos(theta);
         let tip_x = scale_x * (child_x + child_reach * cosine);
         let tip_y = scale_y * (child_y + child_reach * sine);
-        let tip_distance = tip_x.hypot(tip_y);
+        let tip_distance = float::hypot(tip_x, tip_y);
         if !tip_distance.is_finite() {
             continue;
         }
@@ -478,12 +507,12 @@ pub(crate) fn solve_two_bone_ik(
             if target_distance <= GEOMETRY_EPSILON || tip_distance <= GEOMETRY_EPSILON {
                 current_parent_rotation
             } else {
-                target_y.atan2(target_x) - tip_y.atan2(tip_x)
+                float::atan2(target_y, target_x) - float::atan2(tip_y, tip_x)
             };
         let child_target = theta - child_shear;
         let parent_delta = shortest_angle_delta_f64(current_parent_rotation, parent_target);
         let child_delta = shortest_angle_delta_f64(current_child_rotation, child_target);
-        let bend_measure = child_target.sin();
+        let bend_measure = float::sin(child_target);
         let bend_matches = if positive_bend {
             bend_measure >= -GEOMETRY_EPSILON
         } else {
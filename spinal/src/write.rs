@@ -0,0 +1,718 @@
+//! Serialization of a linked asset back to Spine 4.3 skeleton JSON.
+
+use crate::{
+    Angle, BendDirection, BoneTransform, Rgba8, SkeletonAsset,
+    animation::{
+        AnimationData, FrameCurve, NANOS_PER_SECOND, TimelineData, TimelineTime,
+        transform_pose_values,
+    },
+    asset::{AttachmentData, AttachmentDataKind, ConstraintData},
+    json::{JsonMember, JsonValue},
+    mesh::{MeshAttachmentData, MeshVerticesData},
+};
+
+/// Writes a linked asset back out as Spine 4.3 skeleton JSON.
+///
+/// Supported records are regenerated from the typed asset, so edits made by
+/// [`crate::build_animations`], [`crate::retarget_animations`] or
+/// [`crate::mirror_animations`] are written as authored keys. Unknown fields,
+/// unsupported attachments, constraints and timelines, and unknown top-level
+/// sections are written back verbatim from the loaded source. Loading the
+/// output with the original atlas text produces an equivalent asset.
+#[must_use]
+pub fn write_json(asset: &SkeletonAsset) -> Vec<u8> {
+    let mut root = Members::default();
+    let mut metadata = Members::default();
+    metadata.push("spine", string(asset.spine_version()));
+    metadata.extend(asset.retained_metadata());
+    root.push("skeleton", metadata.into_value());
+
+    root.push(
+        "bones",
+        JsonValue::Array(
+            (0..asset.bones().len())
+                .map(|index| bone(asset, index))
+                .collect(),
+        ),
+    );
+    if asset.slots().len() > 0 {
+        root.push(
+            "slots",
+            JsonValue::Array(
+                (0..asset.slots().len())
+                    .map(|index| slot(asset, index))
+                    .collect(),
+            ),
+        );
+    }
+    if asset.constraints().len() > 0 {
+        root.push(
+            "constraints",
+            JsonValue::Array(
+                (0..asset.constraints().len())
+                    .map(|index| constraint(asset, asset.constraint_data(index)))
+                    .collect(),
+            ),
+        );
+    }
+    if asset.skin_count() > 0 {
+        root.push(
+            "skins",
+            JsonValue::Array(
+                (0..asset.skin_count())
+                    .map(|index| skin(asset, index))
+                    .collect(),
+            ),
+        );
+    }
+    if asset.event_definitions().len() > 0 {
+        let mut events = Members::default();
+        for index in 0..asset.event_definitions().len() {
+            let event = asset.event_data(index);
+            let mut fields = Members::default();
+            if event.payload.integer != 0 {
+                fields.push("int", JsonValue::I64(event.payload.integer.into()));
+            }
+            if event.payload.float != 0.0 {
+                fields.push("float", number(event.payload.float));
+            }
+            if let Some(text) = &event.payload.string {
+                fields.push("string", string(text));
+            }
+            if let Some(audio) = &event.audio {
+                fields.push("audio", string(audio));
+            }
+            if event.payload.volume != 1.0 {
+                fields.push("volume", number(event.payload.volume));
+            }
+            if event.payload.balance != 0.0 {
+                fields.push("balance", number(event.payload.balance));
+            }
+            fields.extend(&event.retained);
+            events.push(&event.name, fields.into_value());
+        }
+        root.push("events", events.into_value());
+    }
+    if asset.animations().len() > 0 {
+        let mut animations = Members::default();
+        for index in 0..asset.animations().len() {
+            let data = asset.animation_data(index);
+            animations.push(&data.name, animation(asset, data));
+        }
+        root.push("animations", animations.into_value());
+    }
+    root.extend(asset.retained_sections());
+
+    serde_json::to_vec_pretty(&root.into_value())
+        .expect("an in-memory JSON tree with finite numbers always serializes")
+}
+
+fn bone(asset: &SkeletonAsset, index: usize) -> JsonValue {
+    let data = asset.bone_data(index);
+    let mut fields = Members::default();
+    fields.push("name", string(&data.name));
+    if let Some(parent) = data.parent {
+        fields.push("parent", string(&asset.bone_data(parent as usize).name));
+    }
+    if data.length != 0.0 {
+        fields.push("length", number(data.length));
+    }
+    transform(&mut fields, data.setup_transform);
+    let shear = data.setup_transform.shear();
+    if shear.x() != Angle::ZERO {
+        fields.push("shearX", number(degrees(shear.x())));
+    }
+    if shear.y() != Angle::ZERO {
+        fields.push("shearY", number(degrees(shear.y())));
+    }
+    fields.extend(&data.retained);
+    fields.into_value()
+}
+
+fn transform(fields: &mut Members, transform: BoneTransform) {
+    let translation = transform.translation();
+    if translation.x != 0.0 {
+        fields.push("x", number(translation.x));
+    }
+    if translation.y != 0.0 {
+        fields.push("y", number(translation.y));
+    }
+    if transform.rotation() != Angle::ZERO {
+        fields.push("rotation", number(degrees(transform.rotation())));
+    }
+    let scale = transform.scale();
+    if scale.x != 1.0 {
+        fields.push("scaleX", number(scale.x));
+    }
+    if scale.y != 1.0 {
+        fields.push("scaleY", number(scale.y));
+    }
+}
+
+fn slot(asset: &SkeletonAsset, index: usize) -> JsonValue {
+    let data = asset.slot_data(index);
+    let mut fields = Members::default();
+    fields.push("name", string(&data.name));
+    fields.push("bone", string(&asset.bone_data(data.bone as usize).name));
+    if data.colour != Rgba8::WHITE {
+        fields.push("color", colour(data.colour));
+    }
+    if let Some(attachment) = &data.setup_attachment_name {
+        fields.push("attachment", string(attachment));
+    }
+    if data.blend_token.as_ref() != "normal" {
+        fields.push("blend", string(&data.blend_token));
+    }
+    fields.extend(&data.retained);
+    fields.into_value()
+}
+
+fn constraint(asset: &SkeletonAsset, data: &ConstraintData) -> JsonValue {
+    let bone_names = |bones: &[u32]| {
+        JsonValue::Array(
+            bones
+                .iter()
+                .map(|bone| string(&asset.bone_data(*bone as usize).name))
+                .collect(),
+        )
+    };
+    let mut fields = Members::default();
+    fields.push("type", string(&data.source_type));
+    fields.push("name", string(&data.name));
+    fields.push("order", JsonValue::U64(data.order.into()));
+    if let Some(index) = data.ik_constraint {
+        let ik = asset.ik_constraint_data(index as usize);
+        fields.push("bones", bone_names(&ik.bones));
+        fields.push("target", string(&asset.bone_data(ik.target as usize).name));
+        if ik.mix.get() != 1.0 {
+            fields.push("mix", number(ik.mix.get()));
+        }
+        if ik.softness != 0.0 {
+            fields.push("softness", number(ik.softness));
+        }
+        if ik.bend_direction == BendDirection::Negative {
+            fields.push("bendPositive", JsonValue::Bool(false));
+        }
+        for (name, enabled) in [
+            ("compress", ik.compress),
+            ("stretch", ik.stretch),
+            ("uniform", ik.uniform),
+        ] {
+            if enabled {
+                fields.push(name, JsonValue::Bool(true));
+            }
+        }
+    } else if let Some(index) = data.transform_constraint {
+        let transform = asset.transform_constraint_data(index as usize);
+        fields.push("bones", bone_names(&transform.bones));
+        fields.push(
+            "source",
+            string(&asset.bone_data(transform.source as usize).name),
+        );
+        if transform.rotation_offset != Angle::ZERO {
+            fields.push("rotation", number(degrees(transform.rotation_offset)));
+        }
+        // The defaults depend on the retained property map, so every mix is
+        // explicit.
+        for (name, mix) in TRANSFORM_MIXES
+            .into_iter()
+            .zip(transform_pose_values(transform.setup_pose))
+        {
+            fields.push(name, number(mix));
+        }
+        for (name, enabled) in [
+            ("localSource", transform.local_source),
+            ("localTarget", transform.local_target),
+            ("additive", transform.additive),
+            ("clamp", transform.clamped),
+        ] {
+            if enabled {
+                fields.push(name, JsonValue::Bool(true));
+            }
+        }
+    }
+    fields.extend(&data.retained);
+    fields.into_value()
+}
+
+const TRANSFORM_MIXES: [&str; 6] = [
+    "mixRotate",
+    "mixX",
+    "mixY",
+    "mixScaleX",
+    "mixScaleY",
+    "mixShearY",
+];
+
+fn skin(asset: &SkeletonAsset, index: usize) -> JsonValue {
+    let data = asset.skin_data(index);
+    let mut fields = Members::default();
+    fields.push("name", string(&data.name));
+    let mut slots = Members::default();
+    for attachment_index in data.attachments.clone() {
+        let attachment = asset.attachment_data(attachment_index as usize);
+        slots.insert(
+            &[
+                &asset.slot_data(attachment.slot as usize).name,
+                &attachment.placeholder_name,
+            ],
+            self::attachment(asset, attachment),
+        );
+    }
+    if !slots.is_empty() {
+        fields.push("attachments", slots.into_value());
+    }
+    fields.extend(&data.retained);
+    fields.into_value()
+}
+
+fn attachment(asset: &SkeletonAsset, data: &AttachmentData) -> JsonValue {
+    let mut fields = Members::default();
+    let source_type = match &data.kind {
+        AttachmentDataKind::Region(_) => "region",
+        AttachmentDataKind::Mesh(mesh) if mesh.source_mesh.is_some() => "linkedmesh",
+        AttachmentDataKind::Mesh(_) => "mesh",
+        AttachmentDataKind::BoundingBox => "boundingbox",
+        AttachmentDataKind::Point => "point",
+        AttachmentDataKind::Unsupported { source_type } => source_type,
+    };
+    if source_type != "region" {
+        fields.push("type", string(source_type));
+    }
+    if data.name != data.placeholder_name {
+        fields.push("name", string(&data.name));
+    }
+    if let Some(path) = &data.atlas_path {
+        fields.push("path", string(path));
+    }
+    match &data.kind {
+        AttachmentDataKind::Region(region) => {
+            transform(&mut fields, region.transform);
+            fields.push("width", JsonValue::U64(region.size.width().into()));
+            fields.push("height", JsonValue::U64(region.size.height().into()));
+            if region.colour != Rgba8::WHITE {
+                fields.push("color", colour(region.colour));
+            }
+        }
+        AttachmentDataKind::Mesh(mesh) => self::mesh(asset, mesh, &mut fields),
+        AttachmentDataKind::BoundingBox
+        | AttachmentDataKind::Point
+        | AttachmentDataKind::Unsupported { .. } => {}
+    }
+    fields.extend(&data.retained);
+    fields.into_value()
+}
+
+fn mesh(asset: &SkeletonAsset, mesh: &MeshAttachmentData, fields: &mut Members) {
+    if mesh.colour != Rgba8::WHITE {
+        fields.push("color", colour(mesh.colour));
+    }
+    if let Some(source) = mesh.source_mesh {
+        let source = asset.attachment_data(source as usize);
+        let skin = &asset.skin_data(source.skin as usize).name;
+        if skin.as_ref() != "default" {
+            fields.push("skin", string(skin));
+        }
+        fields.push("parent", string(&source.placeholder_name));
+        if !mesh.inherits_deform {
+            fields.push("deform", JsonValue::Bool(false));
+        }
+        return;
+    }
+    let geometry = asset.mesh_geometry_data(mesh.geometry as usize);
+    fields.push(
+        "uvs",
+        numbers(geometry.uvs.iter().flat_map(|uv| [uv.x, uv.y])),
+    );
+    fields.push(
+        "triangles",
+        JsonValue::Array(
+            geometry
+                .triangles
+                .iter()
+                .map(|index| JsonValue::U64((*index).into()))
+                .collect(),
+        ),
+    );
+    let vertices = match &geometry.vertices {
+        MeshVerticesData::Unweighted(positions) => numbers(
+            positions
+                .iter()
+                .flat_map(|position| [position.x, position.y]),
+        ),
+        MeshVerticesData::Weighted {
+            vertices,
+            influences,
+        } => {
+            let mut stream = Vec::new();
+            for range in vertices {
+                stream.push(JsonValue::U64((range.end - range.start).into()));
+                for influence in &influences[range.start as usize..range.end as usize] {
+                    stream.push(JsonValue::U64(influence.bone.into()));
+                    stream.push(number(influence.bind_position.x));
+                    stream.push(number(influence.bind_position.y));
+                    stream.push(number(influence.weight));
+                }
+            }
+            JsonValue::Array(stream.into_boxed_slice())
+        }
+    };
+    fields.push("vertices", vertices);
+    fields.push("hull", JsonValue::U64(geometry.hull.into()));
+}
+
+fn animation(asset: &SkeletonAsset, data: &AnimationData) -> JsonValue {
+    // Timelines are inserted in table order, so grouping them back into
+    // per-element objects reproduces the order the loader emitted them in.
+    let mut sections = Members::default();
+    for timeline in &data.timelines {
+        match timeline {
+            TimelineData::BoneRotate { bone, frames } => {
+                let keys = frames.iter().enumerate().map(|(index, frame)| {
+                    let mut key = keyed(frame.time);
+                    if frame.value != 0.0 {
+                        key.push("value", number(frame.value));
+                    }
+                    curve(
+                        &mut key,
+                        &frame.curve,
+                        frame.time,
+                        frames.get(index + 1).map(|next| next.time),
+                    );
+                    key.into_value()
+                });
+                let bone = &asset.bone_data(*bone as usize).name;
+                sections.insert(&["bones", bone, "rotate"], array(keys));
+            }
+            TimelineData::BoneTranslate { bone, frames }
+            | TimelineData::BoneScale { bone, frames }
+            | TimelineData::BoneShear { bone, frames } => {
+                let (name, default) = match timeline {
+                    TimelineData::BoneTranslate { .. } => ("translate", 0.0),
+                    TimelineData::BoneScale { .. } => ("scale", 1.0),
+                    _ => ("shear", 0.0),
+                };
+                let keys = frames.iter().enumerate().map(|(index, frame)| {
+                    let mut key = keyed(frame.time);
+                    if frame.x != default {
+                        key.push("x", number(frame.x));
+                    }
+                    if frame.y != default {
+                        key.push("y", number(frame.y));
+                    }
+                    curve(
+                        &mut key,
+                        &frame.curve,
+                        frame.time,
+                        frames.get(index + 1).map(|next| next.time),
+                    );
+                    key.into_value()
+                });
+                let bone = &asset.bone_data(*bone as usize).name;
+                sections.insert(&["bones", bone, name], array(keys));
+            }
+            TimelineData::SlotAttachment { slot, frames } => {
+                let keys = frames.iter().map(|frame| {
+                    let mut key = keyed(frame.time);
+                    key.push(
+                        "name",
+                        frame
+                            .placeholder_name
+                            .as_deref()
+                            .map_or(JsonValue::Null, string),
+                    );
+                    key.into_value()
+                });
+                let slot = &asset.slot_data(*slot as usize).name;
+                sections.insert(&["slots", slot, "attachment"], array(keys));
+            }
+            TimelineData::SlotColour { slot, frames } => {
+                let keys = frames.iter().enumerate().map(|(index, frame)| {
+                    let mut key = keyed(frame.time);
+                    key.push("color", colour(frame.colour));
+                    curve(
+                        &mut key,
+                        &frame.curve,
+                        frame.time,
+                        frames.get(index + 1).map(|next| next.time),
+                    );
+                    key.into_value()
+                });
+                let slot = &asset.slot_data(*slot as usize).name;
+                sections.insert(&["slots", slot, "rgba"], array(keys));
+            }
+            TimelineData::Ik { constraint, frames } => {
+                let keys = frames.iter().enumerate().map(|(index, frame)| {
+                    let mut key = keyed(frame.time);
+                    if frame.mix.get() != 1.0 {
+                        key.push("mix", number(frame.mix.get()));
+                    }
+                    if frame.bend_direction == BendDirection::Negative {
+                        key.push("bendPositive", JsonValue::Bool(false));
+                    }
+                    curve(
+                        &mut key,
+                        &frame.curve,
+                        frame.time,
+                        frames.get(index + 1).map(|next| next.time),
+                    );
+                    key.into_value()
+                });
+                let constraint = &asset.ik_constraint_data(*constraint as usize).name;
+                sections.insert(&["ik", constraint], array(keys));
+            }
+            TimelineData::Transform { constraint, frames } => {
+                let data = asset.transform_constraint_data(*constraint as usize);
+                let setup = transform_pose_values(data.setup_pose);
+                let keys = frames.iter().enumerate().map(|(index, frame)| {
+                    let mut key = keyed(frame.time);
+                    let values = transform_pose_values(frame.pose);
+                    let mut written = [false; 6];
+                    for channel in 0..6 {
+                        // An omitted Y mix falls back to the written X mix
+                        // rather than to the setup pose.
+                        let fallback_written = matches!(channel, 2 | 4) && written[channel - 1];
+                        if values[channel] != setup[channel] || fallback_written {
+                            key.push(TRANSFORM_MIXES[channel], number(values[channel]));
+                            written[channel] = true;
+                        }
+                    }
+                    curve(
+                        &mut key,
+                        &frame.curve,
+                        frame.time,
+                        frames.get(index + 1).map(|next| next.time),
+                    );
+                    key.into_value()
+                });
+                sections.insert(&["transform", &data.name], array(keys));
+            }
+            TimelineData::DrawOrder { frames } => {
+                let keys = frames.iter().map(|frame| {
+                    let mut key = keyed(frame.time);
+                    if !frame.offsets.is_empty() {
+                        let offsets = frame.offsets.iter().map(|offset| {
+                            let mut fields = Members::default();
+                            fields
+                                .push("slot", string(&asset.slot_data(offset.slot as usize).name));
+                            fields.push("offset", JsonValue::I64(offset.offset.into()));
+                            fields.into_value()
+                        });
+                        key.push("offsets", array(offsets));
+                    }
+                    key.into_value()
+                });
+                sections.insert(&["drawOrder"], array(keys));
+            }
+            TimelineData::Events { frames } => {
+                let keys = frames.iter().map(|frame| {
+                    let definition = asset.event_data(frame.event as usize);
+                    let (payload, setup) = (&frame.payload, &definition.payload);
+                    let mut key = keyed(frame.time);
+                    key.push("name", string(&definition.name));
+                    if payload.integer != setup.integer {
+                        key.push("int", JsonValue::I64(payload.integer.into()));
+                    }
+                    if payload.float != setup.float {
+                        key.push("float", number(payload.float));
+                    }
+                    if payload.string != setup.string {
+                        key.push(
+                            "string",
+                            payload.string.as_deref().map_or(JsonValue::Null, string),
+                        );
+                    }
+                    if payload.volume != setup.volume {
+                        key.push("volume", number(payload.volume));
+                    }
+                    if payload.balance != setup.balance {
+                        key.push("balance", number(payload.balance));
+                    }
+                    key.into_value()
+                });
+                sections.insert(&["events"], array(keys));
+            }
+            TimelineData::Unsupported { source, .. } => {
+                let path = source.path.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+                sections.insert(&path, source.value.clone());
+            }
+        }
+    }
+    sections.into_value()
+}
+
+fn keyed(time: TimelineTime) -> Members {
+    let mut key = Members::default();
+    if time != TimelineTime::ZERO {
+        key.push("time", JsonValue::F64(seconds(time)));
+    }
+    key
+}
+
+fn seconds(time: TimelineTime) -> f64 {
+    time.ticks as f64 / NANOS_PER_SECOND as f64
+}
+
+/// Writes a curve with its Bezier X handles back in absolute seconds.
+fn curve<const CHANNELS: usize>(
+    key: &mut Members,
+    curve: &FrameCurve<CHANNELS>,
+    start: TimelineTime,
+    end: Option<TimelineTime>,
+) {
+    match curve {
+        FrameCurve::Linear => {}
+        FrameCurve::Stepped => key.push("curve", string("stepped")),
+        FrameCurve::Bezier(channels) => {
+            let start = seconds(start);
+            let span = end.map_or(0.0, |end| seconds(end) - start);
+            let values = channels.iter().flat_map(|[x1, y1, x2, y2]| {
+                [
+                    handle_time(*x1, start, span),
+                    *y1,
+                    handle_time(*x2, start, span),
+                    *y2,
+                ]
+            });
+            key.push("curve", numbers(values));
+        }
+    }
+}
+
+/// Finds the authored handle time the loader normalizes back to `x`.
+///
+/// Handle times are authored as `f32` seconds, so the nearest `f32` to the
+/// exact product may normalize one ulp away; its neighbours are searched for
+/// an exact preimage before falling back to the nearest value.
+fn handle_time(x: f32, start: f64, span: f64) -> f32 {
+    let nearest = (start + f64::from(x) * span) as f32;
+    if span == 0.0 {
+        return nearest;
+    }
+    preimage(nearest, |time| {
+        ((f64::from(time) - start) / span) as f32 == x
+    })
+}
+
+/// Finds the authored degree value the loader converts back to `angle`.
+fn degrees(angle: Angle) -> f32 {
+    preimage(angle.as_degrees(), |degrees| {
+        degrees.to_radians() == angle.as_radians()
+    })
+}
+
+/// Picks the shortest decimal near `nearest` that `maps_back` accepts.
+///
+/// Several neighbours may convert to the same stored value; the shortest is
+/// the one an editor most likely wrote, so it is preferred over the closest.
+fn preimage(nearest: f32, maps_back: impl Fn(f32) -> bool) -> f32 {
+    nearby(nearest)
+        .filter(|candidate| maps_back(*candidate))
+        .min_by_key(|candidate| candidate.to_string().len())
+        .unwrap_or(nearest)
+}
+
+/// Yields `value` and then its neighbours, alternating outward by one ulp.
+fn nearby(value: f32) -> impl Iterator<Item = f32> {
+    const SEARCHED_ULPS: u32 = 8;
+    let (mut below, mut above) = (value, value);
+    core::iter::once(value).chain((0..SEARCHED_ULPS).flat_map(move |_step| {
+        below = below.next_down();
+        above = above.next_up();
+        [below, above]
+    }))
+}
+
+/// Encodes an `f32` as the shortest decimal that reads back exactly.
+fn number(value: f32) -> JsonValue {
+    let shortest = value
+        .to_string()
+        .parse::<f64>()
+        .ok()
+        .filter(|parsed| *parsed as f32 == value);
+    JsonValue::F64(shortest.unwrap_or(f64::from(value)))
+}
+
+fn numbers(values: impl IntoIterator<Item = f32>) -> JsonValue {
+    array(values.into_iter().map(number))
+}
+
+fn array(values: impl IntoIterator<Item = JsonValue>) -> JsonValue {
+    JsonValue::Array(values.into_iter().collect())
+}
+
+fn string(value: &str) -> JsonValue {
+    JsonValue::String(value.into())
+}
+
+fn colour(value: Rgba8) -> JsonValue {
+    JsonValue::String(value.to_string().to_ascii_lowercase().into_boxed_str())
+}
+
+/// An ordered JSON object under construction.
+#[derive(Default)]
+struct Members(Vec<(Box<str>, Node)>);
+
+enum Node {
+    Value(JsonValue),
+    Object(Members),
+}
+
+impl Members {
+    fn push(&mut self, name: &str, value: JsonValue) {
+        self.0.push((name.into(), Node::Value(value)));
+    }
+
+    fn extend(&mut self, members: &[JsonMember]) {
+        for member in members {
+            self.push(member.name(), member.value().clone());
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Inserts `value` below nested objects, creating each object the first
+    /// time its name is used.
+    fn insert(&mut self, path: &[&str], value: JsonValue) {
+        let Some((name, parents)) = path.split_last() else {
+            return;
+        };
+        let mut members = self;
+        for parent in parents {
+            let position = members
+                .0
+                .iter()
+                .position(|(existing, node)| {
+                    existing.as_ref() == *parent && matches!(node, Node::Object(_))
+                })
+                .unwrap_or_else(|| {
+                    members
+                        .0
+                        .push(((*parent).into(), Node::Object(Members::default())));
+                    members.0.len() - 1
+                });
+            let Node::Object(child) = &mut members.0[position].1 else {
+                unreachable!("the position was selected for an object node")
+            };
+            members = child;
+        }
+        members.push(name, value);
+    }
+
+    fn into_value(self) -> JsonValue {
+        JsonValue::Object(
+            self.0
+                .into_iter()
+                .map(|(name, node)| {
+                    let value = match node {
+                        Node::Value(value) => value,
+                        Node::Object(members) => members.into_value(),
+                    };
+                    JsonMember::new(name, value)
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,226 @@
+//! Public contract tests for building animations from typed keys.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationBuilder, AnimationRef, BendDirection, BezierCurve, EventKey, KeyCurve, LoadErrorKind,
+    Mix, PlaybackMode, Skeleton, SkeletonAsset, TimelineRef, build_animations, glam::Vec2,
+    load_json,
+};
+
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"thigh","parent":"root","length":10,"rotation":-90},
+    {"name":"shin","parent":"thigh","x":10,"length":10},
+    {"name":"paw-target","parent":"root","y":-18}
+  ],
+  "slots":[
+    {"name":"eyes-slot","bone":"root","attachment":"open"},
+    {"name":"tail-slot","bone":"root"}
+  ],
+  "ik":[{"name":"paw-ik","bones":["thigh","shin"],"target":"paw-target"}],
+  "skins":[{"name":"default","attachments":{"eyes-slot":{
+    "open":{"width":4,"height":2},
+    "closed":{"width":4,"height":1}
+  }}}],
+  "events":{"step":{"int":1,"volume":0.5}},
+  "animations":{
+    "walk":{
+      "bones":{"thigh":{"rotate":[
+        {"value":0,"curve":[0.25,0,0.75,30]},
+        {"time":1,"value":30}
+      ]}},
+      "slots":{"eyes-slot":{"attachment":[{"time":0.5,"name":"closed"}]}},
+      "ik":{"paw-ik":[{"mix":1},{"time":1,"mix":0,"bendPositive":false}]},
+      "drawOrder":[{"time":0.5,"offsets":[{"slot":"eyes-slot","offset":1}]}],
+      "events":[{"time":0.5,"name":"step","int":3}]
+    }
+  }
+}"#;
+
+const ATLAS: &[u8] = b"cat.png
+\tsize: 16, 8
+open
+\tbounds: 0, 0, 4, 2
+closed
+\tbounds: 4, 0, 4, 1
+";
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, ATLAS)
+        .expect("the builder fixture loads")
+        .into_asset()
+}
+
+fn animation<'a>(asset: &'a SkeletonAsset, name: &str) -> AnimationRef<'a> {
+    asset
+        .animation(asset.animation_id(name).expect("the animation exists"))
+        .expect("local")
+}
+
+fn seconds(seconds: f32) -> Duration {
+    Duration::from_secs_f32(seconds)
+}
+
+fn walk(asset: &SkeletonAsset) -> AnimationBuilder {
+    let thigh = asset.bone_id("thigh").expect("the bone exists");
+    let eyes = asset.slot_id("eyes-slot").expect("the slot exists");
+    let paw = asset
+        .ik_constraint_id("paw-ik")
+        .expect("the constraint exists");
+    let mut walk = AnimationBuilder::new("walk");
+    walk.rotate_key(
+        thigh,
+        Duration::ZERO,
+        0.0,
+        KeyCurve::Bezier(BezierCurve::new(
+            Vec2::new(0.25, 0.0),
+            Vec2::new(0.75, 30.0),
+        )),
+    )
+    .rotate_key(thigh, seconds(1.0), 30.0, KeyCurve::Linear)
+    .attachment_key(eyes, seconds(0.5), Some("closed"))
+    .ik_key(
+        paw,
+        Duration::ZERO,
+        Mix::ONE,
+        BendDirection::Positive,
+        KeyCurve::Linear,
+    )
+    .ik_key(
+        paw,
+        seconds(1.0),
+        Mix::ZERO,
+        BendDirection::Negative,
+        KeyCurve::Linear,
+    )
+    .draw_order_key(seconds(0.5), &[(eyes, 1)])
+    .event_key(
+        seconds(0.5),
+        EventKey::new(asset.event_id("step").expect("the event exists")).with_integer(3),
+    );
+    walk
+}
+
+fn pose(asset: &Arc<SkeletonAsset>, time: Duration) -> Vec<f32> {
+    let mut skeleton = Skeleton::new(Arc::clone(asset));
+    skeleton
+        .sample_animation(
+            asset.animation_id("walk").expect("walk exists"),
+            time,
+            PlaybackMode::Once,
+        )
+        .expect("walk samples");
+    let frame = skeleton.editable_pose().solve();
+    asset
+        .bones()
+        .flat_map(|bone| {
+            let world = frame.bone(bone.id()).expect("local").world_transform();
+            let position = world.translation();
+            [position.x, position.y]
+        })
+        .collect()
+}
+
+#[test]
+fn built_clips_match_the_equivalent_json() {
+    let asset = asset();
+    let report = build_animations(&asset, [walk(&asset)]).expect("the clip is valid");
+    let derived = report.asset();
+    assert_eq!(derived.animations().len(), 1, "walk is replaced");
+    let built = animation(derived, "walk");
+    let authored = animation(&asset, "walk");
+    assert_eq!(built.duration(), authored.duration());
+    assert_eq!(
+        built.properties().count(),
+        authored.properties().count(),
+        "the same properties are keyed"
+    );
+    for millis in [0, 100, 250, 500, 750, 1000] {
+        let time = Duration::from_millis(millis);
+        assert_eq!(pose(derived, time), pose(&asset, time), "at {time:?}");
+    }
+
+    let events = built
+        .timelines()
+        .find_map(|timeline| match timeline {
+            TimelineRef::Events(events) => Some(events),
+            _ => None,
+        })
+        .expect("the step is keyed");
+    let step = events.keys().next().expect("one key");
+    assert_eq!(
+        (step.integer(), step.volume()),
+        (3, 0.5),
+        "unset payload values use the definition"
+    );
+    assert_eq!(built.marker("step"), authored.marker("step"));
+}
+
+#[test]
+fn invalid_keys_fail_as_the_loader_would() {
+    let asset = asset();
+    let thigh = asset.bone_id("thigh").expect("the bone exists");
+    let eyes = asset.slot_id("eyes-slot").expect("the slot exists");
+    let tail = asset.slot_id("tail-slot").expect("the slot exists");
+    let other = self::asset();
+
+    let mut unordered = AnimationBuilder::new("wave");
+    unordered
+        .rotate_key(thigh, seconds(1.0), 0.0, KeyCurve::Linear)
+        .rotate_key(thigh, seconds(0.5), 0.0, KeyCurve::Linear);
+    let error = build_animations(&asset, [unordered]).expect_err("keys go backward");
+    assert_eq!(error.kind(), LoadErrorKind::InvalidOrder);
+    assert_eq!(
+        error.path(),
+        Some("/animations/wave/bones/thigh/rotate/1/time")
+    );
+
+    let mut unknown = AnimationBuilder::new("blink");
+    unknown.attachment_key(eyes, Duration::ZERO, Some("winking"));
+    let error = build_animations(&asset, [unknown]).expect_err("no such placeholder");
+    assert_eq!(error.kind(), LoadErrorKind::UnresolvedReference);
+    assert_eq!(
+        error.path(),
+        Some("/animations/blink/slots/eyes-slot/attachment/0/name")
+    );
+
+    let mut crowded = AnimationBuilder::new("swap");
+    crowded.draw_order_key(Duration::ZERO, &[(tail, -1), (eyes, 0)]);
+    let error = build_animations(&asset, [crowded]).expect_err("two slots land together");
+    assert_eq!(error.kind(), LoadErrorKind::InvalidOrder);
+
+    let mut mixed = AnimationBuilder::new("hop");
+    mixed.translate_key(
+        thigh,
+        Duration::ZERO,
+        Vec2::ZERO,
+        [KeyCurve::Stepped, KeyCurve::Linear],
+    );
+    let error = build_animations(&asset, [mixed]).expect_err("stepping is per key");
+    assert_eq!(error.kind(), LoadErrorKind::SchemaViolation);
+
+    let mut foreign = AnimationBuilder::new("stretch");
+    foreign.rotate_key(
+        other.bone_id("thigh").expect("the bone exists"),
+        Duration::ZERO,
+        f32::NAN,
+        KeyCurve::Linear,
+    );
+    let error = build_animations(&asset, [foreign]).expect_err("the bone is foreign");
+    assert_eq!(error.kind(), LoadErrorKind::UnresolvedReference);
+
+    let mut nan = AnimationBuilder::new("stretch");
+    nan.rotate_key(thigh, Duration::ZERO, f32::NAN, KeyCurve::Linear);
+    let error = build_animations(&asset, [nan]).expect_err("the value is NaN");
+    assert_eq!(error.kind(), LoadErrorKind::NonFiniteNumber);
+
+    let error = build_animations(
+        &asset,
+        [AnimationBuilder::new("a"), AnimationBuilder::new("a")],
+    )
+    .expect_err("names are unique");
+    assert_eq!(error.kind(), LoadErrorKind::DuplicateName);
+}
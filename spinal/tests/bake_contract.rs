@@ -0,0 +1,199 @@
+//! Public contract tests for pose baking and cached playback.
+
+use std::{num::NonZeroU32, sync::Arc, time::Duration};
+
+use spinal::{
+    BakeOptions, BakedAnimation, BakedPlayer, DrawItemRef, PlaybackMode, Skeleton, SkeletonAsset,
+    glam::Vec2, load_json,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"upper","parent":"root","x":5,"y":3,"rotation":13,"length":20},
+    {"name":"lower","parent":"upper","x":20,"length":15},
+    {"name":"head","parent":"root","x":-6,"y":12,"length":9}
+  ],
+  "slots":[
+    {"name":"arm-slot","bone":"upper","attachment":"arm"},
+    {"name":"head-slot","bone":"head","attachment":"face"}
+  ],
+  "skins":[{
+    "name":"default",
+    "attachments":{
+      "arm-slot":{"arm":{
+        "type":"mesh",
+        "uvs":[0,0,1,0,1,1,0,1],
+        "triangles":[0,1,2,0,2,3],
+        "vertices":[
+          1,1,0,-3,1,
+          2,1,20,-3,0.5,2,-1,-3,0.5,
+          2,1,20,3,0.5,2,-1,3,0.5,
+          1,1,0,3,1
+        ],
+        "hull":4
+      }},
+      "head-slot":{"face":{"path":"face","x":4,"width":8,"height":8}}
+    }
+  }],
+  "animations":{
+    "swing":{
+      "bones":{
+        "upper":{"rotate":[{"value":0},{"time":0.5,"value":40},{"time":1,"value":-25}]},
+        "lower":{"rotate":[{"value":5},{"time":1,"value":-60}]},
+        "head":{"translate":[{"x":0,"y":0},{"time":1,"x":10,"y":-4}]}
+      },
+      "slots":{"head-slot":{"attachment":[{"time":0.6,"name":null}]}}
+    }
+  }
+}"#;
+
+const ATLAS: &[u8] = b"rig.png
+\tsize: 64, 64
+arm
+\tbounds: 0, 0, 32, 16
+face
+\tbounds: 0, 16, 16, 16
+";
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(JSON, ATLAS)
+        .expect("the bake fixture loads")
+        .into_asset()
+}
+
+fn bake(asset: &Arc<SkeletonAsset>, options: BakeOptions) -> Arc<BakedAnimation> {
+    let mut skeleton = Skeleton::new(Arc::clone(asset));
+    let swing = asset
+        .animation(asset.animation_id("swing").expect("swing exists"))
+        .expect("swing is local");
+    Arc::new(BakedAnimation::bake(&mut skeleton, swing, options).expect("swing bakes"))
+}
+
+fn positions(item: DrawItemRef<'_>) -> Vec<Vec2> {
+    match item {
+        DrawItemRef::Region(region) => region.positions().to_vec(),
+        DrawItemRef::Mesh(mesh) => mesh.positions().to_vec(),
+        _other => panic!("the rig only draws regions and meshes"),
+    }
+}
+
+fn live_positions(asset: &Arc<SkeletonAsset>, time: Duration) -> Vec<Vec<Vec2>> {
+    let mut skeleton = Skeleton::new(Arc::clone(asset));
+    let swing = asset.animation_id("swing").expect("swing exists");
+    skeleton
+        .sample_animation(swing, time, PlaybackMode::Once)
+        .expect("swing samples");
+    let frame = skeleton.editable_pose().solve();
+    frame.draw_items().map(positions).collect()
+}
+
+#[test]
+fn baked_frames_match_live_solves_at_sample_times() {
+    let asset = asset();
+    for meshes in [false, true] {
+        let baked = bake(
+            &asset,
+            BakeOptions::new()
+                .with_frame_rate(NonZeroU32::new(10).expect("ten is nonzero"))
+                .with_meshes(meshes),
+        );
+        assert_eq!(baked.report().frames(), 11);
+        assert_eq!(baked.bakes_meshes(), meshes);
+        let mut player = BakedPlayer::new(Arc::clone(&baked), PlaybackMode::Once);
+        for frame in [0_u64, 3, 7, 10] {
+            let time = Duration::from_millis(frame * 100);
+            player.seek_to(time);
+            let cached: Vec<_> = player.draw_items().map(positions).collect();
+            let live = live_positions(&asset, time);
+            assert_eq!(cached.len(), live.len(), "draw count at {time:?}");
+            for (cached, live) in cached.iter().zip(&live) {
+                for (cached, live) in cached.iter().zip(live) {
+                    assert!(cached.distance(*live) < 1.0e-3, "{cached} != {live}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn interpolated_frames_stay_within_the_reported_error() {
+    let asset = asset();
+    let coarse = bake(
+        &asset,
+        BakeOptions::new().with_frame_rate(NonZeroU32::new(4).expect("four is nonzero")),
+    );
+    let fine = bake(&asset, BakeOptions::new().with_meshes(true));
+    assert!(coarse.report().max_vertex_error() > fine.report().max_vertex_error());
+    assert!(fine.report().bytes() > coarse.report().bytes());
+    assert!(coarse.report().max_bone_error() >= 0.0);
+
+    let mut player = BakedPlayer::new(Arc::clone(&coarse), PlaybackMode::Once);
+    let budget = coarse.report().max_vertex_error() * 1.5 + 1.0e-3;
+    for millis in [40_u64, 125, 330, 575, 910] {
+        let time = Duration::from_millis(millis);
+        player.seek_to(time);
+        let cached: Vec<_> = player.draw_items().map(positions).collect();
+        let live = live_positions(&asset, time);
+        for (cached, live) in cached.iter().zip(&live).filter(|(a, b)| a.len() == b.len()) {
+            for (cached, live) in cached.iter().zip(live) {
+                assert!(
+                    cached.distance(*live) <= budget,
+                    "{millis}ms exceeds the budget"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn baked_players_loop_or_hold_the_end() {
+    let asset = asset();
+    let baked = bake(&asset, BakeOptions::new());
+    let head = asset.bone_id("head").expect("head exists");
+
+    let mut once = BakedPlayer::new(Arc::clone(&baked), PlaybackMode::Once);
+    once.advance(Duration::from_millis(1_400));
+    assert!(once.is_complete());
+    assert_eq!(once.position(), baked.duration());
+    assert_eq!(
+        once.draw_items().count(),
+        1,
+        "the face is hidden at the end"
+    );
+
+    let mut looping = BakedPlayer::new(Arc::clone(&baked), PlaybackMode::Loop);
+    looping.advance(Duration::from_millis(1_250));
+    assert!(!looping.is_complete());
+    assert_eq!(looping.position(), Duration::from_millis(250));
+    assert_eq!(looping.draw_items().count(), 2);
+    let start = BakedPlayer::new(Arc::clone(&baked), PlaybackMode::Loop)
+        .bone_world(head)
+        .expect("head is local");
+    assert_ne!(
+        looping
+            .bone_world(head)
+            .expect("head is local")
+            .translation(),
+        start.translation()
+    );
+}
+
+#[test]
+fn baked_playback_allocates_nothing() {
+    let asset = asset();
+    for meshes in [false, true] {
+        let baked = bake(&asset, BakeOptions::new().with_meshes(meshes));
+        let mut player = BakedPlayer::new(baked, PlaybackMode::Loop);
+        let allocations = allocation_counter::measure(|| {
+            for _frame in 0..120 {
+                player.advance(Duration::from_millis(16));
+                for item in player.draw_items() {
+                    std::hint::black_box(item);
+                }
+            }
+        });
+        assert_eq!(allocations.count_total, 0);
+    }
+}
@@ -0,0 +1,219 @@
+//! Public contract tests for batch evaluation of independent instances.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationId, AnimationMixer, BatchContext, BatchInstance, BatchParts, BoneId, BoneTransform,
+    EditablePose, PlayOptions, PlayerError, Skeleton, SkeletonAsset, SolvedFrame,
+    TrackAnimationEvent, TrackOptions, WorldTransform, load_json, update_batch,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"body","parent":"root","x":4,"length":10},
+    {"name":"tail","parent":"body","x":10,"rotation":20}
+  ],
+  "events":{"step":{},"swish":{}},
+  "animations":{
+    "walk":{
+      "bones":{"root":{"translate":[{"x":0,"y":0},{"time":0.5,"x":20,"y":0}]}},
+      "events":[{"time":0.25,"name":"step"}]
+    },
+    "swish":{
+      "bones":{"tail":{"rotate":[{"value":-30},{"time":0.3,"value":45},{"time":0.6,"value":-30}]}},
+      "events":[{"time":0.1,"name":"swish"}]
+    }
+  }
+}"#;
+
+#[derive(Debug, Default)]
+struct CatFrame {
+    lean: Option<(BoneId, BoneTransform)>,
+    events: Vec<(AnimationId, Duration, u128)>,
+    world: Vec<WorldTransform>,
+    error: Option<PlayerError>,
+}
+
+impl spinal::TrackEventSink for CatFrame {
+    fn event(&mut self, event: TrackAnimationEvent<'_>) {
+        let event = event.event();
+        self.events
+            .push((event.animation(), event.local_time(), event.loop_index()));
+    }
+}
+
+impl BatchContext for CatFrame {
+    fn edit(&mut self, pose: &mut EditablePose<'_>) {
+        if let Some((bone, transform)) = self.lean {
+            pose.edit()
+                .set_bone_local(bone, transform)
+                .expect("the lean bone belongs to the cat");
+        }
+    }
+
+    fn solved(&mut self, _mixer: &AnimationMixer, frame: Result<SolvedFrame<'_>, PlayerError>) {
+        self.world.clear();
+        self.error = None;
+        match frame {
+            Ok(frame) => self
+                .world
+                .extend(frame.bones().map(|bone| bone.world_transform())),
+            Err(error) => self.error = Some(error),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Cat {
+    mixer: AnimationMixer,
+    skeleton: Skeleton,
+    frame: CatFrame,
+}
+
+impl BatchInstance for Cat {
+    type Context = CatFrame;
+
+    fn parts(&mut self) -> BatchParts<'_, CatFrame> {
+        BatchParts::new(&mut self.mixer, &mut self.skeleton, &mut self.frame)
+    }
+}
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(JSON, b"page.png\n")
+        .expect("the batch fixture loads")
+        .into_asset()
+}
+
+fn herd(asset: &Arc<SkeletonAsset>, count: usize) -> Vec<Cat> {
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let swish = asset.animation_id("swish").expect("swish exists");
+    let body = asset.bone_id("body").expect("body exists");
+    (0..count)
+        .map(|index| {
+            let skeleton = Skeleton::new(Arc::clone(asset));
+            let mut mixer = AnimationMixer::new(&skeleton);
+            mixer
+                .base_track_mut()
+                .play(walk, PlayOptions::looping())
+                .expect("walk is local");
+            mixer
+                .base_track_mut()
+                .set_speed(0.5 + index as f32 * 0.125)
+                .expect("the speed is valid");
+            mixer.base_track_mut().seek_to(Duration::from_millis(
+                u64::try_from(index).expect("small index") * 37,
+            ));
+            if index % 3 == 0 {
+                let tail = mixer
+                    .insert_track(TrackOptions::override_track())
+                    .expect("the track is inserted");
+                mixer
+                    .track_mut(tail)
+                    .expect("the track exists")
+                    .play(swish, PlayOptions::looping())
+                    .expect("swish is local");
+            }
+            let lean = (index % 2 == 0).then(|| {
+                let setup = skeleton
+                    .bone_pose(body)
+                    .expect("body is local")
+                    .local_transform();
+                let lean = BoneTransform::new(
+                    setup.translation(),
+                    spinal::Angle::from_degrees(index as f32).expect("finite angle"),
+                    setup.scale(),
+                    setup.shear(),
+                )
+                .expect("finite transform");
+                (body, lean)
+            });
+            Cat {
+                mixer,
+                skeleton,
+                frame: CatFrame {
+                    lean,
+                    events: Vec::with_capacity(16),
+                    world: Vec::with_capacity(8),
+                    error: None,
+                },
+            }
+        })
+        .collect()
+}
+
+fn sequential(cat: &mut Cat, delta: Duration) {
+    cat.frame.events.clear();
+    match cat.mixer.update(&mut cat.skeleton, delta, &mut cat.frame) {
+        Ok(mut pose) => {
+            cat.frame.edit(&mut pose);
+            cat.frame.solved(&cat.mixer, Ok(pose.solve()));
+        }
+        Err(error) => cat.frame.solved(&cat.mixer, Err(error)),
+    }
+}
+
+#[test]
+fn batches_match_sequential_updates_event_for_event() {
+    let asset = asset();
+    let mut batched = herd(&asset, 40);
+    let mut reference = herd(&asset, 40);
+
+    for delta in [0, 16, 33, 140, 16, 500, 7] {
+        let delta = Duration::from_millis(delta);
+        for cat in &mut batched {
+            cat.frame.events.clear();
+        }
+        update_batch(&mut batched, delta);
+        for cat in &mut reference {
+            sequential(cat, delta);
+        }
+        for (batched, reference) in batched.iter().zip(&reference) {
+            assert_eq!(batched.frame.events, reference.frame.events);
+            assert_eq!(batched.frame.world, reference.frame.world);
+            assert_eq!(batched.frame.error, None);
+            assert_eq!(
+                batched.mixer.base_track().status(),
+                reference.mixer.base_track().status()
+            );
+        }
+    }
+    assert!(
+        batched.iter().any(|cat| !cat.frame.events.is_empty()),
+        "the batch emits events"
+    );
+}
+
+#[test]
+fn batch_failures_stay_with_their_instance() {
+    let asset = asset();
+    let mut cats = herd(&asset, 3);
+    // Bind the middle cat's mixer to a different skeleton instance.
+    cats[1].mixer = AnimationMixer::new(&Skeleton::new(Arc::clone(&asset)));
+
+    update_batch(&mut cats, Duration::from_millis(16));
+    assert_eq!(cats[0].frame.error, None);
+    assert_eq!(cats[1].frame.error, Some(PlayerError::ForeignSkeleton));
+    assert!(cats[1].frame.world.is_empty());
+    assert_eq!(cats[2].frame.error, None);
+    assert_eq!(cats[2].frame.world.len(), 3);
+}
+
+#[cfg(not(feature = "rayon"))]
+#[test]
+fn serial_batches_allocate_nothing_in_steady_state() {
+    let asset = asset();
+    let mut cats = herd(&asset, 12);
+    update_batch(&mut cats, Duration::from_millis(16));
+
+    let allocations = allocation_counter::measure(|| {
+        for _frame in 0..60 {
+            for cat in &mut cats {
+                cat.frame.events.clear();
+            }
+            update_batch(&mut cats, Duration::from_millis(16));
+        }
+    });
+    assert_eq!(allocations.count_total, 0);
+}
@@ -0,0 +1,125 @@
+//! Public contract tests for bone-space conversions.
+
+use std::sync::Arc;
+
+use glam::Vec2;
+use spinal::{Angle, BoneId, BoneTransform, Mix, Skeleton, SkeletonAsset, load_json};
+
+// The arm points up from the shoulder and the paw is scaled up along it. The
+// arm's IK turns it toward the toy once enabled.
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"arm","parent":"root","x":10,"rotation":90,"length":5},
+    {"name":"paw","parent":"arm","x":5,"scaleX":2,"length":2},
+    {"name":"toy","parent":"root","x":20}
+  ],
+  "constraints":[
+    {"name":"reach","type":"ik","bones":["arm"],"target":"toy","mix":0}
+  ]
+}"#;
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, b"page.png\n")
+        .expect("the bone-space fixture loads")
+        .into_asset()
+}
+
+fn bone(asset: &SkeletonAsset, name: &str) -> BoneId {
+    asset.bone_id(name).expect("the bone exists")
+}
+
+fn degrees(angle: Option<Angle>) -> f32 {
+    angle.expect("the conversion succeeds").as_degrees()
+}
+
+#[test]
+fn solved_frames_convert_points_vectors_and_rotations() {
+    let asset = asset();
+    let paw = bone(&asset, "paw");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let frame = skeleton.editable_pose().solve();
+    let space = frame.bone_space(paw).expect("local");
+    assert_eq!(space.bone(), paw);
+    assert_eq!(
+        space.world_transform(),
+        frame.bone(paw).expect("local").world_transform()
+    );
+
+    // The paw sits at (10, 5) with its doubled X axis pointing up.
+    let world = space.local_to_world_point(Vec2::new(1.0, 1.0));
+    assert!(world.abs_diff_eq(Vec2::new(9.0, 7.0), 1.0e-5), "{world}");
+    let local = space.world_to_local_point(world).expect("invertible");
+    assert!(local.abs_diff_eq(Vec2::ONE, 1.0e-5), "{local}");
+    let vector = space.local_to_world_vector(Vec2::X);
+    assert!(vector.abs_diff_eq(Vec2::new(0.0, 2.0), 1.0e-5), "{vector}");
+    let vector = space.world_to_local_vector(Vec2::new(0.0, 2.0));
+    assert!(vector.expect("invertible").abs_diff_eq(Vec2::X, 1.0e-5));
+
+    let right = Angle::ZERO;
+    assert!((degrees(space.world_to_local_rotation(right)) + 90.0).abs() < 1.0e-4);
+    assert!((degrees(space.local_to_world_rotation(right)) - 90.0).abs() < 1.0e-4);
+
+    // Parent space is the arm's frame: skeleton-space right is its -Y.
+    let parent = space.world_to_parent_point(Vec2::new(11.0, 5.0));
+    assert!(
+        parent
+            .expect("invertible")
+            .abs_diff_eq(Vec2::new(5.0, -1.0), 1.0e-5)
+    );
+    let point = space.parent_to_world_point(Vec2::new(5.0, -1.0));
+    assert!(point.abs_diff_eq(Vec2::new(11.0, 5.0), 1.0e-5), "{point}");
+    let rotation = space.world_to_parent_rotation(right);
+    assert!((degrees(rotation) + 90.0).abs() < 1.0e-4);
+    let rotation = space.parent_to_world_rotation(rotation.expect("solvable"));
+    assert!(degrees(rotation).abs() < 1.0e-4);
+
+    let other = self::asset();
+    assert!(frame.bone_space(bone(&other, "paw")).is_err());
+}
+
+#[test]
+fn editable_poses_convert_before_constraints() {
+    let asset = asset();
+    let [arm, paw] = ["arm", "paw"].map(|name| bone(&asset, name));
+    let reach = asset.ik_constraint_id("reach").expect("reach exists");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mut pose = skeleton.editable_pose();
+    pose.edit().set_ik_mix(reach, Mix::ONE).expect("local");
+
+    // Point the paw along skeleton-space +X by writing its local rotation.
+    let space = pose.bone_space(paw).expect("local");
+    let rotation = space
+        .world_to_parent_rotation(Angle::ZERO)
+        .expect("solvable");
+    let local = space.local_transform();
+    pose.edit()
+        .set_bone_local(
+            paw,
+            BoneTransform::new(local.translation(), rotation, local.scale(), local.shear())
+                .expect("finite"),
+        )
+        .expect("local");
+    let unconstrained = pose.bone_space(paw).expect("local").world_transform();
+    assert!(
+        unconstrained
+            .x_axis()
+            .abs_diff_eq(Vec2::new(2.0, 0.0), 1.0e-5)
+    );
+    assert!(
+        pose.bone_space(arm)
+            .expect("local")
+            .world_transform()
+            .x_axis()
+            .abs_diff_eq(Vec2::Y, 1.0e-5),
+        "the arm's IK has not run yet"
+    );
+
+    let frame = pose.solve();
+    let solved = frame.bone_space(arm).expect("local").world_transform();
+    assert!(
+        solved.x_axis().abs_diff_eq(Vec2::X, 1.0e-5),
+        "IK then turns the arm"
+    );
+}
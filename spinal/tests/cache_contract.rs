@@ -0,0 +1,188 @@
+//! Public contract tests for binary skeleton caches.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    CacheError, Diagnostic, DiagnosticScope, DrawItemRef, LoadReport, PlaybackMode,
+    SKELETON_CACHE_VERSION, Skeleton, SkeletonAsset, load_json, read_cache, write_cache,
+    write_json,
+};
+
+const VIEWER_JSON: &[u8] = include_bytes!("../../bevy_spinal/examples/assets/viewer.spine.json");
+const VIEWER_ATLAS: &[u8] = include_bytes!("../../bevy_spinal/examples/assets/viewer.atlas");
+const MINIMAL_JSON: &[u8] = include_bytes!("../../fuzz/corpus/skeleton_json/minimal.json");
+const MINIMAL_ATLAS: &[u8] = include_bytes!("../../fuzz/corpus/text_atlas/minimal.atlas");
+
+const DEGRADED: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23","x-exporter":{"build":7}},
+  "bones":[
+    {"name":"root"},
+    {"name":"tail","parent":"root","rotation":12.5,"inherit":"noScale"},
+    {"name":"paw","parent":"root","x":3}
+  ],
+  "slots":[
+    {"name":"tail-slot","bone":"tail","attachment":"tail","dark":"102030"},
+    {"name":"fur-slot","bone":"root","attachment":"fur","blend":"screen"}
+  ],
+  "constraints":[
+    {"type":"ik","name":"reach","bones":["tail"],"target":"paw","mix":0.5,"bendPositive":false},
+    {"type":"path","name":"spine-path","bones":["tail"],"target":"fur-slot"}
+  ],
+  "skins":[{
+    "name":"default",
+    "attachments":{
+      "tail-slot":{"tail":{
+        "type":"mesh","uvs":[0,0,1,0,1,1],"triangles":[0,1,2],
+        "vertices":[1,1,0,0,1,2,1,4,0,0.5,2,0,0,0.5,1,2,4,4,1],"hull":3
+      }},
+      "fur-slot":{
+        "fur":{"x":2,"rotation":15.7,"width":8,"height":4},
+        "clip":{"type":"clipping","end":"tail-slot","vertexCount":3,"vertices":[0,0,2,0,2,2]}
+      }
+    }
+  }],
+  "events":{"step":{"int":1,"audio":"step.ogg"}},
+  "animations":{
+    "wag":{
+      "bones":{"tail":{
+        "rotate":[{"value":10,"curve":[0.3,12,0.9,30]},{"time":1,"value":-10}],
+        "inherit":[{"time":0.25,"inherit":"noRotation"}]
+      }},
+      "ik":{"reach":[{"mix":0.25},{"time":0.5,"bendPositive":true}]},
+      "drawOrder":[{"time":0.5,"offsets":[{"slot":"tail-slot","offset":1}]}],
+      "events":[{"time":0.5,"name":"step","int":4}]
+    }
+  },
+  "x-tooling":{"guides":[1,2,3]}
+}"#;
+
+const DEGRADED_ATLAS: &[u8] = b"cat.png
+\tsize: 16, 16
+\tpma: true
+fur
+\tbounds: 0, 0, 8, 4
+tail
+\tbounds: 8, 0, 4, 4
+\trotate: 45
+";
+
+/// Describes a diagnostic by the names of what it is scoped to, which survive
+/// the new asset identity a cache read assigns.
+fn describe(asset: &SkeletonAsset, diagnostic: &Diagnostic) -> String {
+    let scope = match diagnostic.scope() {
+        DiagnosticScope::Asset => "asset".to_owned(),
+        DiagnosticScope::Bone(id) => asset.bone(id).expect("local").name().to_owned(),
+        DiagnosticScope::Slot(id) => asset.slot(id).expect("local").name().to_owned(),
+        DiagnosticScope::Animation(id) => asset.animation(id).expect("local").name().to_owned(),
+        DiagnosticScope::Attachment(id) => asset.attachment(id).expect("local").name().to_owned(),
+        DiagnosticScope::Constraint(id) => asset.constraint(id).expect("local").name().to_owned(),
+        DiagnosticScope::AtlasPage(id) => asset.atlas_page(id).expect("local").name().to_owned(),
+        DiagnosticScope::AtlasRegion(id) => {
+            asset.atlas_region(id).expect("local").name().to_owned()
+        }
+        other => format!("{other:?}"),
+    };
+    format!(
+        "{:?} {:?} {scope}: {}",
+        diagnostic.severity(),
+        diagnostic.code(),
+        diagnostic.message()
+    )
+}
+
+fn diagnostics(report: &LoadReport) -> Vec<String> {
+    report
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| describe(report.asset(), diagnostic))
+        .collect()
+}
+
+fn poses(asset: &Arc<SkeletonAsset>) -> Vec<f32> {
+    let mut samples = Vec::new();
+    for animation in asset.animations() {
+        for millis in [0, 250, 500, 1000] {
+            let mut skeleton = Skeleton::new(Arc::clone(asset));
+            skeleton
+                .sample_animation(
+                    animation.id(),
+                    Duration::from_millis(millis),
+                    PlaybackMode::Loop,
+                )
+                .expect("the animation belongs to the asset");
+            let frame = skeleton.editable_pose().solve();
+            for bone in asset.bones() {
+                let world = frame.bone(bone.id()).expect("local").world_transform();
+                samples.extend(world.translation().to_array());
+                samples.extend(world.x_axis().to_array());
+            }
+            for item in frame.draw_items() {
+                match item {
+                    DrawItemRef::Region(region) => {
+                        samples
+                            .extend(region.positions().iter().flat_map(|point| point.to_array()));
+                    }
+                    DrawItemRef::Mesh(mesh) => {
+                        samples.extend(mesh.positions().iter().flat_map(|point| point.to_array()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    samples
+}
+
+#[test]
+fn cached_assets_match_the_loaded_export() {
+    for (label, json, atlas) in [
+        ("viewer", VIEWER_JSON, VIEWER_ATLAS),
+        ("minimal", MINIMAL_JSON, MINIMAL_ATLAS),
+        ("degraded", DEGRADED, DEGRADED_ATLAS),
+    ] {
+        let loaded = load_json(json, atlas).unwrap_or_else(|error| panic!("{label}: {error}"));
+        let cache = write_cache(loaded.asset());
+        let cached = read_cache(&cache).unwrap_or_else(|error| panic!("{label}: {error}"));
+
+        assert!(!Arc::ptr_eq(cached.asset(), loaded.asset()));
+        assert_eq!(diagnostics(&cached), diagnostics(&loaded), "{label}");
+        assert_eq!(cached.has_degradations(), loaded.has_degradations());
+        assert_eq!(
+            write_json(cached.asset()),
+            write_json(loaded.asset()),
+            "{label} keeps every authored and retained record"
+        );
+        assert_eq!(poses(cached.asset()), poses(loaded.asset()), "{label}");
+        assert_eq!(write_cache(cached.asset()), cache, "{label} re-encodes");
+    }
+}
+
+#[test]
+fn foreign_stale_and_damaged_caches_are_rejected() {
+    let asset = load_json(DEGRADED, DEGRADED_ATLAS)
+        .expect("the fixture loads")
+        .into_asset();
+    let cache = write_cache(&asset);
+
+    assert_eq!(
+        read_cache(DEGRADED).expect_err("JSON is not a cache"),
+        CacheError::NotACache
+    );
+
+    let mut stale = cache.clone();
+    stale[8..12].copy_from_slice(&(SKELETON_CACHE_VERSION + 1).to_le_bytes());
+    assert_eq!(
+        read_cache(&stale).expect_err("another format version"),
+        CacheError::UnsupportedVersion {
+            found: SKELETON_CACHE_VERSION + 1
+        }
+    );
+
+    let mut damaged = cache;
+    let last = damaged.len() - 1;
+    damaged[last] ^= 0x40;
+    assert_eq!(
+        read_cache(&damaged).expect_err("a flipped bit"),
+        CacheError::ChecksumMismatch
+    );
+}
@@ -0,0 +1,179 @@
+//! Golden hashes for bit-exact solved frames under the `portable-math` feature.
+//!
+//! Run with `cargo test -p spinal --features portable-math`. The hashes cover
+//! every world transform and draw vertex of a rig exercising rotation, shear,
+//! non-uniform scale, one- and two-bone IK, weighted meshes, crossfades and
+//! weight fades, and must match on every target.
+#![cfg(feature = "portable-math")]
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationMixer, Crossfade, DrawItemRef, Mix, PlayOptions, Skeleton, SolvedFrame, TrackOptions,
+    Transition, WeightFade, load_json,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"upper","parent":"root","x":5,"y":3,"rotation":13,"length":20,"shearX":7},
+    {"name":"lower","parent":"upper","x":20,"length":15,"scaleX":1.1,"scaleY":0.8},
+    {"name":"hand","parent":"lower","x":15,"rotation":-21,"shearY":4},
+    {"name":"head","parent":"root","x":-6,"y":12,"rotation":33,"length":9,"scaleX":-1.2},
+    {"name":"target","parent":"root","x":24,"y":17}
+  ],
+  "slots":[
+    {"name":"arm-slot","bone":"upper","attachment":"arm"},
+    {"name":"hand-slot","bone":"hand","attachment":"glove"},
+    {"name":"head-slot","bone":"head","attachment":"face"}
+  ],
+  "skins":[{
+    "name":"default",
+    "attachments":{
+      "arm-slot":{"arm":{
+        "type":"mesh",
+        "uvs":[0,0,1,0,1,1,0,1],
+        "triangles":[0,1,2,0,2,3],
+        "vertices":[
+          1,1,0,-3,1,
+          2,1,20,-3,0.5,2,-1,-3,0.5,
+          2,1,20,3,0.5,2,-1,3,0.5,
+          1,1,0,3,1
+        ],
+        "hull":4
+      }},
+      "hand-slot":{"glove":{"path":"glove","x":2,"y":1,"rotation":17,"width":6,"height":4}},
+      "head-slot":{"face":{"path":"face","x":4,"rotation":-90,"width":8,"height":8}}
+    }
+  }],
+  "constraints":[
+    {"name":"reach","type":"ik","bones":["upper","lower"],"target":"target","mix":0.85,"bendPositive":false},
+    {"name":"look","type":"ik","bones":["head"],"target":"target","mix":0.6}
+  ],
+  "animations":{
+    "swing":{
+      "bones":{
+        "upper":{"rotate":[{"value":0,"curve":[0.3,-10,0.6,35]},{"time":0.7,"value":40},{"time":1.3,"value":-25}]},
+        "hand":{"shear":[{"x":0,"y":0},{"time":1.3,"x":12,"y":-9}]},
+        "target":{"translate":[{"x":0,"y":0,"curve":[0.2,0,0.4,1,0.2,0,0.8,1]},{"time":1.3,"x":-31.5,"y":14.25}]}
+      }
+    },
+    "wave":{
+      "bones":{
+        "head":{
+          "rotate":[{"value":-15},{"time":0.9,"value":70}],
+          "scale":[{"x":1,"y":1},{"time":0.9,"x":0.7,"y":1.3}]
+        },
+        "lower":{"rotate":[{"value":5},{"time":0.45,"value":-60},{"time":0.9,"value":5}]}
+      }
+    }
+  }
+}"#;
+
+const ATLAS: &[u8] = b"rig.png
+\tsize: 64, 64
+arm
+\tbounds: 0, 0, 32, 16
+glove
+\tbounds: 32, 0, 12, 8
+face
+\tbounds: 0, 16, 16, 16
+";
+
+const WORLD_HASH: u64 = 9_825_521_256_979_031_522;
+const DRAW_HASH: u64 = 16_398_593_769_894_214_832;
+
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, value: f32) {
+        for byte in value.to_bits().to_le_bytes() {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+fn hash_frame(frame: &SolvedFrame<'_>, world: &mut Fnv, draw: &mut Fnv) {
+    for bone in frame.bones() {
+        let transform = bone.world_transform();
+        for axis in [
+            transform.translation(),
+            transform.x_axis(),
+            transform.y_axis(),
+        ] {
+            world.write(axis.x);
+            world.write(axis.y);
+        }
+    }
+    for item in frame.draw_items() {
+        let positions = match item {
+            DrawItemRef::Region(region) => region.positions().to_vec(),
+            DrawItemRef::Mesh(mesh) => mesh.positions().to_vec(),
+            _other => panic!("the rig only draws regions and meshes"),
+        };
+        for position in positions {
+            draw.write(position.x);
+            draw.write(position.y);
+        }
+    }
+}
+
+#[test]
+fn portable_frames_match_their_golden_hashes() {
+    let report = load_json(JSON, ATLAS).expect("the determinism rig loads");
+    assert_eq!(
+        report.diagnostics(),
+        [],
+        "the rig loads without degradation"
+    );
+    let asset = report.into_asset();
+    let swing = asset.animation_id("swing").expect("swing exists");
+    let wave = asset.animation_id("wave").expect("wave exists");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mut mixer = AnimationMixer::new(&skeleton);
+    mixer
+        .base_track_mut()
+        .play(swing, PlayOptions::looping())
+        .expect("swing is local");
+    let track = mixer
+        .insert_track(TrackOptions::override_track().with_weight(Mix::ZERO))
+        .expect("the track is inserted");
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .play(wave, PlayOptions::looping())
+        .expect("wave is local");
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .fade_weight(Mix::ONE, WeightFade::new(Duration::from_millis(700)));
+
+    let mut world = Fnv(0xcbf2_9ce4_8422_2325);
+    let mut draw = Fnv(0xcbf2_9ce4_8422_2325);
+    for (index, delta) in [0, 16, 33, 100, 250, 401, 17, 777, 1_303, 59]
+        .into_iter()
+        .enumerate()
+    {
+        if index == 4 {
+            mixer
+                .base_track_mut()
+                .play(
+                    wave,
+                    PlayOptions::looping().with_transition(Transition::Crossfade(Crossfade::new(
+                        Duration::from_millis(500),
+                    ))),
+                )
+                .expect("wave is local");
+        }
+        let frame = mixer
+            .update(&mut skeleton, Duration::from_millis(delta), &mut ())
+            .expect("the update succeeds")
+            .solve();
+        assert_eq!(frame.draw_items().count(), 3);
+        hash_frame(&frame, &mut world, &mut draw);
+    }
+
+    assert_eq!(world.0, WORLD_HASH, "world transforms changed");
+    assert_eq!(draw.0, DRAW_HASH, "draw vertices changed");
+}
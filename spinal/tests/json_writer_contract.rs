@@ -0,0 +1,295 @@
+//! Public contract tests for writing linked assets back to Spine JSON.
+
+use std::{env, fs, path::Path, sync::Arc, time::Duration};
+
+use serde_json::Value;
+use spinal::{
+    AnimationBuilder, DrawItemRef, KeyCurve, LoadReport, PlaybackMode, Skeleton, SkeletonAsset,
+    build_animations, load_json, write_json,
+};
+
+const VIEWER_JSON: &[u8] = include_bytes!("../../bevy_spinal/examples/assets/viewer.spine.json");
+const VIEWER_ATLAS: &[u8] = include_bytes!("../../bevy_spinal/examples/assets/viewer.atlas");
+const MINIMAL_JSON: &[u8] = include_bytes!("../../fuzz/corpus/skeleton_json/minimal.json");
+const MINIMAL_ATLAS: &[u8] = include_bytes!("../../fuzz/corpus/text_atlas/minimal.atlas");
+
+const RETAINED: &[u8] = br#"{
+  "skeleton":{"hash":"cat","spine":"4.3.23","fps":24,"x-exporter":{"build":7}},
+  "bones":[
+    {"name":"root"},
+    {"name":"body","parent":"root","x":1.5,"rotation":33.3,"shearX":4.2,"color":"ff0000ff"},
+    {"name":"tail","parent":"body","length":7,"inherit":"noScale","icon":"star"},
+    {"name":"paw","parent":"root","y":-3,"x-note":"tip"}
+  ],
+  "slots":[
+    {"name":"body-slot","bone":"body","attachment":"fur","color":"ffcc88ff","dark":"102030"},
+    {"name":"tail-slot","bone":"tail","attachment":"tail","blend":"screen"},
+    {"name":"hit-slot","bone":"root","x-layer":2}
+  ],
+  "constraints":[
+    {"type":"ik","name":"reach","order":1,"bones":["tail"],"target":"paw","mix":0.5,"bendPositive":false},
+    {
+      "type":"transform","name":"follow","order":0,"bones":["tail"],"source":"paw",
+      "rotation":12.5,"x":3,"mixRotate":0.25,"mixX":0.5,
+      "properties":{"rotate":{"to":{"rotate":{}}},"x":{"to":{"x":{}}}}
+    },
+    {"type":"path","name":"spine-path","order":2,"bones":["tail"],"target":"hit-slot","spacing":4},
+    {"type":"physics","name":"sway","order":3,"bone":"tail","inertia":0.5}
+  ],
+  "skins":[
+    {
+      "name":"default",
+      "attachments":{
+        "body-slot":{
+          "fur":{"x":2,"rotation":15.7,"width":8,"height":4,"color":"ffffff80"},
+          "fur-seq":{"path":"fur","width":8,"height":4,"sequence":{"count":3,"digits":2}}
+        },
+        "tail-slot":{
+          "tail":{
+            "type":"mesh","uvs":[0,0,1,0,1,1],"triangles":[0,1,2],
+            "vertices":[0,0,4,0,4,4],"hull":3,"edges":[0,2,2,4,4,0],"width":4,"height":4
+          }
+        },
+        "hit-slot":{
+          "box":{"type":"boundingbox","vertexCount":3,"vertices":[0,0,1,0,1,1],"color":"60f000ff"},
+          "tip":{"type":"point","x":3,"rotation":90},
+          "clip":{"type":"clipping","end":"tail-slot","vertexCount":3,"vertices":[0,0,2,0,2,2]}
+        }
+      }
+    },
+    {
+      "name":"spotted",
+      "bones":["paw"],
+      "attachments":{
+        "tail-slot":{
+          "tail":{"type":"linkedmesh","path":"tail","parent":"tail","skin":"default","deform":false,"width":4,"height":4}
+        }
+      }
+    }
+  ],
+  "events":{"step":{"int":1,"audio":"step.ogg","volume":0.5,"x-priority":3}},
+  "animations":{
+    "walk":{
+      "bones":{
+        "body":{
+          "rotate":[
+            {"value":10,"curve":[0.3,12,0.9,30]},
+            {"time":1.3333,"value":30,"curve":"stepped"},
+            {"time":2.1,"value":-5}
+          ],
+          "translate":[{"x":1,"curve":[0.1,1,0.2,1.5,0.3,0,0.4,2]},{"time":0.5,"x":2,"y":2}],
+          "inherit":[{"time":0.25,"inherit":"noRotation"}]
+        },
+        "tail":{"scale":[{"x":1.5}],"shear":[{"time":0.1,"y":4,"x-ease":1}]}
+      },
+      "slots":{
+        "body-slot":{
+          "rgba":[{"color":"ffffffff","curve":[0.2,1,0.4,1,0.2,1,0.4,1,0.2,1,0.4,0.5,0.2,1,0.4,1]},{"time":1,"color":"ff00ff80"}],
+          "rgb2":[{"light":"ffffffff","dark":"000000"}]
+        },
+        "tail-slot":{"attachment":[{"time":0.4,"name":null},{"time":0.8,"name":"tail"}]}
+      },
+      "ik":{"reach":[{"mix":0.25,"curve":"stepped"},{"time":1.5,"bendPositive":false}]},
+      "transform":{"follow":[{"mixX":1},{"time":2,"mixRotate":1}]},
+      "attachments":{"spotted":{"tail-slot":{"tail":{"deform":[{"time":0.5,"vertices":[1,1]}]}}}},
+      "drawOrder":[{"time":0.5,"offsets":[{"slot":"body-slot","offset":1}]},{"time":1}],
+      "events":[{"time":0.5,"name":"step","int":4,"string":"left"},{"time":1.5,"name":"step","volume":1}]
+    },
+    "idle":{}
+  },
+  "x-tooling":{"guides":[1,2,3],"locked":true}
+}"#;
+
+const RETAINED_ATLAS: &[u8] = b"cat.png
+\tsize: 16, 16
+fur
+\tbounds: 0, 0, 8, 4
+tail
+\tbounds: 8, 0, 4, 4
+";
+
+type Diagnostics = Vec<(spinal::DiagnosticCode, String)>;
+
+fn diagnostics(report: &LoadReport) -> Diagnostics {
+    report
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| (diagnostic.code(), diagnostic.message().to_owned()))
+        .collect()
+}
+
+/// Samples every animation and returns the solved bone axes and draw vertices.
+fn poses(asset: &Arc<SkeletonAsset>) -> Vec<f32> {
+    let mut samples = Vec::new();
+    for animation in asset.animations() {
+        for millis in [0, 100, 333, 500, 1250, 2000, 5000] {
+            let mut skeleton = Skeleton::new(Arc::clone(asset));
+            skeleton
+                .sample_animation(
+                    animation.id(),
+                    Duration::from_millis(millis),
+                    PlaybackMode::Once,
+                )
+                .expect("the animation belongs to the asset");
+            let frame = skeleton.editable_pose().solve();
+            for bone in asset.bones() {
+                let world = frame.bone(bone.id()).expect("local").world_transform();
+                samples.extend(world.translation().to_array());
+                samples.extend(world.x_axis().to_array());
+                samples.extend(world.y_axis().to_array());
+            }
+            for item in frame.draw_items() {
+                match item {
+                    DrawItemRef::Region(region) => {
+                        samples
+                            .extend(region.positions().iter().flat_map(|point| point.to_array()));
+                    }
+                    DrawItemRef::Mesh(mesh) => {
+                        samples.extend(mesh.positions().iter().flat_map(|point| point.to_array()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    samples
+}
+
+/// Proves `load_json(write_json(load_json(x)))` is equivalent to the first
+/// load and returns the written JSON.
+fn assert_round_trip(json: &[u8], atlas: &[u8], label: &str) -> Vec<u8> {
+    let first = load_json(json, atlas).unwrap_or_else(|error| panic!("{label} loads: {error}"));
+    let written = write_json(first.asset());
+    let second =
+        load_json(&written, atlas).unwrap_or_else(|error| panic!("written {label} loads: {error}"));
+    assert_eq!(diagnostics(&second), diagnostics(&first), "{label}");
+    assert_eq!(
+        String::from_utf8(write_json(second.asset())).expect("UTF-8"),
+        String::from_utf8(written.clone()).expect("UTF-8"),
+        "{label} writes identically after a reload"
+    );
+    assert_eq!(poses(second.asset()), poses(first.asset()), "{label}");
+    written
+}
+
+fn json(bytes: &[u8]) -> Value {
+    serde_json::from_slice(bytes).expect("valid JSON")
+}
+
+#[test]
+fn every_tracked_fixture_round_trips() {
+    assert_round_trip(VIEWER_JSON, VIEWER_ATLAS, "viewer");
+    assert_round_trip(MINIMAL_JSON, MINIMAL_ATLAS, "minimal");
+    assert_round_trip(RETAINED, RETAINED_ATLAS, "retained");
+}
+
+#[test]
+fn unsupported_records_are_written_verbatim() {
+    let source = json(RETAINED);
+    let written = json(&assert_round_trip(RETAINED, RETAINED_ATLAS, "retained"));
+
+    assert_eq!(written["x-tooling"], source["x-tooling"]);
+    assert_eq!(
+        written["skeleton"]["x-exporter"],
+        source["skeleton"]["x-exporter"]
+    );
+    assert_eq!(written["skeleton"]["fps"], 24);
+    assert_eq!(written["bones"][2]["inherit"], "noScale");
+    assert_eq!(written["bones"][3]["x-note"], "tip");
+    assert_eq!(written["slots"][0]["dark"], "102030");
+    assert_eq!(written["skins"][1]["bones"], source["skins"][1]["bones"]);
+    assert_eq!(written["events"]["step"]["x-priority"], 3);
+
+    let hits = &source["skins"][0]["attachments"]["hit-slot"];
+    assert_eq!(written["skins"][0]["attachments"]["hit-slot"], *hits);
+    assert_eq!(
+        written["skins"][0]["attachments"]["body-slot"]["fur-seq"],
+        source["skins"][0]["attachments"]["body-slot"]["fur-seq"]
+    );
+    assert_eq!(
+        written["skins"][0]["attachments"]["tail-slot"]["tail"]["edges"],
+        source["skins"][0]["attachments"]["tail-slot"]["tail"]["edges"]
+    );
+    for (index, name) in [(2, "spine-path"), (3, "sway")] {
+        let source = source["constraints"][index].as_object().expect("object");
+        let written = written["constraints"]
+            .as_array()
+            .expect("array")
+            .iter()
+            .find(|constraint| constraint["name"] == name)
+            .and_then(Value::as_object)
+            .expect("the unsupported constraint is written");
+        assert_eq!(written, source);
+    }
+
+    let (source, written) = (
+        &source["animations"]["walk"],
+        &written["animations"]["walk"],
+    );
+    assert_eq!(written["attachments"], source["attachments"]);
+    assert_eq!(
+        written["slots"]["body-slot"]["rgb2"],
+        source["slots"]["body-slot"]["rgb2"]
+    );
+    assert_eq!(
+        written["bones"]["body"]["inherit"],
+        source["bones"]["body"]["inherit"]
+    );
+    assert_eq!(
+        written["bones"]["tail"]["shear"],
+        source["bones"]["tail"]["shear"]
+    );
+    let handles = |value: &Value| {
+        value["bones"]["body"]["rotate"][0]["curve"]
+            .as_array()
+            .expect("a Bezier curve")
+            .iter()
+            .map(|number| number.as_f64().expect("a number"))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        handles(written),
+        handles(source),
+        "absolute Bezier handles are restored"
+    );
+}
+
+#[test]
+fn generated_clips_are_written_as_authored_keys() {
+    let asset = load_json(VIEWER_JSON, VIEWER_ATLAS)
+        .expect("the viewer loads")
+        .into_asset();
+    let tail = asset.bone_id("tail").expect("the bone exists");
+    let mut wag = AnimationBuilder::new("wag");
+    wag.rotate_key(tail, Duration::ZERO, 0.0, KeyCurve::Linear)
+        .rotate_key(tail, Duration::from_millis(250), 20.0, KeyCurve::Stepped)
+        .rotate_key(tail, Duration::from_millis(500), -20.0, KeyCurve::Linear);
+    let derived = build_animations(&asset, [wag]).expect("the clip is valid");
+
+    let written = write_json(derived.asset());
+    let reloaded = load_json(&written, VIEWER_ATLAS).expect("the written JSON loads");
+    assert_eq!(
+        reloaded.asset().animations().len(),
+        derived.asset().animations().len()
+    );
+    assert_eq!(poses(reloaded.asset()), poses(derived.asset()));
+    assert_eq!(
+        json(&written)["animations"]["wag"]["bones"]["tail"]["rotate"][1],
+        serde_json::json!({"time": 0.25, "value": 20.0, "curve": "stepped"})
+    );
+}
+
+#[test]
+#[ignore = "requires external fixtures; see github.com/gak/spinal/blob/main/fixtures/README.md"]
+fn official_exports_round_trip() {
+    let root = env::var_os("SPINAL_4_3_23_FIXTURES")
+        .expect("SPINAL_4_3_23_FIXTURES must point at the external fixture root");
+    for (directory, stem) in [("ess", "spineboy-ess"), ("pro", "spineboy-pro")] {
+        let directory = Path::new(&root).join(directory);
+        let read = |extension: &str| {
+            let path = directory.join(format!("{stem}.{extension}"));
+            fs::read(&path).unwrap_or_else(|error| panic!("{}: {error}", path.display()))
+        };
+        assert_round_trip(&read("json"), &read("atlas"), stem);
+    }
+}
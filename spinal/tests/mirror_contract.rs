@@ -0,0 +1,153 @@
+//! Public contract tests for left/right animation mirroring.
+
+use std::{sync::Arc, time::Duration};
+
+use glam::Vec2;
+use spinal::{
+    DiagnosticCode, MirrorOptions, PlaybackMode, Skeleton, SkeletonAsset, WorldTransform,
+    load_json, mirror_animations,
+};
+
+// A cat with an asymmetric setup pose: the left ear droops, the right one
+// stands up, and only the left side has a marking slot.
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"body","parent":"root","y":10,"length":20},
+    {"name":"ear_l","parent":"body","x":-4,"y":6,"rotation":150,"length":5},
+    {"name":"ear_r","parent":"body","x":4,"y":6,"rotation":60,"length":5},
+    {"name":"tail","parent":"body","x":-20,"rotation":200,"length":10,"shearY":5}
+  ],
+  "slots":[
+    {"name":"ear_l","bone":"ear_l","attachment":"ear_l"},
+    {"name":"ear_r","bone":"ear_r","attachment":"ear_r"},
+    {"name":"patch_l","bone":"body"}
+  ],
+  "skins":[{"name":"default","attachments":{
+    "ear_l":{"ear_l":{"width":4,"height":4},"ear_r":{"width":4,"height":4}},
+    "ear_r":{"ear_l":{"width":4,"height":4},"ear_r":{"width":4,"height":4}}
+  }}],
+  "animations":{
+    "turn":{
+      "bones":{
+        "body":{"translate":[{"x":0,"y":0},{"time":1,"x":6,"y":2}]},
+        "ear_l":{"rotate":[{"value":0},{"time":1,"value":25}]},
+        "tail":{"rotate":[{"value":0},{"time":1,"value":-40}],"scale":[{"x":1,"y":1},{"time":1,"x":1.5,"y":0.5}]}
+      },
+      "slots":{"patch_l":{"rgba":[{"color":"ffffffff"},{"time":1,"color":"ff0000ff"}]}}
+    }
+  }
+}"#;
+
+const ATLAS: &[u8] = b"page.png
+\tsize: 16, 16
+ear_l
+\tbounds: 0, 0, 4, 4
+ear_r
+\tbounds: 4, 0, 4, 4
+";
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, ATLAS)
+        .expect("the mirror fixture loads")
+        .into_asset()
+}
+
+fn world(
+    asset: &Arc<SkeletonAsset>,
+    animation: &str,
+    time: Duration,
+    bone: &str,
+) -> WorldTransform {
+    let mut skeleton = Skeleton::new(Arc::clone(asset));
+    let animation = asset.animation_id(animation).expect("the clip exists");
+    skeleton
+        .sample_animation(animation, time, PlaybackMode::Once)
+        .expect("the clip samples");
+    let frame = skeleton.editable_pose().solve();
+    frame
+        .bone(asset.bone_id(bone).expect("the bone exists"))
+        .expect("the bone is local")
+        .world_transform()
+}
+
+fn assert_near(actual: Vec2, expected: Vec2, what: &str) {
+    assert!(
+        actual.abs_diff_eq(expected, 1.0e-3),
+        "{what}: {actual} != {expected}"
+    );
+}
+
+#[test]
+fn mirrored_clips_reflect_the_counterpart_world_pose() {
+    let asset = asset();
+    let turn = asset.animation_id("turn").expect("turn exists");
+    let report = mirror_animations(&asset, [turn], &MirrorOptions::by_suffix("_l", "_r"))
+        .expect("the clip mirrors");
+    let derived = report.asset();
+    let mirrored = derived.animation(report.animations()[0]).expect("local");
+    assert_eq!(mirrored.name(), "turn-mirrored");
+    assert!(derived.animation_id("turn").is_some(), "the source is kept");
+
+    for time in [
+        Duration::ZERO,
+        Duration::from_millis(400),
+        Duration::from_secs(1),
+    ] {
+        for (from, to) in [
+            ("body", "body"),
+            ("ear_l", "ear_r"),
+            ("ear_r", "ear_l"),
+            ("tail", "tail"),
+        ] {
+            let from = world(&asset, "turn", time, from);
+            let to = world(derived, "turn-mirrored", time, to);
+            let reflect = |point: Vec2| Vec2::new(-point.x, point.y);
+            assert_near(to.translation(), reflect(from.translation()), "origin");
+            assert_near(to.x_axis(), reflect(from.x_axis()), "bone direction");
+            // A proper rotation keeps attachment art unflipped.
+            assert_near(to.y_axis(), -reflect(from.y_axis()), "bone normal");
+        }
+    }
+}
+
+#[test]
+fn explicit_pairs_and_missing_counterparts() {
+    let asset = asset();
+    let turn = asset.animation_id("turn").expect("turn exists");
+
+    let report = mirror_animations(
+        &asset,
+        [turn],
+        &MirrorOptions::new()
+            .with_bone_pair("ear_l", "ear_r")
+            .with_animation_suffix("-flipped"),
+    )
+    .expect("the clip mirrors");
+    assert!(report.diagnostics().is_empty());
+    assert!(report.asset().animation_id("turn-flipped").is_some());
+    let end = Duration::from_secs(1);
+    let source = world(&asset, "turn", end, "ear_l");
+    let result = world(report.asset(), "turn-flipped", end, "ear_r");
+    assert_near(
+        result.translation(),
+        Vec2::new(-source.translation().x, source.translation().y),
+        "explicitly paired ear",
+    );
+
+    let report = mirror_animations(&asset, [turn], &MirrorOptions::by_suffix("_l", "_r"))
+        .expect("the clip mirrors");
+    assert!(report.diagnostics().iter().any(|diagnostic| {
+        diagnostic.code() == DiagnosticCode::UnmappedSlot
+            && diagnostic.message().contains("patch_l")
+    }));
+}
+
+#[test]
+fn mirroring_rejects_foreign_animations() {
+    let asset = asset();
+    let other = self::asset();
+    let turn = other.animation_id("turn").expect("turn exists");
+    assert!(mirror_animations(&asset, [turn], &MirrorOptions::default()).is_err());
+}
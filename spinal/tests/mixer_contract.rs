@@ -3,9 +3,9 @@
 use std::{sync::Arc, time::Duration};
 
 use spinal::{
-    AnimationMixer, Crossfade, Mix, OverrideSupport, PlayOptions, PlayerError, PropertyKey,
-    Skeleton, TrackAnimationEvent, TrackErrorKind, TrackOptions, TransformMixChannel, Transition,
-    WeightFade, glam::Vec2, load_json,
+    AnimationMixer, Crossfade, Mix, MixCurve, MixTable, OverrideSupport, PlayOptions, PlayerError,
+    PropertyKey, Skeleton, TrackAnimationEvent, TrackErrorKind, TrackOptions, TransformMixChannel,
+    Transition, WeightFade, glam::Vec2, load_json,
 };
 
 const MIXER_JSON: &[u8] = br#"{
@@ -2154,3 +2154,83 @@ fn base_track_seek_forwards_clock_and_event_baseline_without_restarting() {
     assert_eq!(events, [(mixer.base_track_id(), "step".to_owned())]);
     assert_eq!(mixer.base_track().status().playback(), Some(playback));
 }
+
+#[test]
+fn base_and_override_tracks_consult_their_own_mix_tables() {
+    let (asset, mut skeleton) = mixer_fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let fall = asset.animation_id("fall").expect("fall exists");
+    let look = asset.animation_id("look").expect("look exists");
+    let look_back = asset.animation_id("look-back").expect("look-back exists");
+    let table = Arc::new(
+        MixTable::from_names(
+            &asset,
+            Duration::from_millis(400),
+            MixCurve::SmoothStep,
+            [("look", "look-back", Duration::from_millis(200))],
+        )
+        .expect("every pair name exists"),
+    );
+    let mut mixer = AnimationMixer::new(&skeleton);
+    mixer
+        .base_track_mut()
+        .set_mix_table(Some(Arc::clone(&table)))
+        .expect("the table belongs to the mixer asset");
+    let aim = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the mixer has track identity capacity");
+    mixer
+        .track_mut(aim)
+        .expect("aim track exists")
+        .set_mix_table(Some(Arc::clone(&table)))
+        .expect("the table belongs to the mixer asset");
+    assert_eq!(
+        mixer.track(aim).expect("aim track exists").mix_table(),
+        Some(&*table)
+    );
+
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk belongs to the mixer");
+    mixer
+        .track_mut(aim)
+        .expect("aim track exists")
+        .play(look, PlayOptions::looping())
+        .expect("look belongs to the mixer");
+    let _frame = mixer
+        .update(&mut skeleton, Duration::from_millis(50), &mut ())
+        .expect("update succeeds")
+        .solve();
+    assert_eq!(mixer.base_track().status().transition_mix(), None);
+
+    mixer
+        .base_track_mut()
+        .play(fall, PlayOptions::once())
+        .expect("fall belongs to the mixer");
+    mixer
+        .track_mut(aim)
+        .expect("aim track exists")
+        .play(look_back, PlayOptions::looping())
+        .expect("look-back belongs to the mixer");
+    let _frame = mixer
+        .update(&mut skeleton, Duration::from_millis(100), &mut ())
+        .expect("update succeeds")
+        .solve();
+    let smooth = |amount: f32| amount * amount * (3.0 - 2.0 * amount);
+    let base_mix = mixer
+        .base_track()
+        .status()
+        .transition_mix()
+        .expect("the default mix is active")
+        .get();
+    let aim_mix = mixer
+        .track(aim)
+        .expect("aim track exists")
+        .status()
+        .transition_mix()
+        .expect("the pair mix is active")
+        .get();
+    assert!((base_mix - smooth(0.25)).abs() < 1.0e-6);
+    assert!((aim_mix - smooth(0.5)).abs() < 1.0e-6);
+}
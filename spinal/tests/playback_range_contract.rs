@@ -0,0 +1,229 @@
+//! Public contract tests for sub-range playback, loop ranges and markers.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationEvent, AnimationId, AnimationMixer, AnimationPlayer, PlayOptions, PlayerError,
+    Skeleton, SkeletonAsset, TrackOptions, load_json,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"}],
+  "events":{"intro":{},"loop_start":{},"hit":{},"loop_end":{},"outro_end":{}},
+  "animations":{
+    "attack":{
+      "bones":{"root":{"translate":[{"x":0,"y":0},{"time":2,"x":200,"y":0}]}},
+      "events":[
+        {"time":0,"name":"intro"},
+        {"time":0.5,"name":"loop_start"},
+        {"time":1,"name":"hit"},
+        {"time":1.5,"name":"loop_end"},
+        {"time":2,"name":"outro_end"}
+      ]
+    }
+  }
+}"#;
+
+fn fixture() -> (Arc<SkeletonAsset>, Skeleton, AnimationId) {
+    let asset = load_json(JSON, b"page.png\n")
+        .expect("the range fixture loads")
+        .into_asset();
+    let skeleton = Skeleton::new(Arc::clone(&asset));
+    let attack = asset.animation_id("attack").expect("attack exists");
+    (asset, skeleton, attack)
+}
+
+fn millis(value: u64) -> Duration {
+    Duration::from_millis(value)
+}
+
+fn step(player: &mut AnimationPlayer, skeleton: &mut Skeleton, delta: u64) -> Vec<(String, u128)> {
+    let mut events = Vec::new();
+    let _frame = player
+        .update(skeleton, millis(delta), &mut |event: AnimationEvent<'_>| {
+            events.push((event.definition().name().to_owned(), event.loop_index()));
+        })
+        .expect("the update succeeds");
+    events
+}
+
+fn names(events: &[(String, u128)]) -> Vec<&str> {
+    events.iter().map(|(name, _)| name.as_str()).collect()
+}
+
+fn root_x(skeleton: &Skeleton) -> f32 {
+    let root = skeleton.asset().bone_id("root").expect("root exists");
+    skeleton
+        .bone_pose(root)
+        .expect("root is local")
+        .local_transform()
+        .translation()
+        .x
+}
+
+#[test]
+fn markers_follow_authored_event_keys_in_time_order() {
+    let (asset, _skeleton, attack) = fixture();
+    let animation = asset.animation(attack).expect("attack is local");
+
+    let markers: Vec<_> = animation
+        .markers()
+        .map(|marker| (marker.name(), marker.time()))
+        .collect();
+    assert_eq!(
+        markers,
+        [
+            ("intro", Duration::ZERO),
+            ("loop_start", millis(500)),
+            ("hit", millis(1_000)),
+            ("loop_end", millis(1_500)),
+            ("outro_end", millis(2_000)),
+        ]
+    );
+    assert_eq!(animation.marker("loop_end"), Some(millis(1_500)));
+    assert_eq!(animation.marker("missing"), None);
+}
+
+#[test]
+fn once_ranges_start_at_their_window_and_hold_its_end() {
+    let (_asset, mut skeleton, attack) = fixture();
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .play(
+            attack,
+            PlayOptions::once().with_range(millis(500), millis(1_500)),
+        )
+        .expect("the range lies within attack");
+
+    let events = step(&mut player, &mut skeleton, 0);
+    assert_eq!(names(&events), ["loop_start"]);
+    assert_eq!(player.status().position(), Some(millis(500)));
+    assert!((root_x(&skeleton) - 50.0).abs() < 1.0e-3);
+
+    let events = step(&mut player, &mut skeleton, 2_000);
+    assert_eq!(names(&events), ["hit", "loop_end"]);
+    assert_eq!(player.status().position(), Some(millis(1_500)));
+    assert!(player.status().is_complete());
+    assert!((root_x(&skeleton) - 150.0).abs() < 1.0e-3);
+
+    // Seeking and restarting stay relative to the window.
+    player.seek_to(millis(250));
+    assert_eq!(player.status().position(), Some(millis(750)));
+    player.restart().expect("restart succeeds");
+    let events = step(&mut player, &mut skeleton, 100);
+    assert_eq!(names(&events), ["loop_start"]);
+    assert_eq!(player.status().position(), Some(millis(600)));
+}
+
+#[test]
+fn looping_ranges_wrap_to_their_start_with_exact_boundary_events() {
+    let (_asset, mut skeleton, attack) = fixture();
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .play(
+            attack,
+            PlayOptions::looping().with_range(millis(500), millis(1_500)),
+        )
+        .expect("the range lies within attack");
+
+    let events = step(&mut player, &mut skeleton, 1_250);
+    assert_eq!(
+        events,
+        [
+            ("loop_start".to_owned(), 0),
+            ("hit".to_owned(), 0),
+            ("loop_end".to_owned(), 0),
+            ("loop_start".to_owned(), 1),
+        ]
+    );
+    assert_eq!(player.status().position(), Some(millis(750)));
+    assert_eq!(player.status().loop_index(), Some(1));
+}
+
+#[test]
+fn loop_ranges_repeat_after_the_intro_until_released_into_the_outro() {
+    let (asset, mut skeleton, attack) = fixture();
+    let animation = asset.animation(attack).expect("attack is local");
+    let loop_start = animation.marker("loop_start").expect("the marker exists");
+    let loop_end = animation.marker("loop_end").expect("the marker exists");
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .play(
+            attack,
+            PlayOptions::once().with_loop_range(loop_start, loop_end),
+        )
+        .expect("the loop lies within attack");
+
+    let events = step(&mut player, &mut skeleton, 1_700);
+    assert_eq!(
+        names(&events),
+        ["intro", "loop_start", "hit", "loop_end", "loop_start"]
+    );
+    assert_eq!(player.status().position(), Some(millis(700)));
+    let events = step(&mut player, &mut skeleton, 1_000);
+    assert_eq!(names(&events), ["hit", "loop_end", "loop_start"]);
+    assert!(!player.status().is_complete());
+
+    let playback = player.status().playback();
+    assert_eq!(player.release_loop(), playback);
+    let events = step(&mut player, &mut skeleton, 2_000);
+    assert_eq!(names(&events), ["hit", "loop_end", "outro_end"]);
+    assert_eq!(player.status().position(), Some(millis(2_000)));
+    assert!(player.status().is_complete());
+}
+
+#[test]
+fn mixer_tracks_play_ranges_and_release_loops() {
+    let (_asset, mut skeleton, attack) = fixture();
+    let mut mixer = AnimationMixer::new(&skeleton);
+    let track = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .play(
+            attack,
+            PlayOptions::once().with_loop_range(millis(500), millis(1_000)),
+        )
+        .expect("the loop lies within attack");
+    let _frame = mixer
+        .update(&mut skeleton, millis(1_200), &mut ())
+        .expect("the update succeeds");
+    let position = |mixer: &AnimationMixer| {
+        mixer
+            .track(track)
+            .expect("the track exists")
+            .status()
+            .position()
+    };
+    assert_eq!(position(&mixer), Some(millis(700)));
+
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .release_loop();
+    let _frame = mixer
+        .update(&mut skeleton, millis(1_200), &mut ())
+        .expect("the update succeeds");
+    assert_eq!(position(&mixer), Some(millis(1_900)));
+}
+
+#[test]
+fn ranges_outside_the_animation_or_window_are_rejected() {
+    let (_asset, skeleton, attack) = fixture();
+    let mut player = AnimationPlayer::new(&skeleton);
+
+    for options in [
+        PlayOptions::once().with_range(millis(1_000), millis(500)),
+        PlayOptions::looping().with_range(millis(0), millis(2_001)),
+        PlayOptions::once()
+            .with_range(millis(500), millis(1_000))
+            .with_loop_range(millis(0), millis(1_000)),
+        PlayOptions::once().with_loop_range(millis(1_500), millis(1_000)),
+    ] {
+        assert_eq!(player.play(attack, options), Err(PlayerError::InvalidRange));
+    }
+    assert!(player.status().is_idle());
+}
@@ -3,8 +3,9 @@
 use std::{sync::Arc, time::Duration};
 
 use spinal::{
-    Angle, AnimationEvent, AnimationPlayer, BoneTransform, Crossfade, DiscreteSwitches, Mix,
-    PlayOptions, PlayerError, RotationPath, Skeleton, TransformMix, Transition, load_json,
+    Angle, AnimationEvent, AnimationPlayer, BoneTransform, Crossfade, DiscreteSwitches,
+    IdErrorKind, Mix, MixCurve, MixTable, MixTableError, PlayOptions, PlayerError, RotationPath,
+    Skeleton, TransformMix, Transition, load_json,
 };
 
 const ATLAS: &str = "\
@@ -1371,3 +1372,102 @@ fn seek_handles_zero_duration_once_and_loop_without_events_or_completion_pulses(
     assert_eq!(once_frame.report().completed(), None);
     assert_eq!(event_count, 0);
 }
+
+fn cat_rotation(player: &mut AnimationPlayer, skeleton: &mut Skeleton, delta: Duration) -> f32 {
+    let cat = skeleton.asset().bone_id("cat").expect("bone exists");
+    let frame = player
+        .update(skeleton, delta, &mut ())
+        .expect("the player remains bound to this skeleton")
+        .solve();
+    frame
+        .bone(cat)
+        .expect("asset-local bone")
+        .local_transform()
+        .rotation()
+        .as_degrees()
+}
+
+#[test]
+fn mix_tables_choose_pair_crossfades_only_without_an_explicit_transition() {
+    let (asset, mut skeleton) = fixture();
+    let idle = asset.animation_id("idle").expect("animation exists");
+    let fall = asset.animation_id("fall").expect("animation exists");
+    let jump = asset.animation_id("jump").expect("animation exists");
+    let table = MixTable::from_names(
+        &asset,
+        Duration::from_secs(1),
+        MixCurve::Linear,
+        [("fall", "jump", Duration::ZERO)],
+    )
+    .expect("every pair name exists");
+    assert_eq!(table.mix(idle, fall), Ok(Duration::from_secs(1)));
+    assert_eq!(table.mix(fall, jump), Ok(Duration::ZERO));
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .set_mix_table(Some(Arc::new(table)))
+        .expect("the table belongs to the player asset");
+
+    player
+        .play(idle, PlayOptions::looping())
+        .expect("animation belongs to the bound skeleton");
+    assert_eq!(player.status().transition_mix(), None);
+    cat_rotation(&mut player, &mut skeleton, Duration::ZERO);
+
+    player
+        .play(fall, PlayOptions::once())
+        .expect("animation belongs to the bound skeleton");
+    assert_eq!(
+        PlayOptions::once().requested_transition(),
+        Transition::Immediate
+    );
+    assert_eq!(player.status().transition_mix(), Some(Mix::ZERO));
+    let rotation = cat_rotation(&mut player, &mut skeleton, Duration::from_millis(500));
+    assert!((rotation - 45.0).abs() < 1.0e-4);
+
+    player
+        .play(jump, PlayOptions::once())
+        .expect("animation belongs to the bound skeleton");
+    let rotation = cat_rotation(&mut player, &mut skeleton, Duration::from_millis(10));
+    assert!((rotation - 120.0).abs() < 1.0e-4);
+
+    player
+        .play(
+            idle,
+            PlayOptions::once().with_transition(Transition::Immediate),
+        )
+        .expect("animation belongs to the bound skeleton");
+    let rotation = cat_rotation(&mut player, &mut skeleton, Duration::from_millis(10));
+    assert!(rotation.abs() < 1.0e-4);
+}
+
+#[test]
+fn mix_tables_reject_foreign_assets_and_animation_ids() {
+    let (asset, skeleton) = fixture();
+    let (other, _other_skeleton) = fixture();
+    let idle = asset.animation_id("idle").expect("animation exists");
+    let foreign = other.animation_id("idle").expect("animation exists");
+    let mut table = MixTable::new(&asset);
+    assert_eq!(
+        table
+            .set_mix(idle, foreign, Duration::from_secs(1))
+            .map_err(|error| error.kind()),
+        Err(IdErrorKind::ForeignAsset)
+    );
+    assert!(table.is_empty());
+    assert_eq!(
+        MixTable::from_names(
+            &asset,
+            Duration::ZERO,
+            MixCurve::Linear,
+            [("idle", "swim", Duration::from_secs(1))]
+        ),
+        Err(MixTableError::UnknownAnimation("swim".into()))
+    );
+
+    let mut player = AnimationPlayer::new(&skeleton);
+    let error = player
+        .set_mix_table(Some(Arc::new(MixTable::new(&other))))
+        .expect_err("foreign tables are rejected");
+    assert_eq!(error.kind(), IdErrorKind::ForeignAsset);
+    assert!(player.mix_table().is_none());
+}
@@ -0,0 +1,254 @@
+//! Public contract tests for retargeting animations between rigs.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationPlayer, DiagnosticCode, DiagnosticScope, PlayOptions, PlaybackMode, RetargetOptions,
+    Skeleton, SkeletonAsset, TranslationRetarget, load_json, retarget_animations,
+};
+
+// The adult cat rig the clips were authored on.
+const ADULT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root","length":10},
+    {"name":"body","parent":"root","y":10,"length":20},
+    {"name":"tail","parent":"body","x":20,"length":10},
+    {"name":"whisker","parent":"body","x":18,"y":4}
+  ],
+  "slots":[{"name":"body-slot","bone":"body"},{"name":"whisker-slot","bone":"whisker"}],
+  "events":{"step":{},"purr":{}},
+  "animations":{
+    "walk":{
+      "bones":{
+        "body":{"translate":[{"x":0,"y":0,"curve":[0.25,0,0.75,4,0.25,0,0.75,2]},{"time":1,"x":4,"y":2}]},
+        "tail":{"rotate":[{"value":0},{"time":1,"value":30}]},
+        "whisker":{"rotate":[{"value":0},{"time":1,"value":10}]}
+      },
+      "slots":{"whisker-slot":{"rgba":[{"color":"ffffffff"},{"time":1,"color":"ff0000ff"}]}},
+      "events":[{"time":0.25,"name":"step"},{"time":0.5,"name":"purr"}]
+    }
+  }
+}"#;
+
+// A kitten with half-length bones, a renamed tail and no whiskers.
+const KITTEN: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root","length":5},
+    {"name":"body","parent":"root","y":5,"length":10},
+    {"name":"tail-base","parent":"body","x":10,"length":5}
+  ],
+  "slots":[{"name":"body-slot","bone":"body"}],
+  "events":{"step":{}},
+  "animations":{
+    "walk":{"bones":{"body":{"rotate":[{"value":0},{"time":1,"value":-90}]}}},
+    "sit":{"bones":{"body":{"rotate":[{"value":0},{"time":1,"value":5}]}}}
+  }
+}"#;
+
+fn asset(json: &[u8]) -> Arc<SkeletonAsset> {
+    load_json(json, b"page.png\n")
+        .expect("the retarget fixture loads")
+        .into_asset()
+}
+
+fn local(asset: &Arc<SkeletonAsset>, animation: &str, bone: &str, time: Duration) -> (f32, f32) {
+    let mut skeleton = Skeleton::new(Arc::clone(asset));
+    let animation = asset.animation_id(animation).expect("the clip exists");
+    skeleton
+        .sample_animation(animation, time, PlaybackMode::Once)
+        .expect("the clip samples");
+    let transform = skeleton
+        .bone_pose(asset.bone_id(bone).expect("the bone exists"))
+        .expect("the bone is local")
+        .local_transform();
+    (transform.translation().y, transform.rotation().as_degrees())
+}
+
+#[test]
+fn retargeted_clips_play_on_the_target_with_scaled_translation() {
+    let adult = asset(ADULT);
+    let kitten = asset(KITTEN);
+    let walk = adult
+        .animation(adult.animation_id("walk").expect("walk exists"))
+        .expect("walk is local");
+    let report = retarget_animations(
+        &kitten,
+        [walk],
+        &RetargetOptions::new().with_bone("tail", "tail-base"),
+    )
+    .expect("the clip retargets");
+    let derived = report.asset();
+
+    assert_eq!(report.animations().len(), 1);
+    assert_eq!(
+        derived.animations().len(),
+        2,
+        "walk replaces the kitten walk"
+    );
+    assert!(derived.animation_id("sit").is_some());
+    let retargeted = derived.animation(report.animations()[0]).expect("local");
+    assert_eq!(retargeted.name(), "walk");
+    assert_eq!(retargeted.duration(), walk.duration());
+    assert_eq!(
+        retargeted
+            .markers()
+            .map(|marker| marker.name())
+            .collect::<Vec<_>>(),
+        ["step"]
+    );
+
+    // The kitten's root is half as long, halving the body's lift; the
+    // renamed tail takes the adult's rotation.
+    let end = Duration::from_secs(1);
+    let (lift, body_rotation) = local(derived, "walk", "body", end);
+    assert!((lift - (5.0 + 1.0)).abs() < 1.0e-5);
+    assert!(body_rotation.abs() < 1.0e-5, "the kitten walk is replaced");
+    assert!((local(derived, "walk", "tail-base", end).1 - 30.0).abs() < 1.0e-4);
+
+    let mut skeleton = Skeleton::new(Arc::clone(derived));
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .play(report.animations()[0], PlayOptions::once())
+        .expect("the retargeted clip plays");
+    let frame = player
+        .update(&mut skeleton, Duration::from_millis(600), &mut ())
+        .expect("the update succeeds")
+        .solve();
+    assert!(frame.has_degradations(), "the dropped whisker is reported");
+}
+
+#[test]
+fn translation_follows_the_chosen_proportions() {
+    let adult = asset(ADULT);
+    let kitten = asset(KITTEN);
+    let walk = adult
+        .animation(adult.animation_id("walk").expect("walk exists"))
+        .expect("walk is local");
+    let half = Duration::from_millis(500);
+    let source = local(&adult, "walk", "body", half).0 - 10.0;
+
+    for (translation, scale) in [
+        (TranslationRetarget::Unscaled, 1.0),
+        (TranslationRetarget::BoneLength, 0.5),
+        (TranslationRetarget::SetupOffset, 0.5),
+    ] {
+        let report = retarget_animations(
+            &kitten,
+            [walk],
+            &RetargetOptions::new().with_translation(translation),
+        )
+        .expect("the clip retargets");
+        let moved = local(report.asset(), "walk", "body", half).0 - 5.0;
+        assert!(
+            (moved - source * scale).abs() < 1.0e-4,
+            "{translation:?}: {moved} != {source} * {scale}"
+        );
+    }
+}
+
+#[test]
+fn unmapped_elements_are_reported_per_animation() {
+    let adult = asset(ADULT);
+    let kitten = asset(KITTEN);
+    let walk = adult
+        .animation(adult.animation_id("walk").expect("walk exists"))
+        .expect("walk is local");
+    let report =
+        retarget_animations(&kitten, [walk], &RetargetOptions::new()).expect("the clip retargets");
+
+    let mut codes = report
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| {
+            assert!(diagnostic.is_degraded());
+            assert_eq!(
+                diagnostic.scope(),
+                DiagnosticScope::Animation(report.animations()[0])
+            );
+            diagnostic.code()
+        })
+        .collect::<Vec<_>>();
+    codes.sort_by_key(|code| format!("{code:?}"));
+    assert_eq!(
+        codes,
+        [
+            DiagnosticCode::UnmappedBone,
+            DiagnosticCode::UnmappedBone,
+            DiagnosticCode::UnmappedEvent,
+            DiagnosticCode::UnmappedSlot,
+        ],
+        "tail and whisker, purr, and the whisker slot"
+    );
+    assert_eq!(report.asset().diagnostics(), report.diagnostics());
+    assert!(!kitten.has_degradations(), "the target asset is unchanged");
+}
+
+#[test]
+fn attachment_keys_missing_on_the_target_slot_are_dropped_and_reported() {
+    let eye_asset = |json: &[u8]| {
+        load_json(
+            json,
+            b"page.png\nopen\n\tbounds: 0, 0, 2, 2\nclosed\n\tbounds: 0, 2, 2, 1\n",
+        )
+        .expect("the eye fixture loads")
+        .into_asset()
+    };
+    let blinking = eye_asset(
+        br#"{
+          "skeleton":{"spine":"4.3.23"},
+          "bones":[{"name":"root"}],
+          "slots":[{"name":"eye","bone":"root","attachment":"open"}],
+          "skins":[{"name":"default","attachments":{"eye":{
+            "open":{"width":2,"height":2},
+            "closed":{"width":2,"height":1}
+          }}}],
+          "animations":{"blink":{"slots":{"eye":{"attachment":[
+            {"name":"open"},{"time":0.5,"name":"closed"},{"time":0.8,"name":null}
+          ]}}}}
+        }"#,
+    );
+    let one_eyed = eye_asset(
+        br#"{
+          "skeleton":{"spine":"4.3.23"},
+          "bones":[{"name":"root"}],
+          "slots":[{"name":"eye","bone":"root","attachment":"open"}],
+          "skins":[{"name":"default","attachments":{"eye":{"open":{"width":2,"height":2}}}}]
+        }"#,
+    );
+    let blink = blinking
+        .animation(blinking.animation_id("blink").expect("blink exists"))
+        .expect("blink is local");
+    let report = retarget_animations(&one_eyed, [blink], &RetargetOptions::new())
+        .expect("the clip retargets");
+
+    let [diagnostic] = report.diagnostics() else {
+        panic!("one diagnostic: {:?}", report.diagnostics());
+    };
+    assert_eq!(diagnostic.code(), DiagnosticCode::UnmappedAttachment);
+    assert!(diagnostic.is_degraded());
+    assert!(diagnostic.message().contains("\"closed\""));
+
+    let target = report.asset();
+    let eye = target.slot_id("eye").expect("the slot exists");
+    let attachment_at = |millis: u64| {
+        let mut skeleton = Skeleton::new(Arc::clone(target));
+        skeleton
+            .sample_animation(
+                report.animations()[0],
+                Duration::from_millis(millis),
+                PlaybackMode::Once,
+            )
+            .expect("the clip samples");
+        skeleton
+            .slot_pose(eye)
+            .expect("the slot is local")
+            .attachment()
+    };
+    assert!(
+        attachment_at(600).is_some(),
+        "the dropped key keeps the open eye"
+    );
+    assert_eq!(attachment_at(900), None, "hiding keys always map");
+}
@@ -0,0 +1,230 @@
+//! Public contract tests for root-motion extraction.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationMixer, AnimationPlayer, BoneId, Crossfade, IdErrorKind, Mix, PlayOptions, RootMotion,
+    RootMotionDelta, Skeleton, SkeletonAsset, TrackOptions, Transition, glam::Vec2, load_json,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"hip","parent":"root","x":5},
+    {"name":"arm","parent":"hip"}
+  ],
+  "animations":{
+    "walk":{
+      "bones":{
+        "hip":{
+          "translate":[{"x":0,"y":0},{"time":1,"x":100,"y":0}],
+          "rotate":[{"value":0},{"time":1,"value":90}]
+        }
+      }
+    },
+    "slide":{
+      "bones":{
+        "hip":{"translate":[{"x":0,"y":0},{"time":1,"x":200,"y":0}]}
+      }
+    },
+    "wave":{
+      "bones":{"arm":{"rotate":[{"value":0},{"time":1,"value":30}]}}
+    }
+  }
+}"#;
+
+fn fixture() -> (Arc<SkeletonAsset>, Skeleton, BoneId) {
+    let asset = load_json(JSON, b"page.png\n")
+        .expect("the root-motion fixture loads")
+        .into_asset();
+    let skeleton = Skeleton::new(Arc::clone(&asset));
+    let hip = asset.bone_id("hip").expect("hip exists");
+    (asset, skeleton, hip)
+}
+
+fn assert_motion(delta: RootMotionDelta, x: f32, degrees: f32) {
+    assert!(
+        (delta.translation() - Vec2::new(x, 0.0)).length() < 1.0e-3,
+        "translation {:?} differs from {x}",
+        delta.translation()
+    );
+    assert!(
+        (delta.rotation().as_degrees() - degrees).abs() < 1.0e-3,
+        "rotation {} differs from {degrees}",
+        delta.rotation().as_degrees()
+    );
+}
+
+#[test]
+fn looping_motion_stays_continuous_across_one_or_many_loop_wraps() {
+    let (asset, mut skeleton, hip) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .set_root_motion(Some(RootMotion::new(hip)))
+        .expect("the bone is local");
+    player
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(750), &mut ())
+        .expect("the update succeeds");
+    assert_motion(player.root_motion_delta(), 75.0, 67.5);
+
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(500), &mut ())
+        .expect("the update succeeds");
+    assert_motion(player.root_motion_delta(), 50.0, 45.0);
+
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(2_500), &mut ())
+        .expect("the update succeeds");
+    assert_motion(player.root_motion_delta(), 250.0, 225.0);
+
+    // Without zeroing the authored motion stays in the pose.
+    let pose = skeleton.bone_pose(hip).expect("hip is local");
+    assert!((pose.local_transform().translation().x - 80.0).abs() < 1.0e-3);
+}
+
+#[test]
+fn zeroing_pins_the_bone_to_setup_and_once_playback_stops_at_its_end() {
+    let (asset, mut skeleton, hip) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .set_root_motion(Some(RootMotion::new(hip).with_zero_in_pose(true)))
+        .expect("the bone is local");
+    player
+        .play(walk, PlayOptions::once())
+        .expect("walk is local");
+
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(1_500), &mut ())
+        .expect("the update succeeds");
+    assert_motion(player.root_motion_delta(), 100.0, 90.0);
+    let pose = skeleton.bone_pose(hip).expect("hip is local");
+    assert_eq!(pose.local_transform().translation(), Vec2::new(5.0, 0.0));
+    assert_eq!(pose.local_transform().rotation().as_radians(), 0.0);
+
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(500), &mut ())
+        .expect("the update succeeds");
+    assert_eq!(player.root_motion_delta(), RootMotionDelta::ZERO);
+
+    player
+        .set_root_motion(None)
+        .expect("clearing always succeeds");
+    player.restart().expect("restart succeeds");
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(500), &mut ())
+        .expect("the update succeeds");
+    assert_eq!(player.root_motion_delta(), RootMotionDelta::ZERO);
+    let pose = skeleton.bone_pose(hip).expect("hip is local");
+    assert!((pose.local_transform().translation().x - 55.0).abs() < 1.0e-3);
+}
+
+#[test]
+fn crossfades_scale_motion_by_the_incoming_mix() {
+    let (asset, mut skeleton, hip) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let slide = asset.animation_id("slide").expect("slide exists");
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .set_root_motion(Some(RootMotion::new(hip)))
+        .expect("the bone is local");
+    player
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(250), &mut ())
+        .expect("the update succeeds");
+
+    player
+        .play(
+            slide,
+            PlayOptions::looping().with_transition(Transition::Crossfade(Crossfade::new(
+                Duration::from_secs(1),
+            ))),
+        )
+        .expect("slide is local");
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(500), &mut ())
+        .expect("the update succeeds");
+    assert_motion(player.root_motion_delta(), 50.0, 0.0);
+
+    let _frame = player
+        .update(&mut skeleton, Duration::from_millis(500), &mut ())
+        .expect("the update succeeds");
+    assert_motion(player.root_motion_delta(), 100.0, 0.0);
+}
+
+#[test]
+fn mixer_motion_follows_track_weights_and_keyed_channels() {
+    let (asset, mut skeleton, hip) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let slide = asset.animation_id("slide").expect("slide exists");
+    let wave = asset.animation_id("wave").expect("wave exists");
+    let mut mixer = AnimationMixer::new(&skeleton);
+    mixer
+        .set_root_motion(Some(RootMotion::new(hip).with_zero_in_pose(true)))
+        .expect("the bone is local");
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    let half = Mix::new(0.5).expect("half is normalized");
+    let slide_track = mixer
+        .insert_track(TrackOptions::override_track().with_weight(half))
+        .expect("the track is inserted");
+    mixer
+        .track_mut(slide_track)
+        .expect("the track exists")
+        .play(slide, PlayOptions::looping())
+        .expect("slide is local");
+    let wave_track = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    mixer
+        .track_mut(wave_track)
+        .expect("the track exists")
+        .play(wave, PlayOptions::looping())
+        .expect("wave is local");
+
+    let _frame = mixer
+        .update(&mut skeleton, Duration::from_millis(500), &mut ())
+        .expect("the update succeeds");
+    // Slide keys only translation, so rotation comes from the base alone.
+    assert_motion(mixer.root_motion_delta(), 75.0, 45.0);
+    let pose = skeleton.bone_pose(hip).expect("hip is local");
+    assert_eq!(pose.local_transform().translation(), Vec2::new(5.0, 0.0));
+
+    mixer
+        .track_mut(slide_track)
+        .expect("the track exists")
+        .set_weight(Mix::ZERO);
+    let _frame = mixer
+        .update(&mut skeleton, Duration::from_millis(500), &mut ())
+        .expect("the update succeeds");
+    assert_motion(mixer.root_motion_delta(), 50.0, 45.0);
+}
+
+#[test]
+fn root_motion_rejects_bones_from_another_asset() {
+    let (_asset, skeleton, _hip) = fixture();
+    let (_other_asset, _other_skeleton, other_hip) = fixture();
+    let mut player = AnimationPlayer::new(&skeleton);
+    let mut mixer = AnimationMixer::new(&skeleton);
+
+    let error = player
+        .set_root_motion(Some(RootMotion::new(other_hip)))
+        .expect_err("foreign bones are rejected");
+    assert_eq!(error.kind(), IdErrorKind::ForeignAsset);
+    assert_eq!(player.root_motion(), None);
+    let error = mixer
+        .set_root_motion(Some(RootMotion::new(other_hip)))
+        .expect_err("foreign bones are rejected");
+    assert_eq!(error.kind(), IdErrorKind::ForeignAsset);
+    assert_eq!(mixer.root_motion(), None);
+}
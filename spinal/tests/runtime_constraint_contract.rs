@@ -0,0 +1,276 @@
+//! Public contract tests for caller-defined constraints in the solve order.
+
+use std::sync::Arc;
+
+use glam::Vec2;
+use spinal::{
+    Angle, BoneId, ConstraintPose, ConstraintPosition, RuntimeConstraint, RuntimeConstraintError,
+    RuntimeConstraintStatus, Skeleton, SkeletonAsset, load_json,
+};
+
+// The paw reaches for a toy, then the head looks at the toy target. A runtime
+// constraint between them can redirect the gaze to the solved paw.
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"leg","parent":"root","length":10},
+    {"name":"head","parent":"root","y":10,"length":4},
+    {"name":"toy","parent":"root","x":0,"y":-10},
+    {"name":"gaze","parent":"root","x":10,"y":10}
+  ],
+  "constraints":[
+    {"name":"reach","type":"ik","bones":["leg"],"target":"toy"},
+    {"name":"look","type":"ik","bones":["head"],"target":"gaze"}
+  ]
+}"#;
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, b"page.png\n")
+        .expect("the runtime constraint fixture loads")
+        .into_asset()
+}
+
+fn bone(asset: &SkeletonAsset, name: &str) -> BoneId {
+    asset.bone_id(name).expect("the bone exists")
+}
+
+/// Moves one bone onto another bone's tip.
+#[derive(Debug)]
+struct FollowTip {
+    bone: BoneId,
+    tip_of: BoneId,
+    length: f32,
+}
+
+impl RuntimeConstraint for FollowTip {
+    fn apply(&mut self, pose: &mut ConstraintPose<'_>) -> RuntimeConstraintStatus {
+        let tip = pose
+            .world_transform(self.tip_of)
+            .expect("the bone is local")
+            .transform_point(Vec2::new(self.length, 0.0));
+        match pose.set_world_position(self.bone, tip) {
+            Ok(()) => RuntimeConstraintStatus::APPLIED,
+            Err(_) => RuntimeConstraintStatus::DEGRADED,
+        }
+    }
+}
+
+/// Limits one bone's world rotation.
+#[derive(Debug)]
+struct ClampRotation {
+    bone: BoneId,
+    max_degrees: f32,
+}
+
+impl RuntimeConstraint for ClampRotation {
+    fn apply(&mut self, pose: &mut ConstraintPose<'_>) -> RuntimeConstraintStatus {
+        let axis = pose.world_transform(self.bone).expect("local").x_axis();
+        let degrees = axis.y.atan2(axis.x).to_degrees();
+        if degrees <= self.max_degrees {
+            return RuntimeConstraintStatus::INACTIVE;
+        }
+        let limit = Angle::from_degrees(self.max_degrees).expect("finite");
+        pose.set_world_rotation(self.bone, limit)
+            .map_or(RuntimeConstraintStatus::DEGRADED, |()| {
+                RuntimeConstraintStatus::APPLIED
+            })
+    }
+}
+
+fn head_direction(skeleton: &mut Skeleton) -> Vec2 {
+    let head = bone(skeleton.asset(), "head");
+    skeleton
+        .editable_pose()
+        .solve()
+        .bone(head)
+        .expect("local")
+        .world_transform()
+        .x_axis()
+        .normalize()
+}
+
+#[test]
+fn runtime_constraints_run_between_authored_constraints() {
+    let asset = asset();
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    assert!(head_direction(&mut skeleton).abs_diff_eq(Vec2::X, 1.0e-5));
+
+    let reach = asset.constraint_id("reach").expect("reach exists");
+    let follow = skeleton
+        .insert_runtime_constraint(
+            ConstraintPosition::After(reach),
+            FollowTip {
+                bone: bone(&asset, "gaze"),
+                tip_of: bone(&asset, "leg"),
+                length: 10.0,
+            },
+        )
+        .expect("the position belongs to the asset");
+
+    // The leg reached down to the toy, so the head now looks at its paw.
+    assert!(head_direction(&mut skeleton).abs_diff_eq(Vec2::NEG_Y, 1.0e-5));
+    let frame = skeleton.editable_pose().solve();
+    assert_eq!(
+        frame.runtime_constraint_statuses().collect::<Vec<_>>(),
+        [(follow, RuntimeConstraintStatus::APPLIED)]
+    );
+    assert!(!frame.has_degradations());
+
+    skeleton
+        .runtime_constraint_mut::<FollowTip>(follow)
+        .expect("the constraint is a FollowTip")
+        .length = 0.0;
+    let frame = skeleton.editable_pose().solve();
+    let gaze = frame.bone(bone(&asset, "gaze")).expect("local");
+    assert!(
+        gaze.world_transform()
+            .translation()
+            .abs_diff_eq(Vec2::ZERO, 1.0e-5)
+    );
+
+    skeleton
+        .remove_runtime_constraint(follow)
+        .expect("the constraint is registered");
+    assert!(head_direction(&mut skeleton).abs_diff_eq(Vec2::X, 1.0e-5));
+    assert_eq!(
+        skeleton.remove_runtime_constraint(follow).unwrap_err(),
+        RuntimeConstraintError::UnknownConstraint
+    );
+}
+
+#[test]
+fn later_positions_observe_earlier_results_and_report_status() {
+    let asset = asset();
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let head = bone(&asset, "head");
+    let clamp = skeleton
+        .insert_runtime_constraint(
+            ConstraintPosition::Last,
+            ClampRotation {
+                bone: head,
+                max_degrees: 30.0,
+            },
+        )
+        .expect("last is always valid");
+    let look = asset.constraint_id("look").expect("look exists");
+    skeleton
+        .insert_runtime_constraint(
+            ConstraintPosition::Before(look),
+            FollowTip {
+                bone: bone(&asset, "gaze"),
+                tip_of: head,
+                length: 0.0,
+            },
+        )
+        .expect("the position belongs to the asset");
+    let order = skeleton.runtime_constraints().collect::<Vec<_>>();
+    assert_eq!(order.len(), 2);
+    assert_eq!(order[1], clamp, "positions, not insertion, define order");
+
+    // The gaze target sits on the head, so the look is preserved and the
+    // clamp has nothing to do.
+    let frame = skeleton.editable_pose().solve();
+    assert_eq!(
+        frame.runtime_constraint_status(clamp),
+        Ok(RuntimeConstraintStatus::INACTIVE)
+    );
+    drop(frame);
+
+    skeleton
+        .remove_runtime_constraint(order[0])
+        .expect("registered");
+    let gaze = bone(&asset, "gaze");
+    skeleton
+        .editable_pose()
+        .targets()
+        .set_skeleton_position(gaze, Vec2::new(0.0, 30.0))
+        .expect("the target moves");
+    let frame = skeleton.editable_pose().solve();
+    assert_eq!(
+        frame.runtime_constraint_status(clamp),
+        Ok(RuntimeConstraintStatus::APPLIED)
+    );
+    let axis = frame.bone(head).expect("local").world_transform().x_axis();
+    assert!((axis.y.atan2(axis.x).to_degrees() - 30.0).abs() < 1.0e-3);
+    assert!(!frame.has_runtime_degradations());
+}
+
+#[test]
+fn foreign_positions_and_types_are_rejected() {
+    let asset = asset();
+    let other = self::asset();
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let foreign = other.constraint_id("reach").expect("reach exists");
+    let clamp = ClampRotation {
+        bone: bone(&asset, "head"),
+        max_degrees: 0.0,
+    };
+    assert!(matches!(
+        skeleton.insert_runtime_constraint(ConstraintPosition::After(foreign), clamp),
+        Err(RuntimeConstraintError::InvalidPosition(_))
+    ));
+
+    let id = skeleton
+        .insert_runtime_constraint(
+            ConstraintPosition::First,
+            ClampRotation {
+                bone: bone(&asset, "head"),
+                max_degrees: 0.0,
+            },
+        )
+        .expect("first is always valid");
+    assert_eq!(
+        skeleton.runtime_constraint::<FollowTip>(id).unwrap_err(),
+        RuntimeConstraintError::TypeMismatch
+    );
+    assert!(skeleton.runtime_constraint::<ClampRotation>(id).is_ok());
+    let mut sibling = Skeleton::new(asset);
+    assert_eq!(
+        sibling.remove_runtime_constraint(id).unwrap_err(),
+        RuntimeConstraintError::UnknownConstraint
+    );
+}
+
+#[test]
+fn edits_move_interleaved_descendants_but_not_siblings() {
+    // `paw` is listed after its parent's sibling, so descendants are not a
+    // contiguous run of bones.
+    let asset = load_json(
+        br#"{
+          "skeleton":{"spine":"4.3.23"},
+          "bones":[
+            {"name":"root"},
+            {"name":"leg","parent":"root","x":4},
+            {"name":"tail","parent":"root","x":-4},
+            {"name":"paw","parent":"leg","x":2}
+          ]
+        }"#,
+        b"page.png\n",
+    )
+    .expect("the interleaved fixture loads")
+    .into_asset();
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    skeleton
+        .insert_runtime_constraint(
+            ConstraintPosition::Last,
+            FollowTip {
+                bone: bone(&asset, "leg"),
+                tip_of: bone(&asset, "root"),
+                length: 10.0,
+            },
+        )
+        .expect("last is always valid");
+
+    let frame = skeleton.editable_pose().solve();
+    let origin = |name: &str| {
+        frame
+            .bone(bone(&asset, name))
+            .expect("local")
+            .world_transform()
+            .translation()
+    };
+    assert_eq!(origin("leg"), Vec2::new(10.0, 0.0));
+    assert_eq!(origin("paw"), Vec2::new(12.0, 0.0));
+    assert_eq!(origin("tail"), Vec2::new(-4.0, 0.0));
+}
@@ -0,0 +1,154 @@
+//! Public contract tests for caller-created IK chains.
+
+use std::sync::Arc;
+
+use glam::Vec2;
+use spinal::{
+    BendDirection, BoneId, ConstraintPosition, IkTarget, IkTargetReach, Mix,
+    RuntimeConstraintStatus, RuntimeIk, RuntimeIkError, Skeleton, SkeletonAsset, load_json,
+};
+
+// The leg's authored IK starts disabled so the runtime chain can be compared
+// with the same solve.
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"thigh","parent":"root","rotation":-60,"length":10},
+    {"name":"shin","parent":"thigh","x":10,"length":10},
+    {"name":"head","parent":"root","y":20,"length":5},
+    {"name":"toy","parent":"root","x":12,"y":-5}
+  ],
+  "constraints":[
+    {"name":"reach","type":"ik","bones":["thigh","shin"],"target":"toy","mix":0,"bendPositive":false}
+  ]
+}"#;
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, b"page.png\n")
+        .expect("the runtime IK fixture loads")
+        .into_asset()
+}
+
+fn bone(asset: &SkeletonAsset, name: &str) -> BoneId {
+    asset.bone_id(name).expect("the bone exists")
+}
+
+#[test]
+fn runtime_chains_match_authored_ik() {
+    let asset = asset();
+    let [thigh, shin, toy] = ["thigh", "shin", "toy"].map(|name| bone(&asset, name));
+
+    let mut authored = Skeleton::new(Arc::clone(&asset));
+    let reach = asset.ik_constraint_id("reach").expect("reach exists");
+    authored
+        .editable_pose()
+        .edit()
+        .set_ik_mix(reach, Mix::new(0.75).expect("valid"))
+        .expect("local");
+    let expected = {
+        let frame = authored.editable_pose().solve();
+        assert!(frame.ik_status(reach).expect("local").is_active());
+        [thigh, shin].map(|bone| frame.bone(bone).expect("local").world_transform())
+    };
+
+    let mut runtime = Skeleton::new(Arc::clone(&asset));
+    let chain = RuntimeIk::two_bone(&asset, thigh, shin, IkTarget::Bone(toy))
+        .expect("the leg is a chain")
+        .with_mix(Mix::new(0.75).expect("valid"))
+        .with_bend_direction(BendDirection::Negative);
+    let id = runtime
+        .insert_runtime_constraint(ConstraintPosition::Last, chain)
+        .expect("last is always valid");
+    {
+        let frame = runtime.editable_pose().solve();
+        assert_eq!(
+            [thigh, shin].map(|bone| frame.bone(bone).expect("local").world_transform()),
+            expected
+        );
+        assert_eq!(
+            frame.runtime_constraint_status(id),
+            Ok(RuntimeConstraintStatus::APPLIED)
+        );
+    }
+    let status = runtime
+        .runtime_constraint::<RuntimeIk>(id)
+        .expect("registered")
+        .status();
+    assert_eq!(
+        status,
+        authored
+            .editable_pose()
+            .solve()
+            .ik_status(reach)
+            .expect("local")
+    );
+    assert_eq!(status.target_reach(), Some(IkTargetReach::Reachable));
+    assert_eq!(asset.ik_constraints().len(), 1, "the asset is untouched");
+}
+
+#[test]
+fn look_at_points_follow_the_target_and_mix() {
+    let asset = asset();
+    let head = bone(&asset, "head");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let look = RuntimeIk::one_bone(&asset, head, IkTarget::Point(Vec2::new(0.0, 40.0)))
+        .expect("the head exists");
+    let id = skeleton
+        .insert_runtime_constraint(ConstraintPosition::First, look)
+        .expect("first is always valid");
+
+    let direction = |skeleton: &mut Skeleton| {
+        skeleton
+            .editable_pose()
+            .solve()
+            .bone(head)
+            .expect("local")
+            .world_transform()
+            .x_axis()
+    };
+    assert!(direction(&mut skeleton).abs_diff_eq(Vec2::Y, 1.0e-5));
+
+    let chain = skeleton
+        .runtime_constraint_mut::<RuntimeIk>(id)
+        .expect("registered");
+    chain.set_target(IkTarget::Point(Vec2::new(-30.0, 20.0)));
+    assert!(direction(&mut skeleton).abs_diff_eq(Vec2::NEG_X, 1.0e-5));
+
+    skeleton
+        .runtime_constraint_mut::<RuntimeIk>(id)
+        .expect("registered")
+        .set_mix(Mix::ZERO);
+    let frame = skeleton.editable_pose().solve();
+    assert_eq!(
+        frame.runtime_constraint_status(id),
+        Ok(RuntimeConstraintStatus::INACTIVE)
+    );
+    assert!(
+        frame
+            .bone(head)
+            .expect("local")
+            .world_transform()
+            .x_axis()
+            .abs_diff_eq(Vec2::X, 1.0e-5)
+    );
+}
+
+#[test]
+fn chains_are_validated() {
+    let asset = asset();
+    let [thigh, head] = ["thigh", "head"].map(|name| bone(&asset, name));
+    assert_eq!(
+        RuntimeIk::two_bone(&asset, thigh, head, IkTarget::Point(Vec2::ZERO)).unwrap_err(),
+        RuntimeIkError::Disconnected { child: head }
+    );
+    let other = self::asset();
+    assert!(matches!(
+        RuntimeIk::one_bone(&other, head, IkTarget::Point(Vec2::ZERO)),
+        Err(RuntimeIkError::InvalidBone(_))
+    ));
+    assert!(matches!(
+        RuntimeIk::one_bone(&asset, head, IkTarget::Bone(bone(&other, "toy"))),
+        Err(RuntimeIkError::InvalidBone(_))
+    ));
+}
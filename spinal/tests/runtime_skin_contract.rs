@@ -0,0 +1,261 @@
+//! Public contract tests for skins assembled at runtime.
+
+use std::sync::Arc;
+
+use spinal::{
+    AttachmentId, DrawItemRef, RuntimeSkin, RuntimeSkinError, Skeleton, SkeletonAsset,
+    SkinAttachment, SkinLayer, SlotId, load_json,
+};
+
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"head","parent":"root","y":20},
+    {"name":"tail","parent":"root","x":-10}
+  ],
+  "slots":[
+    {"name":"hat-slot","bone":"head","attachment":"hat"},
+    {"name":"collar-slot","bone":"root","attachment":"collar"},
+    {"name":"tail-slot","bone":"tail","attachment":"tail"}
+  ],
+  "skins":[
+    {
+      "name":"default",
+      "attachments":{
+        "collar-slot":{"collar":{"path":"collar","width":8,"height":2}},
+        "tail-slot":{"tail":{
+          "type":"mesh",
+          "uvs":[0,0,1,0,1,1],
+          "triangles":[0,1,2],
+          "vertices":[0,0,8,0,8,2],
+          "hull":3
+        }}
+      }
+    },
+    {
+      "name":"hat/red",
+      "attachments":{"hat-slot":{"hat":{"path":"red-hat","width":8,"height":8}}}
+    },
+    {
+      "name":"hat/blue",
+      "attachments":{
+        "hat-slot":{"hat":{"path":"blue-hat","width":8,"height":8}},
+        "collar-slot":{"collar":{"path":"blue-collar","width":8,"height":2}}
+      }
+    },
+    {
+      "name":"accessories",
+      "attachments":{"collar-slot":{"bow":{"path":"bow","width":4,"height":4}}}
+    }
+  ]
+}"#;
+
+const ATLAS: &[u8] = b"cat.png
+\tsize: 64, 16
+collar
+\tbounds: 0, 0, 8, 2
+tail
+\tbounds: 0, 2, 8, 2
+red-hat
+\tbounds: 8, 0, 8, 8
+blue-hat
+\tbounds: 16, 0, 8, 8
+blue-collar
+\tbounds: 24, 0, 8, 2
+bow
+\tbounds: 32, 0, 4, 4
+";
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, ATLAS)
+        .expect("the runtime skin fixture loads")
+        .into_asset()
+}
+
+fn slot(asset: &SkeletonAsset, name: &str) -> SlotId {
+    asset.slot_id(name).expect("the slot exists")
+}
+
+fn attachment(asset: &SkeletonAsset, skin: &str, slot_name: &str, name: &str) -> AttachmentId {
+    asset
+        .skin(asset.skin_id(skin).expect("the skin exists"))
+        .expect("local")
+        .attachment(slot(asset, slot_name), name)
+        .expect("local")
+        .expect("the attachment exists")
+}
+
+fn shown<'a>(asset: &'a SkeletonAsset, skeleton: &Skeleton, slot_name: &str) -> Option<&'a str> {
+    let attachment = skeleton
+        .slot_pose(slot(asset, slot_name))
+        .ok()?
+        .attachment()?;
+    asset
+        .attachment(attachment)
+        .ok()?
+        .as_region()?
+        .attachment()
+        .atlas_path()
+}
+
+#[test]
+fn runtime_skins_pick_and_retarget_attachments_from_authored_skins() {
+    let asset = asset();
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let blue = asset.skin_id("hat/blue").expect("the skin exists");
+    let outfit = RuntimeSkin::new(
+        &asset,
+        "outfit",
+        [
+            SkinAttachment::new(attachment(&asset, "hat/red", "hat-slot", "hat")),
+            SkinAttachment::new(attachment(&asset, "hat/blue", "collar-slot", "collar")),
+        ],
+    )
+    .expect("the picks are valid");
+    assert_eq!(outfit.name(), "outfit");
+    assert_eq!(outfit.attachments().len(), 2);
+    let outfit = skeleton.insert_runtime_skin(outfit).expect("same asset");
+    assert_eq!(
+        shown(&asset, &skeleton, "hat-slot"),
+        None,
+        "not yet layered"
+    );
+
+    skeleton
+        .set_skin_layer_stack(&[SkinLayer::Authored(blue), outfit.into()])
+        .expect("both layers are valid");
+    assert_eq!(shown(&asset, &skeleton, "hat-slot"), Some("red-hat"));
+    assert_eq!(shown(&asset, &skeleton, "collar-slot"), Some("blue-collar"));
+    assert_eq!(skeleton.skin_layers().collect::<Vec<_>>(), [blue]);
+    assert_eq!(
+        skeleton.skin_layer_stack().collect::<Vec<_>>(),
+        [SkinLayer::Authored(blue), SkinLayer::Runtime(outfit)]
+    );
+
+    // The bow moves from the collar onto the hat placeholder and follows
+    // the head like any hat.
+    let bow = RuntimeSkin::new(
+        &asset,
+        "bow-hat",
+        [
+            SkinAttachment::new(attachment(&asset, "accessories", "collar-slot", "bow"))
+                .with_target(slot(&asset, "hat-slot"), "hat"),
+        ],
+    )
+    .expect("regions draw on any slot");
+    let bow = skeleton.insert_runtime_skin(bow).expect("same asset");
+    skeleton
+        .set_skin_layer_stack(&[outfit.into(), bow.into()])
+        .expect("both layers are valid");
+    assert_eq!(shown(&asset, &skeleton, "hat-slot"), Some("bow"));
+    assert_eq!(shown(&asset, &skeleton, "collar-slot"), Some("blue-collar"));
+    let frame = skeleton.editable_pose().solve();
+    let hat = frame
+        .draw_items()
+        .find_map(|item| match item {
+            DrawItemRef::Region(region) if region.slot() == slot(&asset, "hat-slot") => {
+                Some(region)
+            }
+            _ => None,
+        })
+        .expect("the bow is drawn");
+    assert!(hat.positions().iter().all(|position| position.y >= 18.0));
+
+    skeleton.remove_runtime_skin(bow).expect("registered");
+    assert_eq!(shown(&asset, &skeleton, "hat-slot"), Some("red-hat"));
+    assert_eq!(skeleton.runtime_skins().collect::<Vec<_>>(), [outfit]);
+    assert_eq!(
+        skeleton.remove_runtime_skin(bow).unwrap_err(),
+        RuntimeSkinError::UnknownSkin
+    );
+}
+
+#[test]
+fn layering_runtime_skins_allocates_nothing() {
+    let asset = asset();
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let red = asset.skin_id("hat/red").expect("the skin exists");
+    let outfit = RuntimeSkin::new(
+        &asset,
+        "outfit",
+        [SkinAttachment::new(attachment(
+            &asset, "hat/blue", "hat-slot", "hat",
+        ))],
+    )
+    .expect("the pick is valid");
+    let outfit = skeleton.insert_runtime_skin(outfit).expect("same asset");
+    let layers = [SkinLayer::Authored(red), SkinLayer::Runtime(outfit)];
+
+    let allocations = allocation_counter::measure(|| {
+        for _ in 0..10 {
+            skeleton
+                .set_skin_layer_stack(&layers)
+                .expect("valid layers");
+            skeleton
+                .set_skin_layer_stack(&layers[..1])
+                .expect("valid layers");
+        }
+    });
+    assert_eq!(allocations.count_total, 0);
+}
+
+#[test]
+fn picks_and_layers_are_validated() {
+    let asset = asset();
+    let other = self::asset();
+    let bow = attachment(&asset, "accessories", "collar-slot", "bow");
+    let hat_slot = slot(&asset, "hat-slot");
+    assert_eq!(
+        RuntimeSkin::new(
+            &asset,
+            "scarf",
+            [SkinAttachment::new(bow).with_target(hat_slot, "scarf")],
+        )
+        .unwrap_err(),
+        RuntimeSkinError::UnknownPlaceholder { slot: hat_slot }
+    );
+    let tail = attachment(&asset, "default", "tail-slot", "tail");
+    assert_eq!(
+        RuntimeSkin::new(
+            &asset,
+            "tail-hat",
+            [SkinAttachment::new(tail).with_target(hat_slot, "hat")],
+        )
+        .unwrap_err(),
+        RuntimeSkinError::IncompatibleSlot {
+            attachment: tail,
+            slot: hat_slot,
+        }
+    );
+    assert!(matches!(
+        RuntimeSkin::new(&other, "foreign", [SkinAttachment::new(bow)]),
+        Err(RuntimeSkinError::InvalidAttachment(_))
+    ));
+
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let foreign = RuntimeSkin::new(&other, "foreign", []).expect("empty skins are valid");
+    assert_eq!(
+        skeleton.insert_runtime_skin(foreign).unwrap_err(),
+        RuntimeSkinError::ForeignAsset
+    );
+
+    let red = asset.skin_id("hat/red").expect("the skin exists");
+    skeleton.set_skin_layers(&[red]).expect("local");
+    let mut elsewhere = Skeleton::new(Arc::clone(&asset));
+    let stranger = elsewhere
+        .insert_runtime_skin(RuntimeSkin::new(&asset, "stranger", []).expect("valid"))
+        .expect("same asset");
+    assert_eq!(
+        skeleton
+            .set_skin_layer_stack(&[SkinLayer::Authored(red), stranger.into()])
+            .unwrap_err(),
+        RuntimeSkinError::UnknownSkin
+    );
+    assert_eq!(
+        skeleton.skin_layer_stack().collect::<Vec<_>>(),
+        [SkinLayer::Authored(red)],
+        "a rejected stack changes nothing"
+    );
+    assert_eq!(shown(&asset, &skeleton, "hat-slot"), Some("red-hat"));
+}
@@ -0,0 +1,323 @@
+//! Public contract tests for name-based save states of mixers and skeletons.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    ANIMATION_SAVE_STATE_VERSION, AnimationMixer, AnimationSaveState, Mix, MixCurve, MixTable,
+    PlayOptions, SaveStateError, SaveStateMismatch, SavedTrack, Skeleton, SkeletonAsset,
+    SyncPolicy, TrackErrorKind, TrackOptions, WorldTransform, load_json,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"},{"name":"arm","parent":"root","x":10}],
+  "skins":[{"name":"party"},{"name":"armor"}],
+  "animations":{
+    "walk":{"bones":{"root":{"translate":[{"x":0,"y":0},{"time":1,"x":20,"y":0}]}}},
+    "wave":{"bones":{"arm":{"rotate":[{"value":0},{"time":0.8,"value":90}]}}}
+  }
+}"#;
+
+// A hot reload that renamed `wave` to `greet`, shortened `walk` and removed a
+// skin.
+const RELOADED: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"},{"name":"arm","parent":"root","x":10}],
+  "skins":[{"name":"party"}],
+  "animations":{
+    "walk":{"bones":{"root":{"translate":[{"x":0,"y":0},{"time":0.2,"x":20,"y":0}]}}},
+    "greet":{"bones":{"arm":{"rotate":[{"value":0},{"time":0.8,"value":90}]}}}
+  }
+}"#;
+
+fn asset(json: &[u8]) -> Arc<SkeletonAsset> {
+    load_json(json, b"page.png\n")
+        .expect("the save state fixture loads")
+        .into_asset()
+}
+
+fn millis(value: u64) -> Duration {
+    Duration::from_millis(value)
+}
+
+fn world(mixer: &mut AnimationMixer, skeleton: &mut Skeleton, delta: u64) -> Vec<WorldTransform> {
+    mixer
+        .update(skeleton, millis(delta), &mut ())
+        .expect("the update succeeds")
+        .solve()
+        .bones()
+        .map(|bone| bone.world_transform())
+        .collect()
+}
+
+fn playing_mixer(asset: &Arc<SkeletonAsset>, skeleton: &mut Skeleton) -> AnimationMixer {
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let wave = asset.animation_id("wave").expect("wave exists");
+    let armor = asset.skin_id("armor").expect("armor exists");
+    let party = asset.skin_id("party").expect("party exists");
+    let mut mixer = AnimationMixer::new(skeleton);
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    mixer
+        .base_track_mut()
+        .set_speed(1.5)
+        .expect("the speed is valid");
+    let idle = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    mixer
+        .track_mut(idle)
+        .expect("the track exists")
+        .set_paused(true);
+    let wave_track = mixer
+        .insert_track(TrackOptions::override_track().with_weight(Mix::new(0.5).expect("valid")))
+        .expect("the track is inserted");
+    mixer
+        .track_mut(wave_track)
+        .expect("the track exists")
+        .play(
+            wave,
+            PlayOptions::once().with_range(millis(100), millis(700)),
+        )
+        .expect("the range lies within wave");
+    skeleton
+        .set_skin_layers(&[armor, party])
+        .expect("the skins are local");
+    let _ = world(&mut mixer, skeleton, 1_000);
+    mixer
+}
+
+#[test]
+fn save_states_round_trip_through_serde_into_fresh_instances() {
+    let asset = asset(JSON);
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mut mixer = playing_mixer(&asset, &mut skeleton);
+
+    let state = mixer.save_state(&skeleton).expect("the skeleton is bound");
+    assert_eq!(state.version(), ANIMATION_SAVE_STATE_VERSION);
+    assert_eq!(state.skin_layers().collect::<Vec<_>>(), ["armor", "party"]);
+    assert_eq!(state.tracks().len(), 2);
+    let base = state.base().playback().expect("the base track plays");
+    assert_eq!(base.animation(), "walk");
+    assert_eq!(base.position(), millis(500));
+    assert_eq!(base.loop_index(), 1);
+    assert!(state.tracks()[0].playback().is_none());
+    assert!(state.tracks()[0].is_paused());
+    assert_eq!(state.tracks()[1].weight(), 0.5);
+    assert_eq!(
+        state.tracks()[1]
+            .playback()
+            .map(|playback| playback.range()),
+        Some(Some((millis(100), millis(700))))
+    );
+
+    let text = serde_json::to_string(&state).expect("the state serializes");
+    let decoded: AnimationSaveState = serde_json::from_str(&text).expect("the state parses");
+    assert_eq!(decoded, state);
+
+    // A fresh load of the same data, as after restarting the game.
+    let loaded = self::asset(JSON);
+    let mut restored_skeleton = Skeleton::new(Arc::clone(&loaded));
+    let mut restored = AnimationMixer::new(&restored_skeleton);
+    let tracks = restored
+        .restore_save_state(&mut restored_skeleton, &decoded)
+        .expect("the state matches the asset");
+    assert_eq!(restored.tracks().collect::<Vec<_>>(), tracks);
+    assert_eq!(
+        restored.save_state(&restored_skeleton),
+        Ok(state.clone()),
+        "a restored mixer saves the same state"
+    );
+
+    for delta in [0, 16, 250, 700] {
+        assert_eq!(
+            world(&mut restored, &mut restored_skeleton, delta),
+            world(&mut mixer, &mut skeleton, delta)
+        );
+    }
+}
+
+#[test]
+fn mismatched_states_report_every_problem_and_change_nothing() {
+    let asset = asset(JSON);
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mixer = playing_mixer(&asset, &mut skeleton);
+    let text = serde_json::to_string(&mixer.save_state(&skeleton).expect("the skeleton is bound"))
+        .expect("the state serializes");
+    let state: AnimationSaveState = serde_json::from_str(&text).expect("the state parses");
+
+    let reloaded = self::asset(RELOADED);
+    let mut reloaded_skeleton = Skeleton::new(Arc::clone(&reloaded));
+    let mut reloaded_mixer = AnimationMixer::new(&reloaded_skeleton);
+    let existing = reloaded_mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let error = reloaded_mixer
+        .restore_save_state(&mut reloaded_skeleton, &state)
+        .expect_err("the reload renamed and shortened animations");
+    assert_eq!(
+        error.mismatches(),
+        [
+            SaveStateMismatch::UnknownSkin("armor".into()),
+            SaveStateMismatch::OutOfRange {
+                track: SavedTrack::Base,
+                name: "walk".into(),
+            },
+            SaveStateMismatch::UnknownAnimation {
+                track: SavedTrack::Override(1),
+                name: "wave".into(),
+            },
+        ]
+    );
+    assert_eq!(
+        reloaded_mixer.tracks().collect::<Vec<_>>(),
+        [existing],
+        "a rejected state leaves the mixer untouched"
+    );
+    assert_eq!(reloaded_skeleton.skin_layers().count(), 0);
+    assert!(reloaded_mixer.base_track().status().is_idle());
+}
+
+#[test]
+fn restored_tracks_take_over_the_sync_groups_of_the_tracks_they_replace() {
+    let asset = asset(JSON);
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mut mixer = AnimationMixer::new(&skeleton);
+    let lead = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let group = mixer
+        .insert_sync_group(SyncPolicy::Leader(lead))
+        .expect("the group is created");
+    mixer
+        .set_sync_group(mixer.base_track_id(), Some(group))
+        .expect("the base track joins");
+    mixer
+        .set_sync_group(lead, Some(group))
+        .expect("the leader joins");
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    mixer
+        .track_mut(lead)
+        .expect("the track exists")
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    let _ = world(&mut mixer, &mut skeleton, 300);
+    let state = mixer.save_state(&skeleton).expect("the skeleton is bound");
+
+    let extra = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let orphaned = mixer
+        .insert_sync_group(SyncPolicy::Leader(extra))
+        .expect("the group is created");
+    let _ = world(&mut mixer, &mut skeleton, 400);
+
+    let ids = mixer
+        .restore_save_state(&mut skeleton, &state)
+        .expect("the state restores");
+    assert_eq!(ids.len(), 1);
+    assert_eq!(
+        mixer.track(lead).map(|_| ()).map_err(|error| error.kind()),
+        Err(TrackErrorKind::Removed)
+    );
+    assert_eq!(mixer.sync_policy(group), Ok(SyncPolicy::Leader(ids[0])));
+    assert_eq!(mixer.sync_group(ids[0]), Ok(Some(group)));
+    assert_eq!(mixer.sync_group(mixer.base_track_id()), Ok(Some(group)));
+    assert_eq!(
+        mixer.sync_policy(orphaned),
+        Ok(SyncPolicy::Weighted),
+        "a leader without a restored successor leaves its group weighted"
+    );
+
+    let _ = world(&mut mixer, &mut skeleton, 200);
+    assert_eq!(mixer.sync_phase(group), Ok(Some(0.5)));
+    assert_eq!(mixer.base_track().status().position(), Some(millis(500)));
+    assert_eq!(
+        mixer.track(ids[0]).expect("restored").status().position(),
+        Some(millis(500))
+    );
+}
+
+#[test]
+fn restored_tracks_keep_the_mix_tables_of_the_tracks_they_replace() {
+    let asset = asset(JSON);
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let wave = asset.animation_id("wave").expect("wave exists");
+    let table = Arc::new(
+        MixTable::from_names(&asset, millis(200), MixCurve::Linear, [])
+            .expect("the table has no pairs to resolve"),
+    );
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mut mixer = AnimationMixer::new(&skeleton);
+    let track = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let mut handle = mixer.track_mut(track).expect("the track exists");
+    handle
+        .set_mix_table(Some(Arc::clone(&table)))
+        .expect("the table belongs to the mixer asset");
+    handle
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    let _ = world(&mut mixer, &mut skeleton, 100);
+    let state = mixer.save_state(&skeleton).expect("the skeleton is bound");
+
+    let ids = mixer
+        .restore_save_state(&mut skeleton, &state)
+        .expect("the state restores");
+    assert_eq!(
+        mixer.track(ids[0]).expect("restored").mix_table(),
+        Some(&*table)
+    );
+    mixer
+        .track_mut(ids[0])
+        .expect("restored")
+        .play(wave, PlayOptions::looping())
+        .expect("wave is local");
+    let _ = world(&mut mixer, &mut skeleton, 100);
+    assert_eq!(
+        mixer
+            .track(ids[0])
+            .expect("restored")
+            .status()
+            .transition_mix()
+            .map(Mix::get),
+        Some(0.5),
+        "the restored track still crossfades through its table"
+    );
+}
+
+#[test]
+fn save_states_require_the_bound_skeleton_and_a_known_version() {
+    let asset = asset(JSON);
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mut other = Skeleton::new(Arc::clone(&asset));
+    let mut mixer = playing_mixer(&asset, &mut skeleton);
+
+    assert_eq!(
+        mixer.save_state(&other),
+        Err(SaveStateError::ForeignSkeleton)
+    );
+    let state = mixer.save_state(&skeleton).expect("the skeleton is bound");
+    assert_eq!(
+        mixer.restore_save_state(&mut other, &state),
+        Err(SaveStateError::ForeignSkeleton)
+    );
+
+    let text = serde_json::to_string(&state)
+        .expect("the state serializes")
+        .replace("\"version\":1", "\"version\":99");
+    let future: AnimationSaveState = serde_json::from_str(&text).expect("the state parses");
+    assert_eq!(
+        mixer.restore_save_state(&mut skeleton, &future),
+        Err(SaveStateError::Mismatch(
+            [SaveStateMismatch::UnsupportedVersion { found: 99 }].into()
+        ))
+    );
+}
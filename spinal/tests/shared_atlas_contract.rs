@@ -0,0 +1,225 @@
+//! Public contract tests for linking one skeleton against several atlases.
+
+use std::sync::Arc;
+
+use spinal::{
+    AtlasPageId, DiagnosticCode, DiagnosticScope, DiagnosticSeverity, LoadDocument, LoadErrorKind,
+    SkeletonAsset, TextAtlas, load_json_with_atlases, read_cache, write_cache,
+};
+
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"},{"name":"head","parent":"root","y":20}],
+  "slots":[
+    {"name":"body","bone":"root","attachment":"body"},
+    {"name":"hat","bone":"head","attachment":"hat"}
+  ],
+  "skins":[{
+    "name":"default",
+    "attachments":{
+      "body":{"body":{"width":8,"height":4}},
+      "hat":{"hat":{"path":"cosmetics/hat","width":4,"height":4}}
+    }
+  }]
+}"#;
+
+const TABBY: &[u8] = b"tabby.png
+\tsize: 16, 16
+body
+\tbounds: 0, 0, 8, 4
+";
+
+const CALICO: &[u8] = b"calico.png
+\tsize: 16, 16
+body
+\tbounds: 0, 4, 8, 4
+";
+
+const COSMETICS: &[u8] = b"cosmetics.png
+\tsize: 32, 32
+\tpma: true
+cosmetics/hat
+\tbounds: 0, 0, 4, 4
+cosmetics/collar
+\tbounds: 4, 0, 8, 2
+";
+
+fn atlas(text: &[u8]) -> Arc<TextAtlas> {
+    Arc::new(TextAtlas::parse(text).expect("the atlas parses"))
+}
+
+fn page_id(asset: &SkeletonAsset, name: &str) -> AtlasPageId {
+    asset.atlas_page_id(name).expect("the page is linked")
+}
+
+/// Returns the page name, linked atlas position and region bounds drawn by a
+/// slot's setup attachment.
+fn drawn_from(asset: &SkeletonAsset, slot: &str) -> (String, usize, [u32; 2]) {
+    let slot = asset.slot_id(slot).expect("the slot exists");
+    let setup = asset
+        .slot(slot)
+        .expect("local")
+        .setup_attachment_name()
+        .expect("the slot shows an attachment");
+    let attachment = asset
+        .default_skin()
+        .expect("a default skin")
+        .attachment(slot, setup)
+        .expect("local slot")
+        .expect("the default skin has the attachment");
+    let region = asset
+        .attachment(attachment)
+        .expect("local")
+        .as_region()
+        .expect("a region attachment")
+        .atlas_region();
+    let region = asset.atlas_region(region).expect("local");
+    let page = asset.atlas_page(region.page()).expect("local");
+    (
+        page.name().to_owned(),
+        page.atlas(),
+        [region.bounds().x(), region.bounds().y()],
+    )
+}
+
+#[test]
+fn skeletons_share_one_cosmetics_atlas_linked_after_their_own() {
+    let cosmetics = atlas(COSMETICS);
+    let tabby = load_json_with_atlases(CAT, &[atlas(TABBY), Arc::clone(&cosmetics)])
+        .expect("the tabby links")
+        .into_asset();
+    let calico = load_json_with_atlases(CAT, &[atlas(CALICO), Arc::clone(&cosmetics)])
+        .expect("the calico links")
+        .into_asset();
+
+    for asset in [&tabby, &calico] {
+        assert!(Arc::ptr_eq(&asset.atlases()[1], &cosmetics));
+        assert_eq!(
+            drawn_from(asset, "hat"),
+            ("cosmetics.png".to_owned(), 1, [0, 0])
+        );
+        let pages = asset
+            .atlas_pages()
+            .map(|page| (page.ordinal(), page.atlas(), page.name().to_owned()))
+            .collect::<Vec<_>>();
+        assert_eq!(pages[1], (1, 1, "cosmetics.png".to_owned()));
+        let collar = asset
+            .atlas_regions_named("cosmetics/collar")
+            .next()
+            .expect("shared regions are listed");
+        assert_eq!(collar.ordinal(), 2);
+        assert_eq!(collar.page(), page_id(asset, "cosmetics.png"));
+
+        let cosmetics_page = page_id(asset, "cosmetics.png");
+        assert_eq!(
+            asset
+                .diagnostics()
+                .iter()
+                .map(|diagnostic| (diagnostic.code(), diagnostic.scope()))
+                .collect::<Vec<_>>(),
+            [(
+                DiagnosticCode::AlphaEncodingMismatch,
+                DiagnosticScope::AtlasPage(cosmetics_page)
+            )],
+            "the shared atlas's issues are reported against each skeleton"
+        );
+    }
+    assert_eq!(
+        drawn_from(&tabby, "body"),
+        ("tabby.png".to_owned(), 0, [0, 0])
+    );
+    assert_eq!(
+        drawn_from(&calico, "body"),
+        ("calico.png".to_owned(), 0, [0, 4])
+    );
+    assert_eq!(Arc::strong_count(&cosmetics), 3);
+}
+
+#[test]
+fn earlier_atlases_win_and_shadowed_regions_are_reported() {
+    let own = b"tabby.png
+\tsize: 16, 16
+body
+\tbounds: 0, 0, 8, 4
+cosmetics/hat
+\tbounds: 8, 0, 4, 4
+";
+    let report = load_json_with_atlases(CAT, &[atlas(own), atlas(COSMETICS)])
+        .expect("a shadowed region still links");
+    let asset = report.asset();
+
+    assert_eq!(
+        drawn_from(asset, "hat"),
+        ("tabby.png".to_owned(), 0, [8, 0])
+    );
+    let shadowed = report
+        .diagnostics()
+        .iter()
+        .filter(|diagnostic| diagnostic.code() == DiagnosticCode::ShadowedAtlasRegion)
+        .collect::<Vec<_>>();
+    assert_eq!(shadowed.len(), 1);
+    assert_eq!(shadowed[0].severity(), DiagnosticSeverity::Warning);
+    let DiagnosticScope::Attachment(attachment) = shadowed[0].scope() else {
+        panic!("the warning names the attachment");
+    };
+    assert_eq!(asset.attachment(attachment).expect("local").name(), "hat");
+    assert!(shadowed[0].message().contains("atlas 1"));
+
+    let reversed = load_json_with_atlases(CAT, &[atlas(COSMETICS), atlas(own)])
+        .expect("the reversed order links");
+    assert_eq!(
+        drawn_from(reversed.asset(), "hat"),
+        ("cosmetics.png".to_owned(), 0, [0, 0]),
+        "resolution follows link order"
+    );
+}
+
+#[test]
+fn unresolved_and_ambiguous_regions_fail_across_atlases() {
+    let error = load_json_with_atlases(CAT, &[atlas(TABBY)]).expect_err("no atlas has the hat");
+    assert_eq!(error.kind(), LoadErrorKind::MissingAtlasRegion);
+    assert_eq!(error.location().document(), LoadDocument::SkeletonJson);
+
+    let sequence = b"sequence.png
+cosmetics/hat
+\tindex: 0
+\tbounds: 0, 0, 4, 4
+cosmetics/hat
+\tindex: 1
+\tbounds: 4, 0, 4, 4
+";
+    let error = load_json_with_atlases(CAT, &[atlas(TABBY), atlas(sequence), atlas(COSMETICS)])
+        .expect_err("the first atlas with the name has two such regions");
+    assert_eq!(error.kind(), LoadErrorKind::AmbiguousAtlasRegion);
+}
+
+#[test]
+fn cached_assets_keep_their_atlas_boundaries() {
+    let cosmetics = atlas(COSMETICS);
+    let loaded = load_json_with_atlases(CAT, &[atlas(TABBY), cosmetics])
+        .expect("the tabby links")
+        .into_asset();
+    let cached = read_cache(&write_cache(&loaded)).expect("the cache reads back");
+    let cached = cached.asset();
+
+    assert_eq!(cached.atlases().len(), 2);
+    assert_eq!(drawn_from(cached, "hat"), drawn_from(&loaded, "hat"));
+    assert_eq!(drawn_from(cached, "body"), drawn_from(&loaded, "body"));
+    assert_eq!(cached.diagnostics().len(), loaded.diagnostics().len());
+
+    let relinked = load_json_with_atlases(CAT, &[Arc::clone(&cached.atlases()[1])])
+        .expect_err("the tabby atlas is missing");
+    assert_eq!(relinked.kind(), LoadErrorKind::MissingAtlasRegion);
+    let relinked = load_json_with_atlases(
+        CAT,
+        &[
+            Arc::clone(&cached.atlases()[0]),
+            Arc::clone(&cached.atlases()[1]),
+        ],
+    )
+    .expect("a cached atlas links like a parsed one");
+    assert_eq!(
+        relinked.diagnostics()[0].code(),
+        DiagnosticCode::AlphaEncodingMismatch
+    );
+}
@@ -0,0 +1,267 @@
+//! Public contract tests for building skeletons without JSON.
+
+use std::sync::Arc;
+
+use spinal::{
+    AlphaEncoding, AtlasPageDefinition, AtlasRegionDefinition, BoneDefinition, DiagnosticCode,
+    DrawItemRef, IkConstraintDefinition, LoadDocument, LoadErrorKind, MeshDefinition, PixelRect,
+    PixelSize, RegionDefinition, Rgba8, Skeleton, SkeletonAsset, SkeletonBuilder, SkinDefinition,
+    SlotDefinition, TransformConstraintDefinition, TransformMix, glam::Vec2, load_json,
+};
+
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"body","parent":"root","x":5,"y":2,"rotation":30,"length":12},
+    {"name":"tail","parent":"body","x":-6,"scaleX":1.5,"shearY":10},
+    {"name":"paw-target","parent":"root","x":4,"y":-9},
+    {"name":"leg","parent":"body","x":8,"rotation":-90,"length":9},
+    {"name":"head","parent":"root","y":8}
+  ],
+  "slots":[
+    {"name":"tail-slot","bone":"tail","attachment":"tail","color":"FFCC88FF"},
+    {"name":"body-slot","bone":"body","attachment":"body","blend":"additive"}
+  ],
+  "skins":[{
+    "name":"default",
+    "attachments":{
+      "body-slot":{"body":{"path":"fur","x":1,"rotation":15,"width":8,"height":4}},
+      "tail-slot":{"tail":{
+        "type":"mesh",
+        "uvs":[0,0,1,0,1,1],
+        "triangles":[0,1,2],
+        "vertices":[1,1,0,0,1,2,1,4,0,0.5,2,0,0,0.5,1,2,4,4,1],
+        "hull":3
+      }}
+    }
+  }],
+  "constraints":[
+    {"type":"ik","name":"paw-ik","bones":["leg"],"target":"paw-target","mix":0.75},
+    {
+      "type":"transform",
+      "name":"look",
+      "bones":["head"],
+      "source":"body",
+      "properties":{"rotate":{"to":{"rotate":{}}}},
+      "rotation":5,
+      "mixRotate":0.5
+    }
+  ]
+}"#;
+
+const ATLAS: &[u8] = b"cat.png
+\tsize: 32, 16
+\tpma: true
+fur
+\tbounds: 0, 0, 8, 4
+tail
+\tbounds: 8, 0, 4, 4
+\trotate: true
+";
+
+fn built() -> SkeletonBuilder {
+    SkeletonBuilder::new()
+        .with_bone(BoneDefinition::new("root"))
+        .with_bone(
+            BoneDefinition::new("body")
+                .with_parent("root")
+                .with_translation(Vec2::new(5.0, 2.0))
+                .with_rotation(30.0)
+                .with_length(12.0),
+        )
+        .with_bone(
+            BoneDefinition::new("tail")
+                .with_parent("body")
+                .with_translation(Vec2::new(-6.0, 0.0))
+                .with_scale(Vec2::new(1.5, 1.0))
+                .with_shear(Vec2::new(0.0, 10.0)),
+        )
+        .with_bone(
+            BoneDefinition::new("paw-target")
+                .with_parent("root")
+                .with_translation(Vec2::new(4.0, -9.0)),
+        )
+        .with_bone(
+            BoneDefinition::new("leg")
+                .with_parent("body")
+                .with_translation(Vec2::new(8.0, 0.0))
+                .with_rotation(-90.0)
+                .with_length(9.0),
+        )
+        .with_bone(
+            BoneDefinition::new("head")
+                .with_parent("root")
+                .with_translation(Vec2::new(0.0, 8.0)),
+        )
+        .with_slot(
+            SlotDefinition::new("tail-slot", "tail")
+                .with_attachment("tail")
+                .with_color(Rgba8::new(0xFF, 0xCC, 0x88, 0xFF)),
+        )
+        .with_slot(
+            SlotDefinition::new("body-slot", "body")
+                .with_attachment("body")
+                .with_blend_mode(spinal::SlotBlendMode::Additive),
+        )
+        .with_skin(
+            SkinDefinition::new("default")
+                .with_attachment(
+                    "body-slot",
+                    "body",
+                    RegionDefinition::new(Vec2::new(8.0, 4.0))
+                        .with_path("fur")
+                        .with_translation(Vec2::new(1.0, 0.0))
+                        .with_rotation(15.0),
+                )
+                .with_attachment(
+                    "tail-slot",
+                    "tail",
+                    MeshDefinition::weighted(
+                        &[Vec2::ZERO, Vec2::X, Vec2::ONE],
+                        &[0, 1, 2],
+                        &[
+                            &[("body", Vec2::ZERO, 1.0)],
+                            &[
+                                ("body", Vec2::new(4.0, 0.0), 0.5),
+                                ("tail", Vec2::ZERO, 0.5),
+                            ],
+                            &[("tail", Vec2::new(4.0, 4.0), 1.0)],
+                        ],
+                        3,
+                    ),
+                ),
+        )
+        .with_ik_constraint(
+            IkConstraintDefinition::new("paw-ik", &["leg"], "paw-target")
+                .with_mix(spinal::Mix::new(0.75).expect("normalized")),
+        )
+        .with_transform_constraint(
+            TransformConstraintDefinition::new("look", &["head"], "body")
+                .with_rotation_offset(5.0)
+                .with_rotate_mix(TransformMix::new(0.5).expect("finite")),
+        )
+        .with_atlas_page(
+            AtlasPageDefinition::new("cat.png", PixelSize::new(32, 16))
+                .with_alpha_encoding(AlphaEncoding::Premultiplied)
+                .with_region(AtlasRegionDefinition::new(
+                    "fur",
+                    PixelRect::new(0, 0, 8, 4),
+                ))
+                .with_region(
+                    AtlasRegionDefinition::new("tail", PixelRect::new(8, 0, 4, 4)).rotated(),
+                ),
+        )
+}
+
+fn frame(asset: &Arc<SkeletonAsset>) -> (Vec<Vec2>, Vec<Vec2>) {
+    let mut skeleton = Skeleton::new(Arc::clone(asset));
+    let frame = skeleton.editable_pose().solve();
+    let bones = asset
+        .bones()
+        .map(|bone| {
+            frame
+                .bone(bone.id())
+                .expect("local")
+                .world_transform()
+                .translation()
+        })
+        .collect();
+    let draws = frame
+        .draw_items()
+        .flat_map(|item| match item {
+            DrawItemRef::Region(region) => region.positions().to_vec(),
+            DrawItemRef::Mesh(mesh) => mesh.positions().to_vec(),
+            _ => unreachable!("the fixture draws regions and meshes"),
+        })
+        .collect();
+    (bones, draws)
+}
+
+#[test]
+fn built_skeletons_match_the_equivalent_json() {
+    let authored = load_json(CAT, ATLAS).expect("the fixture loads");
+    let built = built().build().expect("the definitions are valid");
+
+    let codes = |diagnostics: &[spinal::Diagnostic]| {
+        diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.code(), diagnostic.message().to_owned()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(codes(built.diagnostics()), codes(authored.diagnostics()));
+    assert!(
+        built
+            .diagnostics()
+            .iter()
+            .any(|diagnostic| diagnostic.code() == DiagnosticCode::AlphaEncodingMismatch)
+    );
+
+    let (built, authored) = (built.asset(), authored.asset());
+    assert_eq!(
+        built.bones().map(|bone| bone.name()).collect::<Vec<_>>(),
+        authored.bones().map(|bone| bone.name()).collect::<Vec<_>>()
+    );
+    assert_eq!(built.slots().len(), authored.slots().len());
+    assert_eq!(built.constraints().len(), authored.constraints().len());
+    assert_eq!(frame(built), frame(authored));
+}
+
+#[test]
+fn invalid_definitions_fail_as_the_loader_would() {
+    let orphan = SkeletonBuilder::new()
+        .with_bone(BoneDefinition::new("root"))
+        .with_bone(BoneDefinition::new("paw").with_parent("leg"))
+        .with_bone(BoneDefinition::new("leg").with_parent("root"))
+        .with_atlas_page(AtlasPageDefinition::new("cat.png", PixelSize::new(8, 8)));
+    let error = orphan.build().expect_err("the parent comes later");
+    assert_eq!(error.kind(), LoadErrorKind::InvalidTopology);
+    assert_eq!(error.path(), Some("/bones/1/parent"));
+
+    let twice = built().with_bone(BoneDefinition::new("head").with_parent("root"));
+    assert_eq!(
+        twice.build().expect_err("two heads").kind(),
+        LoadErrorKind::DuplicateName
+    );
+
+    let cyclic =
+        built().with_ik_constraint(IkConstraintDefinition::new("reach", &["body"], "tail"));
+    assert_eq!(
+        cyclic
+            .build()
+            .expect_err("the target follows the chain")
+            .kind(),
+        LoadErrorKind::InvalidTopology
+    );
+
+    let ghost = built().with_skin(SkinDefinition::new("ghost").with_attachment(
+        "tail-slot",
+        "tail",
+        MeshDefinition::weighted(
+            &[Vec2::ZERO, Vec2::X, Vec2::ONE],
+            &[0, 1, 2],
+            &[
+                &[("root", Vec2::ZERO, 1.0)],
+                &[("whisker", Vec2::ZERO, 1.0)],
+                &[("root", Vec2::ZERO, 1.0)],
+            ],
+            3,
+        ),
+    ));
+    let error = ghost.build().expect_err("no such bone");
+    assert_eq!(error.kind(), LoadErrorKind::UnresolvedReference);
+    assert_eq!(
+        error.path(),
+        Some("/skins/1/attachments/tail-slot/tail/vertices/6")
+    );
+
+    let spilled = built().with_atlas_page(
+        AtlasPageDefinition::new("hats.png", PixelSize::new(8, 8)).with_region(
+            AtlasRegionDefinition::new("hat", PixelRect::new(4, 4, 8, 8)),
+        ),
+    );
+    let error = spilled.build().expect_err("the hat leaves its page");
+    assert_eq!(error.kind(), LoadErrorKind::SchemaViolation);
+    assert_eq!(error.location().document(), LoadDocument::Atlas);
+    assert_eq!(error.path(), Some("/regions/2/bounds"));
+}
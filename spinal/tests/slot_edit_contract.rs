@@ -0,0 +1,183 @@
+//! Public contract tests for procedural slot edits.
+
+use std::sync::Arc;
+
+use spinal::{
+    AttachmentId, DrawItemRef, Rgba, Skeleton, SkeletonAsset, SlotEditError, SlotId, load_json,
+};
+
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"head","parent":"root","y":20},
+    {"name":"paw","parent":"root","x":10}
+  ],
+  "slots":[
+    {"name":"paw-slot","bone":"paw","attachment":"paw"},
+    {"name":"bowl-slot","bone":"root","attachment":"bowl"},
+    {"name":"hat-slot","bone":"head","attachment":"hat"},
+    {"name":"eyes-slot","bone":"head","attachment":"open"}
+  ],
+  "skins":[{
+    "name":"default",
+    "attachments":{
+      "paw-slot":{"paw":{
+        "type":"mesh",
+        "uvs":[0,0,1,0,1,1],
+        "triangles":[0,1,2],
+        "vertices":[0,0,4,0,4,4],
+        "hull":3
+      }},
+      "bowl-slot":{"bowl":{"width":8,"height":4}},
+      "hat-slot":{"hat":{"width":8,"height":8}},
+      "eyes-slot":{
+        "open":{"width":4,"height":2},
+        "closed":{"width":4,"height":1}
+      }
+    }
+  }]
+}"#;
+
+const ATLAS: &[u8] = b"cat.png
+\tsize: 64, 16
+paw
+\tbounds: 0, 0, 4, 4
+bowl
+\tbounds: 4, 0, 8, 4
+hat
+\tbounds: 12, 0, 8, 8
+open
+\tbounds: 20, 0, 4, 2
+closed
+\tbounds: 24, 0, 4, 1
+";
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, ATLAS)
+        .expect("the slot edit fixture loads")
+        .into_asset()
+}
+
+fn slot(asset: &SkeletonAsset, name: &str) -> SlotId {
+    asset.slot_id(name).expect("the slot exists")
+}
+
+fn attachment(asset: &SkeletonAsset, slot_name: &str, name: &str) -> AttachmentId {
+    asset
+        .default_skin()
+        .expect("the default skin exists")
+        .attachment(slot(asset, slot_name), name)
+        .expect("local")
+        .expect("the attachment exists")
+}
+
+fn drawn_slots(skeleton: &mut Skeleton) -> Vec<SlotId> {
+    let frame = skeleton.editable_pose().solve();
+    frame
+        .draw_items()
+        .map(|item| match item {
+            DrawItemRef::Region(region) => region.slot(),
+            DrawItemRef::Mesh(mesh) => mesh.slot(),
+            _ => unreachable!("the fixture draws regions and meshes"),
+        })
+        .collect()
+}
+
+#[test]
+fn slot_edits_reach_the_draw_stream() {
+    let asset = asset();
+    let [paw, bowl, hat, eyes] =
+        ["paw-slot", "bowl-slot", "hat-slot", "eyes-slot"].map(|name| slot(&asset, name));
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let flash = Rgba::new(1.0, 0.2, 0.2, 1.0).expect("normalized");
+
+    let mut pose = skeleton.editable_pose();
+    let mut editor = pose.edit();
+    editor.set_slot_color(bowl, flash).expect("local");
+    editor.set_slot_alpha(bowl, 0.5).expect("normalized");
+    editor.set_slot_attachment(hat, None).expect("local");
+    editor
+        .set_slot_attachment(eyes, Some("closed"))
+        .expect("the placeholder exists");
+    editor.draw_slot_in_front_of(paw, bowl).expect("local");
+    assert_eq!(
+        editor.slot_color(bowl),
+        Ok(Rgba::new(1.0, 0.2, 0.2, 0.5).expect("normalized"))
+    );
+    assert_eq!(
+        editor.slot_attachment(eyes),
+        Ok(Some(attachment(&asset, "eyes-slot", "closed")))
+    );
+    let frame = pose.solve();
+    let items = frame.draw_items().collect::<Vec<_>>();
+    assert_eq!(items.len(), 3, "the hat is hidden");
+    let DrawItemRef::Region(bowl_item) = items[0] else {
+        panic!("the bowl is drawn behind the paw");
+    };
+    assert_eq!(bowl_item.slot(), bowl);
+    assert_eq!(bowl_item.color().to_array(), [1.0, 0.2, 0.2, 0.5]);
+    assert!(matches!(items[1], DrawItemRef::Mesh(mesh) if mesh.slot() == paw));
+    let DrawItemRef::Region(eyes_item) = items[2] else {
+        panic!("the eyes are drawn last");
+    };
+    assert_eq!(
+        eyes_item.attachment(),
+        attachment(&asset, "eyes-slot", "closed")
+    );
+
+    let mut pose = skeleton.editable_pose();
+    pose.edit().draw_slot_behind(eyes, paw).expect("local");
+    pose.edit().draw_slot_behind(paw, paw).expect("a no-op");
+    drop(pose.solve());
+    assert_eq!(drawn_slots(&mut skeleton), [bowl, eyes, paw]);
+}
+
+#[test]
+fn attachments_can_be_shown_by_id() {
+    let asset = asset();
+    let [paw, bowl, eyes] = ["paw-slot", "bowl-slot", "eyes-slot"].map(|name| slot(&asset, name));
+    let hat = attachment(&asset, "hat-slot", "hat");
+    let paw_mesh = attachment(&asset, "paw-slot", "paw");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let mut pose = skeleton.editable_pose();
+    let mut editor = pose.edit();
+
+    editor
+        .set_slot_attachment_id(bowl, Some(hat))
+        .expect("regions draw on any slot");
+    assert_eq!(editor.slot_attachment(bowl), Ok(Some(hat)));
+    assert_eq!(
+        editor.set_slot_attachment_id(eyes, Some(paw_mesh)),
+        Err(SlotEditError::IncompatibleAttachment {
+            attachment: paw_mesh,
+            slot: eyes,
+        })
+    );
+    assert_eq!(
+        editor.set_slot_attachment(paw, Some("bowl")),
+        Err(SlotEditError::UnknownPlaceholder)
+    );
+    assert!(matches!(
+        editor.set_slot_alpha(paw, 1.5),
+        Err(SlotEditError::InvalidColor(_))
+    ));
+    let other = self::asset();
+    assert!(matches!(
+        editor.set_slot_attachment_id(paw, Some(attachment(&other, "hat-slot", "hat"))),
+        Err(SlotEditError::InvalidAttachment(_))
+    ));
+    assert!(
+        editor
+            .draw_slot_in_front_of(paw, slot(&other, "bowl-slot"))
+            .is_err()
+    );
+    editor.set_slot_attachment_id(paw, None).expect("local");
+    let frame = pose.solve();
+    let DrawItemRef::Region(bowl_item) = frame.draw_items().next().expect("the bowl draws") else {
+        panic!("the hat region draws in the bowl slot");
+    };
+    assert_eq!(bowl_item.slot(), bowl);
+    assert_eq!(bowl_item.attachment(), hat);
+    assert_eq!(frame.draw_items().count(), 3, "the paw is hidden");
+}
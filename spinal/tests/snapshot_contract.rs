@@ -0,0 +1,251 @@
+//! Public contract tests for rollback snapshots of animators and skeletons.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationEvent, AnimationMixer, AnimationPlayer, AttachmentId, Crossfade, Mix, PlayOptions,
+    Skeleton, SkeletonAsset, SnapshotError, TrackAnimationEvent, TrackOptions, Transition,
+    WeightFade, WorldTransform, load_json,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"},{"name":"arm","parent":"root","x":10}],
+  "slots":[{"name":"hat-slot","bone":"arm","attachment":"hat"}],
+  "skins":[{
+    "name":"party",
+    "attachments":{"hat-slot":{"hat":{"path":"hat","width":8,"height":8}}}
+  }],
+  "events":{"step":{},"wave":{}},
+  "animations":{
+    "walk":{
+      "bones":{"root":{"translate":[{"x":0,"y":0},{"time":0.5,"x":20,"y":0}]}},
+      "events":[{"time":0.25,"name":"step"}]
+    },
+    "run":{
+      "bones":{"root":{"translate":[{"x":0,"y":0},{"time":0.3,"x":40,"y":10}]}},
+      "events":[{"time":0.1,"name":"step"}]
+    },
+    "wave":{
+      "bones":{"arm":{"rotate":[{"value":0},{"time":0.4,"value":90}]}},
+      "events":[{"time":0.2,"name":"wave"}]
+    }
+  }
+}"#;
+
+type Frame = (
+    Vec<(String, Duration, u128)>,
+    Vec<WorldTransform>,
+    Option<AttachmentId>,
+);
+
+fn fixture() -> (Arc<SkeletonAsset>, Skeleton) {
+    let asset = load_json(JSON, b"page.png\n\tsize: 8, 8\nhat\n\tbounds: 0, 0, 8, 8\n")
+        .expect("the snapshot fixture loads")
+        .into_asset();
+    let skeleton = Skeleton::new(Arc::clone(&asset));
+    (asset, skeleton)
+}
+
+fn mixer_frame(mixer: &mut AnimationMixer, skeleton: &mut Skeleton, millis: u64) -> Frame {
+    let hat_slot = skeleton
+        .asset()
+        .slot_id("hat-slot")
+        .expect("the slot exists");
+    let mut events = Vec::new();
+    let frame = mixer
+        .update(
+            skeleton,
+            Duration::from_millis(millis),
+            &mut |event: TrackAnimationEvent<'_>| {
+                let event = event.event();
+                events.push((
+                    event.definition().name().to_owned(),
+                    event.local_time(),
+                    event.loop_index(),
+                ));
+            },
+        )
+        .expect("the update succeeds")
+        .solve();
+    let attachment = frame
+        .slot(hat_slot)
+        .expect("the slot is local")
+        .attachment();
+    let world = frame.bones().map(|bone| bone.world_transform()).collect();
+    (events, world, attachment)
+}
+
+fn player_frame(player: &mut AnimationPlayer, skeleton: &mut Skeleton, millis: u64) -> Frame {
+    let mut events = Vec::new();
+    let frame = player
+        .update(
+            skeleton,
+            Duration::from_millis(millis),
+            &mut |event: AnimationEvent<'_>| {
+                events.push((
+                    event.definition().name().to_owned(),
+                    event.local_time(),
+                    event.loop_index(),
+                ));
+            },
+        )
+        .expect("the update succeeds")
+        .solve();
+    let world = frame.bones().map(|bone| bone.world_transform()).collect();
+    (events, world, None)
+}
+
+#[test]
+fn restored_mixers_resimulate_identical_frames_and_events() {
+    let (asset, mut skeleton) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let run = asset.animation_id("run").expect("run exists");
+    let wave = asset.animation_id("wave").expect("wave exists");
+    let party = asset.skin_id("party").expect("the skin exists");
+    let mut mixer = AnimationMixer::new(&skeleton);
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    let track = mixer
+        .insert_track(TrackOptions::override_track().with_weight(Mix::ZERO))
+        .expect("the track is inserted");
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .play(wave, PlayOptions::looping())
+        .expect("wave is local");
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .fade_weight(Mix::ONE, WeightFade::new(Duration::from_millis(400)));
+    let _ = mixer_frame(&mut mixer, &mut skeleton, 120);
+    mixer
+        .base_track_mut()
+        .play(
+            run,
+            PlayOptions::looping().with_transition(Transition::Crossfade(Crossfade::new(
+                Duration::from_millis(300),
+            ))),
+        )
+        .expect("run is local");
+    mixer
+        .base_track_mut()
+        .set_speed(1.5)
+        .expect("the speed is valid");
+
+    let mixer_snapshot = mixer.snapshot();
+    let skeleton_snapshot = skeleton.snapshot();
+    let deltas = [16, 33, 150, 0, 275];
+    let mut original: Vec<Frame> = Vec::new();
+    for (index, delta) in deltas.into_iter().enumerate() {
+        if index == 2 {
+            skeleton
+                .set_skin_layers(&[party])
+                .expect("the skin is local");
+        }
+        original.push(mixer_frame(&mut mixer, &mut skeleton, delta));
+    }
+    // Diverge further before rolling back, including a new track.
+    let extra = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let _ = mixer_frame(&mut mixer, &mut skeleton, 500);
+
+    mixer
+        .restore(&mixer_snapshot)
+        .expect("the snapshot is this mixer's");
+    skeleton
+        .restore(&skeleton_snapshot)
+        .expect("the snapshot is this skeleton's");
+    assert_eq!(mixer.tracks().collect::<Vec<_>>(), [track]);
+    assert!(mixer.track(extra).is_err());
+    assert_eq!(skeleton.skin_layers().count(), 0);
+
+    let mut replayed = Vec::new();
+    for (index, delta) in deltas.into_iter().enumerate() {
+        if index == 2 {
+            skeleton
+                .set_skin_layers(&[party])
+                .expect("the skin is local");
+        }
+        replayed.push(mixer_frame(&mut mixer, &mut skeleton, delta));
+    }
+    assert_eq!(replayed, original);
+    assert!(
+        original.iter().any(|(events, _, _)| !events.is_empty()),
+        "the rollback window emits events"
+    );
+}
+
+#[test]
+fn player_snapshots_reuse_their_storage_and_restore_crossfades() {
+    let (asset, mut skeleton) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let run = asset.animation_id("run").expect("run exists");
+    let mut player = AnimationPlayer::new(&skeleton);
+    player
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    let _ = player_frame(&mut player, &mut skeleton, 200);
+    let mut player_snapshot = player.snapshot();
+    let mut skeleton_snapshot = skeleton.snapshot();
+
+    player
+        .play(
+            run,
+            PlayOptions::once().with_transition(Transition::Crossfade(Crossfade::new(
+                Duration::from_millis(200),
+            ))),
+        )
+        .expect("run is local");
+    let _ = player_frame(&mut player, &mut skeleton, 50);
+    player.snapshot_into(&mut player_snapshot);
+    skeleton.snapshot_into(&mut skeleton_snapshot);
+    let status = player.status();
+
+    let original: Vec<_> = [40, 80, 300]
+        .into_iter()
+        .map(|delta| player_frame(&mut player, &mut skeleton, delta))
+        .collect();
+    player.stop(Transition::Immediate);
+    player
+        .restore(&player_snapshot)
+        .expect("the snapshot is this player's");
+    skeleton
+        .restore(&skeleton_snapshot)
+        .expect("the snapshot is this skeleton's");
+    assert_eq!(player.status(), status);
+    let replayed: Vec<_> = [40, 80, 300]
+        .into_iter()
+        .map(|delta| player_frame(&mut player, &mut skeleton, delta))
+        .collect();
+    assert_eq!(replayed, original);
+}
+
+#[test]
+fn snapshots_restore_only_into_compatible_owners() {
+    let (asset, skeleton) = fixture();
+    let (_other_asset, other_asset_skeleton) = fixture();
+    let mut sibling = Skeleton::new(Arc::clone(&asset));
+    let mut player = AnimationPlayer::new(&skeleton);
+    let mut mixer = AnimationMixer::new(&skeleton);
+    let sibling_player = AnimationPlayer::new(&sibling);
+    let other_mixer = AnimationMixer::new(&skeleton);
+
+    assert_eq!(
+        player.restore(&sibling_player.snapshot()),
+        Err(SnapshotError::ForeignSkeleton)
+    );
+    assert_eq!(
+        mixer.restore(&other_mixer.snapshot()),
+        Err(SnapshotError::ForeignMixer)
+    );
+    assert_eq!(
+        sibling.restore(&other_asset_skeleton.snapshot()),
+        Err(SnapshotError::ForeignAsset)
+    );
+    // Skeleton state moves between instances of one asset.
+    assert_eq!(sibling.restore(&skeleton.snapshot()), Ok(()));
+}
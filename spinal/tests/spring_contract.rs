@@ -0,0 +1,227 @@
+//! Public contract tests for spring-chain secondary motion.
+
+use std::{num::NonZeroU32, sync::Arc, time::Duration};
+
+use glam::Vec2;
+use spinal::{
+    Angle, BoneTransform, ConstraintPosition, RuntimeConstraintId, Skeleton, SkeletonAsset,
+    SpringChain, SpringChainError, SpringOptions, load_json,
+};
+
+// A cat whose two-bone tail hangs straight down from its body.
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"body","parent":"root","y":20,"length":20},
+    {"name":"tail","parent":"body","rotation":-90,"length":10},
+    {"name":"tail-tip","parent":"tail","x":10,"length":10},
+    {"name":"ear","parent":"body","x":20,"length":4}
+  ]
+}"#;
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, b"page.png\n")
+        .expect("the spring fixture loads")
+        .into_asset()
+}
+
+fn tail(asset: &SkeletonAsset) -> [spinal::BoneId; 2] {
+    ["tail", "tail-tip"].map(|name| asset.bone_id(name).expect("the bone exists"))
+}
+
+fn rigged(options: SpringOptions) -> (Skeleton, RuntimeConstraintId) {
+    let asset = asset();
+    let chain = SpringChain::new(&asset, tail(&asset), options).expect("the tail is a chain");
+    let mut skeleton = Skeleton::new(asset);
+    let id = skeleton
+        .insert_runtime_constraint(ConstraintPosition::First, chain)
+        .expect("first is always valid");
+    (skeleton, id)
+}
+
+/// Solves after `elapsed` and returns the tail tip's world direction.
+fn step(skeleton: &mut Skeleton, elapsed: Duration) -> Vec2 {
+    let tip = tail(skeleton.asset())[1];
+    skeleton
+        .editable_pose()
+        .with_elapsed(elapsed)
+        .solve()
+        .bone(tip)
+        .expect("local")
+        .world_transform()
+        .x_axis()
+}
+
+fn chain(skeleton: &mut Skeleton, id: RuntimeConstraintId) -> &mut SpringChain {
+    skeleton
+        .runtime_constraint_mut::<SpringChain>(id)
+        .expect("the spring chain is registered")
+}
+
+#[test]
+fn tips_trail_skeleton_motion_and_settle_on_the_animation() {
+    let (mut skeleton, id) = rigged(SpringOptions::new());
+    assert!(step(&mut skeleton, Duration::ZERO).abs_diff_eq(Vec2::NEG_Y, 1.0e-5));
+
+    chain(&mut skeleton, id).move_skeleton(Vec2::new(5.0, 0.0));
+    let trailing = step(&mut skeleton, Duration::from_millis(16));
+    assert!(trailing.x < -0.01, "the tail lags behind: {trailing}");
+
+    for _ in 0..300 {
+        step(&mut skeleton, Duration::from_millis(16));
+    }
+    assert!(step(&mut skeleton, Duration::from_millis(16)).abs_diff_eq(Vec2::NEG_Y, 1.0e-3));
+}
+
+#[test]
+fn fixed_substeps_make_motion_independent_of_frame_rate() {
+    let run = |frames: u32| {
+        let (mut skeleton, id) = rigged(SpringOptions::new().with_damping(4.0));
+        step(&mut skeleton, Duration::ZERO);
+        chain(&mut skeleton, id).move_skeleton(Vec2::new(8.0, 3.0));
+        let mut direction = Vec2::ZERO;
+        for _ in 0..frames {
+            direction = step(&mut skeleton, Duration::from_secs(1) / frames);
+        }
+        direction
+    };
+    let at_sixty = run(60);
+    assert_eq!(at_sixty, run(30));
+    assert_eq!(at_sixty, run(60), "identical inputs are bit-identical");
+}
+
+#[test]
+fn a_frame_that_exactly_fills_the_substep_cap_keeps_its_remainder() {
+    // At 100 Hz a 25 ms frame is due two substeps and keeps 5 ms, which the
+    // following 15 ms frame completes into two more.
+    let run = |max_substeps: u32| {
+        let options = SpringOptions::new()
+            .with_frequency(NonZeroU32::new(100).expect("nonzero"))
+            .with_max_substeps(NonZeroU32::new(max_substeps).expect("nonzero"));
+        let (mut skeleton, id) = rigged(options);
+        step(&mut skeleton, Duration::ZERO);
+        chain(&mut skeleton, id).move_skeleton(Vec2::new(8.0, 3.0));
+        let mut direction = Vec2::ZERO;
+        for frame in 0..40 {
+            direction = step(
+                &mut skeleton,
+                Duration::from_millis(if frame % 2 == 0 { 25 } else { 15 }),
+            );
+        }
+        direction
+    };
+    assert_eq!(run(2), run(8));
+}
+
+#[test]
+fn gravity_swings_within_the_angle_limit() {
+    let options = SpringOptions::new()
+        .with_gravity(Vec2::new(2000.0, 0.0))
+        .with_angle_limit(Some(Angle::from_degrees(30.0).expect("finite")))
+        .with_max_substeps(NonZeroU32::new(2).expect("nonzero"));
+    let (mut skeleton, _) = rigged(options);
+    step(&mut skeleton, Duration::ZERO);
+    for _ in 0..200 {
+        step(&mut skeleton, Duration::from_millis(16));
+    }
+    let asset = Arc::clone(skeleton.asset_handle());
+    let frame = skeleton.editable_pose().solve();
+    let root_swing = frame
+        .bone(tail(&asset)[0])
+        .expect("local")
+        .world_transform()
+        .x_axis();
+    let degrees = root_swing.y.atan2(root_swing.x).to_degrees();
+    assert!((degrees + 60.0).abs() < 1.0e-2, "{degrees}");
+}
+
+#[test]
+fn reset_snaps_and_teleport_keeps_the_swing() {
+    let (mut skeleton, id) = rigged(SpringOptions::new());
+    step(&mut skeleton, Duration::ZERO);
+    chain(&mut skeleton, id).move_skeleton(Vec2::new(5.0, 0.0));
+    let swing = step(&mut skeleton, Duration::from_millis(16));
+
+    // Jumping the body far away would fling an unprepared chain.
+    let body = skeleton.asset().bone_id("body").expect("body exists");
+    let jumped = BoneTransform::new(
+        Vec2::new(300.0, 20.0),
+        Angle::ZERO,
+        Vec2::ONE,
+        spinal::Shear::ZERO,
+    )
+    .expect("finite");
+    chain(&mut skeleton, id).teleport();
+    skeleton
+        .editable_pose()
+        .edit()
+        .set_bone_local(body, jumped)
+        .expect("local");
+    let carried = step(&mut skeleton, Duration::ZERO);
+    assert!(carried.abs_diff_eq(swing, 1.0e-4), "{carried} != {swing}");
+
+    chain(&mut skeleton, id).reset();
+    assert!(step(&mut skeleton, Duration::from_millis(16)).abs_diff_eq(Vec2::NEG_Y, 1.0e-6));
+}
+
+#[test]
+fn snapshots_roll_the_simulation_back_bit_exactly() {
+    let (mut skeleton, id) = rigged(SpringOptions::new().with_damping(2.0));
+    step(&mut skeleton, Duration::ZERO);
+    chain(&mut skeleton, id).move_skeleton(Vec2::new(6.0, 0.0));
+    step(&mut skeleton, Duration::from_millis(16));
+    let run = |skeleton: &mut Skeleton| {
+        (0..20)
+            .map(|_| {
+                step(skeleton, Duration::from_millis(7))
+                    .to_array()
+                    .map(f32::to_bits)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut snapshot = skeleton.snapshot();
+    let original = run(&mut skeleton);
+    skeleton.restore(&snapshot).expect("same asset");
+    assert_eq!(
+        run(&mut skeleton),
+        original,
+        "velocity and substep time roll back"
+    );
+
+    skeleton.snapshot_into(&mut snapshot);
+    chain(&mut skeleton, id).move_skeleton(Vec2::new(0.0, 3.0));
+    let original = run(&mut skeleton);
+    skeleton.restore(&snapshot).expect("same asset");
+    chain(&mut skeleton, id).move_skeleton(Vec2::new(0.0, 3.0));
+    assert_eq!(
+        run(&mut skeleton),
+        original,
+        "a refreshed snapshot captures the later state"
+    );
+}
+
+#[test]
+fn chains_are_validated() {
+    let asset = asset();
+    let [tail, tip] = tail(&asset);
+    let ear = asset.bone_id("ear").expect("ear exists");
+    assert_eq!(
+        SpringChain::new(&asset, [], SpringOptions::new()).unwrap_err(),
+        SpringChainError::EmptyChain
+    );
+    assert_eq!(
+        SpringChain::new(&asset, [tail, ear], SpringOptions::new()).unwrap_err(),
+        SpringChainError::Disconnected { bone: ear }
+    );
+    assert_eq!(
+        SpringChain::new(&asset, [tail, tip], SpringOptions::new().with_damping(-1.0)).unwrap_err(),
+        SpringChainError::InvalidSetting("damping")
+    );
+    let other = self::asset();
+    assert!(matches!(
+        SpringChain::new(&other, [tail], SpringOptions::new()),
+        Err(SpringChainError::InvalidBone(_))
+    ));
+}
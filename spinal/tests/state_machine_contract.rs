@@ -0,0 +1,476 @@
+//! Public contract tests for declarative animation state machines.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationMixer, Comparison, ConditionDefinition, CrossfadeDefinition, ParameterDefinition,
+    ParameterKind, ParameterValue, PlaybackMode, Skeleton, SkeletonAsset, StateDefinition,
+    StateMachine, StateMachineDefinition, StateMachineDefinitionError, StateMachineError,
+    StateMachineUpdateError, TrackAnimationEvent, TrackOptions, TransitionDefinition, load_json,
+};
+
+const MACHINE_JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[
+    {"name":"root"},
+    {"name":"body","parent":"root"}
+  ],
+  "events":{"land":{}},
+  "animations":{
+    "idle":{
+      "bones":{
+        "body":{"rotate":[{"value":0},{"time":1,"value":10}]}
+      }
+    },
+    "run":{
+      "bones":{
+        "root":{"translate":[{"x":0,"y":0},{"time":0.5,"x":10,"y":0}]}
+      }
+    },
+    "jump":{
+      "bones":{
+        "body":{"rotate":[{"value":0},{"time":0.5,"value":45}]}
+      },
+      "events":[{"time":0.25,"name":"land"}]
+    }
+  }
+}"#;
+
+const DEFINITION_JSON: &str = r#"{
+  "initial":"idle",
+  "parameters":[
+    {"type":"float","name":"speed"},
+    {"type":"trigger","name":"jump"},
+    {"type":"bool","name":"grounded","default":true}
+  ],
+  "states":[
+    {"name":"idle","animation":"idle","mode":"loop"},
+    {"name":"run","animation":"run","mode":"loop","speed":2},
+    {"name":"jump","animation":"jump"},
+    {"name":"landed","animation":"idle"}
+  ],
+  "transitions":[
+    {
+      "to":"jump",
+      "conditions":[
+        {"type":"trigger","parameter":"jump"},
+        {"type":"bool","parameter":"grounded","value":true}
+      ]
+    },
+    {
+      "from":"idle",
+      "to":"run",
+      "conditions":[{"type":"float","parameter":"speed","comparison":"greater","value":0.5}],
+      "crossfade":{"seconds":0.2,"curve":"smooth_step"}
+    },
+    {
+      "from":"run",
+      "to":"idle",
+      "conditions":[{"type":"float","parameter":"speed","comparison":"less_or_equal","value":0.5}]
+    },
+    {"from":"jump","to":"landed","conditions":[{"type":"event","event":"land"}]},
+    {"from":"landed","to":"idle","conditions":[{"type":"complete"}]}
+  ]
+}"#;
+
+fn machine_fixture() -> (Arc<SkeletonAsset>, Skeleton, AnimationMixer, StateMachine) {
+    let asset = load_json(MACHINE_JSON, b"page.png\n")
+        .expect("the state machine fixture loads")
+        .into_asset();
+    let skeleton = Skeleton::new(Arc::clone(&asset));
+    let mixer = AnimationMixer::new(&skeleton);
+    let definition: StateMachineDefinition =
+        serde_json::from_str(DEFINITION_JSON).expect("the definition deserializes");
+    let machine = StateMachine::new(&asset, &definition).expect("the definition compiles");
+    (asset, skeleton, mixer, machine)
+}
+
+fn step(
+    machine: &mut StateMachine,
+    mixer: &mut AnimationMixer,
+    skeleton: &mut Skeleton,
+    millis: u64,
+) -> Option<(Option<String>, String, Option<usize>)> {
+    let _frame = machine
+        .update(mixer, skeleton, Duration::from_millis(millis), &mut ())
+        .expect("state machine update succeeds")
+        .solve();
+    machine.last_transition().map(|transition| {
+        let name = |id| {
+            machine
+                .state_name(id)
+                .expect("reported states belong to the machine")
+                .to_owned()
+        };
+        (
+            transition.from().map(name),
+            name(transition.to()),
+            transition.transition(),
+        )
+    })
+}
+
+#[test]
+fn definitions_round_trip_through_serde_and_builders() {
+    let built = StateMachineDefinition::new("idle")
+        .with_parameter(ParameterDefinition::Float {
+            name: "speed".into(),
+            default: 0.0,
+        })
+        .with_state(StateDefinition::new("idle", "idle").with_mode(PlaybackMode::Loop))
+        .with_state(
+            StateDefinition::new("run", "run")
+                .with_mode(PlaybackMode::Loop)
+                .with_speed(2.0),
+        )
+        .with_transition(
+            TransitionDefinition::new("idle", "run")
+                .with_condition(ConditionDefinition::Float {
+                    parameter: "speed".into(),
+                    comparison: Comparison::Greater,
+                    value: 0.5,
+                })
+                .with_crossfade(CrossfadeDefinition::new(0.2)),
+        );
+    let json = serde_json::to_string(&built).expect("definitions serialize");
+    let parsed: StateMachineDefinition =
+        serde_json::from_str(&json).expect("serialized definitions deserialize");
+    assert_eq!(parsed, built);
+
+    let rejected = serde_json::from_str::<StateMachineDefinition>(
+        r#"{"initial":"idle","states":[{"name":"idle","animation":"idle","loop":true}]}"#,
+    );
+    assert!(rejected.is_err());
+}
+
+#[test]
+fn the_first_update_enters_the_initial_state_and_parameters_drive_crossfades() {
+    let (asset, mut skeleton, mut mixer, mut machine) = machine_fixture();
+    let speed = machine.parameter_id("speed").expect("speed exists");
+    let idle = machine.state_id("idle").expect("idle exists");
+    let run = machine.state_id("run").expect("run exists");
+    assert_eq!(machine.current_state(), None);
+
+    assert_eq!(
+        step(&mut machine, &mut mixer, &mut skeleton, 100),
+        Some((None, "idle".to_owned(), None))
+    );
+    assert_eq!(machine.current_state(), Some(idle));
+    assert_eq!(step(&mut machine, &mut mixer, &mut skeleton, 100), None);
+
+    machine.set_float(speed, 1.0).expect("speed is a float");
+    assert_eq!(
+        step(&mut machine, &mut mixer, &mut skeleton, 100),
+        Some((Some("idle".to_owned()), "run".to_owned(), Some(1)))
+    );
+    let status = mixer.base_track().status();
+    assert_eq!(status.animation(), asset.animation_id("run"));
+    assert_eq!(status.mode(), Some(PlaybackMode::Loop));
+    assert_eq!(status.position(), Some(Duration::from_millis(200)));
+    let mix = status
+        .transition_mix()
+        .expect("the crossfade is active")
+        .get();
+    assert!(mix > 0.0 && mix < 1.0);
+    assert_eq!(mixer.base_track().speed(), 2.0);
+
+    machine.set_float(speed, 0.0).expect("speed is a float");
+    assert_eq!(
+        step(&mut machine, &mut mixer, &mut skeleton, 100),
+        Some((Some("run".to_owned()), "idle".to_owned(), Some(2)))
+    );
+    assert_eq!(machine.current_state(), Some(idle));
+    assert_ne!(machine.current_state(), Some(run));
+}
+
+#[test]
+fn triggers_are_consumed_by_one_update_and_any_state_edges_skip_their_target() {
+    let (_asset, mut skeleton, mut mixer, mut machine) = machine_fixture();
+    let jump = machine.parameter_id("jump").expect("jump exists");
+    let grounded = machine.parameter_id("grounded").expect("grounded exists");
+    step(&mut machine, &mut mixer, &mut skeleton, 10);
+
+    machine
+        .set_bool(grounded, false)
+        .expect("grounded is a bool");
+    machine.set_trigger(jump).expect("jump is a trigger");
+    assert_eq!(step(&mut machine, &mut mixer, &mut skeleton, 10), None);
+    assert_eq!(machine.parameter(jump), Ok(ParameterValue::Trigger(false)));
+
+    machine
+        .set_bool(grounded, true)
+        .expect("grounded is a bool");
+    machine.set_trigger(jump).expect("jump is a trigger");
+    assert_eq!(
+        step(&mut machine, &mut mixer, &mut skeleton, 10),
+        Some((Some("idle".to_owned()), "jump".to_owned(), Some(0)))
+    );
+    machine.set_trigger(jump).expect("jump is a trigger");
+    assert_eq!(step(&mut machine, &mut mixer, &mut skeleton, 10), None);
+}
+
+#[test]
+fn event_and_completion_conditions_observe_the_previous_update() {
+    let (_asset, mut skeleton, mut mixer, mut machine) = machine_fixture();
+    let jump = machine.parameter_id("jump").expect("jump exists");
+    step(&mut machine, &mut mixer, &mut skeleton, 10);
+    machine.set_trigger(jump).expect("jump is a trigger");
+    step(&mut machine, &mut mixer, &mut skeleton, 0);
+
+    let mut seen = Vec::new();
+    let _frame = machine
+        .update(
+            &mut mixer,
+            &mut skeleton,
+            Duration::from_millis(300),
+            &mut |event: TrackAnimationEvent<'_>| {
+                seen.push(event.event().definition().name().to_owned());
+            },
+        )
+        .expect("the eventful update succeeds")
+        .solve();
+    assert_eq!(seen, ["land"]);
+    assert_eq!(machine.last_transition(), None);
+
+    assert_eq!(
+        step(&mut machine, &mut mixer, &mut skeleton, 0),
+        Some((Some("jump".to_owned()), "landed".to_owned(), Some(3)))
+    );
+    assert_eq!(step(&mut machine, &mut mixer, &mut skeleton, 1000), None);
+    assert!(mixer.base_track().status().is_complete());
+    assert_eq!(
+        step(&mut machine, &mut mixer, &mut skeleton, 0),
+        Some((Some("landed".to_owned()), "idle".to_owned(), Some(4)))
+    );
+}
+
+#[test]
+fn failed_updates_keep_pending_triggers_for_the_next_update() {
+    let (asset, mut skeleton, mut mixer, mut machine) = machine_fixture();
+    let mut foreign = Skeleton::new(Arc::clone(&asset));
+    let jump = machine.parameter_id("jump").expect("jump exists");
+    step(&mut machine, &mut mixer, &mut skeleton, 10);
+
+    machine.set_trigger(jump).expect("jump is a trigger");
+    let error = machine
+        .update(&mut mixer, &mut foreign, Duration::from_millis(10), &mut ())
+        .map(|_pose| ())
+        .expect_err("the mixer rejects a foreign skeleton");
+    assert!(matches!(error, StateMachineUpdateError::Player(_)));
+    assert_eq!(machine.current_state(), machine.state_id("jump"));
+    assert_eq!(machine.parameter(jump), Ok(ParameterValue::Trigger(true)));
+
+    // The any-state edge skips the state it already entered.
+    assert_eq!(step(&mut machine, &mut mixer, &mut skeleton, 10), None);
+    assert_eq!(machine.parameter(jump), Ok(ParameterValue::Trigger(false)));
+}
+
+#[test]
+fn machines_can_drive_an_override_track_and_report_its_removal() {
+    let (asset, mut skeleton, mut mixer, machine) = machine_fixture();
+    let track = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the mixer has track identity capacity");
+    let mut machine = machine.with_track(track);
+    step(&mut machine, &mut mixer, &mut skeleton, 10);
+    assert_eq!(
+        mixer
+            .track(track)
+            .expect("track exists")
+            .status()
+            .animation(),
+        asset.animation_id("idle")
+    );
+    assert!(mixer.base_track().status().is_idle());
+
+    mixer.remove_track(track).expect("track exists");
+    let error = machine
+        .update(
+            &mut mixer,
+            &mut skeleton,
+            Duration::from_millis(10),
+            &mut (),
+        )
+        .map(|_pose| ())
+        .expect_err("removed tracks cannot be driven");
+    assert!(matches!(error, StateMachineUpdateError::Track(_)));
+}
+
+#[test]
+fn parameter_and_state_access_rejects_foreign_ids_and_wrong_types() {
+    let (asset, _skeleton, _mixer, mut machine) = machine_fixture();
+    let definition: StateMachineDefinition =
+        serde_json::from_str(DEFINITION_JSON).expect("the definition deserializes");
+    let other = StateMachine::new(&asset, &definition).expect("the definition compiles");
+    let speed = machine.parameter_id("speed").expect("speed exists");
+    let foreign = other.parameter_id("speed").expect("speed exists");
+
+    assert_eq!(
+        machine.set_float(foreign, 1.0),
+        Err(StateMachineError::ForeignMachine)
+    );
+    assert_eq!(
+        machine.set_bool(speed, true),
+        Err(StateMachineError::ParameterTypeMismatch {
+            expected: ParameterKind::Bool,
+            actual: ParameterKind::Float,
+        })
+    );
+    assert_eq!(
+        machine.set_float(speed, f32::NAN),
+        Err(StateMachineError::NonFiniteValue)
+    );
+    assert_eq!(machine.parameter(speed), Ok(ParameterValue::Float(0.0)));
+    assert_eq!(
+        machine.state_name(other.state_id("idle").expect("idle exists")),
+        Err(StateMachineError::ForeignMachine)
+    );
+}
+
+#[test]
+fn definitions_are_validated_against_the_asset() {
+    let asset = load_json(MACHINE_JSON, b"page.png\n")
+        .expect("the state machine fixture loads")
+        .into_asset();
+    let base = || {
+        StateMachineDefinition::new("idle")
+            .with_parameter(ParameterDefinition::Int {
+                name: "count".into(),
+                default: 0,
+            })
+            .with_state(StateDefinition::new("idle", "idle"))
+    };
+    let error = |definition: StateMachineDefinition| {
+        StateMachine::new(&asset, &definition).expect_err("the definition is invalid")
+    };
+
+    assert_eq!(
+        error(
+            StateMachineDefinition::new("missing").with_state(StateDefinition::new("idle", "idle"))
+        ),
+        StateMachineDefinitionError::UnknownState("missing".into())
+    );
+    assert_eq!(
+        error(base().with_state(StateDefinition::new("idle", "run"))),
+        StateMachineDefinitionError::DuplicateState("idle".into())
+    );
+    assert_eq!(
+        error(base().with_state(StateDefinition::new("fly", "fly"))),
+        StateMachineDefinitionError::UnknownAnimation("fly".into())
+    );
+    assert_eq!(
+        error(base().with_state(StateDefinition::new("fast", "run").with_speed(-1.0))),
+        StateMachineDefinitionError::InvalidSpeed("fast".into())
+    );
+    assert_eq!(
+        error(
+            base().with_transition(TransitionDefinition::from_any("idle").with_condition(
+                ConditionDefinition::Bool {
+                    parameter: "count".into(),
+                    value: true,
+                }
+            ))
+        ),
+        StateMachineDefinitionError::ParameterTypeMismatch {
+            name: "count".into(),
+            expected: ParameterKind::Bool,
+            actual: ParameterKind::Int,
+        }
+    );
+    assert_eq!(
+        error(
+            base().with_transition(TransitionDefinition::from_any("idle").with_condition(
+                ConditionDefinition::Trigger {
+                    parameter: "go".into(),
+                }
+            ))
+        ),
+        StateMachineDefinitionError::UnknownParameter("go".into())
+    );
+    assert_eq!(
+        error(
+            base().with_transition(TransitionDefinition::from_any("idle").with_condition(
+                ConditionDefinition::Event {
+                    event: "splash".into(),
+                }
+            ))
+        ),
+        StateMachineDefinitionError::UnknownEvent("splash".into())
+    );
+    assert_eq!(
+        error(
+            base().with_transition(
+                TransitionDefinition::from_any("idle")
+                    .with_crossfade(CrossfadeDefinition::new(0.1).with_discrete_switch(2.0))
+            )
+        ),
+        StateMachineDefinitionError::InvalidCrossfade(0)
+    );
+}
+
+#[test]
+fn identical_inputs_produce_identical_transition_reports() {
+    let trace = || {
+        let (_asset, mut skeleton, mut mixer, mut machine) = machine_fixture();
+        let speed = machine.parameter_id("speed").expect("speed exists");
+        let jump = machine.parameter_id("jump").expect("jump exists");
+        let mut reports = Vec::new();
+        for frame in 0_u32..90 {
+            if frame % 17 == 3 {
+                machine
+                    .set_float(speed, if frame % 2 == 0 { 0.0 } else { 1.0 })
+                    .expect("speed is a float");
+            }
+            if frame % 23 == 5 {
+                machine.set_trigger(jump).expect("jump is a trigger");
+            }
+            reports.push(step(&mut machine, &mut mixer, &mut skeleton, 33));
+        }
+        reports
+    };
+    let first = trace();
+    assert!(first.iter().flatten().count() > 4);
+    assert_eq!(first, trace());
+}
+
+#[test]
+fn steady_state_updates_allocate_nothing_after_warmup() {
+    let (_asset, mut skeleton, mut mixer, mut machine) = machine_fixture();
+    let speed = machine.parameter_id("speed").expect("speed exists");
+    let jump = machine.parameter_id("jump").expect("jump exists");
+    for _frame in 0..4 {
+        let _solved = machine
+            .update(
+                &mut mixer,
+                &mut skeleton,
+                Duration::from_millis(16),
+                &mut (),
+            )
+            .expect("warmup succeeds")
+            .solve();
+    }
+
+    let allocations = allocation_counter::measure(|| {
+        for frame in 0..120 {
+            if frame % 30 == 0 {
+                machine
+                    .set_float(speed, if frame % 60 == 0 { 1.0 } else { 0.0 })
+                    .expect("speed is a float");
+            }
+            if frame % 45 == 0 {
+                machine.set_trigger(jump).expect("jump is a trigger");
+            }
+            let _solved = machine
+                .update(
+                    &mut mixer,
+                    &mut skeleton,
+                    Duration::from_millis(16),
+                    &mut (),
+                )
+                .expect("steady-state update succeeds")
+                .solve();
+        }
+    });
+    assert_eq!(allocations.count_total, 0);
+    assert_eq!(allocations.bytes_total, 0);
+}
@@ -0,0 +1,389 @@
+//! Public contract tests for phase-synchronized mixer track groups.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationMixer, Crossfade, Mix, PlayOptions, PlayerError, Skeleton, SkeletonAsset, SyncPolicy,
+    TrackAnimationEvent, TrackErrorKind, TrackOptions, Transition, load_json,
+};
+
+const JSON: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"}],
+  "events":{"step":{}},
+  "animations":{
+    "walk":{
+      "bones":{"root":{"translate":[{"x":0,"y":0},{"time":1,"x":10,"y":0}]}},
+      "events":[{"time":0.5,"name":"step"}]
+    },
+    "trot":{
+      "bones":{"root":{"translate":[{"x":0,"y":0},{"time":0.5,"x":10,"y":0}]}},
+      "events":[{"time":0.25,"name":"step"}]
+    }
+  }
+}"#;
+
+fn fixture() -> (Arc<SkeletonAsset>, Skeleton, AnimationMixer) {
+    let asset = load_json(JSON, b"page.png\n")
+        .expect("the sync fixture loads")
+        .into_asset();
+    let skeleton = Skeleton::new(Arc::clone(&asset));
+    let mixer = AnimationMixer::new(&skeleton);
+    (asset, skeleton, mixer)
+}
+
+fn step(mixer: &mut AnimationMixer, skeleton: &mut Skeleton, millis: u64) -> Vec<Duration> {
+    let mut events = Vec::new();
+    let _frame = mixer
+        .update(
+            skeleton,
+            Duration::from_millis(millis),
+            &mut |event: TrackAnimationEvent<'_>| events.push(event.event().local_time()),
+        )
+        .expect("the update succeeds");
+    events
+}
+
+fn base_position(mixer: &AnimationMixer) -> Duration {
+    mixer
+        .base_track()
+        .status()
+        .position()
+        .expect("the base track is playing")
+}
+
+#[test]
+fn a_new_base_playback_starts_at_the_group_phase_and_keeps_local_events() {
+    let (asset, mut skeleton, mut mixer) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let trot = asset.animation_id("trot").expect("trot exists");
+    let group = mixer
+        .insert_sync_group(SyncPolicy::Weighted)
+        .expect("the group is created");
+    mixer
+        .set_sync_group(mixer.base_track_id(), Some(group))
+        .expect("the base track joins");
+    assert_eq!(mixer.sync_phase(group), Ok(None));
+
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    step(&mut mixer, &mut skeleton, 300);
+    assert_eq!(mixer.sync_phase(group), Ok(Some(0.3)));
+
+    mixer
+        .base_track_mut()
+        .play(
+            trot,
+            PlayOptions::looping().with_transition(Transition::Crossfade(Crossfade::new(
+                Duration::from_millis(200),
+            ))),
+        )
+        .expect("trot is local");
+    let events = step(&mut mixer, &mut skeleton, 50);
+    // Trot enters at 30% of its own half-second cycle, not at zero.
+    assert_eq!(base_position(&mixer), Duration::from_millis(200));
+    assert!(events.is_empty(), "skipped events are not replayed");
+
+    let events = step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(base_position(&mixer), Duration::from_millis(300));
+    assert_eq!(events, [Duration::from_millis(250)]);
+    assert_eq!(mixer.sync_phase(group), Ok(Some(0.6)));
+}
+
+#[test]
+fn a_failed_update_leaves_group_phases_and_clocks_unchanged() {
+    let (asset, mut skeleton, mut mixer) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let group = mixer
+        .insert_sync_group(SyncPolicy::Weighted)
+        .expect("the group is created");
+    mixer
+        .set_sync_group(mixer.base_track_id(), Some(group))
+        .expect("the base track joins");
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    step(&mut mixer, &mut skeleton, 300);
+
+    // Walk's step fires once a cycle, so a day-long update exceeds the
+    // per-update event budget.
+    let error = mixer
+        .update(&mut skeleton, Duration::from_secs(86_400), &mut ())
+        .map(|_| ())
+        .expect_err("the update exceeds the event budget");
+    assert!(matches!(error, PlayerError::EventLimitExceeded { .. }));
+    assert_eq!(mixer.sync_phase(group), Ok(Some(0.3)));
+    assert_eq!(base_position(&mixer), Duration::from_millis(300));
+
+    step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(mixer.sync_phase(group), Ok(Some(0.4)));
+    assert_eq!(base_position(&mixer), Duration::from_millis(400));
+}
+
+#[test]
+fn followers_match_the_leader_phase_at_their_own_durations() {
+    let (asset, mut skeleton, mut mixer) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let trot = asset.animation_id("trot").expect("trot exists");
+    let follower = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let group = mixer
+        .insert_sync_group(SyncPolicy::Leader(mixer.base_track_id()))
+        .expect("the group is created");
+    mixer
+        .set_sync_group(mixer.base_track_id(), Some(group))
+        .expect("the base track joins");
+    mixer
+        .set_sync_group(follower, Some(group))
+        .expect("the follower joins");
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    mixer
+        .track_mut(follower)
+        .expect("the follower exists")
+        .play(trot, PlayOptions::looping())
+        .expect("trot is local");
+    mixer
+        .track_mut(follower)
+        .expect("the follower exists")
+        .set_speed(3.0)
+        .expect("the speed is valid");
+
+    let events = step(&mut mixer, &mut skeleton, 600);
+    assert_eq!(base_position(&mixer), Duration::from_millis(600));
+    let follower_position = mixer
+        .track(follower)
+        .expect("the follower exists")
+        .status()
+        .position();
+    assert_eq!(follower_position, Some(Duration::from_millis(300)));
+    // Each clip reports its own step at its own local time, base first.
+    assert_eq!(
+        events,
+        [Duration::from_millis(500), Duration::from_millis(250)]
+    );
+}
+
+#[test]
+fn held_followers_keep_their_clock_and_realign_when_they_resume() {
+    let (asset, mut skeleton, mut mixer) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let trot = asset.animation_id("trot").expect("trot exists");
+    let follower = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let group = mixer
+        .insert_sync_group(SyncPolicy::Leader(mixer.base_track_id()))
+        .expect("the group is created");
+    mixer
+        .set_sync_group(mixer.base_track_id(), Some(group))
+        .expect("the base track joins");
+    mixer
+        .set_sync_group(follower, Some(group))
+        .expect("the follower joins");
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    mixer
+        .track_mut(follower)
+        .expect("the follower exists")
+        .play(trot, PlayOptions::looping())
+        .expect("trot is local");
+    let follower_position = |mixer: &AnimationMixer| {
+        mixer
+            .track(follower)
+            .expect("the follower exists")
+            .status()
+            .position()
+    };
+
+    step(&mut mixer, &mut skeleton, 200);
+    assert_eq!(follower_position(&mixer), Some(Duration::from_millis(100)));
+
+    mixer
+        .track_mut(follower)
+        .expect("the follower exists")
+        .set_paused(true);
+    step(&mut mixer, &mut skeleton, 300);
+    assert_eq!(base_position(&mixer), Duration::from_millis(500));
+    assert_eq!(
+        follower_position(&mixer),
+        Some(Duration::from_millis(100)),
+        "a paused follower does not move with its group"
+    );
+
+    mixer
+        .track_mut(follower)
+        .expect("the follower exists")
+        .set_paused(false);
+    step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(
+        follower_position(&mixer),
+        Some(Duration::from_millis(300)),
+        "a resumed follower rejoins the group phase"
+    );
+
+    mixer
+        .track_mut(follower)
+        .expect("the follower exists")
+        .set_speed(0.0)
+        .expect("the speed is valid");
+    step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(follower_position(&mixer), Some(Duration::from_millis(300)));
+    mixer
+        .set_sync_policy(group, SyncPolicy::Weighted)
+        .expect("the group exists");
+    step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(
+        mixer.sync_phase(group),
+        Ok(Some(0.8)),
+        "a zero-speed member does not slow a weighted group"
+    );
+    assert_eq!(follower_position(&mixer), Some(Duration::from_millis(300)));
+}
+
+#[test]
+fn weighted_groups_blend_member_rates_by_track_weight() {
+    let (asset, mut skeleton, mut mixer) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let trot = asset.animation_id("trot").expect("trot exists");
+    let quarter = Mix::new(0.25).expect("a quarter is normalized");
+    let track = mixer
+        .insert_track(TrackOptions::override_track().with_weight(quarter))
+        .expect("the track is inserted");
+    let group = mixer
+        .insert_sync_group(SyncPolicy::Weighted)
+        .expect("the group is created");
+    for member in [mixer.base_track_id(), track] {
+        mixer
+            .set_sync_group(member, Some(group))
+            .expect("the member joins");
+    }
+    assert_eq!(
+        mixer.sync_group(track).expect("the track exists"),
+        Some(group)
+    );
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .play(trot, PlayOptions::looping())
+        .expect("trot is local");
+
+    // Walk would advance 0.1 cycles at weight one and trot 0.2 at a quarter,
+    // so the group advances (0.1 + 0.05) / 1.25 = 0.12 cycles.
+    step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(base_position(&mixer), Duration::from_millis(120));
+    assert_eq!(
+        mixer
+            .track(track)
+            .expect("the track exists")
+            .status()
+            .position(),
+        Some(Duration::from_millis(60))
+    );
+
+    mixer
+        .remove_sync_group(group)
+        .expect("the group is removed");
+    assert_eq!(mixer.sync_group(track).expect("the track exists"), None);
+    step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(base_position(&mixer), Duration::from_millis(220));
+    assert_eq!(
+        mixer
+            .track(track)
+            .expect("the track exists")
+            .status()
+            .position(),
+        Some(Duration::from_millis(160))
+    );
+}
+
+#[test]
+fn joining_tracks_realign_and_group_ids_are_scoped_to_their_mixer() {
+    let (asset, mut skeleton, mut mixer) = fixture();
+    let walk = asset.animation_id("walk").expect("walk exists");
+    let trot = asset.animation_id("trot").expect("trot exists");
+    let track = mixer
+        .insert_track(TrackOptions::override_track())
+        .expect("the track is inserted");
+    let group = mixer
+        .insert_sync_group(SyncPolicy::Leader(mixer.base_track_id()))
+        .expect("the group is created");
+    mixer
+        .set_sync_group(mixer.base_track_id(), Some(group))
+        .expect("the base track joins");
+    mixer
+        .base_track_mut()
+        .play(walk, PlayOptions::looping())
+        .expect("walk is local");
+    mixer
+        .track_mut(track)
+        .expect("the track exists")
+        .play(trot, PlayOptions::looping())
+        .expect("trot is local");
+    step(&mut mixer, &mut skeleton, 400);
+    assert_eq!(
+        mixer
+            .track(track)
+            .expect("the track exists")
+            .status()
+            .position(),
+        Some(Duration::from_millis(400))
+    );
+
+    mixer
+        .set_sync_group(track, Some(group))
+        .expect("the track joins");
+    step(&mut mixer, &mut skeleton, 100);
+    assert_eq!(base_position(&mixer), Duration::from_millis(500));
+    assert_eq!(
+        mixer
+            .track(track)
+            .expect("the track exists")
+            .status()
+            .position(),
+        Some(Duration::from_millis(250))
+    );
+
+    let (_other_asset, other_skeleton, _other_mixer) = fixture();
+    let mut other = AnimationMixer::new(&other_skeleton);
+    let foreign = other
+        .insert_sync_group(SyncPolicy::Weighted)
+        .expect("the group is created");
+    assert_eq!(
+        mixer
+            .set_sync_group(track, Some(foreign))
+            .map_err(|error| error.kind()),
+        Err(TrackErrorKind::ForeignMixer)
+    );
+    assert_eq!(
+        mixer
+            .insert_sync_group(SyncPolicy::Leader(other.base_track_id()))
+            .map_err(|error| error.kind()),
+        Err(TrackErrorKind::ForeignMixer)
+    );
+    mixer
+        .remove_sync_group(group)
+        .expect("the group is removed");
+    assert_eq!(
+        mixer.sync_policy(group).map_err(|error| error.kind()),
+        Err(TrackErrorKind::SyncGroupRemoved)
+    );
+    assert_eq!(
+        mixer
+            .set_sync_group(track, Some(group))
+            .map_err(|error| error.kind()),
+        Err(TrackErrorKind::SyncGroupRemoved)
+    );
+}
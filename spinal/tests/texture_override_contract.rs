@@ -0,0 +1,169 @@
+//! Public contract tests for caller-supplied attachment textures.
+
+use std::sync::Arc;
+
+use glam::Vec2;
+use spinal::{
+    AttachmentId, CustomPageKey, DrawItemRef, Skeleton, SkeletonAsset, TextureOverride,
+    TextureOverrideError, load_json,
+};
+
+// The banner is packed trimmed and rotated; the flag is a mesh.
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"}],
+  "slots":[
+    {"name":"banner-slot","bone":"root","attachment":"banner"},
+    {"name":"flag-slot","bone":"root","attachment":"flag"},
+    {"name":"hitbox-slot","bone":"root","attachment":"hitbox"}
+  ],
+  "skins":[{
+    "name":"default",
+    "attachments":{
+      "banner-slot":{"banner":{"width":20,"height":10}},
+      "flag-slot":{"flag":{
+        "type":"mesh",
+        "uvs":[0,0,1,0,1,1],
+        "triangles":[0,1,2],
+        "vertices":[0,0,8,0,8,2],
+        "hull":3
+      }},
+      "hitbox-slot":{"hitbox":{"type":"boundingbox","vertexCount":3,"vertices":[0,0,1,0,1,1]}}
+    }
+  }]
+}"#;
+
+const ATLAS: &[u8] = b"cat.png
+\tsize: 64, 32
+banner
+\tbounds: 0, 0, 16, 8
+\toffsets: 2, 1, 20, 10
+\trotate: 90
+flag
+\tbounds: 32, 0, 8, 2
+";
+
+const BANNER_PAGE: CustomPageKey = CustomPageKey::new(7);
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, ATLAS)
+        .expect("the texture override fixture loads")
+        .into_asset()
+}
+
+fn attachment(asset: &SkeletonAsset, slot: &str, name: &str) -> AttachmentId {
+    asset
+        .default_skin()
+        .expect("the default skin exists")
+        .attachment(asset.slot_id(slot).expect("the slot exists"), name)
+        .expect("local")
+        .expect("the attachment exists")
+}
+
+#[test]
+fn overridden_attachments_draw_from_the_custom_page() {
+    let asset = asset();
+    let banner = attachment(&asset, "banner-slot", "banner");
+    let flag = attachment(&asset, "flag-slot", "flag");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let half =
+        TextureOverride::new(BANNER_PAGE, Vec2::ZERO, Vec2::new(0.5, 1.0)).expect("finite UVs");
+    skeleton
+        .set_texture_override(banner, half)
+        .expect("regions are textured");
+    skeleton
+        .set_texture_override(flag, TextureOverride::full_page(BANNER_PAGE))
+        .expect("meshes are textured");
+    assert_eq!(skeleton.texture_override(banner), Ok(Some(half)));
+
+    let frame = skeleton.editable_pose().solve();
+    let mut items = frame.draw_items();
+    let Some(DrawItemRef::Region(region)) = items.next() else {
+        panic!("the banner is drawn first");
+    };
+    assert_eq!(region.texture_override(), Some(half));
+    // The whole 20x10 image is drawn, ignoring the packed trim and rotation.
+    assert_eq!(
+        region.positions(),
+        [
+            Vec2::new(-10.0, -5.0),
+            Vec2::new(-10.0, 5.0),
+            Vec2::new(10.0, 5.0),
+            Vec2::new(10.0, -5.0),
+        ]
+    );
+    assert_eq!(
+        region.uvs(),
+        Some([
+            Vec2::new(0.0, 1.0),
+            Vec2::ZERO,
+            Vec2::new(0.5, 0.0),
+            Vec2::new(0.5, 1.0),
+        ])
+    );
+    let Some(DrawItemRef::Mesh(mesh)) = items.next() else {
+        panic!("the flag is drawn second");
+    };
+    assert_eq!(
+        mesh.texture_override(),
+        Some(TextureOverride::full_page(BANNER_PAGE))
+    );
+    assert_eq!(
+        mesh.uvs().expect("custom UVs").collect::<Vec<_>>(),
+        mesh.source_uvs()
+    );
+    drop(items);
+
+    assert_eq!(skeleton.clear_texture_override(banner), Ok(Some(half)));
+    let frame = skeleton.editable_pose().solve();
+    let Some(DrawItemRef::Region(region)) = frame.draw_items().next() else {
+        panic!("the banner is still drawn");
+    };
+    assert_eq!(region.texture_override(), None);
+    assert_eq!(
+        region.positions(),
+        [
+            Vec2::new(-8.0, -4.0),
+            Vec2::new(-8.0, 4.0),
+            Vec2::new(8.0, 4.0),
+            Vec2::new(8.0, -4.0),
+        ],
+        "the trimmed atlas region is drawn again"
+    );
+}
+
+#[test]
+fn overrides_are_validated() {
+    let asset = asset();
+    let other = self::asset();
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let page = TextureOverride::full_page(BANNER_PAGE);
+    assert_eq!(
+        TextureOverride::new(BANNER_PAGE, Vec2::ZERO, Vec2::new(f32::NAN, 1.0)),
+        Err(TextureOverrideError::NonFiniteUv)
+    );
+    assert_eq!(
+        skeleton.set_texture_override(attachment(&asset, "hitbox-slot", "hitbox"), page),
+        Err(TextureOverrideError::Untextured)
+    );
+    assert!(matches!(
+        skeleton.set_texture_override(attachment(&other, "banner-slot", "banner"), page),
+        Err(TextureOverrideError::InvalidAttachment(_))
+    ));
+}
+
+#[test]
+fn snapshots_leave_overrides_with_their_instance() {
+    let asset = asset();
+    let banner = attachment(&asset, "banner-slot", "banner");
+    let mut skeleton = Skeleton::new(Arc::clone(&asset));
+    let snapshot = skeleton.snapshot();
+    skeleton
+        .set_texture_override(banner, TextureOverride::full_page(BANNER_PAGE))
+        .expect("regions are textured");
+    skeleton.restore(&snapshot).expect("same asset");
+    assert_eq!(
+        skeleton.texture_override(banner),
+        Ok(Some(TextureOverride::full_page(BANNER_PAGE)))
+    );
+}
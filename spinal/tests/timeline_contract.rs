@@ -0,0 +1,162 @@
+//! Public contract tests for borrowed timeline and key inspection.
+
+use std::{sync::Arc, time::Duration};
+
+use spinal::{
+    AnimationRef, KeyCurve, PropertyKey, Rgba8, SkeletonAsset, TimelineRef, glam::Vec2, load_json,
+};
+
+const CAT: &[u8] = br#"{
+  "skeleton":{"spine":"4.3.23"},
+  "bones":[{"name":"root"},{"name":"paw","parent":"root"}],
+  "slots":[
+    {"name":"paw-slot","bone":"paw"},
+    {"name":"eyes-slot","bone":"root","attachment":"open"}
+  ],
+  "skins":[{"name":"default","attachments":{"eyes-slot":{
+    "open":{"width":4,"height":2},
+    "closed":{"width":4,"height":1}
+  }}}],
+  "events":{"step":{"int":1,"string":"soft"}},
+  "animations":{"wave":{
+    "bones":{"paw":{
+      "rotate":[
+        {"value":0,"curve":[0.125,0,0.375,30]},
+        {"time":0.5,"value":30}
+      ],
+      "translate":[
+        {"x":1,"y":2,"curve":"stepped"},
+        {"time":1,"x":3,"y":4}
+      ]
+    }},
+    "slots":{"eyes-slot":{
+      "attachment":[{"time":0.25,"name":"closed"},{"time":0.5,"name":null}],
+      "rgba":[{"color":"ff000080"}]
+    }},
+    "drawOrder":[
+      {"time":0.5,"offsets":[{"slot":"paw-slot","offset":1}]},
+      {"time":0.75}
+    ],
+    "events":[{"time":0.75,"name":"step","int":3}]
+  }}
+}"#;
+
+const ATLAS: &[u8] = b"cat.png
+\tsize: 16, 8
+open
+\tbounds: 0, 0, 4, 2
+closed
+\tbounds: 4, 0, 4, 1
+";
+
+fn asset() -> Arc<SkeletonAsset> {
+    load_json(CAT, ATLAS)
+        .expect("the timeline fixture loads")
+        .into_asset()
+}
+
+fn wave(asset: &SkeletonAsset) -> AnimationRef<'_> {
+    asset
+        .animation(asset.animation_id("wave").expect("the animation exists"))
+        .expect("local")
+}
+
+#[test]
+fn value_keys_expose_exact_times_and_curves() {
+    let asset = asset();
+    let paw = asset.bone_id("paw").expect("the bone exists");
+    let wave = wave(&asset);
+    assert_eq!(wave.timelines().len(), 6);
+
+    let Some(TimelineRef::BoneRotation(bone, rotation)) =
+        wave.timeline(PropertyKey::BoneRotation(paw))
+    else {
+        panic!("the paw rotation is keyed");
+    };
+    assert_eq!(bone, paw);
+    let keys = rotation.keys().collect::<Vec<_>>();
+    assert_eq!(
+        keys.iter().map(|key| key.ticks()).collect::<Vec<_>>(),
+        [0, 500_000_000]
+    );
+    assert_eq!(keys[1].time(), Duration::from_millis(500));
+    assert_eq!(keys[1].value(), 30.0);
+    let KeyCurve::Bezier(curve) = keys[0].curve() else {
+        panic!("the first rotation key eases");
+    };
+    assert_eq!(curve.control1(), Vec2::new(0.25, 0.0));
+    assert_eq!(curve.control2(), Vec2::new(0.75, 30.0));
+    assert_eq!(rotation.sample(Duration::from_millis(250)), Some(15.0));
+    assert_eq!(rotation.sample(Duration::from_secs(2)), Some(30.0));
+
+    let Some(TimelineRef::BoneTranslation(_, translation)) =
+        wave.timeline(PropertyKey::BoneTranslation(paw))
+    else {
+        panic!("the paw translation is keyed");
+    };
+    let first = translation.keys().next().expect("a first key");
+    assert_eq!(first.value(), Vec2::new(1.0, 2.0));
+    assert_eq!(first.curves(), [KeyCurve::Stepped; 2]);
+    assert_eq!(
+        translation.sample(Duration::from_millis(999)),
+        Some(Vec2::new(1.0, 2.0))
+    );
+    assert!(
+        wave.timeline(PropertyKey::BoneScaleMagnitude(paw))
+            .is_none()
+    );
+
+    let eyes = asset.slot_id("eyes-slot").expect("the slot exists");
+    let Some(TimelineRef::SlotColor(_, colour)) = wave.timeline(PropertyKey::SlotColor(eyes))
+    else {
+        panic!("the eyes colour is keyed");
+    };
+    let key = colour.keys().next().expect("one key");
+    assert_eq!(key.color8(), Rgba8::new(255, 0, 0, 128));
+    assert_eq!(colour.sample(Duration::ZERO), Some(key.color()));
+    let Some(TimelineRef::SlotAttachment(_, attachment)) =
+        wave.timeline(PropertyKey::SlotAttachment(eyes))
+    else {
+        panic!("the eyes attachment is keyed");
+    };
+    assert_eq!(
+        attachment
+            .keys()
+            .map(|key| (key.ticks(), key.placeholder()))
+            .collect::<Vec<_>>(),
+        [(250_000_000, Some("closed")), (500_000_000, None)]
+    );
+}
+
+#[test]
+fn draw_order_and_event_keys_are_typed() {
+    let asset = asset();
+    let paw_slot = asset.slot_id("paw-slot").expect("the slot exists");
+    let wave = wave(&asset);
+
+    let Some(TimelineRef::DrawOrder(draw_order)) = wave.timeline(PropertyKey::DrawOrder) else {
+        panic!("draw order is keyed");
+    };
+    let keys = draw_order.keys().collect::<Vec<_>>();
+    assert_eq!(keys[0].offsets().collect::<Vec<_>>(), [(paw_slot, 1)]);
+    assert_eq!(keys[1].ticks(), 750_000_000);
+    assert_eq!(keys[1].offsets().len(), 0, "the setup order is restored");
+
+    let events = wave
+        .timelines()
+        .find_map(|timeline| match timeline {
+            TimelineRef::Events(events) => Some(events),
+            _ => None,
+        })
+        .expect("events are keyed");
+    let key = events.keys().next().expect("one event key");
+    assert_eq!(key.definition().name(), "step");
+    assert_eq!(key.time(), Duration::from_millis(750));
+    assert_eq!(key.integer(), 3, "the key overrides the default");
+    assert_eq!(key.string(), Some("soft"), "the default is resolved");
+    assert_eq!(
+        wave.marker("step"),
+        Some(key.time()),
+        "markers and keys agree"
+    );
+}